@@ -1,15 +1,23 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{HashValue, Transaction, TransactionOnChainData, U64};
-use poem_openapi::Object;
+use crate::{
+    transaction::{BlockMetadataTransaction, UserTransaction},
+    HashValue, Transaction, TransactionOnChainData, U64,
+};
+use anyhow::{ensure, Context, Result};
+use aptos_types::{account_config::NewBlockEvent, transaction::SignedTransaction};
+use move_core_types::{
+    account_address::AccountAddress, language_storage::TypeTag, move_resource::MoveStructType,
+};
+// use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 
 /// A Block with or without transactions
 ///
 /// This contains the information about a transactions along with
 /// associated transactions if requested
-#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Block {
     pub block_height: U64,
     pub block_hash: HashValue,
@@ -21,6 +29,42 @@ pub struct Block {
     pub transactions: Option<Vec<Transaction>>,
 }
 
+impl Block {
+    /// The metadata of the block, taken from its block metadata transaction. Fails if the block
+    /// was fetched without its transactions, and for the genesis block, which has none.
+    pub fn metadata(&self) -> Result<BlockMetadataSummary> {
+        let txn = self
+            .transactions
+            .iter()
+            .flatten()
+            .find_map(|txn| match txn {
+                Transaction::BlockMetadataTransaction(txn) => Some(txn),
+                _ => None,
+            })
+            .with_context(|| {
+                format!(
+                    "block {} has no block metadata transaction",
+                    self.block_height
+                )
+            })?;
+        let metadata = BlockMetadataSummary::try_from(txn)?;
+        metadata.ensure_height(self.block_height.0)?;
+        Ok(metadata)
+    }
+
+    /// The user transactions of the block in order, which carry their `TransactionInfo` and
+    /// events
+    pub fn user_transactions(&self) -> impl Iterator<Item = &UserTransaction> {
+        self.transactions
+            .iter()
+            .flatten()
+            .filter_map(|txn| match txn {
+                Transaction::UserTransaction(txn) => Some(txn),
+                _ => None,
+            })
+    }
+}
+
 /// A Block with or without transactions for encoding in BCS
 ///
 /// This contains the information about a transactions along with
@@ -39,3 +83,317 @@ pub struct BcsBlock {
     /// The transactions in the block in sequential order
     pub transactions: Option<Vec<TransactionOnChainData>>,
 }
+
+impl BcsBlock {
+    /// The metadata of the block, taken from its block metadata transaction. Fails if the block
+    /// was fetched without its transactions, and for the genesis block, which has none.
+    pub fn metadata(&self) -> Result<BlockMetadataSummary> {
+        let (data, metadata) = self
+            .transactions
+            .iter()
+            .flatten()
+            .find_map(|data| Some((data, data.transaction.try_as_block_metadata_ext()?)))
+            .with_context(|| {
+                format!(
+                    "block {} has no block metadata transaction",
+                    self.block_height
+                )
+            })?;
+        let new_block_tag = TypeTag::Struct(Box::new(NewBlockEvent::struct_tag()));
+        let event = data
+            .events
+            .iter()
+            .find(|event| *event.type_tag() == new_block_tag)
+            .context("block metadata transaction emits no NewBlockEvent")?;
+        let metadata = BlockMetadataSummary {
+            height: NewBlockEvent::try_from(event)?.height(),
+            epoch: metadata.epoch(),
+            round: metadata.round(),
+            timestamp_usecs: metadata.timestamp_usecs(),
+            proposer: metadata.proposer(),
+        };
+        metadata.ensure_height(self.block_height)?;
+        Ok(metadata)
+    }
+
+    /// The user transactions of the block in order, each with the on-chain data holding its
+    /// version, `TransactionInfo` and events
+    pub fn user_transactions(
+        &self,
+    ) -> impl Iterator<Item = (&SignedTransaction, &TransactionOnChainData)> {
+        self.transactions
+            .iter()
+            .flatten()
+            .filter_map(|data| Some((data.transaction.try_as_signed_user_txn()?, data)))
+    }
+}
+
+/// What the block metadata transaction at the start of a block records about the block
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockMetadataSummary {
+    pub height: u64,
+    pub epoch: u64,
+    pub round: u64,
+    /// The block timestamp in Unix epoch microseconds
+    pub timestamp_usecs: u64,
+    /// The validator that proposed the block, or the zero address for blocks without a
+    /// proposer, such as the first one of an epoch
+    pub proposer: AccountAddress,
+}
+
+impl BlockMetadataSummary {
+    fn ensure_height(&self, block_height: u64) -> Result<()> {
+        ensure!(
+            self.height == block_height,
+            "block {} has the metadata of block {}",
+            block_height,
+            self.height
+        );
+        Ok(())
+    }
+}
+
+impl TryFrom<&BlockMetadataTransaction> for BlockMetadataSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(txn: &BlockMetadataTransaction) -> Result<Self> {
+        Ok(Self {
+            height: txn.block_height()?,
+            epoch: txn.epoch.0,
+            round: txn.round.0,
+            timestamp_usecs: txn.timestamp.0,
+            proposer: *txn.proposer.inner(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aptos_crypto::{ed25519::Ed25519PublicKey, hash::CryptoHash, SigningKey};
+    use aptos_types::{
+        account_config::new_block_event_key,
+        block_metadata::BlockMetadata,
+        chain_id::ChainId,
+        contract_event::ContractEvent,
+        test_helpers::{private_key, transfer},
+        transaction::{
+            ExecutionStatus, RawTransaction, Transaction as CoreTransaction, TransactionInfo,
+        },
+        write_set::WriteSet,
+    };
+    use serde_json::json;
+
+    const PROPOSER: AccountAddress = AccountAddress::TWO;
+
+    fn on_chain_data(
+        version: u64,
+        transaction: CoreTransaction,
+        gas_used: u64,
+        events: Vec<ContractEvent>,
+    ) -> TransactionOnChainData {
+        TransactionOnChainData {
+            version,
+            info: TransactionInfo::new(
+                CryptoHash::hash(&transaction),
+                aptos_crypto::HashValue::zero(),
+                aptos_crypto::HashValue::zero(),
+                None,
+                gas_used,
+                ExecutionStatus::Success,
+            ),
+            transaction,
+            events,
+            accumulator_root_hash: aptos_crypto::HashValue::zero(),
+            changes: WriteSet::default(),
+        }
+    }
+
+    fn user_transaction() -> SignedTransaction {
+        let private_key = private_key(1);
+        let public_key = Ed25519PublicKey::from(&private_key);
+        let raw_txn = RawTransaction::new_entry_function(
+            aptos_types::account_address::from_public_key(&public_key),
+            0,
+            transfer(vec![]),
+            1_000,
+            100,
+            1_700_000_030,
+            ChainId::test(),
+        );
+        let signature = private_key.sign(&raw_txn).unwrap();
+        SignedTransaction::new(raw_txn, public_key, signature)
+    }
+
+    fn bcs_block(height: u64) -> BcsBlock {
+        let metadata = BlockMetadata::new(
+            aptos_crypto::HashValue::zero(),
+            2,
+            7,
+            PROPOSER,
+            vec![],
+            vec![],
+            1_700_000_000_000_000,
+        );
+        let new_block_event = NewBlockEvent::new(
+            AccountAddress::ZERO,
+            2,
+            7,
+            height,
+            vec![],
+            PROPOSER,
+            vec![],
+            1_700_000_000_000_000,
+        );
+        let new_block_event = ContractEvent::new_v1(
+            new_block_event_key(),
+            height,
+            TypeTag::Struct(Box::new(NewBlockEvent::struct_tag())),
+            bcs::to_bytes(&new_block_event).unwrap(),
+        );
+        BcsBlock {
+            block_height: height,
+            block_hash: aptos_crypto::HashValue::zero(),
+            block_timestamp: 1_700_000_000_000_000,
+            first_version: 100,
+            last_version: 102,
+            transactions: Some(vec![
+                on_chain_data(
+                    100,
+                    CoreTransaction::BlockMetadata(metadata),
+                    0,
+                    vec![new_block_event],
+                ),
+                on_chain_data(101, user_transaction().into(), 12, vec![]),
+                on_chain_data(
+                    102,
+                    CoreTransaction::StateCheckpoint(aptos_crypto::HashValue::zero()),
+                    0,
+                    vec![],
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_bcs_block() {
+        let block = bcs_block(42);
+        let block: BcsBlock = bcs::from_bytes(&bcs::to_bytes(&block).unwrap()).unwrap();
+
+        assert_eq!(
+            block.metadata().unwrap(),
+            BlockMetadataSummary {
+                height: 42,
+                epoch: 2,
+                round: 7,
+                timestamp_usecs: 1_700_000_000_000_000,
+                proposer: PROPOSER,
+            }
+        );
+
+        let user_txns: Vec<_> = block.user_transactions().collect();
+        assert_eq!(user_txns.len(), 1);
+        assert_eq!(*user_txns[0].0, user_transaction());
+        assert_eq!(user_txns[0].1.version, 101);
+        assert_eq!(user_txns[0].1.info.gas_used(), 12);
+
+        let mut block = bcs_block(42);
+        block.block_height = 43;
+        assert!(block.metadata().is_err());
+        block.transactions = None;
+        assert!(block.metadata().is_err());
+        assert_eq!(block.user_transactions().count(), 0);
+    }
+
+    fn transaction_info(version: u64) -> serde_json::Value {
+        json!({
+            "version": version.to_string(),
+            "hash": format!("0x{}", "00".repeat(32)),
+            "state_change_hash": format!("0x{}", "00".repeat(32)),
+            "event_root_hash": format!("0x{}", "00".repeat(32)),
+            "state_checkpoint_hash": null,
+            "gas_used": "0",
+            "success": true,
+            "vm_status": "Executed successfully",
+            "accumulator_root_hash": format!("0x{}", "00".repeat(32)),
+            "changes": [],
+        })
+    }
+
+    fn with_info(version: u64, fields: serde_json::Value) -> serde_json::Value {
+        let mut txn = transaction_info(version);
+        txn.as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        txn
+    }
+
+    #[test]
+    fn test_json_block() {
+        let block = json!({
+            "block_height": "42",
+            "block_hash": format!("0x{}", "00".repeat(32)),
+            "block_timestamp": "1700000000000000",
+            "first_version": "100",
+            "last_version": "102",
+            "transactions": [
+                with_info(100, json!({
+                    "type": "block_metadata_transaction",
+                    "id": format!("0x{}", "00".repeat(32)),
+                    "epoch": "2",
+                    "round": "7",
+                    "events": [{
+                        "guid": {"creation_number": "3", "account_address": "0x0"},
+                        "sequence_number": "42",
+                        "type": "0x1::block::NewBlockEvent",
+                        "data": {"height": "42", "epoch": "2", "round": "7"},
+                    }],
+                    "previous_block_votes_bitvec": [],
+                    "proposer": "0x2",
+                    "failed_proposer_indices": [],
+                    "timestamp": "1700000000000000",
+                    "block_metadata_extension": {"type": "v0"},
+                })),
+                with_info(101, json!({
+                    "type": "user_transaction",
+                    "sender": "0x1",
+                    "sequence_number": "0",
+                    "max_gas_amount": "1000",
+                    "gas_unit_price": "100",
+                    "expiration_timestamp_secs": "1700000030",
+                    "payload": {
+                        "type": "entry_function_payload",
+                        "function": "0x1::aptos_account::transfer",
+                        "type_arguments": [],
+                        "arguments": ["0x3", "100"],
+                    },
+                    "events": [],
+                    "timestamp": "1700000000000000",
+                })),
+                with_info(102, json!({
+                    "type": "block_epilogue_transaction",
+                    "timestamp": "1700000000000000",
+                    "block_end_info": null,
+                })),
+            ],
+        });
+        let block: Block = serde_json::from_value(block).unwrap();
+        let txns = block.transactions.as_ref().unwrap();
+        assert_eq!(txns[2].type_str(), "block_epilogue_transaction");
+        assert_eq!(txns[2].version(), Some(102));
+
+        assert_eq!(
+            block.metadata().unwrap(),
+            BlockMetadataSummary {
+                height: 42,
+                epoch: 2,
+                round: 7,
+                timestamp_usecs: 1_700_000_000_000_000,
+                proposer: PROPOSER,
+            }
+        );
+        let user_txns: Vec<_> = block.user_transactions().collect();
+        assert_eq!(user_txns.len(), 1);
+        assert_eq!(user_txns[0].info.version.0, 101);
+    }
+}
@@ -9,10 +9,13 @@ use crate::{
     },
     MoveFunction, MoveStructTag, MoveType,
 };
-use aptos_framework::{
+// use aptos_framework::{
+//     get_metadata_from_compiled_module, get_metadata_from_compiled_script, RuntimeModuleMetadataV1,
+// };
+// use aptos_vm::determine_is_view;
+use aptos_types::vm::module_metadata::{
     get_metadata_from_compiled_module, get_metadata_from_compiled_script, RuntimeModuleMetadataV1,
 };
-use aptos_vm::determine_is_view;
 use move_binary_format::{
     access::{ModuleAccess, ScriptAccess},
     file_format::{
@@ -47,13 +50,8 @@ pub trait Bytecode {
     fn function_is_view(&self, name: &IdentStr) -> bool;
 
     fn struct_is_event(&self, name: &IdentStr) -> bool {
-        match self.metadata() {
-            Some(m) => match m.struct_attributes.get(name.as_str()) {
-                Some(attrs) => attrs.iter().any(|attr| attr.is_event()),
-                None => false,
-            },
-            None => false,
-        }
+        self.metadata()
+            .is_some_and(|m| m.struct_is_event(name.as_str()))
     }
 
     fn new_move_struct_field(&self, def: &FieldDefinition) -> MoveStructField {
@@ -227,7 +225,8 @@ impl Bytecode for CompiledModule {
     }
 
     fn function_is_view(&self, name: &IdentStr) -> bool {
-        determine_is_view(self.metadata().as_ref(), name)
+        self.metadata()
+            .is_some_and(|m| m.fun_is_view(name.as_str()))
     }
 }
 
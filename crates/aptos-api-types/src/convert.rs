@@ -3,172 +3,80 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    transaction::{
-        BlockEpilogueTransaction, BlockMetadataTransaction, DecodedTableData, DeleteModule,
-        DeleteResource, DeleteTableItem, DeletedTableData, MultisigPayload,
-        MultisigTransactionPayload, StateCheckpointTransaction, UserTransactionRequestInner,
-        WriteModule, WriteResource, WriteTableItem,
-    },
-    view::{ViewFunction, ViewRequest},
-    Address, Bytecode, DirectWriteSet, EntryFunctionId, EntryFunctionPayload, Event,
-    HexEncodedBytes, MoveFunction, MoveModuleBytecode, MoveResource, MoveScriptBytecode, MoveType,
-    MoveValue, PendingTransaction, ResourceGroup, ScriptPayload, ScriptWriteSet,
-    SubmitTransactionRequest, Transaction, TransactionInfo, TransactionOnChainData,
-    TransactionPayload, UserTransactionRequest, VersionedEvent, WriteSet, WriteSetChange,
-    WriteSetPayload,
+    move_types::{MoveFunction, MoveModule, MoveStructTag},
+    transaction::{MultisigPayload, MultisigTransactionPayload},
+    Address, EntryFunctionId, EntryFunctionPayload, HexEncodedBytes, MoveScriptBytecode, MoveType,
+    PendingTransaction, ScriptPayload, TransactionPayload, UserTransactionRequest,
 };
 use anyhow::{bail, ensure, format_err, Context as AnyhowContext, Result};
-use aptos_crypto::{hash::CryptoHash, HashValue};
-use aptos_logger::{sample, sample::SampleRate};
-use aptos_resource_viewer::AptosValueAnnotator;
-use aptos_storage_interface::DbReader;
 use aptos_types::{
-    access_path::{AccessPath, Path},
     chain_id::ChainId,
-    contract_event::{ContractEvent, EventWithVersion},
-    indexer::indexer_db_reader::IndexerReader,
-    state_store::{
-        state_key::{inner::StateKeyInner, StateKey},
-        table::{TableHandle, TableInfo},
-        StateView,
-    },
     transaction::{
-        BlockEndInfo, BlockEpiloguePayload, EntryFunction, ExecutionStatus, Multisig,
-        RawTransaction, Script, SignedTransaction, TransactionAuxiliaryData,
+        EntryFunction, Multisig, RawTransaction, Script, SignedTransaction, TransactionExecutable,
+        TransactionPayloadInner,
     },
-    vm_status::AbortLocation,
-    write_set::WriteOp,
 };
-use bytes::Bytes;
-use move_binary_format::file_format::FunctionHandleIndex;
 use move_core_types::{
     account_address::AccountAddress,
-    ident_str,
-    identifier::{IdentStr, Identifier},
-    language_storage::{ModuleId, StructTag, TypeTag},
-    transaction_argument::convert_txn_args,
-    value::{MoveStructLayout, MoveTypeLayout},
+    identifier::IdentStr,
+    language_storage::ModuleId,
+    transaction_argument::TransactionArgument,
+    value::{MoveStruct, MoveStructLayout, MoveTypeLayout},
 };
 use serde_json::Value;
-use std::{
-    collections::BTreeMap,
-    convert::{TryFrom, TryInto},
-    iter::IntoIterator,
-    sync::Arc,
-    time::Duration,
-};
 
-const OBJECT_MODULE: &IdentStr = ident_str!("object");
-const OBJECT_STRUCT: &IdentStr = ident_str!("Object");
-
-/// The Move converter for converting Move types to JSON
+/// Looks up the ABIs that give the arguments of entry functions and scripts their JSON form
 ///
-/// This reads the underlying BCS types and ABIs to convert them into
-/// JSON outputs
-pub struct MoveConverter<'a, S> {
-    inner: AptosValueAnnotator<'a, S>,
-    db: Arc<dyn DbReader>,
-    indexer_reader: Option<Arc<dyn IndexerReader>>,
-}
-
-impl<'a, S: StateView> MoveConverter<'a, S> {
-    pub fn new(
-        inner: &'a S,
-        db: Arc<dyn DbReader>,
-        indexer_reader: Option<Arc<dyn IndexerReader>>,
-    ) -> Self {
-        Self {
-            inner: AptosValueAnnotator::new(inner),
-            db,
-            indexer_reader,
-        }
-    }
-
-    pub fn try_into_resources<'b>(
-        &self,
-        data: impl Iterator<Item = (StructTag, &'b [u8])>,
-    ) -> Result<Vec<MoveResource>> {
-        data.map(|(typ, bytes)| self.inner.view_resource(&typ, bytes)?.try_into())
-            .collect()
-    }
-
-    pub fn try_into_resource(&self, tag: &StructTag, bytes: &'_ [u8]) -> Result<MoveResource> {
-        self.inner.view_resource(tag, bytes)?.try_into()
-    }
-
-    pub fn is_resource_group(&self, tag: &StructTag) -> bool {
-        if let Ok(Some(module)) = self.inner.view_module(&tag.module_id()) {
-            if let Some(md) = aptos_framework::get_metadata(&module.metadata) {
-                if let Some(attrs) = md.struct_attributes.get(tag.name.as_ident_str().as_str()) {
-                    return attrs
-                        .iter()
-                        .find(|attr| attr.is_resource_group())
-                        .map(|_| true)
-                        .unwrap_or(false);
-                }
-            }
-        }
-        false
+/// The REST API shows arguments as typed JSON values, while transactions carry them as BCS
+/// bytes, so converting between the two needs the parameter types of the called function.
+pub trait AbiResolver {
+    /// Returns the ABI of the given entry function, or None if it is unknown
+    fn entry_function_abi(&self, module: &ModuleId, function: &IdentStr) -> Option<MoveFunction>;
+
+    /// Returns the ABI of the given script, or None if it is unknown
+    fn script_abi(&self, _code: &[u8]) -> Option<MoveFunction> {
+        None
     }
+}
 
-    pub fn find_resource(
-        &self,
-        state_view: &impl StateView,
-        address: Address,
-        tag: &StructTag,
-    ) -> Result<Option<Bytes>> {
-        Ok(match self.inner.view_resource_group_member(tag) {
-            Some(group_tag) => {
-                let key = StateKey::resource_group(&address.into(), &group_tag);
-                match state_view.get_state_value_bytes(&key)? {
-                    Some(group_bytes) => {
-                        let group: BTreeMap<StructTag, Bytes> = bcs::from_bytes(&group_bytes)?;
-                        group.get(tag).cloned()
-                    },
-                    None => None,
-                }
-            },
-            None => {
-                let key = StateKey::resource(&address.into(), tag)?;
-                state_view.get_state_value_bytes(&key)?
-            },
-        })
+/// Resolves no ABIs, so every entry function argument is shown as hex-encoded BCS bytes
+impl AbiResolver for () {
+    fn entry_function_abi(&self, _: &ModuleId, _: &IdentStr) -> Option<MoveFunction> {
+        None
     }
+}
 
-    pub fn try_into_resources_from_resource_group(
-        &self,
-        bytes: &[u8],
-    ) -> Result<Vec<MoveResource>> {
-        let resources_with_tag: Vec<(StructTag, Vec<u8>)> = bcs::from_bytes::<ResourceGroup>(bytes)
-            .map(|map| {
-                map.into_iter()
-                    .map(|(key, value)| (key, value))
-                    .collect::<Vec<_>>()
-            })?;
-
-        resources_with_tag
-            .iter()
-            .map(|(struct_tag, value_bytes)| self.try_into_resource(struct_tag, value_bytes))
-            .collect::<Result<Vec<_>>>()
+/// Resolves the entry functions of the given modules, e.g. as returned by the
+/// `/accounts/{address}/modules` endpoint
+impl AbiResolver for [MoveModule] {
+    fn entry_function_abi(&self, module: &ModuleId, function: &IdentStr) -> Option<MoveFunction> {
+        self.iter()
+            .find(|m| m.address.inner() == module.address() && m.name.as_ref() == module.name())
+            .and_then(|m| {
+                m.exposed_functions
+                    .iter()
+                    .find(|f| f.is_entry && f.name.as_ref() == function)
+            })
+            .cloned()
     }
+}
 
-    pub fn move_struct_fields(
-        &self,
-        typ: &StructTag,
-        bytes: &'_ [u8],
-    ) -> Result<(
-        Option<Identifier>,
-        Vec<(Identifier, move_core_types::value::MoveValue)>,
-    )> {
-        self.inner.view_struct_fields(typ, bytes)
-    }
+/// The Move converter for converting transactions between BCS and JSON
+///
+/// Entry function arguments are converted to typed JSON values when the resolver knows the
+/// function, and to hex-encoded BCS bytes otherwise. Which form is used depends only on the
+/// resolver, so converting a transaction to JSON and back with the same resolver gives back the
+/// same transaction.
+pub struct MoveConverter<'a, R: ?Sized> {
+    resolver: &'a R,
+}
 
-    pub fn try_into_pending_transaction(&self, txn: SignedTransaction) -> Result<Transaction> {
-        let payload = self.try_into_transaction_payload(txn.payload().clone())?;
-        Ok((txn, payload).into())
+impl<'a, R: AbiResolver + ?Sized> MoveConverter<'a, R> {
+    pub fn new(resolver: &'a R) -> Self {
+        Self { resolver }
     }
 
-    pub fn try_into_pending_transaction_poem(
+    pub fn try_into_pending_transaction(
         &self,
         txn: SignedTransaction,
     ) -> Result<PendingTransaction> {
@@ -176,103 +84,12 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
         Ok((txn, payload).into())
     }
 
-    pub fn try_into_onchain_transaction(
+    pub fn try_into_user_transaction_request(
         &self,
-        timestamp: u64,
-        data: TransactionOnChainData,
-    ) -> Result<Transaction> {
-        use aptos_types::transaction::Transaction::{
-            BlockEpilogue, BlockMetadata, BlockMetadataExt, GenesisTransaction, StateCheckpoint,
-            UserTransaction,
-        };
-        let aux_data = self
-            .db
-            .get_transaction_auxiliary_data_by_version(data.version)?;
-        let info = self.into_transaction_info(
-            data.version,
-            &data.info,
-            data.accumulator_root_hash,
-            data.changes,
-            aux_data,
-        );
-        let events = self.try_into_events(&data.events)?;
-        Ok(match data.transaction {
-            UserTransaction(txn) => {
-                let payload = self.try_into_transaction_payload(txn.payload().clone())?;
-                (&txn, info, payload, events, timestamp).into()
-            },
-            GenesisTransaction(write_set) => {
-                let payload = self.try_into_write_set_payload(write_set)?;
-                (info, payload, events).into()
-            },
-            BlockMetadata(txn) => Transaction::BlockMetadataTransaction(
-                BlockMetadataTransaction::from_internal(txn, info, events),
-            ),
-            BlockMetadataExt(txn) => Transaction::BlockMetadataTransaction(
-                BlockMetadataTransaction::from_internal_ext(txn, info, events),
-            ),
-            StateCheckpoint(_) => {
-                Transaction::StateCheckpointTransaction(StateCheckpointTransaction {
-                    info,
-                    timestamp: timestamp.into(),
-                })
-            },
-            BlockEpilogue(block_epilogue_payload) => {
-                Transaction::BlockEpilogueTransaction(BlockEpilogueTransaction {
-                    info,
-                    timestamp: timestamp.into(),
-                    block_end_info: match block_epilogue_payload {
-                        BlockEpiloguePayload::V0 {
-                            block_end_info:
-                                BlockEndInfo::V0 {
-                                    block_gas_limit_reached,
-                                    block_output_limit_reached,
-                                    block_effective_block_gas_units,
-                                    block_approx_output_size,
-                                },
-                            ..
-                        } => Some(crate::transaction::BlockEndInfo {
-                            block_gas_limit_reached,
-                            block_output_limit_reached,
-                            block_effective_block_gas_units,
-                            block_approx_output_size,
-                        }),
-                    },
-                })
-            },
-            aptos_types::transaction::Transaction::ValidatorTransaction(txn) => {
-                Transaction::ValidatorTransaction((txn, info, events, timestamp).into())
-            },
-        })
-    }
-
-    pub fn into_transaction_info(
-        &self,
-        version: u64,
-        info: &aptos_types::transaction::TransactionInfo,
-        accumulator_root_hash: HashValue,
-        write_set: aptos_types::write_set::WriteSet,
-        txn_aux_data: Option<TransactionAuxiliaryData>,
-    ) -> TransactionInfo {
-        TransactionInfo {
-            version: version.into(),
-            hash: info.transaction_hash().into(),
-            state_change_hash: info.state_change_hash().into(),
-            event_root_hash: info.event_root_hash().into(),
-            state_checkpoint_hash: info.state_checkpoint_hash().map(|h| h.into()),
-            gas_used: info.gas_used().into(),
-            success: info.status().is_success(),
-            vm_status: self.explain_vm_status(info.status(), txn_aux_data),
-            accumulator_root_hash: accumulator_root_hash.into(),
-            // TODO: the resource value is interpreted by the type definition at the version of the converter, not the version of the tx: must be fixed before we allow module updates
-            changes: write_set
-                .into_iter()
-                .filter_map(|(sk, wo)| self.try_into_write_set_changes(sk, wo).ok())
-                .flatten()
-                .collect(),
-            block_height: None,
-            epoch: None,
-        }
+        txn: &SignedTransaction,
+    ) -> Result<UserTransactionRequest> {
+        let payload = self.try_into_transaction_payload(txn.payload().clone())?;
+        Ok((txn, payload).into())
     }
 
     pub fn try_into_transaction_payload(
@@ -281,96 +98,69 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
     ) -> Result<TransactionPayload> {
         use aptos_types::transaction::TransactionPayload::*;
         let ret = match payload {
-            Script(s) => {
-                let (code, ty_args, args) = s.into_inner();
-                let script_args = self.inner.view_script_arguments(&code, &args, &ty_args);
-
-                let json_args = match script_args {
-                    Ok(values) => values
-                        .into_iter()
-                        .map(|v| MoveValue::try_from(v)?.json())
-                        .collect::<Result<_>>()?,
-                    Err(_e) => convert_txn_args(&args)
-                        .into_iter()
-                        .map(|arg| HexEncodedBytes::from(arg).json())
-                        .collect::<Result<_>>()?,
-                };
-                TransactionPayload::ScriptPayload(ScriptPayload {
-                    code: MoveScriptBytecode::new(code).try_parse_abi(),
-                    type_arguments: ty_args.into_iter().map(|arg| arg.into()).collect(),
-                    arguments: json_args,
-                })
+            Script(script) => {
+                TransactionPayload::ScriptPayload(self.try_into_script_payload(script)?)
             },
             EntryFunction(fun) => {
-                let (module, function, ty_args, args) = fun.into_inner();
-                let func_args = self
-                    .inner
-                    .view_function_arguments(&module, &function, &ty_args, &args);
-
-                let json_args = match func_args {
-                    Ok(values) => values
-                        .into_iter()
-                        .map(|v| MoveValue::try_from(v)?.json())
-                        .collect::<Result<_>>()?,
-                    Err(_e) => args
-                        .into_iter()
-                        .map(|arg| HexEncodedBytes::from(arg).json())
-                        .collect::<Result<_>>()?,
-                };
-
-                TransactionPayload::EntryFunctionPayload(EntryFunctionPayload {
-                    arguments: json_args,
-                    function: EntryFunctionId {
-                        module: module.into(),
-                        name: function.into(),
-                    },
-                    type_arguments: ty_args.into_iter().map(|arg| arg.into()).collect(),
-                })
+                TransactionPayload::EntryFunctionPayload(self.try_into_entry_function_payload(fun)?)
             },
             Multisig(multisig) => {
-                let transaction_payload = if let Some(payload) = multisig.transaction_payload {
-                    match payload {
-                        aptos_types::transaction::MultisigTransactionPayload::EntryFunction(
-                            entry_function,
-                        ) => {
-                            let (module, function, ty_args, args) = entry_function.into_inner();
-                            let func_args = self
-                                .inner
-                                .view_function_arguments(&module, &function, &ty_args, &args);
-                            let json_args = match func_args {
-                                Ok(values) => values
-                                    .into_iter()
-                                    .map(|v| MoveValue::try_from(v)?.json())
-                                    .collect::<Result<_>>()?,
-                                Err(_e) => args
-                                    .into_iter()
-                                    .map(|arg| HexEncodedBytes::from(arg).json())
-                                    .collect::<Result<_>>()?,
-                            };
-
-                            Some(MultisigTransactionPayload::EntryFunctionPayload(
-                                EntryFunctionPayload {
-                                    arguments: json_args,
-                                    function: EntryFunctionId {
-                                        module: module.into(),
-                                        name: function.into(),
-                                    },
-                                    type_arguments: ty_args
-                                        .into_iter()
-                                        .map(|arg| arg.into())
-                                        .collect(),
-                                },
-                            ))
-                        },
-                    }
-                } else {
-                    None
+                let transaction_payload = match multisig.transaction_payload {
+                    Some(aptos_types::transaction::MultisigTransactionPayload::EntryFunction(
+                        fun,
+                    )) => Some(MultisigTransactionPayload::EntryFunctionPayload(
+                        self.try_into_entry_function_payload(fun)?,
+                    )),
+                    None => None,
                 };
                 TransactionPayload::MultisigPayload(MultisigPayload {
                     multisig_address: multisig.multisig_address.into(),
                     transaction_payload,
                 })
             },
+            // The JSON API has no versioned payload, it rebuilds one from the nonce of the
+            // request. So only orderless transactions can be shown without loss.
+            Payload(TransactionPayloadInner::V1 {
+                executable,
+                extra_config,
+            }) => {
+                ensure!(
+                    extra_config.replay_protection_nonce().is_some(),
+                    "Versioned payloads without a replay protection nonce have no JSON form"
+                );
+                match (executable, extra_config.multisig_address()) {
+                    (TransactionExecutable::Script(script), None) => {
+                        TransactionPayload::ScriptPayload(self.try_into_script_payload(script)?)
+                    },
+                    (TransactionExecutable::EntryFunction(fun), None) => {
+                        TransactionPayload::EntryFunctionPayload(
+                            self.try_into_entry_function_payload(fun)?,
+                        )
+                    },
+                    (TransactionExecutable::EntryFunction(fun), Some(multisig_address)) => {
+                        TransactionPayload::MultisigPayload(MultisigPayload {
+                            multisig_address: multisig_address.into(),
+                            transaction_payload: Some(
+                                MultisigTransactionPayload::EntryFunctionPayload(
+                                    self.try_into_entry_function_payload(fun)?,
+                                ),
+                            ),
+                        })
+                    },
+                    (TransactionExecutable::Empty, Some(multisig_address)) => {
+                        TransactionPayload::MultisigPayload(MultisigPayload {
+                            multisig_address: multisig_address.into(),
+                            transaction_payload: None,
+                        })
+                    },
+                    (TransactionExecutable::Script(_), Some(_)) => {
+                        bail!("Multisig transactions cannot execute scripts")
+                    },
+                    (TransactionExecutable::Empty, None) => {
+                        bail!("Transaction payload has nothing to execute")
+                    },
+                }
+            },
 
             // Deprecated.
             ModuleBundle(_) => bail!("Module bundle payload has been removed"),
@@ -378,218 +168,72 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
         Ok(ret)
     }
 
-    pub fn try_into_write_set_payload(
-        &self,
-        payload: aptos_types::transaction::WriteSetPayload,
-    ) -> Result<WriteSetPayload> {
-        use aptos_types::transaction::WriteSetPayload::*;
-        let ret = match payload {
-            Script { execute_as, script } => WriteSetPayload {
-                write_set: WriteSet::ScriptWriteSet(ScriptWriteSet {
-                    execute_as: execute_as.into(),
-                    script: script.try_into()?,
-                }),
-            },
-            Direct(d) => {
-                let (write_set, events) = d.into_inner();
-                let nested_writeset_changes: Vec<Vec<WriteSetChange>> = write_set
-                    .into_iter()
-                    .map(|(state_key, op)| self.try_into_write_set_changes(state_key, op))
-                    .collect::<Result<Vec<Vec<_>>>>()?;
-                WriteSetPayload {
-                    write_set: WriteSet::DirectWriteSet(DirectWriteSet {
-                        // TODO: the resource value is interpreted by the type definition at the version of the converter, not the version of the tx: must be fixed before we allow module updates
-                        changes: nested_writeset_changes
-                            .into_iter()
-                            .flatten()
-                            .collect::<Vec<WriteSetChange>>(),
-                        events: self.try_into_events(&events)?,
-                    }),
-                }
-            },
-        };
-        Ok(ret)
-    }
-
-    pub fn try_into_write_set_changes(
-        &self,
-        state_key: StateKey,
-        op: WriteOp,
-    ) -> Result<Vec<WriteSetChange>> {
-        let hash = state_key.hash().to_hex_literal();
-        let state_key = state_key.inner();
-        match state_key {
-            StateKeyInner::AccessPath(access_path) => {
-                self.try_access_path_into_write_set_changes(hash, access_path, op)
-            },
-            StateKeyInner::TableItem { handle, key } => {
-                vec![self.try_table_item_into_write_set_change(hash, *handle, key.to_owned(), op)]
-                    .into_iter()
-                    .collect()
-            },
-            StateKeyInner::Raw(_) => Err(format_err!(
-                "Can't convert account raw key {:?} to WriteSetChange",
-                state_key
-            )),
-        }
-    }
-
-    pub fn try_access_path_into_write_set_changes(
-        &self,
-        state_key_hash: String,
-        access_path: &AccessPath,
-        op: WriteOp,
-    ) -> Result<Vec<WriteSetChange>> {
-        let ret = match op.bytes() {
-            None => match access_path.get_path() {
-                Path::Code(module_id) => vec![WriteSetChange::DeleteModule(DeleteModule {
-                    address: access_path.address.into(),
-                    state_key_hash,
-                    module: module_id.into(),
-                })],
-                Path::Resource(typ) => vec![WriteSetChange::DeleteResource(DeleteResource {
-                    address: access_path.address.into(),
-                    state_key_hash,
-                    resource: typ.into(),
-                })],
-                Path::ResourceGroup(typ) => vec![WriteSetChange::DeleteResource(DeleteResource {
-                    address: access_path.address.into(),
-                    state_key_hash,
-                    resource: typ.into(),
-                })],
-            },
-            Some(bytes) => match access_path.get_path() {
-                Path::Code(_) => vec![WriteSetChange::WriteModule(WriteModule {
-                    address: access_path.address.into(),
-                    state_key_hash,
-                    data: MoveModuleBytecode::new(bytes.to_vec()).try_parse_abi()?,
-                })],
-                Path::Resource(typ) => vec![WriteSetChange::WriteResource(WriteResource {
-                    address: access_path.address.into(),
-                    state_key_hash,
-                    data: self.try_into_resource(&typ, bytes)?,
-                })],
-                Path::ResourceGroup(_) => self
-                    .try_into_resources_from_resource_group(bytes)?
-                    .into_iter()
-                    .map(|data| {
-                        WriteSetChange::WriteResource(WriteResource {
-                            address: access_path.address.into(),
-                            state_key_hash: state_key_hash.clone(),
-                            data,
+    fn try_into_entry_function_payload(&self, fun: EntryFunction) -> Result<EntryFunctionPayload> {
+        let (module, function, ty_args, args) = fun.into_inner();
+        let type_arguments: Vec<MoveType> = ty_args.into_iter().map(|arg| arg.into()).collect();
+        let arguments = match self.entry_function_arg_types(&module, &function, &type_arguments)? {
+            Some(arg_types) => {
+                ensure!(
+                    arg_types.len() == args.len(),
+                    "expected {} arguments for entry function {}::{}, but got {}",
+                    arg_types.len(),
+                    module,
+                    function,
+                    args.len()
+                );
+                arg_types
+                    .iter()
+                    .zip(args)
+                    .enumerate()
+                    .map(|(i, (arg_type, arg))| {
+                        try_into_json_value(arg_type, &arg).with_context(|| {
+                            format!("parse arguments[{}] failed, expect {}", i, arg_type)
                         })
                     })
-                    .collect::<Vec<_>>(),
+                    .collect::<Result<_>>()?
             },
+            None => args
+                .into_iter()
+                .map(|arg| HexEncodedBytes::from(arg).json())
+                .collect::<Result<_>>()?,
         };
-        Ok(ret)
-    }
-
-    pub fn try_table_item_into_write_set_change(
-        &self,
-        state_key_hash: String,
-        handle: TableHandle,
-        key: Vec<u8>,
-        op: WriteOp,
-    ) -> Result<WriteSetChange> {
-        let hex_handle = handle.0.to_vec().into();
-        let key: HexEncodedBytes = key.into();
-        let ret = match op.bytes() {
-            None => {
-                let data = self.try_delete_table_item_into_deleted_table_data(handle, &key.0)?;
-
-                WriteSetChange::DeleteTableItem(DeleteTableItem {
-                    state_key_hash,
-                    handle: hex_handle,
-                    key,
-                    data,
-                })
-            },
-            Some(bytes) => {
-                let data =
-                    self.try_write_table_item_into_decoded_table_data(handle, &key.0, bytes)?;
-
-                WriteSetChange::WriteTableItem(WriteTableItem {
-                    state_key_hash,
-                    handle: hex_handle,
-                    key,
-                    value: bytes.to_vec().into(),
-                    data,
-                })
+        Ok(EntryFunctionPayload {
+            function: EntryFunctionId {
+                module: module.into(),
+                name: function.into(),
             },
-        };
-        Ok(ret)
+            type_arguments,
+            arguments,
+        })
     }
 
-    pub fn try_write_table_item_into_decoded_table_data(
-        &self,
-        handle: TableHandle,
-        key: &[u8],
-        value: &[u8],
-    ) -> Result<Option<DecodedTableData>> {
-        let table_info = match self.get_table_info(handle)? {
-            Some(ti) => ti,
-            None => {
-                log_missing_table_info(handle);
-                return Ok(None); // if table item not found return None anyway to avoid crash
-            },
+    fn try_into_script_payload(&self, script: Script) -> Result<ScriptPayload> {
+        let (code, ty_args, args) = script.into_inner();
+        let serialized_arguments = bcs::to_bytes(&args)?;
+        let abi = self.resolver.script_abi(&code);
+        let type_arguments: Vec<MoveType> = ty_args.into_iter().map(|arg| arg.into()).collect();
+        let arg_types = match &abi {
+            Some(func) => Some(arg_types(func, &type_arguments)?),
+            None => None,
         };
-
-        let key = self.try_into_move_value(&table_info.key_type, key)?;
-        let value = self.try_into_move_value(&table_info.value_type, value)?;
-
-        Ok(Some(DecodedTableData {
-            key: key.json().unwrap(),
-            key_type: table_info.key_type.to_string(),
-            value: value.json().unwrap(),
-            value_type: table_info.value_type.to_string(),
-        }))
-    }
-
-    pub fn try_delete_table_item_into_deleted_table_data(
-        &self,
-        handle: TableHandle,
-        key: &[u8],
-    ) -> Result<Option<DeletedTableData>> {
-        let table_info = match self.get_table_info(handle)? {
-            Some(ti) => ti,
-            None => {
-                log_missing_table_info(handle);
-                return Ok(None); // if table item not found return None anyway to avoid crash
+        let arguments = args
+            .into_iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                let arg_type = arg_types.as_ref().and_then(|types| types.get(i));
+                try_into_script_argument_json(arg, arg_type)
+                    .with_context(|| format!("parse arguments[{}] failed", i))
+            })
+            .collect::<Result<_>>()?;
+        Ok(ScriptPayload {
+            code: MoveScriptBytecode {
+                bytecode: code.into(),
+                abi,
             },
-        };
-
-        let key = self.try_into_move_value(&table_info.key_type, key)?;
-
-        Ok(Some(DeletedTableData {
-            key: key.json().unwrap(),
-            key_type: table_info.key_type.to_string(),
-        }))
-    }
-
-    pub fn try_into_events(&self, events: &[ContractEvent]) -> Result<Vec<Event>> {
-        let mut ret = vec![];
-        for event in events {
-            let data = self
-                .inner
-                .view_value(event.type_tag(), event.event_data())?;
-            ret.push((event, MoveValue::try_from(data)?.json()?).into());
-        }
-        Ok(ret)
-    }
-
-    pub fn try_into_versioned_events(
-        &self,
-        events: &[EventWithVersion],
-    ) -> Result<Vec<VersionedEvent>> {
-        let mut ret = vec![];
-        for event in events {
-            let data = self
-                .inner
-                .view_value(event.event.type_tag(), event.event.event_data())?;
-            ret.push((event, MoveValue::try_from(data)?.json()?).into());
-        }
-        Ok(ret)
+            type_arguments,
+            arguments,
+            serialized_arguments: Some(serialized_arguments.into()),
+        })
     }
 
     pub fn try_into_signed_transaction(
@@ -607,20 +251,6 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
         ))
     }
 
-    pub fn try_into_signed_transaction_poem(
-        &self,
-        submit_transaction_request: SubmitTransactionRequest,
-        chain_id: ChainId,
-    ) -> Result<SignedTransaction> {
-        Ok(SignedTransaction::new_signed_transaction(
-            self.try_into_raw_transaction_poem(
-                submit_transaction_request.user_transaction_request,
-                chain_id,
-            )?,
-            submit_transaction_request.signature.try_into().context("Failed to parse transaction when building SignedTransaction from SubmitTransactionRequest")?,
-        ))
-    }
-
     pub fn try_into_raw_transaction(
         &self,
         txn: UserTransactionRequest,
@@ -634,36 +264,16 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
             expiration_timestamp_secs,
             payload,
             signature: _,
+            replay_protection_nonce,
         } = txn;
         Ok(RawTransaction::new(
             sender.into(),
             sequence_number.into(),
-            self.try_into_aptos_core_transaction_payload(payload)?,
-            max_gas_amount.into(),
-            gas_unit_price.into(),
-            expiration_timestamp_secs.into(),
-            chain_id,
-        ))
-    }
-
-    pub fn try_into_raw_transaction_poem(
-        &self,
-        user_transaction_request: UserTransactionRequestInner,
-        chain_id: ChainId,
-    ) -> Result<RawTransaction> {
-        let UserTransactionRequestInner {
-            sender,
-            sequence_number,
-            max_gas_amount,
-            gas_unit_price,
-            expiration_timestamp_secs,
-            payload,
-        } = user_transaction_request;
-        Ok(RawTransaction::new(
-            sender.into(),
-            sequence_number.into(),
-            self.try_into_aptos_core_transaction_payload(payload)
-                .context("Failed to parse transaction payload")?,
+            self.try_into_aptos_core_transaction_payload(
+                payload,
+                replay_protection_nonce.map(u64::from),
+            )
+            .context("Failed to parse transaction payload")?,
             max_gas_amount.into(),
             gas_unit_price.into(),
             expiration_timestamp_secs.into(),
@@ -671,462 +281,519 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
         ))
     }
 
+    /// Convert a JSON payload into BCS. Orderless transactions, i.e. those with a replay
+    /// protection nonce, use the versioned payload format.
     pub fn try_into_aptos_core_transaction_payload(
         &self,
         payload: TransactionPayload,
+        replay_protection_nonce: Option<u64>,
     ) -> Result<aptos_types::transaction::TransactionPayload> {
         use aptos_types::transaction::TransactionPayload as Target;
 
         let ret = match payload {
             TransactionPayload::EntryFunctionPayload(entry_func_payload) => {
-                let EntryFunctionPayload {
-                    function,
-                    type_arguments,
-                    arguments,
-                } = entry_func_payload;
-
-                let module = function.module.clone();
-                let code =
-                    self.inner.view_existing_module(&module.clone().into())? as Arc<dyn Bytecode>;
-                let func = code
-                    .find_entry_function(function.name.0.as_ident_str())
-                    .ok_or_else(|| format_err!("could not find entry function by {}", function))?;
-                ensure!(
-                    func.generic_type_params.len() == type_arguments.len(),
-                    "expect {} type arguments for entry function {}, but got {}",
-                    func.generic_type_params.len(),
-                    function,
-                    type_arguments.len()
-                );
-                let args = self
-                    .try_into_vm_values(func, arguments)?
-                    .iter()
-                    .map(bcs::to_bytes)
-                    .collect::<Result<_, bcs::Error>>()?;
-
-                Target::EntryFunction(EntryFunction::new(
-                    module.into(),
-                    function.name.into(),
-                    type_arguments
-                        .into_iter()
-                        .map(|v| v.try_into())
-                        .collect::<Result<_>>()?,
-                    args,
-                ))
+                Target::EntryFunction(self.try_into_entry_function(entry_func_payload)?)
             },
             TransactionPayload::ScriptPayload(script) => {
-                let ScriptPayload {
-                    code,
-                    type_arguments,
-                    arguments,
-                } = script;
-
-                let MoveScriptBytecode { bytecode, abi } = code.try_parse_abi();
-                match abi {
-                    Some(func) => {
-                        let args = self.try_into_vm_values(func, arguments)?;
-                        Target::Script(Script::new(
-                            bytecode.into(),
-                            type_arguments
-                                .into_iter()
-                                .map(|v| v.try_into())
-                                .collect::<Result<_>>()?,
-                            args.into_iter()
-                                .map(|arg| arg.try_into())
-                                .collect::<Result<_>>()?,
-                        ))
-                    },
-                    None => return Err(anyhow::anyhow!("invalid transaction script bytecode")),
-                }
+                Target::Script(self.try_into_script(script)?)
             },
             TransactionPayload::MultisigPayload(multisig) => {
-                let transaction_payload = if let Some(payload) = multisig.transaction_payload {
-                    match payload {
-                        MultisigTransactionPayload::EntryFunctionPayload(entry_function) => {
-                            let EntryFunctionPayload {
-                                function,
-                                type_arguments,
-                                arguments,
-                            } = entry_function;
-
-                            let module = function.module.clone();
-                            let code = self.inner.view_existing_module(&module.clone().into())?
-                                as Arc<dyn Bytecode>;
-                            let func = code
-                                .find_entry_function(function.name.0.as_ident_str())
-                                .ok_or_else(|| {
-                                    format_err!("could not find entry function by {}", function)
-                                })?;
-                            ensure!(
-                                func.generic_type_params.len() == type_arguments.len(),
-                                "expect {} type arguments for entry function {}, but got {}",
-                                func.generic_type_params.len(),
-                                function,
-                                type_arguments.len()
-                            );
-
-                            let args = self
-                                .try_into_vm_values(func, arguments)?
-                                .iter()
-                                .map(bcs::to_bytes)
-                                .collect::<Result<_, bcs::Error>>()?;
-                            Some(
-                                aptos_types::transaction::MultisigTransactionPayload::EntryFunction(
-                                    EntryFunction::new(
-                                        module.into(),
-                                        function.name.into(),
-                                        type_arguments
-                                            .into_iter()
-                                            .map(|v| v.try_into())
-                                            .collect::<Result<_>>()?,
-                                        args,
-                                    ),
-                                ),
-                            )
-                        },
-                    }
-                } else {
-                    None
+                let transaction_payload = match multisig.transaction_payload {
+                    Some(MultisigTransactionPayload::EntryFunctionPayload(entry_function)) => Some(
+                        aptos_types::transaction::MultisigTransactionPayload::EntryFunction(
+                            self.try_into_entry_function(entry_function)?,
+                        ),
+                    ),
+                    None => None,
                 };
                 Target::Multisig(Multisig {
                     multisig_address: multisig.multisig_address.into(),
                     transaction_payload,
                 })
             },
+        };
+        match replay_protection_nonce {
+            Some(nonce) => ret.upgrade(Some(nonce)),
+            None => Ok(ret),
+        }
+    }
 
-            // Deprecated.
-            TransactionPayload::ModuleBundlePayload(_) => {
-                bail!("Module bundle payload has been removed")
-            },
+    fn try_into_entry_function(&self, payload: EntryFunctionPayload) -> Result<EntryFunction> {
+        let EntryFunctionPayload {
+            function,
+            type_arguments,
+            arguments,
+        } = payload;
+        let module: ModuleId = function.module.clone().into();
+        let args = match self.entry_function_arg_types(&module, &function.name, &type_arguments)? {
+            Some(arg_types) => try_into_vm_values(&arg_types, arguments, false)?
+                .iter()
+                .map(bcs::to_bytes)
+                .collect::<Result<_, bcs::Error>>()?,
+            None => arguments
+                .into_iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    serde_json::from_value::<HexEncodedBytes>(arg)
+                        .map(Vec::from)
+                        .with_context(|| {
+                            format!(
+                                "parse arguments[{}] failed, expect hex-encoded BCS bytes as the \
+                                 ABI of {} is unknown",
+                                i, function
+                            )
+                        })
+                })
+                .collect::<Result<_>>()?,
         };
-        Ok(ret)
+        Ok(EntryFunction::new(
+            module,
+            function.name.into(),
+            type_arguments
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<_>>()?,
+            args,
+        ))
     }
 
-    pub fn try_into_vm_values(
-        &self,
-        func: MoveFunction,
-        args: Vec<serde_json::Value>,
-    ) -> Result<Vec<move_core_types::value::MoveValue>> {
-        let arg_types = func
-            .params
-            .into_iter()
-            .filter(|p| !p.is_signer())
-            .collect::<Vec<_>>();
+    fn try_into_script(&self, script: ScriptPayload) -> Result<Script> {
+        let ScriptPayload {
+            code,
+            type_arguments,
+            arguments,
+            serialized_arguments,
+        } = script;
+        let MoveScriptBytecode { bytecode, abi } = code;
+        let ty_args = type_arguments
+            .iter()
+            .cloned()
+            .map(|v| v.try_into())
+            .collect::<Result<_>>()?;
+        if let Some(serialized_arguments) = serialized_arguments {
+            let args = self.try_into_script_arguments(
+                bytecode.inner(),
+                abi,
+                &type_arguments,
+                serialized_arguments.inner(),
+                arguments,
+            )?;
+            return Ok(Script::new(bytecode.into(), ty_args, args));
+        }
+
+        let func = abi
+            .or_else(|| self.resolver.script_abi(bytecode.inner()))
+            .ok_or_else(|| format_err!("the ABI of the transaction script is unknown"))?;
+        let arg_types = arg_types(&func, &type_arguments)?;
         ensure!(
-            arg_types.len() == args.len(),
-            "expected {} arguments [{}], but got {} ({:?})",
+            arg_types.len() == arguments.len(),
+            "expected {} arguments, but got {}",
             arg_types.len(),
-            arg_types
-                .into_iter()
-                .map(|t| t.json_type_name())
-                .collect::<Vec<String>>()
-                .join(", "),
-            args.len(),
-            args,
+            arguments.len()
         );
-        arg_types
-            .into_iter()
-            .zip(args)
+        let args = arg_types
+            .iter()
+            .zip(arguments)
             .enumerate()
             .map(|(i, (arg_type, arg))| {
-                self.try_into_vm_value(&arg_type.clone().try_into()?, arg)
-                    .map_err(|e| {
-                        format_err!(
-                            "parse arguments[{}] failed, expect {}, caused by error: {}",
-                            i,
-                            arg_type.json_type_name(),
-                            e,
-                        )
-                    })
+                try_into_script_argument(arg_type, arg)
+                    .with_context(|| format!("parse arguments[{}] failed, expect {}", i, arg_type))
             })
-            .collect::<Result<_>>()
+            .collect::<Result<_>>()?;
+        Ok(Script::new(bytecode.into(), ty_args, args))
     }
 
-    // Converts JSON object to `MoveValue`, which can be bcs serialized into the same
-    // representation in the DB.
-    // Notice that structs are of the `MoveStruct::Runtime` flavor, matching the representation in
-    // DB.
-    pub fn try_into_vm_value(
+    /// Decode the arguments a script was signed with, and check that they are the ones shown in
+    /// `arguments`. Serialized arguments of an unknown type can't be checked, as they are shown
+    /// decoded whenever the ABI was known when the payload was converted to JSON.
+    fn try_into_script_arguments(
         &self,
-        type_tag: &TypeTag,
-        val: Value,
-    ) -> Result<move_core_types::value::MoveValue> {
-        let layout = match type_tag {
-            TypeTag::Struct(boxed_struct) => {
-                // The current framework can't handle generics, so we handle this here
-                if boxed_struct.address == AccountAddress::ONE
-                    && boxed_struct.module.as_ident_str() == OBJECT_MODULE
-                    && boxed_struct.name.as_ident_str() == OBJECT_STRUCT
-                {
-                    // Objects are just laid out as an address
-                    MoveTypeLayout::Address
-                } else {
-                    // For all other structs, use their set layout
-                    self.inner.view_fully_decorated_ty_layout(type_tag)?
-                }
-            },
-            _ => self.inner.view_fully_decorated_ty_layout(type_tag)?,
+        code: &[u8],
+        abi: Option<MoveFunction>,
+        type_arguments: &[MoveType],
+        serialized_arguments: &[u8],
+        arguments: Vec<Value>,
+    ) -> Result<Vec<TransactionArgument>> {
+        let args: Vec<TransactionArgument> = bcs::from_bytes(serialized_arguments)
+            .context("serialized_arguments are not BCS-encoded transaction arguments")?;
+        ensure!(
+            args.len() == arguments.len(),
+            "expected {} arguments, but got {}",
+            args.len(),
+            arguments.len()
+        );
+        let arg_types = match abi.or_else(|| self.resolver.script_abi(code)) {
+            Some(func) => Some(arg_types(&func, type_arguments)?),
+            None => None,
         };
-
-        self.try_into_vm_value_from_layout(&layout, val)
-    }
-
-    fn try_into_vm_value_from_layout(
-        &self,
-        layout: &MoveTypeLayout,
-        val: Value,
-    ) -> Result<move_core_types::value::MoveValue> {
-        use move_core_types::value::MoveValue::*;
-        Ok(match layout {
-            MoveTypeLayout::Bool => Bool(serde_json::from_value::<bool>(val)?),
-            MoveTypeLayout::U8 => U8(serde_json::from_value::<u8>(val)?),
-            MoveTypeLayout::U16 => U16(serde_json::from_value::<u16>(val)?),
-            MoveTypeLayout::U32 => U32(serde_json::from_value::<u32>(val)?),
-            MoveTypeLayout::U64 => serde_json::from_value::<crate::U64>(val)?.into(),
-            MoveTypeLayout::U128 => serde_json::from_value::<crate::U128>(val)?.into(),
-            MoveTypeLayout::U256 => serde_json::from_value::<crate::U256>(val)?.into(),
-            MoveTypeLayout::Address => serde_json::from_value::<crate::Address>(val)?.into(),
-            MoveTypeLayout::Vector(item_layout) => {
-                self.try_into_vm_value_vector(item_layout.as_ref(), val)?
-            },
-            MoveTypeLayout::Struct(struct_layout) => {
-                self.try_into_vm_value_struct(struct_layout, val)?
-            },
-
-            // Some values, e.g., signer or ones with custom serialization
-            // (native), are not stored to storage and so we do not expect
-            // to see them here.
-            MoveTypeLayout::Signer | MoveTypeLayout::Native(..) => {
-                bail!("unexpected move type {:?} for value {:?}", layout, val)
-            },
-        })
-    }
-
-    pub fn try_into_vm_value_vector(
-        &self,
-        layout: &MoveTypeLayout,
-        val: Value,
-    ) -> Result<move_core_types::value::MoveValue> {
-        if matches!(layout, MoveTypeLayout::U8) {
-            Ok(serde_json::from_value::<HexEncodedBytes>(val)?.into())
-        } else if let Value::Array(list) = val {
-            let vals = list
-                .into_iter()
-                .map(|v| self.try_into_vm_value_from_layout(layout, v))
-                .collect::<Result<_>>()?;
-
-            Ok(move_core_types::value::MoveValue::Vector(vals))
-        } else {
-            bail!("expected vector<{:?}>, but got: {:?}", layout, val)
+        for (i, (arg, val)) in args.iter().zip(arguments).enumerate() {
+            let arg_type = arg_types.as_ref().and_then(|types| types.get(i));
+            if matches!(arg, TransactionArgument::Serialized(_)) && arg_type.is_none() {
+                continue;
+            }
+            let expected = try_into_script_argument_json(arg.clone(), arg_type)
+                .with_context(|| format!("parse arguments[{}] failed", i))?;
+            ensure!(
+                expected == val,
+                "arguments[{}] is {}, but serialized_arguments hold {}",
+                i,
+                val,
+                expected
+            );
         }
+        Ok(args)
     }
 
-    pub fn try_into_vm_value_struct(
+    /// The argument types of an entry function, or None if the function is unknown or takes
+    /// arguments whose layout can't be derived from their type alone
+    fn entry_function_arg_types(
         &self,
-        layout: &MoveStructLayout,
-        val: Value,
-    ) -> Result<move_core_types::value::MoveValue> {
-        let (struct_tag, field_layouts) =
-            if let MoveStructLayout::WithTypes { type_, fields } = layout {
-                (type_, fields)
-            } else {
-                bail!(
-                    "Expecting `MoveStructLayout::WithTypes`, getting {:?}",
-                    layout
-                );
-            };
-        if MoveValue::is_utf8_string(struct_tag) {
-            let string = val
-                .as_str()
-                .ok_or_else(|| format_err!("failed to parse string::String."))?;
-            return Ok(new_vm_utf8_string(string));
-        }
-
-        let mut field_values = if let Value::Object(fields) = val {
-            fields
-        } else {
-            bail!("Expecting a JSON Map for struct.");
+        module: &ModuleId,
+        function: &IdentStr,
+        type_arguments: &[MoveType],
+    ) -> Result<Option<Vec<MoveType>>> {
+        let Some(func) = self.resolver.entry_function_abi(module, function) else {
+            return Ok(None);
         };
-        let fields = field_layouts
-            .iter()
-            .map(|field_layout| {
-                let name = field_layout.name.as_str();
-                let value = field_values
-                    .remove(name)
-                    .ok_or_else(|| format_err!("field {} not found.", name))?;
-                let move_value = self.try_into_vm_value_from_layout(&field_layout.layout, value)?;
-                Ok(move_value)
-            })
-            .collect::<Result<_>>()?;
-
-        Ok(move_core_types::value::MoveValue::Struct(
-            move_core_types::value::MoveStruct::Runtime(fields),
-        ))
-    }
-
-    pub fn try_into_move_value(&self, typ: &TypeTag, bytes: &[u8]) -> Result<MoveValue> {
-        self.inner.view_value(typ, bytes)?.try_into()
-    }
-
-    pub fn function_return_types(&self, function: &ViewFunction) -> Result<Vec<MoveType>> {
-        let module = function.module.clone();
-        let code = self.inner.view_existing_module(&module)? as Arc<dyn Bytecode>;
-        let func = code
-            .find_function(function.function.as_ident_str())
-            .ok_or_else(|| format_err!("could not find entry function by {:?}", function))?;
-
-        Ok(func.return_)
-    }
-
-    pub fn convert_view_function(&self, view_request: ViewRequest) -> Result<ViewFunction> {
-        let ViewRequest {
-            function,
-            type_arguments,
-            arguments,
-        } = view_request;
-
-        let module = function.module.clone();
-        let code = self.inner.view_existing_module(&module.clone().into())? as Arc<dyn Bytecode>;
-        let func = code
-            .find_function(function.name.0.as_ident_str())
-            .ok_or_else(|| format_err!("could not find entry function by {}", function))?;
         ensure!(
             func.generic_type_params.len() == type_arguments.len(),
-            "expected {} type arguments for entry function {}, but got {}",
+            "expect {} type arguments for entry function {}::{}, but got {}",
             func.generic_type_params.len(),
+            module,
             function,
             type_arguments.len()
         );
-        let args = self
-            .try_into_vm_values(func, arguments)?
+        let arg_types = arg_types(&func, type_arguments)?;
+        Ok(arg_types
             .iter()
-            .map(bcs::to_bytes)
-            .collect::<Result<_, bcs::Error>>()?;
+            .all(|arg_type| arg_type_layout(arg_type).is_some())
+            .then_some(arg_types))
+    }
+}
 
-        Ok(ViewFunction {
-            module: module.into(),
-            function: function.name.into(),
-            ty_args: type_arguments
-                .into_iter()
-                .map(|v| v.try_into())
+/// The types of the arguments a transaction passes to `func`, i.e. its parameters without the
+/// signers, with the given type arguments substituted
+pub(crate) fn arg_types(func: &MoveFunction, type_arguments: &[MoveType]) -> Result<Vec<MoveType>> {
+    func.params
+        .iter()
+        .filter(|p| !p.is_signer())
+        .map(|p| substitute_type_params(p, type_arguments))
+        .collect()
+}
+
+fn substitute_type_params(typ: &MoveType, type_arguments: &[MoveType]) -> Result<MoveType> {
+    Ok(match typ {
+        MoveType::GenericTypeParam { index } => type_arguments
+            .get(*index as usize)
+            .cloned()
+            .ok_or_else(|| format_err!("missing type argument T{}", index))?,
+        MoveType::Vector { items } => MoveType::Vector {
+            items: Box::new(substitute_type_params(items, type_arguments)?),
+        },
+        MoveType::Struct(tag) => MoveType::Struct(MoveStructTag {
+            generic_type_params: tag
+                .generic_type_params
+                .iter()
+                .map(|t| substitute_type_params(t, type_arguments))
                 .collect::<Result<_>>()?,
-            args,
-        })
-    }
+            ..tag.clone()
+        }),
+        MoveType::Reference { mutable, to } => MoveType::Reference {
+            mutable: *mutable,
+            to: Box::new(substitute_type_params(to, type_arguments)?),
+        },
+        _ => typ.clone(),
+    })
+}
 
-    fn get_table_info(&self, handle: TableHandle) -> Result<Option<TableInfo>> {
-        if let Some(indexer_reader) = self.indexer_reader.as_ref() {
-            return Ok(indexer_reader.get_table_info(handle).unwrap_or(None));
-        }
-        Ok(None)
+pub(crate) fn is_framework_struct(tag: &MoveStructTag, module: &str, name: &str) -> bool {
+    *tag.address.inner() == AccountAddress::ONE
+        && tag.module.as_str() == module
+        && tag.name.as_str() == name
+}
+
+/// The type argument of a framework struct with a single one, such as `Option<T>`
+pub(crate) fn single_type_param(tag: &MoveStructTag) -> Result<&MoveType> {
+    match tag.generic_type_params.as_slice() {
+        [typ] => Ok(typ),
+        _ => bail!("expect one type argument for {}", tag),
     }
+}
 
-    fn explain_vm_status(
-        &self,
-        status: &ExecutionStatus,
-        txn_aux_data: Option<TransactionAuxiliaryData>,
-    ) -> String {
-        let mut status = status.to_owned();
-        status = if let Some(aux_data) = txn_aux_data {
-            ExecutionStatus::aug_with_aux_data(status, &aux_data)
-        } else {
-            status
-        };
-        match &status {
-            ExecutionStatus::MoveAbort {
-                location,
-                code,
-                info,
-            } => match &location {
-                AbortLocation::Module(_) => info
-                    .as_ref()
-                    .map(|i| {
-                        format!(
-                            "Move abort in {}: {}({:#x}): {}",
-                            abort_location_to_str(location),
-                            i.reason_name,
-                            code,
-                            i.description
-                        )
-                    })
-                    .unwrap_or_else(|| {
-                        format!(
-                            "Move abort in {}: {:#x}",
-                            abort_location_to_str(location),
-                            code
-                        )
-                    }),
-                AbortLocation::Script => format!("Move abort: code {:#x}", code),
-            },
-            ExecutionStatus::Success => "Executed successfully".to_owned(),
-            ExecutionStatus::OutOfGas => "Out of gas".to_owned(),
-            ExecutionStatus::ExecutionFailure {
-                location,
-                function,
-                code_offset,
-            } => {
-                let func_name = match location {
-                    AbortLocation::Module(module_id) => self
-                        .explain_function_index(module_id, function)
-                        .map(|name| format!("{}::{}", abort_location_to_str(location), name))
-                        .unwrap_or_else(|_| {
-                            format!(
-                                "{}::<#{} function>",
-                                abort_location_to_str(location),
-                                function
-                            )
-                        }),
-                    AbortLocation::Script => "script".to_owned(),
-                };
-                format!(
-                    "Execution failed in {} at code offset {}",
-                    func_name, code_offset
+/// The BCS layout of an argument of the given type. Only primitives, vectors and the framework
+/// structs the REST API shows specially (`String`, `Object<T>` and `Option<T>`) have one, as
+/// the layouts of other structs depend on their modules.
+fn arg_type_layout(typ: &MoveType) -> Option<MoveTypeLayout> {
+    Some(match typ {
+        MoveType::Bool => MoveTypeLayout::Bool,
+        MoveType::U8 => MoveTypeLayout::U8,
+        MoveType::U16 => MoveTypeLayout::U16,
+        MoveType::U32 => MoveTypeLayout::U32,
+        MoveType::U64 => MoveTypeLayout::U64,
+        MoveType::U128 => MoveTypeLayout::U128,
+        MoveType::U256 => MoveTypeLayout::U256,
+        MoveType::Address => MoveTypeLayout::Address,
+        MoveType::Vector { items } => MoveTypeLayout::Vector(Box::new(arg_type_layout(items)?)),
+        MoveType::Struct(tag) if is_framework_struct(tag, "string", "String") => {
+            MoveTypeLayout::Struct(MoveStructLayout::new(vec![MoveTypeLayout::Vector(
+                Box::new(MoveTypeLayout::U8),
+            )]))
+        },
+        MoveType::Struct(tag) if is_framework_struct(tag, "object", "Object") => {
+            MoveTypeLayout::Struct(MoveStructLayout::new(vec![MoveTypeLayout::Address]))
+        },
+        MoveType::Struct(tag) if is_framework_struct(tag, "option", "Option") => {
+            let item = arg_type_layout(single_type_param(tag).ok()?)?;
+            MoveTypeLayout::Struct(MoveStructLayout::new(vec![MoveTypeLayout::Vector(
+                Box::new(item),
+            )]))
+        },
+        _ => return None,
+    })
+}
+
+/// Decode a BCS encoded argument into the JSON form used by the REST API
+pub(crate) fn try_into_json_value(typ: &MoveType, bytes: &[u8]) -> Result<Value> {
+    let layout =
+        arg_type_layout(typ).ok_or_else(|| format_err!("unsupported argument type {}", typ))?;
+    let value = move_core_types::value::MoveValue::simple_deserialize(bytes, &layout)?;
+    try_into_json_value_from_vm_value(typ, value)
+}
+
+fn try_into_json_value_from_vm_value(
+    typ: &MoveType,
+    value: move_core_types::value::MoveValue,
+) -> Result<Value> {
+    use move_core_types::value::MoveValue::*;
+    Ok(match (typ, value) {
+        (MoveType::Bool, Bool(v)) => Value::Bool(v),
+        (MoveType::U8, U8(v)) => serde_json::to_value(v)?,
+        (MoveType::U16, U16(v)) => serde_json::to_value(v)?,
+        (MoveType::U32, U32(v)) => serde_json::to_value(v)?,
+        (MoveType::U64, U64(v)) => serde_json::to_value(crate::U64::from(v))?,
+        (MoveType::U128, U128(v)) => serde_json::to_value(crate::U128::from(v))?,
+        (MoveType::U256, U256(v)) => serde_json::to_value(crate::U256::from(v))?,
+        (MoveType::Address, Address(v)) => serde_json::to_value(crate::Address::from(v))?,
+        (MoveType::Vector { items }, Vector(values)) => {
+            if matches!(**items, MoveType::U8) {
+                HexEncodedBytes::from(move_core_types::value::MoveValue::vec_to_vec_u8(values)?)
+                    .json()?
+            } else {
+                Value::Array(
+                    values
+                        .into_iter()
+                        .map(|v| try_into_json_value_from_vm_value(items, v))
+                        .collect::<Result<_>>()?,
                 )
-            },
-            ExecutionStatus::MiscellaneousError(code) => {
-                if code.is_none() {
-                    "Execution failed with miscellaneous error and no status code".to_owned()
-                } else {
-                    format!("{:#?}", code.unwrap())
-                }
-            },
-        }
-    }
+            }
+        },
+        (MoveType::Struct(tag), Struct(value)) => {
+            let (_, mut fields) = value.into_optional_variant_and_fields();
+            ensure!(fields.len() == 1, "expect a single field in {}", tag);
+            let field = fields.remove(0);
+            if is_framework_struct(tag, "string", "String") {
+                let Vector(bytes) = field else {
+                    bail!("expect string::String, but failed to decode struct value");
+                };
+                Value::String(String::from_utf8(
+                    move_core_types::value::MoveValue::vec_to_vec_u8(bytes)?,
+                )?)
+            } else if is_framework_struct(tag, "object", "Object") {
+                serde_json::json!({
+                    "inner": try_into_json_value_from_vm_value(&MoveType::Address, field)?
+                })
+            } else if is_framework_struct(tag, "option", "Option") {
+                let item = single_type_param(tag)?.clone();
+                serde_json::json!({
+                    "vec": try_into_json_value_from_vm_value(
+                        &MoveType::Vector { items: Box::new(item) },
+                        field
+                    )?
+                })
+            } else {
+                bail!("unsupported argument type {}", tag)
+            }
+        },
+        (typ, value) => bail!("unexpected value {:?} for type {}", value, typ),
+    })
+}
 
-    fn explain_function_index(&self, module_id: &ModuleId, function: &u16) -> Result<String> {
-        let code = self.inner.view_existing_module(module_id)?;
-        let func = code.function_handle_at(FunctionHandleIndex::new(*function));
-        let id = code.identifier_at(func.name);
-        Ok(id.to_string())
-    }
+/// Encode the JSON arguments of an entry function call into the BCS bytes an `EntryFunction`
+/// carries, given the types of the function's parameters without its signers
+///
+/// Arguments take the JSON form the REST API accepts: `u64`, `u128` and `u256` as strings,
+/// addresses in any AIP-40 form, objects as their address, and options as null or their value.
+/// `vector<u8>` arguments may also be given as UTF-8 text, which is told apart from hex by the
+/// lack of a `0x` prefix.
+pub fn encode_entry_function_args(arg_types: &[MoveType], args: Value) -> Result<Vec<Vec<u8>>> {
+    let Value::Array(args) = args else {
+        bail!("expect an array of arguments, but got: {:?}", args);
+    };
+    try_into_vm_values(arg_types, args, true)?
+        .iter()
+        .map(|value| bcs::to_bytes(value).context("failed to BCS encode argument"))
+        .collect()
 }
 
-fn log_missing_table_info(handle: TableHandle) {
-    sample!(
-        SampleRate::Duration(Duration::from_secs(1)),
-        aptos_logger::debug!(
-            "Table info not found for handle {:?}, can't decode table item. OK for simulation",
-            handle
-        )
+fn try_into_vm_values(
+    arg_types: &[MoveType],
+    args: Vec<Value>,
+    utf8_bytes: bool,
+) -> Result<Vec<move_core_types::value::MoveValue>> {
+    ensure!(
+        arg_types.len() == args.len(),
+        "expected {} arguments [{}], but got {} ({:?})",
+        arg_types.len(),
+        arg_types
+            .iter()
+            .map(|t| t.json_type_name())
+            .collect::<Vec<String>>()
+            .join(", "),
+        args.len(),
+        args,
     );
+    arg_types
+        .iter()
+        .zip(args)
+        .enumerate()
+        .map(|(i, (arg_type, arg))| {
+            try_into_vm_value(arg_type, arg, utf8_bytes).map_err(|e| {
+                format_err!(
+                    "parse arguments[{}] failed, expect {}, caused by error: {}",
+                    i,
+                    arg_type.json_type_name(),
+                    e,
+                )
+            })
+        })
+        .collect::<Result<_>>()
 }
 
-pub trait AsConverter<R> {
-    fn as_converter(
-        &self,
-        db: Arc<dyn DbReader>,
-        indexer_reader: Option<Arc<dyn IndexerReader>>,
-    ) -> MoveConverter<R>;
+// Converts JSON object to `MoveValue`, which can be bcs serialized into the same
+// representation in the DB.
+// Notice that structs are of the `MoveStruct::Runtime` flavor, matching the representation in
+// DB.
+// With `utf8_bytes`, `vector<u8>` strings without a `0x` prefix are taken as UTF-8 text rather
+// than hex.
+fn try_into_vm_value(
+    typ: &MoveType,
+    val: Value,
+    utf8_bytes: bool,
+) -> Result<move_core_types::value::MoveValue> {
+    use move_core_types::value::MoveValue::*;
+    Ok(match typ {
+        MoveType::Bool => Bool(serde_json::from_value::<bool>(val)?),
+        MoveType::U8 => U8(serde_json::from_value::<u8>(val)?),
+        MoveType::U16 => U16(serde_json::from_value::<u16>(val)?),
+        MoveType::U32 => U32(serde_json::from_value::<u32>(val)?),
+        MoveType::U64 => serde_json::from_value::<crate::U64>(val)?.into(),
+        MoveType::U128 => serde_json::from_value::<crate::U128>(val)?.into(),
+        MoveType::U256 => serde_json::from_value::<crate::U256>(val)?.into(),
+        MoveType::Address => serde_json::from_value::<crate::Address>(val)?.into(),
+        MoveType::Vector { items } => {
+            if matches!(**items, MoveType::U8) {
+                match val {
+                    Value::String(text) if utf8_bytes && !text.starts_with("0x") => {
+                        HexEncodedBytes::from(text.into_bytes()).into()
+                    },
+                    val => serde_json::from_value::<HexEncodedBytes>(val)?.into(),
+                }
+            } else if let Value::Array(list) = val {
+                Vector(
+                    list.into_iter()
+                        .map(|v| try_into_vm_value(items, v, utf8_bytes))
+                        .collect::<Result<_>>()?,
+                )
+            } else {
+                bail!("expected vector<{}>, but got: {:?}", items, val)
+            }
+        },
+        MoveType::Struct(tag) if is_framework_struct(tag, "string", "String") => {
+            let string = val
+                .as_str()
+                .ok_or_else(|| format_err!("failed to parse string::String."))?;
+            new_vm_utf8_string(string)
+        },
+        // Objects are accepted both as their address and in the `{"inner": address}` form the
+        // API returns them in.
+        MoveType::Struct(tag) if is_framework_struct(tag, "object", "Object") => {
+            let address = match val {
+                Value::Object(mut fields) => fields
+                    .remove("inner")
+                    .ok_or_else(|| format_err!("field inner not found."))?,
+                address => address,
+            };
+            Struct(MoveStruct::Runtime(vec![try_into_vm_value(
+                &MoveType::Address,
+                address,
+                utf8_bytes,
+            )?]))
+        },
+        // Options are accepted as null, as their value, or in the `{"vec": [value]}` form the
+        // API returns them in.
+        MoveType::Struct(tag) if is_framework_struct(tag, "option", "Option") => {
+            let item = single_type_param(tag)?;
+            let values = match val {
+                Value::Null => vec![],
+                Value::Object(mut fields) if fields.contains_key("vec") => {
+                    let Some(Value::Array(values)) = fields.remove("vec") else {
+                        bail!("expect an array in field vec of {}", tag);
+                    };
+                    ensure!(values.len() <= 1, "expect at most one value in {}", tag);
+                    values
+                },
+                value => vec![value],
+            };
+            Struct(MoveStruct::Runtime(vec![Vector(
+                values
+                    .into_iter()
+                    .map(|v| try_into_vm_value(item, v, utf8_bytes))
+                    .collect::<Result<_>>()?,
+            )]))
+        },
+        _ => bail!("unsupported argument type {}", typ),
+    })
 }
 
-impl<R: StateView> AsConverter<R> for R {
-    fn as_converter(
-        &self,
-        db: Arc<dyn DbReader>,
-        indexer_reader: Option<Arc<dyn IndexerReader>>,
-    ) -> MoveConverter<R> {
-        MoveConverter::new(self, db, indexer_reader)
-    }
+/// Show a script argument in the JSON form used by the REST API. Arguments passed as
+/// serialized bytes are decoded when their type is known, and shown as hex otherwise.
+fn try_into_script_argument_json(
+    arg: TransactionArgument,
+    arg_type: Option<&MoveType>,
+) -> Result<Value> {
+    Ok(match arg {
+        TransactionArgument::U8(v) => serde_json::to_value(v)?,
+        TransactionArgument::U16(v) => serde_json::to_value(v)?,
+        TransactionArgument::U32(v) => serde_json::to_value(v)?,
+        TransactionArgument::U64(v) => serde_json::to_value(crate::U64::from(v))?,
+        TransactionArgument::U128(v) => serde_json::to_value(crate::U128::from(v))?,
+        TransactionArgument::U256(v) => serde_json::to_value(crate::U256::from(v))?,
+        TransactionArgument::Bool(v) => Value::Bool(v),
+        TransactionArgument::Address(v) => serde_json::to_value(Address::from(v))?,
+        TransactionArgument::U8Vector(bytes) => HexEncodedBytes::from(bytes).json()?,
+        TransactionArgument::Serialized(bytes) => match arg_type {
+            Some(arg_type) => try_into_json_value(arg_type, &bytes)?,
+            None => HexEncodedBytes::from(bytes).json()?,
+        },
+    })
+}
+
+/// Parse a script argument of the given type. Primitives and `vector<u8>` get their own
+/// `TransactionArgument` kind, and all other arguments are passed serialized.
+fn try_into_script_argument(arg_type: &MoveType, val: Value) -> Result<TransactionArgument> {
+    let value = try_into_vm_value(arg_type, val, false)?;
+    Ok(match arg_type {
+        MoveType::Bool
+        | MoveType::U8
+        | MoveType::U16
+        | MoveType::U32
+        | MoveType::U64
+        | MoveType::U128
+        | MoveType::U256
+        | MoveType::Address => value.try_into()?,
+        MoveType::Vector { items } if matches!(**items, MoveType::U8) => value.try_into()?,
+        _ => TransactionArgument::Serialized(bcs::to_bytes(&value)?),
+    })
 }
 
 pub fn new_vm_utf8_string(string: &str) -> move_core_types::value::MoveValue {
@@ -1143,11 +810,670 @@ pub fn new_vm_utf8_string(string: &str) -> move_core_types::value::MoveValue {
     MoveValue::Struct(move_string)
 }
 
-fn abort_location_to_str(loc: &AbortLocation) -> String {
-    match loc {
-        AbortLocation::Module(mid) => {
-            format!("{}::{}", mid.address().to_hex_literal(), mid.name())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        move_types::{MoveFunctionGenericTypeParam, MoveFunctionVisibility},
+        transaction::TransactionSignature,
+    };
+    use aptos_crypto::{
+        ed25519::{Ed25519PublicKey, Ed25519Signature},
+        multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
+        SigningKey,
+    };
+    use aptos_types::{
+        test_helpers::{private_key, transfer},
+        transaction::authenticator::{
+            AccountAuthenticator, AnyPublicKey, AnySignature, MultiKey, MultiKeyAuthenticator,
+            SingleKeyAuthenticator, TransactionAuthenticator,
         },
-        _ => loc.to_string(),
+    };
+    use move_core_types::{
+        identifier::Identifier, language_storage::TypeTag, parser::parse_type_tag, u256::U256,
+    };
+    use proptest::{collection::vec, option, prelude::*, sample::Index};
+    use serde_json::json;
+
+    const MESSAGE: &[u8] = b"aptos-api-types";
+
+    fn move_type(s: &str) -> MoveType {
+        s.parse().unwrap()
+    }
+
+    fn object_of_t0() -> MoveType {
+        MoveType::Struct(MoveStructTag {
+            address: AccountAddress::ONE.into(),
+            module: "object".parse().unwrap(),
+            name: "Object".parse().unwrap(),
+            generic_type_params: vec![MoveType::GenericTypeParam { index: 0 }],
+        })
+    }
+
+    fn entry_function_abi(name: &str, type_params: usize, params: Vec<MoveType>) -> MoveFunction {
+        MoveFunction {
+            name: name.parse().unwrap(),
+            visibility: MoveFunctionVisibility::Public,
+            is_entry: true,
+            is_view: false,
+            generic_type_params: vec![
+                MoveFunctionGenericTypeParam {
+                    constraints: vec![]
+                };
+                type_params
+            ],
+            params,
+            return_: vec![],
+        }
+    }
+
+    /// The code of the only script whose ABI is known
+    const TYPED_SCRIPT: &[u8] = b"typed script";
+
+    /// Knows `0x1::test::typed`, which takes every kind of argument with a JSON form,
+    /// `0x1::test::opaque`, which takes a struct without one, and the ABI of `TYPED_SCRIPT`
+    struct TestAbis;
+
+    impl AbiResolver for TestAbis {
+        fn entry_function_abi(
+            &self,
+            module: &ModuleId,
+            function: &IdentStr,
+        ) -> Option<MoveFunction> {
+            if *module != ModuleId::new(AccountAddress::ONE, Identifier::new("test").unwrap()) {
+                return None;
+            }
+            match function.as_str() {
+                "typed" => Some(entry_function_abi(
+                    "typed",
+                    1,
+                    vec![
+                        move_type("&signer"),
+                        MoveType::U8,
+                        MoveType::U16,
+                        MoveType::U32,
+                        MoveType::U64,
+                        MoveType::U128,
+                        MoveType::U256,
+                        MoveType::Bool,
+                        MoveType::Address,
+                        move_type("vector<u8>"),
+                        move_type("0x1::string::String"),
+                        move_type("0x1::option::Option<u64>"),
+                        object_of_t0(),
+                        MoveType::Vector {
+                            items: Box::new(MoveType::GenericTypeParam { index: 0 }),
+                        },
+                    ],
+                )),
+                "opaque" => Some(entry_function_abi(
+                    "opaque",
+                    0,
+                    vec![
+                        move_type("signer"),
+                        move_type("0x1::coin::Coin<0x1::aptos_coin::AptosCoin>"),
+                    ],
+                )),
+                _ => None,
+            }
+        }
+
+        fn script_abi(&self, code: &[u8]) -> Option<MoveFunction> {
+            if code != TYPED_SCRIPT {
+                return None;
+            }
+            Some(entry_function_abi(
+                "main",
+                0,
+                vec![
+                    move_type("&signer"),
+                    MoveType::U64,
+                    MoveType::Address,
+                    move_type("vector<u8>"),
+                    move_type("0x1::string::String"),
+                ],
+            ))
+        }
+    }
+
+    fn address() -> impl Strategy<Value = AccountAddress> {
+        any::<[u8; AccountAddress::LENGTH]>().prop_map(AccountAddress::new)
+    }
+
+    fn typed_entry_function() -> impl Strategy<Value = EntryFunction> {
+        (
+            (any::<u8>(), any::<u16>(), any::<u32>(), any::<u64>()),
+            (any::<u128>(), any::<[u8; 32]>(), any::<bool>(), address()),
+            (
+                vec(any::<u8>(), 0..40),
+                any::<String>(),
+                any::<Option<u64>>(),
+            ),
+            (address(), vec(any::<u64>(), 0..4)),
+        )
+            .prop_map(|((a, b, c, d), (e, f, g, h), (i, j, k), (l, m))| {
+                EntryFunction::new(
+                    ModuleId::new(AccountAddress::ONE, Identifier::new("test").unwrap()),
+                    Identifier::new("typed").unwrap(),
+                    vec![TypeTag::U64],
+                    vec![
+                        bcs::to_bytes(&a).unwrap(),
+                        bcs::to_bytes(&b).unwrap(),
+                        bcs::to_bytes(&c).unwrap(),
+                        bcs::to_bytes(&d).unwrap(),
+                        bcs::to_bytes(&e).unwrap(),
+                        bcs::to_bytes(&U256::from_le_bytes(&f)).unwrap(),
+                        bcs::to_bytes(&g).unwrap(),
+                        bcs::to_bytes(&h).unwrap(),
+                        bcs::to_bytes(&i).unwrap(),
+                        bcs::to_bytes(&j).unwrap(),
+                        bcs::to_bytes(&k).unwrap(),
+                        bcs::to_bytes(&l).unwrap(),
+                        bcs::to_bytes(&m).unwrap(),
+                    ],
+                )
+            })
+    }
+
+    /// Entry functions whose arguments can only be shown as hex-encoded BCS bytes
+    fn untyped_entry_function() -> impl Strategy<Value = EntryFunction> {
+        (
+            prop_oneof![Just("opaque"), Just("unknown")],
+            vec(vec(any::<u8>(), 0..16), 0..4),
+        )
+            .prop_map(|(function, args)| {
+                EntryFunction::new(
+                    ModuleId::new(AccountAddress::ONE, Identifier::new("test").unwrap()),
+                    Identifier::new(function).unwrap(),
+                    vec![parse_type_tag("0x1::aptos_coin::AptosCoin").unwrap()],
+                    args,
+                )
+            })
+    }
+
+    fn entry_function() -> impl Strategy<Value = EntryFunction> {
+        prop_oneof![typed_entry_function(), untyped_entry_function()]
+    }
+
+    fn transaction_argument() -> impl Strategy<Value = TransactionArgument> {
+        prop_oneof![
+            any::<u8>().prop_map(TransactionArgument::U8),
+            any::<u16>().prop_map(TransactionArgument::U16),
+            any::<u32>().prop_map(TransactionArgument::U32),
+            any::<u64>().prop_map(TransactionArgument::U64),
+            any::<u128>().prop_map(TransactionArgument::U128),
+            any::<[u8; 32]>()
+                .prop_map(|bytes| TransactionArgument::U256(U256::from_le_bytes(&bytes))),
+            address().prop_map(TransactionArgument::Address),
+            vec(any::<u8>(), 0..16).prop_map(TransactionArgument::U8Vector),
+            any::<bool>().prop_map(TransactionArgument::Bool),
+            vec(any::<u8>(), 0..16).prop_map(TransactionArgument::Serialized),
+        ]
+    }
+
+    /// Each argument of `TYPED_SCRIPT` in either of the kinds it can be passed as
+    fn typed_script() -> impl Strategy<Value = Script> {
+        (
+            any::<u64>(),
+            address(),
+            vec(any::<u8>(), 0..16),
+            any::<String>(),
+            any::<(bool, bool, bool)>(),
+        )
+            .prop_map(|(amount, address, bytes, string, serialized)| {
+                let arg = |serialized, arg, bytes| {
+                    if serialized {
+                        TransactionArgument::Serialized(bytes)
+                    } else {
+                        arg
+                    }
+                };
+                Script::new(
+                    TYPED_SCRIPT.to_vec(),
+                    vec![],
+                    vec![
+                        arg(
+                            serialized.0,
+                            TransactionArgument::U64(amount),
+                            bcs::to_bytes(&amount).unwrap(),
+                        ),
+                        arg(
+                            serialized.1,
+                            TransactionArgument::Address(address),
+                            bcs::to_bytes(&address).unwrap(),
+                        ),
+                        arg(
+                            serialized.2,
+                            TransactionArgument::U8Vector(bytes.clone()),
+                            bcs::to_bytes(&bytes).unwrap(),
+                        ),
+                        TransactionArgument::Serialized(bcs::to_bytes(&string).unwrap()),
+                    ],
+                )
+            })
+    }
+
+    /// Scripts whose ABI is unknown, with arguments of any kind
+    fn untyped_script() -> impl Strategy<Value = Script> {
+        (vec(any::<u8>(), 1..32), vec(transaction_argument(), 0..6)).prop_map(|(code, args)| {
+            Script::new(
+                code,
+                vec![parse_type_tag("0x1::aptos_coin::AptosCoin").unwrap()],
+                args,
+            )
+        })
+    }
+
+    fn script() -> impl Strategy<Value = Script> {
+        prop_oneof![typed_script(), untyped_script()]
+    }
+
+    fn payload() -> impl Strategy<Value = aptos_types::transaction::TransactionPayload> {
+        use aptos_types::transaction::TransactionPayload as Target;
+
+        let payload = prop_oneof![
+            entry_function().prop_map(Target::EntryFunction),
+            script().prop_map(Target::Script),
+            (address(), option::of(entry_function())).prop_map(|(address, fun)| {
+                Target::Multisig(Multisig::new(
+                    address,
+                    fun.map(aptos_types::transaction::MultisigTransactionPayload::EntryFunction),
+                ))
+            }),
+        ];
+        (payload, any::<Option<u64>>()).prop_map(|(payload, nonce)| match nonce {
+            Some(nonce) => payload.upgrade(Some(nonce)).unwrap(),
+            None => payload,
+        })
+    }
+
+    /// Ed25519 keys with signatures by the first `threshold` of them
+    fn multi_signer() -> impl Strategy<Value = (Vec<Ed25519PublicKey>, Vec<(u8, Ed25519Signature)>)>
+    {
+        (vec(any::<u8>(), 1..4), any::<Index>()).prop_map(|(seeds, threshold)| {
+            let keys: Vec<_> = seeds.into_iter().map(private_key).collect();
+            let threshold = threshold.index(keys.len()) + 1;
+            let signatures = keys[..threshold]
+                .iter()
+                .enumerate()
+                .map(|(i, key)| (i as u8, key.sign_arbitrary_message(MESSAGE)))
+                .collect();
+            (
+                keys.iter().map(Ed25519PublicKey::from).collect(),
+                signatures,
+            )
+        })
+    }
+
+    fn multi_ed25519() -> impl Strategy<Value = (MultiEd25519PublicKey, MultiEd25519Signature)> {
+        multi_signer().prop_map(|(public_keys, signatures)| {
+            let threshold = signatures.len() as u8;
+            (
+                MultiEd25519PublicKey::new(public_keys, threshold).unwrap(),
+                MultiEd25519Signature::new(
+                    signatures
+                        .into_iter()
+                        .map(|(index, signature)| (signature, index))
+                        .collect(),
+                )
+                .unwrap(),
+            )
+        })
+    }
+
+    fn account_authenticator() -> impl Strategy<Value = AccountAuthenticator> {
+        prop_oneof![
+            any::<u8>().prop_map(|seed| {
+                let key = private_key(seed);
+                AccountAuthenticator::ed25519(
+                    Ed25519PublicKey::from(&key),
+                    key.sign_arbitrary_message(MESSAGE),
+                )
+            }),
+            multi_ed25519().prop_map(|(public_key, signature)| {
+                AccountAuthenticator::multi_ed25519(public_key, signature)
+            }),
+            any::<u8>().prop_map(|seed| {
+                let key = private_key(seed);
+                AccountAuthenticator::single_key(SingleKeyAuthenticator::new(
+                    AnyPublicKey::ed25519(Ed25519PublicKey::from(&key)),
+                    AnySignature::ed25519(key.sign_arbitrary_message(MESSAGE)),
+                ))
+            }),
+            multi_signer().prop_map(|(public_keys, signatures)| {
+                let public_keys = MultiKey::new(
+                    public_keys.into_iter().map(AnyPublicKey::ed25519).collect(),
+                    signatures.len() as u8,
+                )
+                .unwrap();
+                let signatures = signatures
+                    .into_iter()
+                    .map(|(index, signature)| (index, AnySignature::ed25519(signature)))
+                    .collect();
+                AccountAuthenticator::multi_key(
+                    MultiKeyAuthenticator::new(public_keys, signatures).unwrap(),
+                )
+            }),
+            Just(AccountAuthenticator::NoAccountAuthenticator),
+        ]
+    }
+
+    fn transaction_authenticator() -> impl Strategy<Value = TransactionAuthenticator> {
+        let secondary_signers = vec((address(), account_authenticator()), 0..3);
+        prop_oneof![
+            any::<u8>().prop_map(|seed| {
+                let key = private_key(seed);
+                TransactionAuthenticator::ed25519(
+                    Ed25519PublicKey::from(&key),
+                    key.sign_arbitrary_message(MESSAGE),
+                )
+            }),
+            multi_ed25519().prop_map(|(public_key, signature)| {
+                TransactionAuthenticator::multi_ed25519(public_key, signature)
+            }),
+            account_authenticator().prop_map(TransactionAuthenticator::single_sender),
+            (account_authenticator(), secondary_signers.clone()).prop_map(
+                |(sender, secondary_signers)| {
+                    let (addresses, signers) = secondary_signers.into_iter().unzip();
+                    TransactionAuthenticator::multi_agent(sender, addresses, signers)
+                }
+            ),
+            (
+                account_authenticator(),
+                secondary_signers,
+                address(),
+                account_authenticator()
+            )
+                .prop_map(
+                    |(sender, secondary_signers, fee_payer, fee_payer_signer)| {
+                        let (addresses, signers) = secondary_signers.into_iter().unzip();
+                        TransactionAuthenticator::fee_payer(
+                            sender,
+                            addresses,
+                            signers,
+                            fee_payer,
+                            fee_payer_signer,
+                        )
+                    }
+                ),
+        ]
+    }
+
+    fn signed_transaction() -> impl Strategy<Value = SignedTransaction> {
+        (
+            address(),
+            any::<(u64, u64, u64, u64)>(),
+            payload(),
+            any::<u8>(),
+            transaction_authenticator(),
+        )
+            .prop_map(
+                |(
+                    sender,
+                    (sequence_number, max_gas_amount, gas_unit_price, expiration),
+                    payload,
+                    chain_id,
+                    authenticator,
+                )| {
+                    SignedTransaction::new_signed_transaction(
+                        RawTransaction::new(
+                            sender,
+                            sequence_number,
+                            payload,
+                            max_gas_amount,
+                            gas_unit_price,
+                            expiration,
+                            ChainId::new(chain_id),
+                        ),
+                        authenticator,
+                    )
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn test_signed_transaction_round_trip(txn in signed_transaction()) {
+            let converter = MoveConverter::new(&TestAbis);
+            let pending = converter.try_into_pending_transaction(txn.clone()).unwrap();
+            prop_assert_eq!(
+                aptos_crypto::HashValue::from(pending.hash),
+                txn.committed_hash()
+            );
+
+            let json = serde_json::to_string(&pending.request).unwrap();
+            let request: UserTransactionRequest = serde_json::from_str(&json).unwrap();
+            let converted = converter
+                .try_into_signed_transaction(request, txn.chain_id())
+                .unwrap();
+            prop_assert_eq!(&converted, &txn);
+            prop_assert_eq!(converted.committed_hash(), txn.committed_hash());
+        }
+
+        #[test]
+        fn test_transaction_signature_round_trip(auth in transaction_authenticator()) {
+            let json = serde_json::to_string(&TransactionSignature::from(&auth)).unwrap();
+            let signature: TransactionSignature = serde_json::from_str(&json).unwrap();
+            prop_assert_eq!(TransactionAuthenticator::try_from(signature).unwrap(), auth);
+        }
+    }
+
+    fn transfer_module() -> Vec<MoveModule> {
+        vec![MoveModule {
+            address: AccountAddress::ONE.into(),
+            name: "aptos_account".parse().unwrap(),
+            friends: vec![],
+            exposed_functions: vec![entry_function_abi(
+                "transfer",
+                0,
+                vec![move_type("&signer"), MoveType::Address, MoveType::U64],
+            )],
+            structs: vec![],
+        }]
+    }
+
+    #[test]
+    fn test_signature_survives_json() {
+        let key = private_key(1);
+        let raw_txn = RawTransaction::new_entry_function(
+            aptos_types::account_address::from_public_key(&Ed25519PublicKey::from(&key)),
+            7,
+            transfer(vec![
+                bcs::to_bytes(&AccountAddress::from_hex_literal("0x3").unwrap()).unwrap(),
+                bcs::to_bytes(&100u64).unwrap(),
+            ]),
+            1_000,
+            100,
+            1_700_000_030,
+            ChainId::test(),
+        );
+        let signature = key.sign(&raw_txn).unwrap();
+        let txn = SignedTransaction::new(raw_txn, Ed25519PublicKey::from(&key), signature);
+
+        let modules = transfer_module();
+        let converter = MoveConverter::new(modules.as_slice());
+        let request = converter.try_into_user_transaction_request(&txn).unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["payload"]["arguments"], json!(["0x3", "100"]));
+        assert_eq!(json["signature"]["type"], json!("ed25519_signature"));
+
+        let request: UserTransactionRequest = serde_json::from_value(json).unwrap();
+        let converted = converter
+            .try_into_signed_transaction(request, ChainId::test())
+            .unwrap();
+        assert!(converted.verify_signature().is_ok());
+        assert_eq!(converted, txn);
+
+        // Without the ABI, the arguments are shown as their BCS bytes.
+        let request = MoveConverter::new(&())
+            .try_into_user_transaction_request(&txn)
+            .unwrap();
+        let TransactionPayload::EntryFunctionPayload(payload) = &request.payload else {
+            panic!("expected an entry function payload");
+        };
+        assert_eq!(
+            payload.arguments,
+            vec![
+                json!("0x0000000000000000000000000000000000000000000000000000000000000003"),
+                json!("0x6400000000000000"),
+            ]
+        );
+        // And typed arguments are rejected when the ABI is unknown.
+        let mut typed = converter.try_into_user_transaction_request(&txn).unwrap();
+        typed.payload = TransactionPayload::EntryFunctionPayload(EntryFunctionPayload {
+            arguments: vec![json!("0x3"), json!("100")],
+            ..payload.clone()
+        });
+        let error = MoveConverter::new(&())
+            .try_into_signed_transaction(typed, ChainId::test())
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("arguments[1]"));
+    }
+
+    #[test]
+    fn test_script_arguments_survive_json() {
+        let key = private_key(1);
+        let raw_txn = RawTransaction::new_script(
+            aptos_types::account_address::from_public_key(&Ed25519PublicKey::from(&key)),
+            7,
+            Script::new(
+                TYPED_SCRIPT.to_vec(),
+                vec![],
+                vec![
+                    TransactionArgument::Serialized(bcs::to_bytes(&100u64).unwrap()),
+                    TransactionArgument::Address(AccountAddress::THREE),
+                    TransactionArgument::U8Vector(vec![]),
+                    TransactionArgument::Serialized(bcs::to_bytes("aptos").unwrap()),
+                ],
+            ),
+            1_000,
+            100,
+            1_700_000_030,
+            ChainId::test(),
+        );
+        let signature = key.sign(&raw_txn).unwrap();
+        let txn = SignedTransaction::new(raw_txn, Ed25519PublicKey::from(&key), signature);
+
+        // The `u64` passed serialized is shown like a `U64` argument, but converted back as it was
+        // signed, with or without the ABI of the script.
+        fn round_trip<R: AbiResolver>(converter: MoveConverter<R>, txn: &SignedTransaction) {
+            let request = converter.try_into_user_transaction_request(txn).unwrap();
+            let json = serde_json::to_value(&request).unwrap();
+            let request: UserTransactionRequest = serde_json::from_value(json).unwrap();
+            let converted = converter
+                .try_into_signed_transaction(request, ChainId::test())
+                .unwrap();
+            assert!(converted.verify_signature().is_ok());
+            assert_eq!(&converted, txn);
+        }
+        round_trip(MoveConverter::new(&TestAbis), &txn);
+        round_trip(MoveConverter::new(&()), &txn);
+
+        let converter = MoveConverter::new(&TestAbis);
+        let request = converter.try_into_user_transaction_request(&txn).unwrap();
+        let TransactionPayload::ScriptPayload(payload) = &request.payload else {
+            panic!("expected a script payload");
+        };
+        assert_eq!(
+            payload.arguments,
+            vec![json!("100"), json!("0x3"), json!("0x"), json!("aptos")]
+        );
+
+        // Arguments that differ from the signed ones are rejected.
+        let mut tampered = request.clone();
+        tampered.payload = TransactionPayload::ScriptPayload(ScriptPayload {
+            arguments: vec![json!("1000"), json!("0x3"), json!("0x"), json!("aptos")],
+            ..payload.clone()
+        });
+        let error = converter
+            .try_into_signed_transaction(tampered, ChainId::test())
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("arguments[0]"));
+
+        // Without them, the kinds are derived from the ABI.
+        let mut derived = request.clone();
+        derived.payload = TransactionPayload::ScriptPayload(ScriptPayload {
+            serialized_arguments: None,
+            ..payload.clone()
+        });
+        let converted = converter
+            .try_into_signed_transaction(derived, ChainId::test())
+            .unwrap();
+        assert!(converted.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_versioned_payload_without_nonce() {
+        let payload =
+            aptos_types::transaction::TransactionPayload::EntryFunction(EntryFunction::new(
+                ModuleId::new(AccountAddress::ONE, Identifier::new("test").unwrap()),
+                Identifier::new("unknown").unwrap(),
+                vec![],
+                vec![],
+            ))
+            .upgrade(None)
+            .unwrap();
+        assert!(MoveConverter::new(&())
+            .try_into_transaction_payload(payload)
+            .is_err());
+    }
+
+    #[test]
+    fn test_encode_entry_function_args() {
+        let arg_types = vec![
+            move_type("u64"),
+            move_type("u256"),
+            move_type("address"),
+            move_type("address"),
+            move_type("vector<u8>"),
+            move_type("vector<u8>"),
+            move_type("0x1::string::String"),
+            move_type("0x1::option::Option<u128>"),
+            move_type("0x1::option::Option<u128>"),
+            move_type("0x1::object::Object<0x1::fungible_asset::Metadata>"),
+            move_type("vector<address>"),
+        ];
+        let long = "0x000000000000000000000000000000000000000000000000000000000000000a";
+        let args = encode_entry_function_args(
+            &arg_types,
+            json!([
+                "18446744073709551615",
+                "1",
+                "0x1",
+                &long[2..],
+                "0xcafe",
+                "cafe",
+                "Aptos",
+                null,
+                "5",
+                { "inner": long },
+                ["0xa", long],
+            ]),
+        )
+        .unwrap();
+        let a = AccountAddress::from_hex_literal("0xa").unwrap();
+        assert_eq!(
+            args,
+            vec![
+                bcs::to_bytes(&u64::MAX).unwrap(),
+                bcs::to_bytes(&U256::one()).unwrap(),
+                bcs::to_bytes(&AccountAddress::ONE).unwrap(),
+                bcs::to_bytes(&a).unwrap(),
+                bcs::to_bytes(&vec![0xcau8, 0xfe]).unwrap(),
+                bcs::to_bytes(&b"cafe".to_vec()).unwrap(),
+                bcs::to_bytes("Aptos").unwrap(),
+                bcs::to_bytes(&None::<u128>).unwrap(),
+                bcs::to_bytes(&Some(5u128)).unwrap(),
+                bcs::to_bytes(&a).unwrap(),
+                bcs::to_bytes(&vec![a, a]).unwrap(),
+            ]
+        );
+
+        let err =
+            encode_entry_function_args(&[move_type("u64"), move_type("u64")], json!(["1", 2]))
+                .unwrap_err();
+        assert!(err.to_string().starts_with("parse arguments[1] failed"));
+        assert!(encode_entry_function_args(&[move_type("u64")], json!([])).is_err());
+        assert!(encode_entry_function_args(&[move_type("u64")], json!("1")).is_err());
     }
 }
@@ -1,6 +1,11 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::LedgerInfo;
+use anyhow::{ensure, Context, Result};
+use aptos_types::chain_id::ChainId;
+use std::str::FromStr;
+
 /// Chain ID of the current chain
 pub const X_APTOS_CHAIN_ID: &str = "X-Aptos-Chain-Id";
 /// Current epoch of the chain
@@ -22,3 +27,217 @@ pub const X_APTOS_CURSOR: &str = "X-Aptos-Cursor";
 pub const X_APTOS_GAS_USED: &str = "X-Aptos-Gas-Used";
 /// Provided by the client to identify what client it is.
 pub const X_APTOS_CLIENT: &str = "x-aptos-client";
+
+/// The state of the ledger a response was served at, as reported by the `X-Aptos-*` headers
+/// the API attaches to its responses
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerState {
+    pub chain_id: u8,
+    pub epoch: u64,
+    pub version: u64,
+    pub timestamp_usecs: u64,
+    pub oldest_ledger_version: u64,
+    pub block_height: u64,
+    pub oldest_block_height: u64,
+    /// The cursor of the next page, for paginated responses
+    pub cursor: Option<String>,
+    /// The gas used by a view function or a simulated transaction
+    pub gas_used: Option<u64>,
+}
+
+impl LedgerState {
+    /// Parse the ledger state from the response headers, whose names are matched
+    /// case-insensitively. Fails if a ledger header is missing or malformed, or if the headers
+    /// contradict each other.
+    pub fn from_headers<I, K, V>(headers: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let headers: Vec<(K, V)> = headers.into_iter().collect();
+        let get = |name: &str| {
+            headers
+                .iter()
+                .find(|(key, _)| key.as_ref().eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_ref())
+        };
+        let required = |name: &str| get(name).with_context(|| format!("missing header {}", name));
+
+        let state = Self {
+            chain_id: parse_header(X_APTOS_CHAIN_ID, required(X_APTOS_CHAIN_ID)?)?,
+            epoch: parse_header(X_APTOS_EPOCH, required(X_APTOS_EPOCH)?)?,
+            version: parse_header(X_APTOS_LEDGER_VERSION, required(X_APTOS_LEDGER_VERSION)?)?,
+            timestamp_usecs: parse_header(
+                X_APTOS_LEDGER_TIMESTAMP,
+                required(X_APTOS_LEDGER_TIMESTAMP)?,
+            )?,
+            oldest_ledger_version: parse_header(
+                X_APTOS_LEDGER_OLDEST_VERSION,
+                required(X_APTOS_LEDGER_OLDEST_VERSION)?,
+            )?,
+            block_height: parse_header(X_APTOS_BLOCK_HEIGHT, required(X_APTOS_BLOCK_HEIGHT)?)?,
+            oldest_block_height: parse_header(
+                X_APTOS_OLDEST_BLOCK_HEIGHT,
+                required(X_APTOS_OLDEST_BLOCK_HEIGHT)?,
+            )?,
+            cursor: get(X_APTOS_CURSOR).map(str::to_owned),
+            gas_used: get(X_APTOS_GAS_USED)
+                .map(|value| parse_header(X_APTOS_GAS_USED, value))
+                .transpose()?,
+        };
+        ensure!(
+            state.oldest_ledger_version <= state.version,
+            "oldest ledger version {} is after ledger version {}",
+            state.oldest_ledger_version,
+            state.version
+        );
+        ensure!(
+            state.oldest_block_height <= state.block_height,
+            "oldest block height {} is above block height {}",
+            state.oldest_block_height,
+            state.block_height
+        );
+        Ok(state)
+    }
+
+    pub fn ledger_info(&self) -> LedgerInfo {
+        LedgerInfo::new_ledger_info(
+            &ChainId::new(self.chain_id),
+            self.epoch,
+            self.version,
+            self.oldest_ledger_version,
+            self.oldest_block_height,
+            self.block_height,
+            self.timestamp_usecs,
+        )
+    }
+
+    /// Check that a response body describing the ledger, such as the index, was served at the
+    /// same ledger state as its headers
+    pub fn ensure_consistent_with(&self, info: &LedgerInfo) -> Result<()> {
+        let headers = self.ledger_info();
+        ensure!(
+            headers == *info,
+            "response headers report {:?} but the body reports {:?}",
+            headers,
+            info
+        );
+        Ok(())
+    }
+
+    pub fn ensure_chain_id(&self, chain_id: ChainId) -> Result<()> {
+        ensure!(
+            self.chain_id == chain_id.id(),
+            "response is from chain {}, expected chain {}",
+            self.chain_id,
+            chain_id
+        );
+        Ok(())
+    }
+
+    /// Check that the ledger is no more than `max_lag_usecs` behind `now_usecs`
+    pub fn ensure_fresh(&self, now_usecs: u64, max_lag_usecs: u64) -> Result<()> {
+        let lag = now_usecs.saturating_sub(self.timestamp_usecs);
+        ensure!(
+            lag <= max_lag_usecs,
+            "ledger at version {} is {}us behind, more than the allowed {}us",
+            self.version,
+            lag,
+            max_lag_usecs
+        );
+        Ok(())
+    }
+}
+
+fn parse_header<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value
+        .trim()
+        .parse()
+        .with_context(|| format!("invalid header {}: {:?}", name, value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{IndexResponse, RoleType};
+
+    fn headers() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("x-aptos-chain-id", "2"),
+            ("x-aptos-epoch", "9000"),
+            ("x-aptos-ledger-version", "6000000000"),
+            ("x-aptos-ledger-oldest-version", "5000000000"),
+            ("x-aptos-ledger-timestampusec", "1700000000000000"),
+            ("x-aptos-block-height", "400000000"),
+            ("x-aptos-oldest-block-height", "300000000"),
+            ("content-type", "application/json"),
+        ]
+    }
+
+    #[test]
+    fn test_from_headers() {
+        let state = LedgerState::from_headers(headers()).unwrap();
+        assert_eq!(state.chain_id, 2);
+        assert_eq!(state.version, 6_000_000_000);
+        assert_eq!(state.timestamp_usecs, 1_700_000_000_000_000);
+        assert_eq!(state.cursor, None);
+        assert_eq!(state.gas_used, None);
+        state.ensure_chain_id(ChainId::testnet()).unwrap();
+        assert!(state.ensure_chain_id(ChainId::mainnet()).is_err());
+        state
+            .ensure_fresh(1_700_000_005_000_000, 10_000_000)
+            .unwrap();
+        assert!(state
+            .ensure_fresh(1_700_000_020_000_000, 10_000_000)
+            .is_err());
+
+        let mut with_page = headers();
+        with_page.push((X_APTOS_CURSOR, "abc"));
+        with_page.push((X_APTOS_GAS_USED, "7"));
+        let state = LedgerState::from_headers(with_page).unwrap();
+        assert_eq!(state.cursor.as_deref(), Some("abc"));
+        assert_eq!(state.gas_used, Some(7));
+
+        let missing: Vec<_> = headers()
+            .into_iter()
+            .filter(|(name, _)| *name != "x-aptos-epoch")
+            .collect();
+        assert!(LedgerState::from_headers(missing).is_err());
+
+        let mut malformed = headers();
+        malformed[0].1 = "two";
+        assert!(LedgerState::from_headers(malformed).is_err());
+
+        let mut inconsistent = headers();
+        inconsistent[3].1 = "7000000000";
+        assert!(LedgerState::from_headers(inconsistent).is_err());
+    }
+
+    #[test]
+    fn test_consistent_with_index() {
+        let state = LedgerState::from_headers(headers()).unwrap();
+        let index: IndexResponse = serde_json::from_value(serde_json::json!({
+            "chain_id": 2,
+            "epoch": "9000",
+            "ledger_version": "6000000000",
+            "oldest_ledger_version": "5000000000",
+            "ledger_timestamp": "1700000000000000",
+            "node_role": "full_node",
+            "oldest_block_height": "300000000",
+            "block_height": "400000000",
+            "git_hash": null,
+        }))
+        .unwrap();
+        assert_eq!(index.node_role, RoleType::FullNode);
+        state.ensure_consistent_with(&index.ledger_info()).unwrap();
+
+        let mut behind = index.ledger_info();
+        behind.ledger_version = 5_999_999_999.into();
+        assert!(state.ensure_consistent_with(&behind).is_err());
+    }
+}
@@ -2,16 +2,44 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{LedgerInfo, U64};
-use aptos_config::config::RoleType;
-use poem_openapi::Object as PoemObject;
+// use aptos_config::config::RoleType;
+// use poem_openapi::Object as PoemObject;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The role of the node serving the API, mirroring `aptos_config::config::RoleType`
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleType {
+    Validator,
+    FullNode,
+}
+
+impl RoleType {
+    pub fn is_validator(self) -> bool {
+        self == RoleType::Validator
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RoleType::Validator => "validator",
+            RoleType::FullNode => "full_node",
+        }
+    }
+}
+
+impl fmt::Display for RoleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 
 // The data in IndexResponse is flattened into a single JSON map to offer
 // easier parsing for clients.
 
 /// The struct holding all data returned to the client by the
 /// index endpoint (i.e., GET "/").  Only for responding in JSON
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, PoemObject, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct IndexResponse {
     /// Chain ID of the current chain
     pub chain_id: u8,
@@ -46,12 +74,25 @@ impl IndexResponse {
             git_hash,
         }
     }
+
+    /// The ledger information the index reports, without the node details
+    pub fn ledger_info(&self) -> LedgerInfo {
+        LedgerInfo {
+            chain_id: self.chain_id,
+            epoch: self.epoch,
+            ledger_version: self.ledger_version,
+            oldest_ledger_version: self.oldest_ledger_version,
+            block_height: self.block_height,
+            oldest_block_height: self.oldest_block_height,
+            ledger_timestamp: self.ledger_timestamp,
+        }
+    }
 }
 
 /// The struct holding all data returned to the client by the
 /// index endpoint (i.e., GET "/").  This is just for the BCS response and
 /// cannot change
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, PoemObject, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct IndexResponseBcs {
     /// Chain ID of the current chain
     pub chain_id: u8,
@@ -77,4 +118,17 @@ impl IndexResponseBcs {
             node_role,
         }
     }
+
+    /// The ledger information the index reports, without the node details
+    pub fn ledger_info(&self) -> LedgerInfo {
+        LedgerInfo {
+            chain_id: self.chain_id,
+            epoch: self.epoch,
+            ledger_version: self.ledger_version,
+            oldest_ledger_version: self.oldest_ledger_version,
+            block_height: self.block_height,
+            oldest_block_height: self.oldest_block_height,
+            ledger_timestamp: self.ledger_timestamp,
+        }
+    }
 }
@@ -3,12 +3,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::U64;
-use aptos_types::{chain_id::ChainId, ledger_info::LedgerInfoWithSignatures};
-use poem_openapi::Object as PoemObject;
+use aptos_types::chain_id::ChainId;
+// use poem_openapi::Object as PoemObject;
 use serde::{Deserialize, Serialize};
 
 /// The Ledger information representing the current state of the chain
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, PoemObject)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct LedgerInfo {
     /// Chain ID of the current chain
     pub chain_id: u8,
@@ -21,24 +21,24 @@ pub struct LedgerInfo {
 }
 
 impl LedgerInfo {
-    pub fn new(
-        chain_id: &ChainId,
-        info: &LedgerInfoWithSignatures,
-        oldest_ledger_version: u64,
-        oldest_block_height: u64,
-        block_height: u64,
-    ) -> Self {
-        let ledger_info = info.ledger_info();
-        Self {
-            chain_id: chain_id.id(),
-            epoch: U64::from(ledger_info.epoch()),
-            ledger_version: ledger_info.version().into(),
-            oldest_ledger_version: oldest_ledger_version.into(),
-            block_height: block_height.into(),
-            oldest_block_height: oldest_block_height.into(),
-            ledger_timestamp: ledger_info.timestamp_usecs().into(),
-        }
-    }
+    // pub fn new(
+    //     chain_id: &ChainId,
+    //     info: &LedgerInfoWithSignatures,
+    //     oldest_ledger_version: u64,
+    //     oldest_block_height: u64,
+    //     block_height: u64,
+    // ) -> Self {
+    //     let ledger_info = info.ledger_info();
+    //     Self {
+    //         chain_id: chain_id.id(),
+    //         epoch: U64::from(ledger_info.epoch()),
+    //         ledger_version: ledger_info.version().into(),
+    //         oldest_ledger_version: oldest_ledger_version.into(),
+    //         block_height: block_height.into(),
+    //         oldest_block_height: oldest_block_height.into(),
+    //         ledger_timestamp: ledger_info.timestamp_usecs().into(),
+    //     }
+    // }
 
     pub fn new_ledger_info(
         chain_id: &ChainId,
@@ -60,6 +60,10 @@ impl LedgerInfo {
         }
     }
 
+    pub fn chain_id(&self) -> ChainId {
+        ChainId::new(self.chain_id)
+    }
+
     pub fn epoch(&self) -> u64 {
         self.epoch.into()
     }
@@ -75,4 +79,12 @@ impl LedgerInfo {
     pub fn timestamp(&self) -> u64 {
         self.ledger_timestamp.into()
     }
+
+    pub fn block_height(&self) -> u64 {
+        self.block_height.into()
+    }
+
+    pub fn oldest_block_height(&self) -> u64 {
+        self.oldest_block_height.into()
+    }
 }
@@ -6,33 +6,34 @@
 
 // mod account;
 mod address;
-// mod block;
-// mod bytecode;
-// mod convert;
+mod block;
+mod bytecode;
+mod convert;
 // mod derives;
 mod error;
 mod hash;
 mod headers;
-// mod index;
-// mod ledger_info;
+mod index;
+mod ledger_info;
 pub mod mime_types;
 pub mod move_types;
+mod registry;
 // mod state;
-// mod table;
+mod table;
 pub mod transaction;
 // mod view;
 mod wrappers;
 
 // pub use account::AccountData;
 pub use address::Address;
-// pub use block::{BcsBlock, Block};
-// pub use bytecode::Bytecode;
-// pub use convert::{new_vm_utf8_string, AsConverter, MoveConverter};
+pub use block::{BcsBlock, Block, BlockMetadataSummary};
+pub use bytecode::Bytecode;
+pub use convert::{encode_entry_function_args, new_vm_utf8_string, AbiResolver, MoveConverter};
 pub use error::{AptosError, AptosErrorCode};
 pub use hash::HashValue;
 pub use headers::*;
-// pub use index::{IndexResponse, IndexResponseBcs};
-// pub use ledger_info::LedgerInfo;
+pub use index::{IndexResponse, IndexResponseBcs, RoleType};
+pub use ledger_info::LedgerInfo;
 // pub use move_types::{
 //     verify_field_identifier, verify_function_identifier, verify_module_identifier, EntryFunctionId,
 //     HexEncodedBytes, MoveAbility, MoveFunction, MoveFunctionGenericTypeParam,
@@ -40,12 +41,21 @@ pub use headers::*;
 //     MoveScriptBytecode, MoveStruct, MoveStructField, MoveStructTag, MoveType, MoveValue,
 //     ResourceGroup, MAX_RECURSIVE_TYPES_ALLOWED, U128, U256, U64,
 // };
-pub use move_types::{U128, U256, U64};
+pub use move_types::{
+    EntryFunctionId, HexEncodedBytes, MoveFunction, MoveFunctionVisibility, MoveModule,
+    MoveModuleBytecode, MoveResource, MoveScriptBytecode, MoveStruct, MoveStructField,
+    MoveStructTag, MoveType, MoveValue, U128, U256, U64,
+};
 use serde::{Deserialize, Deserializer};
 // pub use state::RawStateValueRequest;
+pub use registry::{AbiRegistry, DecodedArgument, DecodedEntryFunction};
 use std::str::FromStr;
-// pub use table::{RawTableItemRequest, TableItemRequest};
-pub use transaction::PendingTransaction;
+pub use table::{RawTableItemRequest, Table, TableItemFetcher, TableItemRequest};
+pub use transaction::{
+    BlockMetadataTransaction, EntryFunctionPayload, PendingTransaction, ScriptPayload,
+    Transaction, TransactionInfo, TransactionOnChainData, TransactionPayload, UserTransaction,
+    UserTransactionRequest,
+};
 //     AccountSignature, BlockMetadataTransaction, DeleteModule, DeleteResource, DeleteTableItem,
 //     DirectWriteSet, Ed25519Signature, EncodeSubmissionRequest, EntryFunctionPayload, Event,
 //     FeePayerSignature, GasEstimation, GasEstimationBcs, GenesisPayload, GenesisTransaction,
@@ -2,11 +2,23 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::format_err;
-use move_binary_format::file_format::Ability;
+use crate::convert::{is_framework_struct, single_type_param};
+use anyhow::{bail, ensure, format_err};
+use move_binary_format::{
+    access::ModuleAccess,
+    file_format::{
+        Ability, AbilitySet, CompiledModule, CompiledScript, StructTypeParameter, Visibility,
+    },
+};
 use move_core_types::{
-    language_storage::{StructTag, TypeTag},
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag, TypeTag, CORE_CODE_ADDRESS},
     parser::{parse_struct_tag, parse_type_tag},
+    value::{
+        MoveFieldLayout, MoveStruct as VmMoveStruct, MoveStructLayout, MoveTypeLayout,
+        MoveValue as VmMoveValue,
+    },
 };
 // use poem_openapi::{types::Type, Enum, Object, Union};
 use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
@@ -19,7 +31,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::{wrappers::IdentifierWrapper, Address};
+use crate::{wrappers::IdentifierWrapper, Address, Bytecode};
 
 pub type ResourceGroup = BTreeMap<StructTag, Vec<u8>>;
 
@@ -42,6 +54,36 @@ pub struct MoveResource {
 //     }
 // }
 
+impl TryFrom<VmMoveStruct> for MoveResource {
+    type Error = anyhow::Error;
+
+    /// Converts a resource decorated with the types of its structs, as deserializing it with a
+    /// `MoveStructLayout::WithTypes` layout gives
+    fn try_from(s: VmMoveStruct) -> anyhow::Result<Self> {
+        let VmMoveStruct::WithTypes { _type_, .. } = &s else {
+            bail!("expect a struct decorated with its type");
+        };
+        Ok(Self {
+            typ: _type_.into(),
+            data: s.try_into()?,
+        })
+    }
+}
+
+impl MoveResource {
+    /// Converts a resource given its layout, which has to be decorated with the types of its
+    /// structs as a `MoveStructLayout::WithTypes` layout is
+    pub fn try_from_vm_struct(layout: &MoveStructLayout, s: VmMoveStruct) -> anyhow::Result<Self> {
+        let MoveStructLayout::WithTypes { type_, .. } = layout else {
+            bail!("expect a struct layout decorated with its type");
+        };
+        Ok(Self {
+            typ: type_.into(),
+            data: MoveStructValue::try_from_vm_struct(layout, s)?,
+        })
+    }
+}
+
 macro_rules! define_integer_type {
     ($n:ident, $t:ty, $d:literal) => {
         #[doc = $d]
@@ -228,6 +270,75 @@ pub struct MoveStructValue(pub BTreeMap<IdentifierWrapper, serde_json::Value>);
 //     }
 // }
 
+impl TryFrom<VmMoveStruct> for MoveStructValue {
+    type Error = anyhow::Error;
+
+    /// Converts a struct decorated with the field names of its structs, see
+    /// [`MoveValue::try_from`] for how its fields are converted
+    fn try_from(s: VmMoveStruct) -> anyhow::Result<Self> {
+        let mut map = BTreeMap::new();
+        let fields = match s {
+            VmMoveStruct::WithFields(fields)
+            | VmMoveStruct::WithTypes {
+                _fields: fields, ..
+            } => fields,
+            VmMoveStruct::WithVariantFields(name, _, fields) => {
+                map.insert(
+                    IdentifierWrapper::from_str("__variant__")?,
+                    MoveValue::String(name.to_string()).json()?,
+                );
+                fields
+            },
+            VmMoveStruct::Runtime(_) | VmMoveStruct::RuntimeVariant(..) => {
+                bail!("expect a struct decorated with its field names")
+            },
+        };
+        for (name, val) in fields {
+            map.insert(name.into(), MoveValue::try_from(val)?.json()?);
+        }
+        Ok(Self(map))
+    }
+}
+
+impl MoveStructValue {
+    /// Converts a struct given its layout, which has to be decorated with the field names of its
+    /// structs. The value itself may be in any representation.
+    pub fn try_from_vm_struct(layout: &MoveStructLayout, s: VmMoveStruct) -> anyhow::Result<Self> {
+        let mut map = BTreeMap::new();
+        let (variant, values) = s.into_optional_variant_and_fields();
+        let fields = match (layout, variant) {
+            (
+                MoveStructLayout::WithFields(fields) | MoveStructLayout::WithTypes { fields, .. },
+                None,
+            ) => fields,
+            (MoveStructLayout::WithVariants(variants), Some(tag)) => {
+                let variant = variants
+                    .get(tag as usize)
+                    .ok_or_else(|| format_err!("unknown variant {}", tag))?;
+                map.insert(
+                    IdentifierWrapper::from_str("__variant__")?,
+                    MoveValue::String(variant.name.to_string()).json()?,
+                );
+                &variant.fields
+            },
+            _ => bail!("expect a struct layout decorated with its field names"),
+        };
+        ensure!(
+            fields.len() == values.len(),
+            "expect {} fields, but got {}",
+            fields.len(),
+            values.len()
+        );
+        for (field, val) in fields.iter().zip(values) {
+            map.insert(
+                field.name.clone().into(),
+                MoveValue::try_from_vm_value(&field.layout, val)?.json()?,
+            );
+        }
+        Ok(Self(map))
+    }
+}
+
 /// An enum of the possible Move value types
 #[derive(Clone, Debug, PartialEq)]
 pub enum MoveValue {
@@ -240,11 +351,15 @@ pub enum MoveValue {
     U256(U256),
     /// A bool Move type
     Bool(bool),
-    // Address(Address),
+    /// An address Move type
+    Address(Address),
     /// A vector Move type.  May have any other [`MoveValue`] nested inside it
     Vector(Vec<MoveValue>),
     Bytes(HexEncodedBytes),
-    // Struct(MoveStructValue),
+    /// A struct Move type
+    Struct(MoveStructValue),
+    /// A `0x1::option::Option<T>`, shown as null when it is empty and as its value otherwise
+    Option(Option<Box<MoveValue>>),
     /// A string Move type
     String(String),
 }
@@ -254,28 +369,258 @@ impl MoveValue {
         Ok(serde_json::to_value(self)?)
     }
 
-    // pub fn is_utf8_string(st: &StructTag) -> bool {
-    //     st.address == CORE_CODE_ADDRESS
-    //         && st.name.to_string() == "String"
-    //         && st.module.to_string() == "string"
-    // }
-
-    // pub fn convert_utf8_string(v: AnnotatedMoveStruct) -> anyhow::Result<MoveValue> {
-    //     if let Some((_, AnnotatedMoveValue::Bytes(bytes))) = v.value.into_iter().next() {
-    //         match String::from_utf8(bytes.clone()) {
-    //             Ok(string) => Ok(MoveValue::String(string)),
-    //             Err(_) => {
-    //                 // There's no real use in logging the error, since this is only done on output conversion
-    //                 Ok(MoveValue::String(format!(
-    //                     "Unparsable utf-8 {}",
-    //                     HexEncodedBytes(bytes)
-    //                 )))
-    //             }
-    //         }
-    //     } else {
-    //         bail!("expect string::String, but failed to decode struct value");
-    //     }
-    // }
+    pub fn is_utf8_string(st: &StructTag) -> bool {
+        st.address == CORE_CODE_ADDRESS
+            && st.name.to_string() == "String"
+            && st.module.to_string() == "string"
+    }
+
+    pub fn convert_utf8_string(
+        fields: Vec<(Identifier, VmMoveValue)>,
+    ) -> anyhow::Result<MoveValue> {
+        if let Some((_, VmMoveValue::Vector(bytes))) = fields.into_iter().next() {
+            let bytes = VmMoveValue::vec_to_vec_u8(bytes)?;
+            match String::from_utf8(bytes.clone()) {
+                Ok(string) => Ok(MoveValue::String(string)),
+                Err(_) => {
+                    // There's no real use in logging the error, since this is only done on output conversion
+                    Ok(MoveValue::String(format!(
+                        "Unparsable utf-8 {}",
+                        HexEncodedBytes(bytes)
+                    )))
+                },
+            }
+        } else {
+            bail!("expect string::String, but failed to decode struct value");
+        }
+    }
+
+    /// Parse the JSON form of a value of the given type, as returned by the REST API or
+    /// accepted as an entry function argument
+    ///
+    /// Options are accepted as null, as their value, or in the `{"vec": [value]}` form, and
+    /// objects as their address or in the `{"inner": address}` form. The fields of other structs
+    /// are kept as JSON, as their types depend on the struct's module.
+    pub fn try_from_json(typ: &MoveType, val: serde_json::Value) -> anyhow::Result<Self> {
+        use serde_json::Value;
+
+        Ok(match typ {
+            MoveType::Bool => MoveValue::Bool(serde_json::from_value(val)?),
+            MoveType::U8 => MoveValue::U8(serde_json::from_value(val)?),
+            MoveType::U16 => MoveValue::U16(serde_json::from_value(val)?),
+            MoveType::U32 => MoveValue::U32(serde_json::from_value(val)?),
+            MoveType::U64 => MoveValue::U64(serde_json::from_value(val)?),
+            MoveType::U128 => MoveValue::U128(serde_json::from_value(val)?),
+            MoveType::U256 => MoveValue::U256(serde_json::from_value(val)?),
+            MoveType::Address | MoveType::Signer => {
+                MoveValue::Address(serde_json::from_value(val)?)
+            },
+            MoveType::Vector { items } => {
+                if matches!(**items, MoveType::U8) {
+                    MoveValue::Bytes(serde_json::from_value(val)?)
+                } else if let Value::Array(list) = val {
+                    MoveValue::Vector(
+                        list.into_iter()
+                            .map(|v| MoveValue::try_from_json(items, v))
+                            .collect::<anyhow::Result<_>>()?,
+                    )
+                } else {
+                    bail!("expected vector<{}>, but got: {:?}", items, val)
+                }
+            },
+            MoveType::Struct(tag) if is_framework_struct(tag, "string", "String") => {
+                let Value::String(string) = val else {
+                    bail!("failed to parse string::String.");
+                };
+                MoveValue::String(string)
+            },
+            MoveType::Struct(tag) if is_framework_struct(tag, "option", "Option") => {
+                let item = single_type_param(tag)?;
+                let value = match val {
+                    Value::Null => None,
+                    Value::Object(mut fields) if fields.contains_key("vec") => {
+                        let Some(Value::Array(mut values)) = fields.remove("vec") else {
+                            bail!("expect an array in field vec of {}", tag);
+                        };
+                        ensure!(values.len() <= 1, "expect at most one value in {}", tag);
+                        values.pop()
+                    },
+                    value => Some(value),
+                };
+                MoveValue::Option(
+                    value
+                        .map(|v| MoveValue::try_from_json(item, v).map(Box::new))
+                        .transpose()?,
+                )
+            },
+            MoveType::Struct(tag) if is_framework_struct(tag, "object", "Object") => {
+                let address = match val {
+                    Value::Object(mut fields) => fields
+                        .remove("inner")
+                        .ok_or_else(|| format_err!("field inner not found."))?,
+                    address => address,
+                };
+                let address = MoveValue::try_from_json(&MoveType::Address, address)?;
+                MoveValue::Struct(MoveStructValue(BTreeMap::from([(
+                    IdentifierWrapper::from_str("inner")?,
+                    address.json()?,
+                )])))
+            },
+            MoveType::Struct(tag) => {
+                let Value::Object(fields) = val else {
+                    bail!("expected struct {}, but got: {:?}", tag, val);
+                };
+                MoveValue::Struct(MoveStructValue(
+                    fields
+                        .into_iter()
+                        .map(|(name, v)| Ok((IdentifierWrapper::from_str(&name)?, v)))
+                        .collect::<anyhow::Result<_>>()?,
+                ))
+            },
+            MoveType::GenericTypeParam { .. }
+            | MoveType::Reference { .. }
+            | MoveType::Unparsable(_) => bail!("unsupported value type {}", typ),
+        })
+    }
+}
+
+impl MoveValue {
+    /// Converts a value given its layout, which has to be decorated with the field names of its
+    /// structs. Layouts decorated with the struct types, as `MoveStructLayout::WithTypes` ones
+    /// are, also give strings, options and objects their API form.
+    pub fn try_from_vm_value(layout: &MoveTypeLayout, val: VmMoveValue) -> anyhow::Result<Self> {
+        Ok(match (layout, val) {
+            (_, VmMoveValue::U8(v)) => MoveValue::U8(v),
+            (_, VmMoveValue::U16(v)) => MoveValue::U16(v),
+            (_, VmMoveValue::U32(v)) => MoveValue::U32(v),
+            (_, VmMoveValue::U64(v)) => MoveValue::U64(U64(v)),
+            (_, VmMoveValue::U128(v)) => MoveValue::U128(U128(v)),
+            (_, VmMoveValue::U256(v)) => MoveValue::U256(U256(v)),
+            (_, VmMoveValue::Bool(v)) => MoveValue::Bool(v),
+            (_, VmMoveValue::Address(v) | VmMoveValue::Signer(v)) => MoveValue::Address(v.into()),
+            (MoveTypeLayout::Vector(item), VmMoveValue::Vector(vals)) => {
+                if matches!(**item, MoveTypeLayout::U8) {
+                    MoveValue::Bytes(HexEncodedBytes(VmMoveValue::vec_to_vec_u8(vals)?))
+                } else {
+                    MoveValue::Vector(
+                        vals.into_iter()
+                            .map(|v| MoveValue::try_from_vm_value(item, v))
+                            .collect::<anyhow::Result<_>>()?,
+                    )
+                }
+            },
+            (MoveTypeLayout::Struct(layout), VmMoveValue::Struct(s)) => {
+                MoveValue::try_from_vm_struct(layout, s)?
+            },
+            (MoveTypeLayout::Native(_, layout), val) => MoveValue::try_from_vm_value(layout, val)?,
+            (layout, val) => bail!("unexpected value {:?} for layout {:?}", val, layout),
+        })
+    }
+
+    fn try_from_vm_struct(layout: &MoveStructLayout, s: VmMoveStruct) -> anyhow::Result<Self> {
+        let MoveStructLayout::WithTypes { type_, fields } = layout else {
+            return Ok(MoveValue::Struct(MoveStructValue::try_from_vm_struct(
+                layout, s,
+            )?));
+        };
+        let tag = MoveStructTag::from(type_);
+        Ok(if is_framework_struct(&tag, "string", "String") {
+            let (_, values) = s.into_optional_variant_and_fields();
+            MoveValue::convert_utf8_string(
+                fields.iter().map(|f| f.name.clone()).zip(values).collect(),
+            )?
+        } else if is_framework_struct(&tag, "option", "Option") {
+            let (_, values) = s.into_optional_variant_and_fields();
+            let (
+                [MoveFieldLayout {
+                    layout: MoveTypeLayout::Vector(item),
+                    ..
+                }],
+                Ok([VmMoveValue::Vector(mut vals)]),
+            ) = (fields.as_slice(), <[_; 1]>::try_from(values))
+            else {
+                bail!("expect option::Option, but failed to decode struct value");
+            };
+            ensure!(vals.len() <= 1, "expect at most one value in {}", tag);
+            MoveValue::Option(
+                vals.pop()
+                    .map(|v| MoveValue::try_from_vm_value(item, v).map(Box::new))
+                    .transpose()?,
+            )
+        } else if is_framework_struct(&tag, "object", "Object") {
+            let (_, values) = s.into_optional_variant_and_fields();
+            let Ok([VmMoveValue::Address(inner)]) = <[_; 1]>::try_from(values) else {
+                bail!("expect object::Object, but failed to decode struct value");
+            };
+            MoveValue::Struct(MoveStructValue(BTreeMap::from([(
+                IdentifierWrapper::from_str("inner")?,
+                MoveValue::Address(inner.into()).json()?,
+            )])))
+        } else {
+            MoveValue::Struct(MoveStructValue::try_from_vm_struct(layout, s)?)
+        })
+    }
+}
+
+/// Converts a value decorated with the field names of its structs, without its layout. Structs
+/// decorated with their types also give strings, options and objects their API form.
+///
+/// A decorated vector does not carry its element type, so only a non-empty `vector<u8>` is shown
+/// as hex bytes, an empty one is shown as `[]`. Use [`MoveValue::try_from_vm_value`] with the
+/// layout to show it as `"0x"`.
+impl TryFrom<VmMoveValue> for MoveValue {
+    type Error = anyhow::Error;
+
+    fn try_from(val: VmMoveValue) -> anyhow::Result<Self> {
+        Ok(match val {
+            VmMoveValue::U8(v) => MoveValue::U8(v),
+            VmMoveValue::U16(v) => MoveValue::U16(v),
+            VmMoveValue::U32(v) => MoveValue::U32(v),
+            VmMoveValue::U64(v) => MoveValue::U64(U64(v)),
+            VmMoveValue::U128(v) => MoveValue::U128(U128(v)),
+            VmMoveValue::U256(v) => MoveValue::U256(U256(v)),
+            VmMoveValue::Bool(v) => MoveValue::Bool(v),
+            VmMoveValue::Address(v) | VmMoveValue::Signer(v) => MoveValue::Address(v.into()),
+            VmMoveValue::Vector(vals)
+                if !vals.is_empty() && vals.iter().all(|v| matches!(v, VmMoveValue::U8(_))) =>
+            {
+                MoveValue::Bytes(HexEncodedBytes(VmMoveValue::vec_to_vec_u8(vals)?))
+            },
+            VmMoveValue::Vector(vals) => MoveValue::Vector(
+                vals.into_iter()
+                    .map(MoveValue::try_from)
+                    .collect::<anyhow::Result<_>>()?,
+            ),
+            VmMoveValue::Struct(VmMoveStruct::WithTypes { _type_, _fields }) => {
+                let tag = MoveStructTag::from(&_type_);
+                if is_framework_struct(&tag, "string", "String") {
+                    MoveValue::convert_utf8_string(_fields)?
+                } else if is_framework_struct(&tag, "option", "Option") {
+                    let Ok([(_, VmMoveValue::Vector(mut vals))]) = <[_; 1]>::try_from(_fields)
+                    else {
+                        bail!("expect option::Option, but failed to decode struct value");
+                    };
+                    ensure!(vals.len() <= 1, "expect at most one value in {}", tag);
+                    MoveValue::Option(
+                        vals.pop()
+                            .map(|v| MoveValue::try_from(v).map(Box::new))
+                            .transpose()?,
+                    )
+                } else if is_framework_struct(&tag, "object", "Object") {
+                    let Ok([(_, VmMoveValue::Address(inner))]) = <[_; 1]>::try_from(_fields) else {
+                        bail!("expect object::Object, but failed to decode struct value");
+                    };
+                    MoveValue::Struct(MoveStructValue(BTreeMap::from([(
+                        IdentifierWrapper::from_str("inner")?,
+                        MoveValue::Address(inner.into()).json()?,
+                    )])))
+                } else {
+                    MoveValue::Struct(VmMoveStruct::WithTypes { _type_, _fields }.try_into()?)
+                }
+            },
+            VmMoveValue::Struct(s) => MoveValue::Struct(s.try_into()?),
+        })
+    }
 }
 
 // impl TryFrom<AnnotatedMoveValue> for MoveValue {
@@ -335,10 +680,11 @@ impl Serialize for MoveValue {
             MoveValue::U128(v) => v.serialize(serializer),
             MoveValue::U256(v) => v.serialize(serializer),
             MoveValue::Bool(v) => v.serialize(serializer),
-            // MoveValue::Address(v) => v.serialize(serializer),
+            MoveValue::Address(v) => v.serialize(serializer),
             MoveValue::Vector(v) => v.serialize(serializer),
             MoveValue::Bytes(v) => v.serialize(serializer),
-            // MoveValue::Struct(v) => v.serialize(serializer),
+            MoveValue::Struct(v) => v.serialize(serializer),
+            MoveValue::Option(v) => v.serialize(serializer),
             MoveValue::String(v) => v.serialize(serializer),
         }
     }
@@ -711,7 +1057,7 @@ impl TryFrom<MoveType> for TypeTag {
             MoveType::Address => TypeTag::Address,
             MoveType::Signer => TypeTag::Signer,
             MoveType::Vector { items } => TypeTag::Vector(Box::new((*items).try_into()?)),
-            // MoveType::Struct(v) => TypeTag::Struct(Box::new(v.try_into()?)),
+            MoveType::Struct(v) => TypeTag::Struct(Box::new(v.try_into()?)),
             MoveType::GenericTypeParam { index: _ } => TypeTag::Address, // Dummy type, allows for Object<T>
             _ => {
                 return Err(anyhow::anyhow!(
@@ -737,40 +1083,62 @@ pub struct MoveModule {
     pub structs: Vec<MoveStruct>,
 }
 
-// impl From<CompiledModule> for MoveModule {
-//     fn from(m: CompiledModule) -> Self {
-//         let (address, name) = <(AccountAddress, Identifier)>::from(m.self_id());
-//         Self {
-//             address: address.into(),
-//             name: name.into(),
-//             friends: m
-//                 .immediate_friends()
-//                 .into_iter()
-//                 .map(|f| f.into())
-//                 .collect(),
-//             exposed_functions: m
-//                 .function_defs
-//                 .iter()
-//                 // Return all entry or public functions.
-//                 // Private entry functions are still callable by entry function transactions so
-//                 // they should be included.
-//                 .filter(|def| {
-//                     def.is_entry
-//                         || match def.visibility {
-//                             Visibility::Public | Visibility::Friend => true,
-//                             Visibility::Private => false,
-//                         }
-//                 })
-//                 .map(|def| m.new_move_function(def))
-//                 .collect(),
-//             structs: m
-//                 .struct_defs
-//                 .iter()
-//                 .map(|def| m.new_move_struct(def))
-//                 .collect(),
-//         }
-//     }
-// }
+impl From<CompiledModule> for MoveModule {
+    fn from(m: CompiledModule) -> Self {
+        let (address, name) = <(AccountAddress, Identifier)>::from(m.self_id());
+        Self {
+            address: address.into(),
+            name: name.into(),
+            friends: m
+                .immediate_friends()
+                .into_iter()
+                .map(|f| f.into())
+                .collect(),
+            exposed_functions: m
+                .function_defs
+                .iter()
+                // Return all entry or public functions.
+                // Private entry functions are still callable by entry function transactions so
+                // they should be included.
+                .filter(|def| {
+                    def.is_entry
+                        || match def.visibility {
+                            Visibility::Public | Visibility::Friend => true,
+                            Visibility::Private => false,
+                        }
+                })
+                .map(|def| m.new_move_function(def))
+                .collect(),
+            structs: m
+                .struct_defs
+                .iter()
+                .map(|def| m.new_move_struct(def))
+                .collect(),
+        }
+    }
+}
+
+impl MoveModule {
+    /// The functions that can be called by entry function transactions
+    pub fn entry_functions(&self) -> impl Iterator<Item = &MoveFunction> {
+        self.exposed_functions.iter().filter(|f| f.is_entry)
+    }
+
+    /// The functions that can be called with the `/view` endpoint
+    pub fn view_functions(&self) -> impl Iterator<Item = &MoveFunction> {
+        self.exposed_functions.iter().filter(|f| f.is_view)
+    }
+
+    pub fn find_function(&self, name: &str) -> Option<&MoveFunction> {
+        self.exposed_functions
+            .iter()
+            .find(|f| f.name.as_str() == name)
+    }
+
+    pub fn find_struct(&self, name: &str) -> Option<&MoveStruct> {
+        self.structs.iter().find(|s| s.name.as_str() == name)
+    }
+}
 
 /// A Move module Id
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -785,21 +1153,21 @@ pub struct MoveModuleId {
 //     }
 // }
 
-// impl From<ModuleId> for MoveModuleId {
-//     fn from(id: ModuleId) -> Self {
-//         let (address, name) = <(AccountAddress, Identifier)>::from(id);
-//         Self {
-//             address: address.into(),
-//             name: name.into(),
-//         }
-//     }
-// }
+impl From<ModuleId> for MoveModuleId {
+    fn from(id: ModuleId) -> Self {
+        let (address, name) = <(AccountAddress, Identifier)>::from(id);
+        Self {
+            address: address.into(),
+            name: name.into(),
+        }
+    }
+}
 
-// impl From<MoveModuleId> for ModuleId {
-//     fn from(id: MoveModuleId) -> Self {
-//         ModuleId::new(id.address.into(), id.name.into())
-//     }
-// }
+impl From<MoveModuleId> for ModuleId {
+    fn from(id: MoveModuleId) -> Self {
+        ModuleId::new(id.address.into(), id.name.into())
+    }
+}
 
 impl fmt::Display for MoveModuleId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -929,18 +1297,18 @@ pub struct MoveStructGenericTypeParam {
     pub is_phantom: bool,
 }
 
-// impl From<&StructTypeParameter> for MoveStructGenericTypeParam {
-//     fn from(param: &StructTypeParameter) -> Self {
-//         Self {
-//             constraints: param
-//                 .constraints
-//                 .into_iter()
-//                 .map(MoveAbility::from)
-//                 .collect(),
-//             is_phantom: param.is_phantom,
-//         }
-//     }
-// }
+impl From<&StructTypeParameter> for MoveStructGenericTypeParam {
+    fn from(param: &StructTypeParameter) -> Self {
+        Self {
+            constraints: param
+                .constraints
+                .into_iter()
+                .map(MoveAbility::from)
+                .collect(),
+            is_phantom: param.is_phantom,
+        }
+    }
+}
 
 /// Move struct field
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -970,28 +1338,28 @@ pub struct MoveFunction {
     pub return_: Vec<MoveType>,
 }
 
-// impl From<&CompiledScript> for MoveFunction {
-//     fn from(script: &CompiledScript) -> Self {
-//         Self {
-//             name: Identifier::new("main").unwrap().into(),
-//             visibility: MoveFunctionVisibility::Public,
-//             is_entry: true,
-//             is_view: false,
-//             generic_type_params: script
-//                 .type_parameters
-//                 .iter()
-//                 .map(MoveFunctionGenericTypeParam::from)
-//                 .collect(),
-//             params: script
-//                 .signature_at(script.parameters)
-//                 .0
-//                 .iter()
-//                 .map(|s| script.new_move_type(s))
-//                 .collect(),
-//             return_: vec![],
-//         }
-//     }
-// }
+impl From<&CompiledScript> for MoveFunction {
+    fn from(script: &CompiledScript) -> Self {
+        Self {
+            name: Identifier::new("main").unwrap().into(),
+            visibility: MoveFunctionVisibility::Public,
+            is_entry: true,
+            is_view: false,
+            generic_type_params: script
+                .type_parameters
+                .iter()
+                .map(MoveFunctionGenericTypeParam::from)
+                .collect(),
+            params: script
+                .signature_at(script.parameters)
+                .0
+                .iter()
+                .map(|s| script.new_move_type(s))
+                .collect(),
+            return_: vec![],
+        }
+    }
+}
 
 /// Move function visibility
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -1006,25 +1374,25 @@ pub enum MoveFunctionVisibility {
     Friend,
 }
 
-// impl From<Visibility> for MoveFunctionVisibility {
-//     fn from(v: Visibility) -> Self {
-//         match &v {
-//             Visibility::Private => Self::Private,
-//             Visibility::Public => Self::Public,
-//             Visibility::Friend => Self::Friend,
-//         }
-//     }
-// }
+impl From<Visibility> for MoveFunctionVisibility {
+    fn from(v: Visibility) -> Self {
+        match &v {
+            Visibility::Private => Self::Private,
+            Visibility::Public => Self::Public,
+            Visibility::Friend => Self::Friend,
+        }
+    }
+}
 
-// impl From<MoveFunctionVisibility> for Visibility {
-//     fn from(v: MoveFunctionVisibility) -> Self {
-//         match &v {
-//             MoveFunctionVisibility::Private => Self::Private,
-//             MoveFunctionVisibility::Public => Self::Public,
-//             MoveFunctionVisibility::Friend => Self::Friend,
-//         }
-//     }
-// }
+impl From<MoveFunctionVisibility> for Visibility {
+    fn from(v: MoveFunctionVisibility) -> Self {
+        match &v {
+            MoveFunctionVisibility::Private => Self::Private,
+            MoveFunctionVisibility::Public => Self::Public,
+            MoveFunctionVisibility::Friend => Self::Friend,
+        }
+    }
+}
 
 /// Move function generic type param
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -1033,13 +1401,13 @@ pub struct MoveFunctionGenericTypeParam {
     pub constraints: Vec<MoveAbility>,
 }
 
-// impl From<&AbilitySet> for MoveFunctionGenericTypeParam {
-//     fn from(constraints: &AbilitySet) -> Self {
-//         Self {
-//             constraints: constraints.into_iter().map(MoveAbility::from).collect(),
-//         }
-//     }
-// }
+impl From<&AbilitySet> for MoveFunctionGenericTypeParam {
+    fn from(constraints: &AbilitySet) -> Self {
+        Self {
+            constraints: constraints.into_iter().map(MoveAbility::from).collect(),
+        }
+    }
+}
 
 /// Move module bytecode along with it's ABI
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -1061,27 +1429,27 @@ pub struct MoveModuleBytecode {
 //     }
 // }
 
-// impl MoveModuleBytecode {
-//     pub fn new(bytes: Vec<u8>) -> Self {
-//         Self {
-//             bytecode: bytes.into(),
-//             abi: None,
-//         }
-//     }
+impl MoveModuleBytecode {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            bytecode: bytes.into(),
+            abi: None,
+        }
+    }
 
-//     #[allow(clippy::unnecessary_fallible_conversions)]
-//     pub fn try_parse_abi(mut self) -> anyhow::Result<Self> {
-//         if self.abi.is_none() {
-//             // Ignore error, because it is possible a transaction module payload contains
-//             // invalid bytecode.
-//             // So we ignore the error and output bytecode without abi.
-//             if let Ok(module) = CompiledModule::deserialize(self.bytecode.inner()) {
-//                 self.abi = Some(module.try_into()?);
-//             }
-//         }
-//         Ok(self)
-//     }
-// }
+    #[allow(clippy::unnecessary_fallible_conversions)]
+    pub fn try_parse_abi(mut self) -> anyhow::Result<Self> {
+        if self.abi.is_none() {
+            // Ignore error, because it is possible a transaction module payload contains
+            // invalid bytecode.
+            // So we ignore the error and output bytecode without abi.
+            if let Ok(module) = CompiledModule::deserialize(self.bytecode.inner()) {
+                self.abi = Some(module.try_into()?);
+            }
+        }
+        Ok(self)
+    }
+}
 
 // impl From<Module> for MoveModuleBytecode {
 //     fn from(m: Module) -> Self {
@@ -1109,26 +1477,26 @@ pub struct MoveScriptBytecode {
 //     }
 // }
 
-// impl MoveScriptBytecode {
-//     pub fn new(bytes: Vec<u8>) -> Self {
-//         Self {
-//             bytecode: bytes.into(),
-//             abi: None,
-//         }
-//     }
+impl MoveScriptBytecode {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            bytecode: bytes.into(),
+            abi: None,
+        }
+    }
 
-//     pub fn try_parse_abi(mut self) -> Self {
-//         if self.abi.is_none() {
-//             // ignore error, because it is possible a transaction script payload contains
-//             // invalid bytecode.
-//             // So we ignore the error and output bytecode without abi.
-//             if let Ok(script) = CompiledScript::deserialize(self.bytecode.inner()) {
-//                 self.abi = Some((&script).into());
-//             }
-//         }
-//         self
-//     }
-// }
+    pub fn try_parse_abi(mut self) -> Self {
+        if self.abi.is_none() {
+            // ignore error, because it is possible a transaction script payload contains
+            // invalid bytecode.
+            // So we ignore the error and output bytecode without abi.
+            if let Ok(script) = CompiledScript::deserialize(self.bytecode.inner()) {
+                self.abi = Some((&script).into());
+            }
+        }
+        self
+    }
+}
 
 /// Entry function id
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -1210,3 +1578,277 @@ impl fmt::Display for EntryFunctionId {
 //         Ok(())
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::value::MoveVariantLayout;
+    use serde_json::json;
+
+    fn struct_tag(s: &str) -> StructTag {
+        parse_struct_tag(s).unwrap()
+    }
+
+    fn field(name: &str, layout: MoveTypeLayout) -> MoveFieldLayout {
+        MoveFieldLayout::new(Identifier::new(name).unwrap(), layout)
+    }
+
+    fn with_types(type_: &str, fields: Vec<MoveFieldLayout>) -> MoveTypeLayout {
+        MoveTypeLayout::Struct(MoveStructLayout::with_types(struct_tag(type_), fields))
+    }
+
+    fn runtime(fields: Vec<VmMoveValue>) -> VmMoveValue {
+        VmMoveValue::Struct(VmMoveStruct::Runtime(fields))
+    }
+
+    fn vm_bytes(bytes: &[u8]) -> VmMoveValue {
+        VmMoveValue::Vector(bytes.iter().copied().map(VmMoveValue::U8).collect())
+    }
+
+    #[test]
+    fn test_vm_value_to_json() {
+        let bytes = || MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8));
+        let string_layout = with_types("0x1::string::String", vec![field("bytes", bytes())]);
+        let option_layout = with_types(
+            "0x1::option::Option<u64>",
+            vec![field(
+                "vec",
+                MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U64)),
+            )],
+        );
+        let layout = MoveStructLayout::with_types(
+            struct_tag("0xcafe::pet::Pet"),
+            vec![
+                field("owner", MoveTypeLayout::Address),
+                field("name", string_layout.clone()),
+                field("age", option_layout.clone()),
+                field("vet", option_layout),
+                field(
+                    "collar",
+                    with_types(
+                        "0x1::object::Object<0x4::token::Token>",
+                        vec![field("inner", MoveTypeLayout::Address)],
+                    ),
+                ),
+                field("chip", bytes()),
+                field("tag", bytes()),
+                field(
+                    "tricks",
+                    MoveTypeLayout::Vector(Box::new(string_layout.clone())),
+                ),
+                field("toys", MoveTypeLayout::Vector(Box::new(string_layout))),
+                field(
+                    "kind",
+                    MoveTypeLayout::Struct(MoveStructLayout::with_variants(vec![
+                        MoveVariantLayout {
+                            name: Identifier::new("Cat").unwrap(),
+                            fields: vec![],
+                        },
+                        MoveVariantLayout {
+                            name: Identifier::new("Dog").unwrap(),
+                            fields: vec![field("good", MoveTypeLayout::Bool)],
+                        },
+                    ])),
+                ),
+            ],
+        );
+        let string = |s: &str| runtime(vec![vm_bytes(s.as_bytes())]);
+        let resource = VmMoveStruct::Runtime(vec![
+            VmMoveValue::Address(AccountAddress::TWO),
+            string("Rex"),
+            runtime(vec![VmMoveValue::Vector(vec![VmMoveValue::U64(3)])]),
+            runtime(vec![VmMoveValue::Vector(vec![])]),
+            runtime(vec![VmMoveValue::Address(AccountAddress::THREE)]),
+            vm_bytes(&[0xab, 0xcd]),
+            vm_bytes(&[]),
+            VmMoveValue::Vector(vec![string("sit"), string("roll")]),
+            VmMoveValue::Vector(vec![]),
+            VmMoveValue::Struct(VmMoveStruct::RuntimeVariant(
+                1,
+                vec![VmMoveValue::Bool(true)],
+            )),
+        ]);
+        let decorated =
+            VmMoveStruct::simple_deserialize(&bcs::to_bytes(&resource).unwrap(), &layout).unwrap();
+        assert!(matches!(decorated, VmMoveStruct::WithTypes { .. }));
+
+        let expected = json!({
+            "owner": "0x2",
+            "name": "Rex",
+            "age": "3",
+            "vet": null,
+            "collar": { "inner": "0x3" },
+            "chip": "0xabcd",
+            "tag": "0x",
+            "tricks": ["sit", "roll"],
+            "toys": [],
+            "kind": { "__variant__": "Dog", "good": true },
+        });
+
+        // The layout gives the same form to runtime and decorated values.
+        for resource in [resource, decorated.clone()] {
+            let resource = MoveResource::try_from_vm_struct(&layout, resource).unwrap();
+            assert_eq!(resource.typ.to_string(), "0xcafe::pet::Pet");
+            assert_eq!(serde_json::to_value(&resource.data).unwrap(), expected);
+        }
+
+        // Without the layout, an empty `vector<u8>` cannot be told apart from other vectors.
+        let resource = MoveResource::try_from(decorated).unwrap();
+        assert_eq!(resource.typ.to_string(), "0xcafe::pet::Pet");
+        let mut expected = expected;
+        expected["tag"] = json!([]);
+        assert_eq!(serde_json::to_value(&resource.data).unwrap(), expected);
+        assert!(MoveResource::try_from(VmMoveStruct::WithFields(vec![])).is_err());
+        assert!(MoveStructValue::try_from(VmMoveStruct::Runtime(vec![])).is_err());
+
+        // Runtime layouts have no field names to show, nor a type for the resource.
+        assert!(MoveValue::try_from_vm_value(
+            &MoveTypeLayout::Struct(MoveStructLayout::new(vec![MoveTypeLayout::U8])),
+            runtime(vec![VmMoveValue::U8(1)])
+        )
+        .is_err());
+        let layout = MoveStructLayout::with_fields(vec![field("x", MoveTypeLayout::U8)]);
+        assert!(MoveStructValue::try_from_vm_struct(
+            &layout,
+            VmMoveStruct::Runtime(vec![VmMoveValue::U8(1)])
+        )
+        .is_ok());
+        assert!(MoveResource::try_from_vm_struct(
+            &layout,
+            VmMoveStruct::Runtime(vec![VmMoveValue::U8(1)])
+        )
+        .is_err());
+        // Values have to match their layout.
+        assert!(
+            MoveStructValue::try_from_vm_struct(&layout, VmMoveStruct::Runtime(vec![])).is_err()
+        );
+        assert!(MoveValue::try_from_vm_value(&MoveTypeLayout::U8, vm_bytes(&[1])).is_err());
+    }
+
+    #[test]
+    fn test_parse_module_abi() {
+        use aptos_types::vm::module_metadata::{
+            KnownAttribute, RuntimeModuleMetadataV1, APTOS_METADATA_KEY_V1,
+        };
+        use move_binary_format::file_format::{
+            basic_test_module, FunctionDefinition, FunctionHandleIndex, IdentifierIndex,
+        };
+        use std::collections::BTreeMap;
+
+        let mut bytes = vec![];
+        move_binary_format::file_format::empty_module()
+            .serialize(&mut bytes)
+            .unwrap();
+        let module = MoveModuleBytecode::new(bytes).try_parse_abi().unwrap();
+        let abi = module.abi.unwrap();
+        assert_eq!(*abi.address.inner(), AccountAddress::ZERO);
+        assert_eq!(abi.entry_functions().count(), 0);
+        assert_eq!(abi.view_functions().count(), 0);
+        assert!(abi.find_struct("Coin").is_none());
+
+        // `struct Bar { x: u64 }` marked as an event, `entry fun foo()` and a
+        // `#[view] public fun balance()` with the same empty signature.
+        let mut module = basic_test_module();
+        module.function_defs[0].is_entry = true;
+        let mut handle = module.function_handles[0].clone();
+        handle.name = IdentifierIndex(module.identifiers.len() as u16);
+        module.identifiers.push(Identifier::new("balance").unwrap());
+        module.function_handles.push(handle);
+        module.function_defs.push(FunctionDefinition {
+            function: FunctionHandleIndex(1),
+            visibility: Visibility::Public,
+            is_entry: false,
+            ..module.function_defs[0].clone()
+        });
+        let metadata = RuntimeModuleMetadataV1 {
+            error_map: BTreeMap::new(),
+            struct_attributes: BTreeMap::from([("Bar".into(), vec![KnownAttribute::event()])]),
+            fun_attributes: BTreeMap::from([(
+                "balance".into(),
+                vec![KnownAttribute::view_function()],
+            )]),
+        };
+        // The metadata entry is a plain `{ key, value }` pair of byte vectors.
+        module.metadata.push(
+            bcs::from_bytes(
+                &bcs::to_bytes(&(APTOS_METADATA_KEY_V1, bcs::to_bytes(&metadata).unwrap()))
+                    .unwrap(),
+            )
+            .unwrap(),
+        );
+        let mut bytes = vec![];
+        module.serialize(&mut bytes).unwrap();
+        let abi = MoveModuleBytecode::new(bytes)
+            .try_parse_abi()
+            .unwrap()
+            .abi
+            .unwrap();
+        let names = |functions: Vec<&MoveFunction>| {
+            functions
+                .into_iter()
+                .map(|f| f.name.as_str().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(abi.entry_functions().collect()), ["foo"]);
+        assert_eq!(names(abi.view_functions().collect()), ["balance"]);
+        let foo = abi.find_function("foo").unwrap();
+        assert_eq!(foo.visibility, MoveFunctionVisibility::Private);
+        assert!(foo.is_entry && !foo.is_view);
+        let balance = abi.find_function("balance").unwrap();
+        assert_eq!(balance.visibility, MoveFunctionVisibility::Public);
+        assert!(!balance.is_entry && balance.is_view);
+        assert!(abi.find_function("missing").is_none());
+        let bar = abi.find_struct("Bar").unwrap();
+        assert!(bar.is_event);
+        assert_eq!(bar.fields.len(), 1);
+        assert_eq!(bar.fields[0].name.as_str(), "x");
+        assert_eq!(bar.fields[0].typ, MoveType::U64);
+
+        // Invalid bytecode is kept without its ABI.
+        let module = MoveModuleBytecode::new(vec![0xde, 0xad])
+            .try_parse_abi()
+            .unwrap();
+        assert_eq!(module.abi, None);
+    }
+
+    #[test]
+    fn test_value_from_json() {
+        let parse = |typ: &str, value| {
+            MoveValue::try_from_json(&typ.parse().unwrap(), value)
+                .unwrap()
+                .json()
+                .unwrap()
+        };
+        assert_eq!(parse("u64", json!("7")), json!("7"));
+        assert_eq!(parse("address", json!("0x1")), json!("0x1"));
+        assert_eq!(parse("vector<u8>", json!("0x0102")), json!("0x0102"));
+        assert_eq!(
+            parse("vector<0x1::string::String>", json!(["a", "b"])),
+            json!(["a", "b"])
+        );
+        assert_eq!(parse("0x1::option::Option<u64>", json!(null)), json!(null));
+        assert_eq!(parse("0x1::option::Option<u64>", json!("1")), json!("1"));
+        assert_eq!(
+            parse("0x1::option::Option<u64>", json!({ "vec": ["1"] })),
+            json!("1")
+        );
+        assert_eq!(
+            parse(
+                "0x1::object::Object<0x1::fungible_asset::Metadata>",
+                json!("0xa")
+            ),
+            json!({ "inner": "0xa" })
+        );
+        assert_eq!(
+            parse("0xcafe::pet::Pet", json!({ "name": "Rex", "age": "3" })),
+            json!({ "name": "Rex", "age": "3" })
+        );
+
+        let typ: MoveType = "u64".parse().unwrap();
+        assert!(MoveValue::try_from_json(&typ, json!(7)).is_err());
+        let typ: MoveType = "0x1::option::Option<u64>".parse().unwrap();
+        assert!(MoveValue::try_from_json(&typ, json!({ "vec": ["1", "2"] })).is_err());
+        let typ: MoveType = "0xcafe::pet::Pet".parse().unwrap();
+        assert!(MoveValue::try_from_json(&typ, json!({ "not an identifier": 1 })).is_err());
+    }
+}
@@ -0,0 +1,391 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    convert::{arg_types, encode_entry_function_args, try_into_json_value},
+    move_types::{MoveStruct, MoveValue},
+    AbiResolver, EntryFunctionId, MoveFunction, MoveModule, MoveType,
+};
+use anyhow::{bail, ensure, format_err, Context, Result};
+use aptos_types::transaction::EntryFunction;
+use move_binary_format::file_format::{Ability, CompiledModule, CompiledScript};
+use move_core_types::{
+    identifier::IdentStr,
+    language_storage::{ModuleId, StructTag, TypeTag},
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A registry of Move module ABIs, which builds and decodes the entry function calls of modules
+/// that have no generated bindings, such as our own modules whose ABIs change between releases
+///
+/// The registry also resolves the ABIs of its modules for the [`crate::MoveConverter`].
+#[derive(Clone, Debug, Default)]
+pub struct AbiRegistry {
+    modules: BTreeMap<ModuleId, MoveModule>,
+}
+
+/// An entry function call decoded with the ABI of its function
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DecodedEntryFunction {
+    pub function: EntryFunctionId,
+    pub type_arguments: Vec<MoveType>,
+    pub arguments: Vec<DecodedArgument>,
+}
+
+/// An entry function argument along with the type of its parameter
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DecodedArgument {
+    #[serde(rename = "type")]
+    pub typ: MoveType,
+    pub value: MoveValue,
+}
+
+impl AbiRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the ABI of a module, replacing the one it had before. Returns the replaced ABI.
+    pub fn add_module(&mut self, module: MoveModule) -> Option<MoveModule> {
+        let id = ModuleId::new(*module.address.inner(), module.name.0.clone());
+        self.modules.insert(id, module)
+    }
+
+    /// Add the ABI of a module in the JSON form the `/accounts/{address}/module/{name}`
+    /// endpoint returns it in
+    pub fn add_module_json(&mut self, abi: Value) -> Result<Option<MoveModule>> {
+        let module = serde_json::from_value(abi).context("invalid module ABI")?;
+        Ok(self.add_module(module))
+    }
+
+    /// Add the ABI of a module parsed from its bytecode
+    pub fn add_module_bytecode(&mut self, bytecode: &[u8]) -> Result<Option<MoveModule>> {
+        let module = CompiledModule::deserialize(bytecode)
+            .map_err(|e| format_err!("invalid module bytecode: {:?}", e))?;
+        Ok(self.add_module(module.into()))
+    }
+
+    pub fn module(&self, id: &ModuleId) -> Option<&MoveModule> {
+        self.modules.get(id)
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = &MoveModule> {
+        self.modules.values()
+    }
+
+    /// The ABI of an entry function, failing if the module is unknown or the function is not
+    /// one of its entry functions
+    pub fn entry_function(&self, module: &ModuleId, function: &IdentStr) -> Result<&MoveFunction> {
+        let abi = self
+            .module(module)
+            .ok_or_else(|| format_err!("unknown module {}", module))?;
+        abi.entry_functions()
+            .find(|f| f.name.as_ref() == function)
+            .ok_or_else(|| format_err!("{}::{} is not an entry function", module, function))
+    }
+
+    /// Build a call of an entry function from the JSON form of its arguments, as accepted by
+    /// [`crate::encode_entry_function_args`]. The type arguments must have the abilities their
+    /// parameters are constrained to.
+    pub fn build_entry_function(
+        &self,
+        function: &EntryFunctionId,
+        type_arguments: Vec<TypeTag>,
+        arguments: Value,
+    ) -> Result<EntryFunction> {
+        let module = ModuleId::from(function.module.clone());
+        let abi = self.entry_function(&module, &function.name)?;
+        self.check_type_arguments(function, abi, &type_arguments)?;
+        let arg_types = arg_types(abi, &move_types(&type_arguments))?;
+        let args = encode_entry_function_args(&arg_types, arguments)
+            .with_context(|| format!("invalid arguments for {}", function))?;
+        Ok(EntryFunction::new(
+            module,
+            function.name.0.clone(),
+            type_arguments,
+            args,
+        ))
+    }
+
+    /// Decode the arguments of an entry function call with the ABI of its function
+    pub fn decode_entry_function(&self, entry: &EntryFunction) -> Result<DecodedEntryFunction> {
+        let function = EntryFunctionId {
+            module: entry.module().clone().into(),
+            name: entry.function().into(),
+        };
+        let abi = self.entry_function(entry.module(), entry.function())?;
+        self.check_type_arguments(&function, abi, entry.ty_args())?;
+        let type_arguments = move_types(entry.ty_args());
+        let arg_types = arg_types(abi, &type_arguments)?;
+        ensure!(
+            arg_types.len() == entry.args().len(),
+            "expect {} arguments for {}, but got {}",
+            arg_types.len(),
+            function,
+            entry.args().len()
+        );
+        let arguments = arg_types
+            .into_iter()
+            .zip(entry.args())
+            .enumerate()
+            .map(|(i, (typ, bytes))| {
+                let value = try_into_json_value(&typ, bytes)
+                    .and_then(|json| MoveValue::try_from_json(&typ, json))
+                    .with_context(|| format!("failed to decode arguments[{}] as {}", i, typ))?;
+                Ok(DecodedArgument { typ, value })
+            })
+            .collect::<Result<_>>()?;
+        Ok(DecodedEntryFunction {
+            function,
+            type_arguments,
+            arguments,
+        })
+    }
+
+    fn check_type_arguments(
+        &self,
+        function: &EntryFunctionId,
+        abi: &MoveFunction,
+        type_arguments: &[TypeTag],
+    ) -> Result<()> {
+        ensure!(
+            abi.generic_type_params.len() == type_arguments.len(),
+            "expect {} type arguments for {}, but got {}",
+            abi.generic_type_params.len(),
+            function,
+            type_arguments.len()
+        );
+        for (i, (param, typ)) in abi
+            .generic_type_params
+            .iter()
+            .zip(type_arguments)
+            .enumerate()
+        {
+            // The abilities of structs from modules the registry does not know can't be checked
+            let Some(abilities) = self.abilities(typ) else {
+                continue;
+            };
+            for constraint in &param.constraints {
+                ensure!(
+                    abilities.contains(&constraint.0),
+                    "type argument T{} of {} is {}, which does not have the {} ability",
+                    i,
+                    function,
+                    typ,
+                    constraint
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The abilities of a type, or None if it is or contains a struct of an unknown module
+    fn abilities(&self, typ: &TypeTag) -> Option<Vec<Ability>> {
+        Some(match typ {
+            TypeTag::Signer => vec![Ability::Drop],
+            TypeTag::Vector(items) => self
+                .abilities(items)?
+                .into_iter()
+                .filter(|ability| *ability != Ability::Key)
+                .collect(),
+            TypeTag::Struct(tag) => {
+                let st = self.find_struct(tag)?;
+                let mut abilities: Vec<Ability> = st.abilities.iter().map(|a| a.0).collect();
+                // A struct instance only has the abilities its non-phantom type arguments
+                // allow, where `key` requires them to have `store`.
+                for (param, arg) in st.generic_type_params.iter().zip(&tag.type_args) {
+                    if param.is_phantom {
+                        continue;
+                    }
+                    let arg_abilities = self.abilities(arg)?;
+                    abilities.retain(|ability| {
+                        let required = match ability {
+                            Ability::Key => Ability::Store,
+                            ability => *ability,
+                        };
+                        arg_abilities.contains(&required)
+                    });
+                }
+                abilities
+            },
+            TypeTag::Bool
+            | TypeTag::U8
+            | TypeTag::U16
+            | TypeTag::U32
+            | TypeTag::U64
+            | TypeTag::U128
+            | TypeTag::U256
+            | TypeTag::Address => vec![Ability::Copy, Ability::Drop, Ability::Store],
+        })
+    }
+
+    fn find_struct(&self, tag: &StructTag) -> Option<&MoveStruct> {
+        self.module(&tag.module_id())?
+            .find_struct(tag.name.as_str())
+    }
+}
+
+impl AbiResolver for AbiRegistry {
+    fn entry_function_abi(&self, module: &ModuleId, function: &IdentStr) -> Option<MoveFunction> {
+        self.entry_function(module, function).ok().cloned()
+    }
+
+    fn script_abi(&self, code: &[u8]) -> Option<MoveFunction> {
+        CompiledScript::deserialize(code)
+            .ok()
+            .map(|script| MoveFunction::from(&script))
+    }
+}
+
+fn move_types(type_arguments: &[TypeTag]) -> Vec<MoveType> {
+    type_arguments.iter().map(MoveType::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::{account_address::AccountAddress, ident_str};
+    use serde_json::json;
+
+    fn registry() -> AbiRegistry {
+        let mut registry = AbiRegistry::new();
+        registry
+            .add_module_json(json!({
+                "address": "0xb1d9e",
+                "name": "bridge",
+                "friends": [],
+                "exposed_functions": [
+                    {
+                        "name": "mint",
+                        "visibility": "public",
+                        "is_entry": true,
+                        "is_view": false,
+                        "generic_type_params": [{ "constraints": ["key"] }],
+                        "params": [
+                            "&signer",
+                            "address",
+                            "u64",
+                            "0x1::string::String",
+                            "0x1::option::Option<vector<u8>>"
+                        ],
+                        "return": []
+                    },
+                    {
+                        "name": "fee",
+                        "visibility": "public",
+                        "is_entry": false,
+                        "is_view": true,
+                        "generic_type_params": [],
+                        "params": [],
+                        "return": ["u64"]
+                    }
+                ],
+                "structs": [
+                    {
+                        "name": "Ticket",
+                        "is_native": false,
+                        "is_event": false,
+                        "abilities": ["key", "store"],
+                        "generic_type_params": [],
+                        "fields": [{ "name": "id", "type": "u64" }]
+                    },
+                    {
+                        "name": "Receipt",
+                        "is_native": false,
+                        "is_event": true,
+                        "abilities": ["drop", "store"],
+                        "generic_type_params": [],
+                        "fields": []
+                    }
+                ]
+            }))
+            .unwrap();
+        registry
+    }
+
+    fn mint() -> EntryFunctionId {
+        "0xb1d9e::bridge::mint".parse().unwrap()
+    }
+
+    fn type_tag(s: &str) -> TypeTag {
+        move_core_types::parser::parse_type_tag(s).unwrap()
+    }
+
+    #[test]
+    fn test_build_and_decode_entry_function() {
+        let registry = registry();
+        let entry = registry
+            .build_entry_function(
+                &mint(),
+                vec![type_tag("0xb1d9e::bridge::Ticket")],
+                json!(["0x2", "100", "omnity", "0x0102"]),
+            )
+            .unwrap();
+        assert_eq!(
+            entry.module().address(),
+            &AccountAddress::from_hex_literal("0xb1d9e").unwrap()
+        );
+        assert_eq!(entry.function(), ident_str!("mint"));
+        assert_eq!(entry.args()[1], bcs::to_bytes(&100u64).unwrap());
+        assert_eq!(entry.args()[3], bcs::to_bytes(&Some(vec![1u8, 2])).unwrap());
+
+        let decoded = registry.decode_entry_function(&entry).unwrap();
+        assert_eq!(decoded.function, mint());
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            json!({
+                "function": "0xb1d9e::bridge::mint",
+                "type_arguments": ["0xb1d9e::bridge::Ticket"],
+                "arguments": [
+                    { "type": "address", "value": "0x2" },
+                    { "type": "u64", "value": "100" },
+                    { "type": "0x1::string::String", "value": "omnity" },
+                    { "type": "0x1::option::Option<vector<u8>>", "value": "0x0102" },
+                ],
+            })
+        );
+
+        // Converting the call to JSON uses the registry's ABIs.
+        assert!(registry
+            .entry_function_abi(entry.module(), entry.function())
+            .is_some());
+    }
+
+    #[test]
+    fn test_reject_invalid_calls() {
+        let registry = registry();
+        let ticket = || vec![type_tag("0xb1d9e::bridge::Ticket")];
+        let args = || json!(["0x2", "100", "omnity", null]);
+        registry
+            .build_entry_function(&mint(), ticket(), args())
+            .unwrap();
+
+        // Wrong number of arguments or type arguments
+        assert!(registry
+            .build_entry_function(&mint(), ticket(), json!(["0x2", "100"]))
+            .is_err());
+        assert!(registry
+            .build_entry_function(&mint(), vec![], args())
+            .is_err());
+        // Type arguments without the required abilities
+        assert!(registry
+            .build_entry_function(&mint(), vec![type_tag("u64")], args())
+            .is_err());
+        assert!(registry
+            .build_entry_function(&mint(), vec![type_tag("0xb1d9e::bridge::Receipt")], args())
+            .is_err());
+        // Structs of unknown modules are not checked
+        registry
+            .build_entry_function(&mint(), vec![type_tag("0x1::coin::CoinStore<u8>")], args())
+            .unwrap();
+        // Unknown functions and functions that are not entry functions
+        assert!(registry
+            .build_entry_function(&"0xb1d9e::bridge::fee".parse().unwrap(), vec![], json!([]))
+            .is_err());
+        assert!(registry
+            .build_entry_function(&"0xb1d9e::vault::mint".parse().unwrap(), ticket(), args())
+            .is_err());
+    }
+}
@@ -1,13 +1,28 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{HexEncodedBytes, MoveType, VerifyInput, VerifyInputWithRecursion};
-use poem_openapi::Object;
-use serde::{Deserialize, Serialize};
+use crate::{convert::try_into_json_value, HexEncodedBytes, MoveType};
+use anyhow::{Context, Result};
+use aptos_types::{
+    account_config::{
+        CoinConversionMapResource, CollectionDataV1, CollectionsResource, Object, TokenDataId,
+        TokenDataV1, TokenId, TokenStoreResource, TokenV1, TypeInfoResource,
+    },
+    state_store::{state_key::StateKey, table::TableHandle},
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    language_storage::{StructTag, TypeTag},
+    move_resource::MoveStructType,
+};
+// use poem_openapi::Object;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
+use std::marker::PhantomData;
 
 /// Table Item request for the GetTableItem API
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TableItemRequest {
     pub key_type: MoveType,
     pub value_type: MoveType,
@@ -15,15 +30,279 @@ pub struct TableItemRequest {
     pub key: Value,
 }
 
-impl VerifyInput for TableItemRequest {
-    fn verify(&self) -> anyhow::Result<()> {
-        self.key_type.verify(0)?;
-        self.value_type.verify(0)
-    }
-}
+// impl VerifyInput for TableItemRequest {
+//     fn verify(&self) -> anyhow::Result<()> {
+//         self.key_type.verify(0)?;
+//         self.value_type.verify(0)
+//     }
+// }
 
 /// Table Item request for the GetTableItemRaw API
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RawTableItemRequest {
     pub key: HexEncodedBytes,
 }
+
+/// Fetches table items from a fullnode, e.g. with HTTPS outcalls to
+/// `POST /tables/{table_handle}/raw_item`
+#[allow(async_fn_in_trait)]
+pub trait TableItemFetcher {
+    /// Returns the BCS bytes of the item, or None if the table has no item under the key
+    async fn fetch_raw_table_item(
+        &self,
+        handle: TableHandle,
+        request: &RawTableItemRequest,
+    ) -> Result<Option<Vec<u8>>>;
+}
+
+/// A typed view of a Move `0x1::table::Table<K, V>`, which builds the requests for its items and
+/// decodes their values
+///
+/// `K` and `V` are the Rust mirrors of the Move key and value types, so that keys BCS-encode
+/// the same way the table stores them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table<K, V> {
+    handle: TableHandle,
+    key_type: MoveType,
+    value_type: MoveType,
+    phantom: PhantomData<fn(&K) -> V>,
+}
+
+impl<K: Serialize, V: DeserializeOwned> Table<K, V> {
+    pub fn new(handle: TableHandle, key_type: MoveType, value_type: MoveType) -> Self {
+        Self {
+            handle,
+            key_type,
+            value_type,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn handle(&self) -> TableHandle {
+        self.handle
+    }
+
+    pub fn key_type(&self) -> &MoveType {
+        &self.key_type
+    }
+
+    pub fn value_type(&self) -> &MoveType {
+        &self.value_type
+    }
+
+    pub fn encode_key(&self, key: &K) -> Result<Vec<u8>> {
+        bcs::to_bytes(key).context("failed to BCS encode table key")
+    }
+
+    /// The state key the item under `key` is stored at
+    pub fn state_key(&self, key: &K) -> Result<StateKey> {
+        Ok(StateKey::table_item(&self.handle, &self.encode_key(key)?))
+    }
+
+    pub fn raw_item_request(&self, key: &K) -> Result<RawTableItemRequest> {
+        Ok(RawTableItemRequest {
+            key: self.encode_key(key)?.into(),
+        })
+    }
+
+    /// Build a request for the JSON form of the item. Only keys of the types the REST API can
+    /// read without the ABI of their module have a JSON form, i.e. primitives, vectors,
+    /// `String`, `Object<T>` and `Option<T>`; use [`Self::raw_item_request`] for others.
+    pub fn item_request(&self, key: &K) -> Result<TableItemRequest> {
+        let key = try_into_json_value(&self.key_type, &self.encode_key(key)?)
+            .with_context(|| format!("table key of type {} has no JSON form", self.key_type))?;
+        Ok(TableItemRequest {
+            key_type: self.key_type.clone(),
+            value_type: self.value_type.clone(),
+            key,
+        })
+    }
+
+    pub fn decode_item(&self, bytes: &[u8]) -> Result<V> {
+        bcs::from_bytes(bytes)
+            .with_context(|| format!("failed to decode table item of type {}", self.value_type))
+    }
+
+    /// Fetch and decode the item under `key`, or None if the table has no such item
+    pub async fn get<F: TableItemFetcher + ?Sized>(
+        &self,
+        fetcher: &F,
+        key: &K,
+    ) -> Result<Option<V>> {
+        let request = self.raw_item_request(key)?;
+        match fetcher.fetch_raw_table_item(self.handle, &request).await? {
+            Some(bytes) => self.decode_item(&bytes).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Table<TokenId, TokenV1> {
+    /// The token v1 tokens an account holds
+    pub fn tokens(store: &TokenStoreResource) -> Self {
+        Self::new(
+            store.tokens().handle(),
+            struct_type::<TokenId>(),
+            struct_type::<TokenV1>(),
+        )
+    }
+}
+
+impl Table<String, CollectionDataV1> {
+    /// The token v1 collections a creator has made, by name
+    pub fn collection_data(collections: &CollectionsResource) -> Self {
+        Self::new(
+            collections.collection_data().handle(),
+            framework_struct_type("string", "String", vec![]),
+            struct_type::<CollectionDataV1>(),
+        )
+    }
+}
+
+impl Table<TokenDataId, TokenDataV1> {
+    /// The token v1 token data a creator has made
+    pub fn token_data(collections: &CollectionsResource) -> Self {
+        Self::new(
+            collections.token_data().handle(),
+            struct_type::<TokenDataId>(),
+            struct_type::<TokenDataV1>(),
+        )
+    }
+}
+
+impl Table<TypeInfoResource, Object> {
+    /// The fungible asset metadata objects that coin types are paired with
+    pub fn coin_conversion_map(map: &CoinConversionMapResource) -> Self {
+        let metadata = TypeTag::Struct(Box::new(StructTag {
+            address: AccountAddress::ONE,
+            module: ident_str!("fungible_asset").to_owned(),
+            name: ident_str!("Metadata").to_owned(),
+            type_args: vec![],
+        }));
+        Self::new(
+            map.coin_to_fungible_asset_map().handle(),
+            struct_type::<TypeInfoResource>(),
+            framework_struct_type("object", "Object", vec![metadata]),
+        )
+    }
+}
+
+fn struct_type<T: MoveStructType>() -> MoveType {
+    MoveType::from(TypeTag::Struct(Box::new(T::struct_tag())))
+}
+
+fn framework_struct_type(module: &str, name: &str, type_args: Vec<TypeTag>) -> MoveType {
+    MoveType::from(TypeTag::Struct(Box::new(StructTag {
+        address: AccountAddress::ONE,
+        module: module.parse().expect("valid module name"),
+        name: name.parse().expect("valid struct name"),
+        type_args,
+    })))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aptos_types::{
+        account_config::{PropertyMap, PropertyValue, Table as MoveTable},
+        event::{EventHandle, EventKey},
+    };
+    use futures::executor::block_on;
+    use std::collections::BTreeMap;
+
+    #[derive(Default)]
+    struct MockFetcher {
+        items: BTreeMap<(TableHandle, Vec<u8>), Vec<u8>>,
+    }
+
+    impl TableItemFetcher for MockFetcher {
+        async fn fetch_raw_table_item(
+            &self,
+            handle: TableHandle,
+            request: &RawTableItemRequest,
+        ) -> Result<Option<Vec<u8>>> {
+            Ok(self.items.get(&(handle, request.key.0.clone())).cloned())
+        }
+    }
+
+    fn token_id() -> TokenId {
+        TokenId::new(
+            TokenDataId::new(AccountAddress::ONE, "Aptos Zero".into(), "Token #1".into()),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_token_store_item() {
+        let tokens = Table::<TokenId, TokenV1>::new(
+            TableHandle(AccountAddress::TWO),
+            struct_type::<TokenId>(),
+            struct_type::<TokenV1>(),
+        );
+        let properties = PropertyMap::new(vec![(
+            "level".into(),
+            PropertyValue::new(bcs::to_bytes(&7u64).unwrap(), "u64".into()),
+        )]);
+        let token = TokenV1::new(token_id(), 1, properties);
+
+        let key = bcs::to_bytes(&token_id()).unwrap();
+        let request = tokens.raw_item_request(&token_id()).unwrap();
+        assert_eq!(request.key.0, key);
+        assert_eq!(
+            tokens.state_key(&token_id()).unwrap(),
+            StateKey::table_item(&TableHandle(AccountAddress::TWO), &key)
+        );
+        assert_eq!(
+            tokens.key_type().to_string(),
+            "0x3::token::TokenId".to_string()
+        );
+        // Struct keys need their module's ABI to be shown as JSON.
+        assert!(tokens.item_request(&token_id()).is_err());
+
+        let mut fetcher = MockFetcher::default();
+        fetcher.items.insert(
+            (TableHandle(AccountAddress::TWO), key),
+            bcs::to_bytes(&token).unwrap(),
+        );
+        let fetched = block_on(tokens.get(&fetcher, &token_id()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(fetched, token);
+        assert_eq!(
+            fetched.token_properties().get("level").unwrap().value(),
+            bcs::to_bytes(&7u64).unwrap()
+        );
+
+        let missing = TokenId::new(token_id().token_data_id().clone(), 1);
+        assert_eq!(block_on(tokens.get(&fetcher, &missing)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_collection_data_request() {
+        let collections = CollectionsResource::new(
+            MoveTable::new(TableHandle(AccountAddress::TWO)),
+            MoveTable::new(TableHandle(AccountAddress::THREE)),
+            EventHandle::new(EventKey::new(0, AccountAddress::ONE), 0),
+            EventHandle::new(EventKey::new(1, AccountAddress::ONE), 0),
+            EventHandle::new(EventKey::new(2, AccountAddress::ONE), 0),
+        );
+        let collection_data = Table::collection_data(&collections);
+        assert_eq!(collection_data.handle(), TableHandle(AccountAddress::TWO));
+        assert_eq!(
+            Table::token_data(&collections).handle(),
+            TableHandle(AccountAddress::THREE)
+        );
+
+        let request = collection_data
+            .item_request(&"Aptos Zero".to_string())
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "key_type": "0x1::string::String",
+                "value_type": "0x3::token::CollectionData",
+                "key": "Aptos Zero",
+            })
+        );
+    }
+}
@@ -11,8 +11,21 @@ use crate::{
     Address, AptosError, HashValue, U64,
 };
 use anyhow::{bail, Context as AnyhowContext, Result};
-use aptos_crypto::ed25519::{self, Ed25519PublicKey};
-use aptos_types::transaction::authenticator::{AccountAuthenticator, TransactionAuthenticator};
+use aptos_crypto::{
+    ed25519::{self, Ed25519PublicKey},
+    multi_ed25519::{self, MultiEd25519PublicKey},
+};
+use aptos_types::{
+    contract_event::ContractEvent,
+    transaction::{
+        authenticator::{
+            AccountAuthenticator, AnyPublicKey, AnySignature, MultiKey, MultiKeyAuthenticator,
+            SingleKeyAuthenticator, TransactionAuthenticator,
+        },
+        SignedTransaction,
+    },
+};
+use move_core_types::account_address::AccountAddress;
 
 use serde::{Deserialize, Serialize};
 use std::{
@@ -28,6 +41,11 @@ use std::{
 pub enum Transaction {
     PendingTransaction(PendingTransaction),
     UserTransaction(UserTransaction),
+    GenesisTransaction(GenesisTransaction),
+    BlockMetadataTransaction(BlockMetadataTransaction),
+    StateCheckpointTransaction(StateCheckpointTransaction),
+    BlockEpilogueTransaction(BlockEpilogueTransaction),
+    ValidatorTransaction(ValidatorTransaction),
 }
 
 impl Transaction {
@@ -35,20 +53,28 @@ impl Transaction {
         match self {
             Transaction::PendingTransaction(_) => 0,
             Transaction::UserTransaction(txn) => txn.timestamp.0,
+            Transaction::GenesisTransaction(_) => 0,
+            Transaction::BlockMetadataTransaction(txn) => txn.timestamp.0,
+            Transaction::StateCheckpointTransaction(txn) => txn.timestamp.0,
+            Transaction::BlockEpilogueTransaction(txn) => txn.timestamp.0,
+            Transaction::ValidatorTransaction(txn) => txn.timestamp().0,
         }
     }
 
     pub fn version(&self) -> Option<u64> {
         match self {
             Transaction::PendingTransaction(_) => None,
-            Transaction::UserTransaction(txn) => Some(txn.info.version.into()),
+            _ => self.transaction_info().ok().map(|info| info.version.into()),
         }
     }
 
     pub fn success(&self) -> bool {
         match self {
             Transaction::PendingTransaction(_txn) => false,
-            Transaction::UserTransaction(txn) => txn.info.success,
+            _ => self
+                .transaction_info()
+                .map(|info| info.success)
+                .unwrap_or_default(),
         }
     }
 
@@ -59,7 +85,10 @@ impl Transaction {
     pub fn vm_status(&self) -> String {
         match self {
             Transaction::PendingTransaction(_txn) => "pending".to_owned(),
-            Transaction::UserTransaction(txn) => txn.info.vm_status.clone(),
+            _ => self
+                .transaction_info()
+                .map(|info| info.vm_status.clone())
+                .unwrap_or_default(),
         }
     }
 
@@ -67,6 +96,11 @@ impl Transaction {
         match self {
             Transaction::PendingTransaction(_) => "pending_transaction",
             Transaction::UserTransaction(_) => "user_transaction",
+            Transaction::GenesisTransaction(_) => "genesis_transaction",
+            Transaction::BlockMetadataTransaction(_) => "block_metadata_transaction",
+            Transaction::StateCheckpointTransaction(_) => "state_checkpoint_transaction",
+            Transaction::BlockEpilogueTransaction(_) => "block_epilogue_transaction",
+            Transaction::ValidatorTransaction(vt) => vt.type_str(),
         }
     }
 
@@ -74,12 +108,36 @@ impl Transaction {
         Ok(match self {
             Transaction::PendingTransaction(_txn) => {
                 bail!("pending transaction does not have TransactionInfo")
-            }
+            },
             Transaction::UserTransaction(txn) => &txn.info,
+            Transaction::GenesisTransaction(txn) => &txn.info,
+            Transaction::BlockMetadataTransaction(txn) => &txn.info,
+            Transaction::StateCheckpointTransaction(txn) => &txn.info,
+            Transaction::BlockEpilogueTransaction(txn) => &txn.info,
+            Transaction::ValidatorTransaction(txn) => txn.transaction_info(),
         })
     }
 }
 
+/// A committed transaction
+///
+/// This is a representation of the onchain payload, outputs, events, and proof of a transaction.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TransactionOnChainData {
+    /// The ledger version of the transaction
+    pub version: u64,
+    /// The transaction submitted
+    pub transaction: aptos_types::transaction::Transaction,
+    /// Information about the transaction
+    pub info: aptos_types::transaction::TransactionInfo,
+    /// Events emitted by the transaction
+    pub events: Vec<ContractEvent>,
+    /// The accumulator root hash at this version
+    pub accumulator_root_hash: aptos_crypto::HashValue,
+    /// Final state of resources changed by the transaction
+    pub changes: aptos_types::write_set::WriteSet,
+}
+
 /// Information related to how a transaction affected the state of the blockchain
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TransactionInfo {
@@ -115,14 +173,14 @@ pub struct PendingTransaction {
     pub request: UserTransactionRequest,
 }
 
-// impl From<(SignedTransaction, TransactionPayload)> for PendingTransaction {
-//     fn from((txn, payload): (SignedTransaction, TransactionPayload)) -> Self {
-//         PendingTransaction {
-//             request: (&txn, payload).into(),
-//             hash: txn.committed_hash().into(),
-//         }
-//     }
-// }
+impl From<(SignedTransaction, TransactionPayload)> for PendingTransaction {
+    fn from((txn, payload): (SignedTransaction, TransactionPayload)) -> Self {
+        PendingTransaction {
+            request: (&txn, payload).into(),
+            hash: txn.committed_hash().into(),
+        }
+    }
+}
 
 /// A transaction submitted by a user to change the state of the blockchain
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -138,6 +196,70 @@ pub struct UserTransaction {
     pub timestamp: U64,
 }
 
+/// The outcome of a transaction simulated by the node's `/transactions/simulate` endpoint, which
+/// responds with a single `UserTransaction` that was executed but not committed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulationResult {
+    /// Whether the transaction would succeed
+    pub success: bool,
+    /// The VM status, e.g. `Executed successfully` or the abort that failed the transaction
+    pub vm_status: String,
+    pub gas_used: u64,
+    pub gas_unit_price: u64,
+    /// The state changes the transaction would make
+    pub changes: Vec<WriteSetChange>,
+    pub events: Vec<Event>,
+}
+
+impl SimulationResult {
+    /// Decode the JSON body of a simulation response.
+    pub fn from_json(body: &[u8]) -> Result<Self> {
+        let txns: Vec<UserTransaction> =
+            serde_json::from_slice(body).context("Failed to parse simulation response")?;
+        txns.try_into()
+    }
+
+    /// The fee the transaction would be charged, in octas.
+    pub fn gas_fee(&self) -> u64 {
+        self.gas_used.saturating_mul(self.gas_unit_price)
+    }
+
+    /// Return an error carrying the VM status if the transaction would fail.
+    pub fn into_result(self) -> Result<Self> {
+        if !self.success {
+            bail!("Simulated transaction failed: {}", self.vm_status);
+        }
+        Ok(self)
+    }
+}
+
+impl From<UserTransaction> for SimulationResult {
+    fn from(txn: UserTransaction) -> Self {
+        Self {
+            success: txn.info.success,
+            vm_status: txn.info.vm_status,
+            gas_used: txn.info.gas_used.into(),
+            gas_unit_price: txn.request.gas_unit_price.into(),
+            changes: txn.info.changes,
+            events: txn.events,
+        }
+    }
+}
+
+impl TryFrom<Vec<UserTransaction>> for SimulationResult {
+    type Error = anyhow::Error;
+
+    fn try_from(mut txns: Vec<UserTransaction>) -> Result<Self> {
+        if txns.len() != 1 {
+            bail!(
+                "Expected a single simulated transaction, got {}",
+                txns.len()
+            );
+        }
+        Ok(txns.remove(0).into())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BlockEndInfo {
     pub block_gas_limit_reached: bool,
@@ -146,6 +268,215 @@ pub struct BlockEndInfo {
     pub block_approx_output_size: u64,
 }
 
+/// The genesis transaction
+///
+/// This only occurs at the genesis transaction (version 0)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisTransaction {
+    #[serde(flatten)]
+    pub info: TransactionInfo,
+    pub payload: GenesisPayload,
+    /// Events emitted during genesis
+    pub events: Vec<Event>,
+}
+
+/// The writeset payload of the Genesis transaction
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+//#[oai(one_of, discriminator_name = "type", rename_all = "snake_case")]
+pub enum GenesisPayload {
+    WriteSetPayload(WriteSetPayload),
+}
+
+/// A writeset payload, used only for genesis
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteSetPayload {
+    pub write_set: WriteSet,
+}
+
+/// The associated writeset with a payload
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+//#[oai(one_of, discriminator_name = "type", rename_all = "snake_case")]
+pub enum WriteSet {
+    ScriptWriteSet(ScriptWriteSet),
+    DirectWriteSet(DirectWriteSet),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptWriteSet {
+    pub execute_as: Address,
+    pub script: ScriptPayload,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DirectWriteSet {
+    pub changes: Vec<WriteSetChange>,
+    pub events: Vec<Event>,
+}
+
+/// A block metadata transaction
+///
+/// This signifies the beginning of a block, and contains information
+/// about the specific block
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockMetadataTransaction {
+    #[serde(flatten)]
+    pub info: TransactionInfo,
+    pub id: HashValue,
+    pub epoch: U64,
+    pub round: U64,
+    /// The events emitted at the block creation
+    pub events: Vec<Event>,
+    /// Previous block votes
+    pub previous_block_votes_bitvec: Vec<u8>,
+    pub proposer: Address,
+    /// The indices of the proposers who failed to propose
+    pub failed_proposer_indices: Vec<u32>,
+    pub timestamp: U64,
+    /// If some, it means the internal txn type is `aptos_types::transaction::Transaction::BlockMetadataExt`.
+    /// Otherwise, it is `aptos_types::transaction::Transaction::BlockMetadata`.
+    ///
+    /// NOTE: we could have introduced a new APT txn type to represent the corresponding internal type,
+    /// but that is a breaking change to the ecosystem.
+    ///
+    /// NOTE: `oai` does not support `flatten` together with `skip_serializing_if`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    //#[oai(default, skip_serializing_if = "Option::is_none")]
+    pub block_metadata_extension: Option<BlockMetadataExtension>,
+}
+
+impl BlockMetadataTransaction {
+    /// The height of the block, read from the `0x1::block::NewBlockEvent` the transaction emits
+    pub fn block_height(&self) -> Result<u64> {
+        let event = self
+            .events
+            .iter()
+            .find(|event| match &event.typ {
+                MoveType::Struct(tag) => {
+                    *tag.address.inner() == AccountAddress::ONE
+                        && tag.module.as_str() == "block"
+                        && tag.name.as_str() == "NewBlockEvent"
+                },
+                _ => false,
+            })
+            .context("block metadata transaction emits no NewBlockEvent")?;
+        let height: U64 = serde_json::from_value(event.data["height"].clone())
+            .context("NewBlockEvent has no valid height")?;
+        Ok(height.0)
+    }
+}
+
+/// A state checkpoint transaction
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateCheckpointTransaction {
+    #[serde(flatten)]
+    pub info: TransactionInfo,
+    pub timestamp: U64,
+}
+
+/// A block epilogue transaction
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockEpilogueTransaction {
+    #[serde(flatten)]
+    pub info: TransactionInfo,
+    pub timestamp: U64,
+    pub block_end_info: Option<BlockEndInfo>,
+}
+
+/// A transaction proposed by a validator
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "validator_transaction_type", rename_all = "snake_case")]
+//#[oai(one_of, discriminator_name = "validator_transaction_type", rename_all = "snake_case")]
+pub enum ValidatorTransaction {
+    ObservedJwkUpdate(JWKUpdateTransaction),
+    DkgResult(DKGResultTransaction),
+}
+
+impl ValidatorTransaction {
+    pub fn type_str(&self) -> &'static str {
+        match self {
+            ValidatorTransaction::ObservedJwkUpdate(_) => {
+                "validator_transaction__observed_jwk_update"
+            },
+            ValidatorTransaction::DkgResult(_) => "validator_transaction__dkg_result",
+        }
+    }
+
+    pub fn transaction_info(&self) -> &TransactionInfo {
+        match self {
+            ValidatorTransaction::ObservedJwkUpdate(t) => &t.info,
+            ValidatorTransaction::DkgResult(t) => &t.info,
+        }
+    }
+
+    pub fn timestamp(&self) -> U64 {
+        match self {
+            ValidatorTransaction::ObservedJwkUpdate(t) => t.timestamp,
+            ValidatorTransaction::DkgResult(t) => t.timestamp,
+        }
+    }
+
+    pub fn events(&self) -> &[Event] {
+        match self {
+            ValidatorTransaction::ObservedJwkUpdate(t) => &t.events,
+            ValidatorTransaction::DkgResult(t) => &t.events,
+        }
+    }
+}
+
+/// A validator transaction that updates the JWKs of an OIDC provider
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JWKUpdateTransaction {
+    #[serde(flatten)]
+    pub info: TransactionInfo,
+    pub events: Vec<Event>,
+    pub timestamp: U64,
+    pub quorum_certified_update: ExportedQuorumCertifiedUpdate,
+}
+
+/// A more API-friendly representation of the on-chain `aptos_types::jwks::QuorumCertifiedUpdate`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedQuorumCertifiedUpdate {
+    pub update: ExportedProviderJWKs,
+    pub multi_sig: ExportedAggregateSignature,
+}
+
+/// A more API-friendly representation of the on-chain `aptos_types::jwks::ProviderJWKs`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedProviderJWKs {
+    pub issuer: String,
+    pub version: u64,
+    /// The keys in their JSON form, which this crate does not interpret
+    pub jwks: Vec<serde_json::Value>,
+}
+
+/// A more API-friendly representation of the on-chain `aptos_types::aggregate_signature::AggregateSignature`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedAggregateSignature {
+    pub signer_indices: Vec<usize>,
+    /// The compressed BLS signature, if any
+    pub sig: Option<HexEncodedBytes>,
+}
+
+/// A validator transaction that publishes the result of a DKG session
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DKGResultTransaction {
+    #[serde(flatten)]
+    pub info: TransactionInfo,
+    pub events: Vec<Event>,
+    pub timestamp: U64,
+    pub dkg_transcript: ExportedDKGTranscript,
+}
+
+/// A more API-friendly representation of the on-chain `aptos_types::dkg::DKGTranscript`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedDKGTranscript {
+    pub epoch: U64,
+    pub author: Address,
+    pub payload: HexEncodedBytes,
+}
+
 /// Batch transaction submission result
 ///
 /// Tells which transactions failed
@@ -174,6 +505,25 @@ pub struct UserTransactionRequest {
     pub payload: TransactionPayload,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature: Option<TransactionSignature>,
+    /// Set for orderless transactions, which are protected against replay by this nonce instead
+    /// of the sequence number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replay_protection_nonce: Option<U64>,
+}
+
+impl From<(&SignedTransaction, TransactionPayload)> for UserTransactionRequest {
+    fn from((txn, payload): (&SignedTransaction, TransactionPayload)) -> Self {
+        Self {
+            sender: txn.sender().into(),
+            sequence_number: txn.sequence_number().into(),
+            max_gas_amount: txn.max_gas_amount().into(),
+            gas_unit_price: txn.gas_unit_price().into(),
+            expiration_timestamp_secs: txn.expiration_timestamp_secs().into(),
+            payload,
+            signature: Some(txn.authenticator_ref().into()),
+            replay_protection_nonce: txn.payload().replay_protection_nonce().map(U64::from),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -213,6 +563,7 @@ pub struct Event {
 pub enum TransactionPayload {
     EntryFunctionPayload(EntryFunctionPayload),
     ScriptPayload(ScriptPayload),
+    MultisigPayload(MultisigPayload),
 }
 
 /// Payload which runs a single entry function
@@ -233,6 +584,29 @@ pub struct ScriptPayload {
     pub type_arguments: Vec<MoveType>,
     /// Arguments of the function
     pub arguments: Vec<serde_json::Value>,
+    /// The BCS-encoded `TransactionArgument`s the script was signed with. Their kinds can't be
+    /// recovered from `arguments`, as a `u64` may be passed either as `U64` or serialized, so
+    /// they are kept to convert the payload back exactly. Nodes don't return them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serialized_arguments: Option<HexEncodedBytes>,
+}
+
+/// A multisig transaction that allows an owner of a multisig account to execute a pre-approved
+/// transaction as the multisig account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigPayload {
+    pub multisig_address: Address,
+
+    // Transaction payload is optional if already stored on chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_payload: Option<MultisigTransactionPayload>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+//#[oai(one_of, discriminator_name = "type", rename_all = "snake_case")]
+pub enum MultisigTransactionPayload {
+    EntryFunctionPayload(EntryFunctionPayload),
 }
 
 /// A final state change of a transaction on a resource or module
@@ -337,6 +711,10 @@ pub struct WriteTableItem {
 //#[oai(one_of, discriminator_name = "type", rename_all = "snake_case")]
 pub enum TransactionSignature {
     Ed25519Signature(Ed25519Signature),
+    MultiEd25519Signature(MultiEd25519Signature),
+    MultiAgentSignature(MultiAgentSignature),
+    FeePayerSignature(FeePayerSignature),
+    SingleSender(AccountSignature),
 }
 
 impl TryFrom<TransactionSignature> for TransactionAuthenticator {
@@ -345,10 +723,60 @@ impl TryFrom<TransactionSignature> for TransactionAuthenticator {
     fn try_from(ts: TransactionSignature) -> anyhow::Result<Self> {
         Ok(match ts {
             TransactionSignature::Ed25519Signature(sig) => sig.try_into()?,
+            TransactionSignature::MultiEd25519Signature(sig) => sig.try_into()?,
+            TransactionSignature::MultiAgentSignature(sig) => sig.try_into()?,
+            TransactionSignature::FeePayerSignature(sig) => sig.try_into()?,
+            TransactionSignature::SingleSender(sig) => {
+                TransactionAuthenticator::single_sender(sig.try_into()?)
+            },
         })
     }
 }
 
+impl From<&TransactionAuthenticator> for TransactionSignature {
+    fn from(auth: &TransactionAuthenticator) -> Self {
+        match auth {
+            TransactionAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => Self::Ed25519Signature((public_key, signature).into()),
+            TransactionAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => Self::MultiEd25519Signature((public_key, signature).into()),
+            TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            } => Self::MultiAgentSignature(MultiAgentSignature {
+                sender: sender.into(),
+                secondary_signer_addresses: secondary_signer_addresses
+                    .iter()
+                    .map(|address| (*address).into())
+                    .collect(),
+                secondary_signers: secondary_signers.iter().map(|s| s.into()).collect(),
+            }),
+            TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                fee_payer_signer,
+            } => Self::FeePayerSignature(FeePayerSignature {
+                sender: sender.into(),
+                secondary_signer_addresses: secondary_signer_addresses
+                    .iter()
+                    .map(|address| (*address).into())
+                    .collect(),
+                secondary_signers: secondary_signers.iter().map(|s| s.into()).collect(),
+                fee_payer_address: (*fee_payer_address).into(),
+                fee_payer_signer: fee_payer_signer.into(),
+            }),
+            TransactionAuthenticator::SingleSender { sender } => Self::SingleSender(sender.into()),
+        }
+    }
+}
+
 /// A single Ed25519 signature
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Ed25519Signature {
@@ -412,6 +840,81 @@ pub struct MultiEd25519Signature {
     pub bitmap: HexEncodedBytes,
 }
 
+impl MultiEd25519Signature {
+    fn into_key_and_signature(
+        self,
+    ) -> Result<(MultiEd25519PublicKey, multi_ed25519::MultiEd25519Signature)> {
+        let MultiEd25519Signature {
+            public_keys,
+            signatures,
+            threshold,
+            bitmap,
+        } = self;
+        let public_keys = public_keys
+            .iter()
+            .map(|key| key.inner().try_into())
+            .collect::<Result<Vec<Ed25519PublicKey>, _>>()
+            .context("Failed to parse given public_keys bytes as Ed25519PublicKeys")?;
+        let signatures = signatures
+            .iter()
+            .map(|signature| signature.inner().try_into())
+            .collect::<Result<Vec<ed25519::Ed25519Signature>, _>>()
+            .context("Failed to parse given signatures as Ed25519Signatures")?;
+        let bitmap = bitmap
+            .inner()
+            .try_into()
+            .context("Failed to parse given bitmap, expected 4 bytes")?;
+        Ok((
+            MultiEd25519PublicKey::new(public_keys, threshold)
+                .context("Invalid MultiEd25519 public key")?,
+            multi_ed25519::MultiEd25519Signature::new_with_signatures_and_bitmap(
+                signatures, bitmap,
+            ),
+        ))
+    }
+}
+
+impl From<(&MultiEd25519PublicKey, &multi_ed25519::MultiEd25519Signature)>
+    for MultiEd25519Signature
+{
+    fn from((pk, sig): (&MultiEd25519PublicKey, &multi_ed25519::MultiEd25519Signature)) -> Self {
+        Self {
+            public_keys: pk
+                .public_keys()
+                .iter()
+                .map(|key| key.to_bytes().to_vec().into())
+                .collect(),
+            signatures: sig
+                .signatures()
+                .iter()
+                .map(|signature| signature.to_bytes().to_vec().into())
+                .collect(),
+            threshold: *pk.threshold(),
+            bitmap: sig.bitmap().to_vec().into(),
+        }
+    }
+}
+
+impl TryFrom<MultiEd25519Signature> for TransactionAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: MultiEd25519Signature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.into_key_and_signature()?;
+        Ok(TransactionAuthenticator::multi_ed25519(
+            public_key, signature,
+        ))
+    }
+}
+
+impl TryFrom<MultiEd25519Signature> for AccountAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: MultiEd25519Signature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.into_key_and_signature()?;
+        Ok(AccountAuthenticator::multi_ed25519(public_key, signature))
+    }
+}
+
 /// A single Secp256k1Ecdsa signature
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Secp256k1EcdsaSignature {
@@ -450,6 +953,31 @@ impl Ed25519 {
     }
 }
 
+impl From<&AnySignature> for Signature {
+    fn from(signature: &AnySignature) -> Self {
+        match signature {
+            AnySignature::Ed25519 { signature } => {
+                Signature::Ed25519(Ed25519::new(signature.to_bytes().to_vec().into()))
+            },
+        }
+    }
+}
+
+impl TryFrom<Signature> for AnySignature {
+    type Error = anyhow::Error;
+
+    fn try_from(signature: Signature) -> Result<Self, Self::Error> {
+        Ok(match signature {
+            Signature::Ed25519(s) => AnySignature::ed25519(
+                s.value
+                    .inner()
+                    .try_into()
+                    .context("Failed to parse given signature as a Ed25519Signature")?,
+            ),
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Secp256k1Ecdsa {
     pub value: HexEncodedBytes,
@@ -515,6 +1043,31 @@ pub enum PublicKey {
     // FederatedKeyless(FederatedKeyless),
 }
 
+impl From<&AnyPublicKey> for PublicKey {
+    fn from(public_key: &AnyPublicKey) -> Self {
+        match public_key {
+            AnyPublicKey::Ed25519 { public_key } => {
+                PublicKey::Ed25519(Ed25519::new(public_key.to_bytes().to_vec().into()))
+            },
+        }
+    }
+}
+
+impl TryFrom<PublicKey> for AnyPublicKey {
+    type Error = anyhow::Error;
+
+    fn try_from(public_key: PublicKey) -> Result<Self, Self::Error> {
+        Ok(match public_key {
+            PublicKey::Ed25519(p) => AnyPublicKey::ed25519(
+                p.value
+                    .inner()
+                    .try_into()
+                    .context("Failed to parse given public_key bytes as a Ed25519PublicKey")?,
+            ),
+        })
+    }
+}
+
 /// A single key signature
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SingleKeySignature {
@@ -522,6 +1075,25 @@ pub struct SingleKeySignature {
     pub signature: Signature,
 }
 
+impl From<&SingleKeyAuthenticator> for SingleKeySignature {
+    fn from(auth: &SingleKeyAuthenticator) -> Self {
+        Self {
+            public_key: auth.public_key().into(),
+            signature: auth.signature().into(),
+        }
+    }
+}
+
+impl TryFrom<SingleKeySignature> for AccountAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: SingleKeySignature) -> Result<Self, Self::Error> {
+        Ok(AccountAuthenticator::single_key(
+            SingleKeyAuthenticator::new(value.public_key.try_into()?, value.signature.try_into()?),
+        ))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IndexedSignature {
     pub index: u8,
@@ -536,10 +1108,65 @@ pub struct MultiKeySignature {
     pub signatures_required: u8,
 }
 
+impl From<&MultiKeyAuthenticator> for MultiKeySignature {
+    fn from(auth: &MultiKeyAuthenticator) -> Self {
+        Self {
+            public_keys: auth
+                .public_keys()
+                .public_keys()
+                .iter()
+                .map(PublicKey::from)
+                .collect(),
+            signatures: auth
+                .signatures()
+                .iter()
+                .map(|(index, signature)| IndexedSignature {
+                    index: *index,
+                    signature: signature.into(),
+                })
+                .collect(),
+            signatures_required: auth.public_keys().signatures_required(),
+        }
+    }
+}
+
+impl TryFrom<MultiKeySignature> for AccountAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: MultiKeySignature) -> Result<Self, Self::Error> {
+        let MultiKeySignature {
+            public_keys,
+            signatures,
+            signatures_required,
+        } = value;
+        let public_keys = MultiKey::new(
+            public_keys
+                .into_iter()
+                .map(|key| key.try_into())
+                .collect::<Result<_>>()?,
+            signatures_required,
+        )?;
+        let signatures = signatures
+            .into_iter()
+            .map(|s| Ok((s.index, s.signature.try_into()?)))
+            .collect::<Result<_>>()?;
+        Ok(AccountAuthenticator::multi_key(MultiKeyAuthenticator::new(
+            public_keys,
+            signatures,
+        )?))
+    }
+}
+
 /// A placeholder to represent the absence of account signature
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NoAccountSignature;
 
+impl From<NoAccountSignature> for AccountAuthenticator {
+    fn from(_: NoAccountSignature) -> Self {
+        AccountAuthenticator::NoAccountAuthenticator
+    }
+}
+
 /// Account signature scheme
 ///
 /// The account signature scheme allows you to have two types of accounts:
@@ -552,10 +1179,10 @@ pub struct NoAccountSignature;
 //#[oai(one_of, discriminator_name = "type", rename_all = "snake_case")]
 pub enum AccountSignature {
     Ed25519Signature(Ed25519Signature),
-    // MultiEd25519Signature(MultiEd25519Signature),
-    // SingleKeySignature(SingleKeySignature),
-    // MultiKeySignature(MultiKeySignature),
-    // NoAccountSignature(NoAccountSignature),
+    MultiEd25519Signature(MultiEd25519Signature),
+    SingleKeySignature(SingleKeySignature),
+    MultiKeySignature(MultiKeySignature),
+    NoAccountSignature(NoAccountSignature),
 }
 
 impl TryFrom<AccountSignature> for AccountAuthenticator {
@@ -564,14 +1191,38 @@ impl TryFrom<AccountSignature> for AccountAuthenticator {
     fn try_from(sig: AccountSignature) -> anyhow::Result<Self> {
         Ok(match sig {
             AccountSignature::Ed25519Signature(s) => s.try_into()?,
-            // AccountSignature::MultiEd25519Signature(s) => s.try_into()?,
-            // AccountSignature::SingleKeySignature(s) => s.try_into()?,
-            // AccountSignature::MultiKeySignature(s) => s.try_into()?,
-            // AccountSignature::NoAccountSignature(s) => s.try_into()?,
+            AccountSignature::MultiEd25519Signature(s) => s.try_into()?,
+            AccountSignature::SingleKeySignature(s) => s.try_into()?,
+            AccountSignature::MultiKeySignature(s) => s.try_into()?,
+            AccountSignature::NoAccountSignature(s) => s.into(),
         })
     }
 }
 
+impl From<&AccountAuthenticator> for AccountSignature {
+    fn from(auth: &AccountAuthenticator) -> Self {
+        match auth {
+            AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => Self::Ed25519Signature((public_key, signature).into()),
+            AccountAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => Self::MultiEd25519Signature((public_key, signature).into()),
+            AccountAuthenticator::SingleKey { authenticator } => {
+                Self::SingleKeySignature(authenticator.into())
+            },
+            AccountAuthenticator::MultiKey { authenticator } => {
+                Self::MultiKeySignature(authenticator.into())
+            },
+            AccountAuthenticator::NoAccountAuthenticator => {
+                Self::NoAccountSignature(NoAccountSignature)
+            },
+        }
+    }
+}
+
 /// Multi agent signature for multi agent transactions
 ///
 /// This allows you to have transactions across multiple accounts
@@ -584,6 +1235,29 @@ pub struct MultiAgentSignature {
     pub secondary_signers: Vec<AccountSignature>,
 }
 
+impl TryFrom<MultiAgentSignature> for TransactionAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: MultiAgentSignature) -> Result<Self, Self::Error> {
+        let MultiAgentSignature {
+            sender,
+            secondary_signer_addresses,
+            secondary_signers,
+        } = value;
+        Ok(TransactionAuthenticator::multi_agent(
+            sender.try_into()?,
+            secondary_signer_addresses
+                .into_iter()
+                .map(|address| address.into())
+                .collect(),
+            secondary_signers
+                .into_iter()
+                .map(|signer| signer.try_into())
+                .collect::<Result<_>>()?,
+        ))
+    }
+}
+
 impl From<(&Ed25519PublicKey, &ed25519::Ed25519Signature)> for Ed25519Signature {
     fn from((pk, sig): (&Ed25519PublicKey, &ed25519::Ed25519Signature)) -> Self {
         Self {
@@ -609,6 +1283,33 @@ pub struct FeePayerSignature {
     pub fee_payer_signer: AccountSignature,
 }
 
+impl TryFrom<FeePayerSignature> for TransactionAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: FeePayerSignature) -> Result<Self, Self::Error> {
+        let FeePayerSignature {
+            sender,
+            secondary_signer_addresses,
+            secondary_signers,
+            fee_payer_address,
+            fee_payer_signer,
+        } = value;
+        Ok(TransactionAuthenticator::fee_payer(
+            sender.try_into()?,
+            secondary_signer_addresses
+                .into_iter()
+                .map(|address| address.into())
+                .collect(),
+            secondary_signers
+                .into_iter()
+                .map(|signer| signer.try_into())
+                .collect::<Result<_>>()?,
+            fee_payer_address.into(),
+            fee_payer_signer.try_into()?,
+        ))
+    }
+}
+
 /// A transaction identifier
 ///
 /// There are 2 types transaction ids from HTTP request inputs:
@@ -673,3 +1374,98 @@ pub struct GasEstimation {
     /// The prioritized estimate for the gas unit price
     pub prioritized_gas_estimate: Option<u64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_types::account_address::AccountAddress;
+    use serde_json::json;
+
+    fn simulation_response(success: bool, vm_status: &str) -> serde_json::Value {
+        let hash = "0xb78e1ba6fa7f7b3a3f3ac2a31e6675d84f2261c711c3b438a252f648b26df3ed";
+        json!([{
+            "version": "0",
+            "hash": hash,
+            "state_change_hash": hash,
+            "event_root_hash": hash,
+            "state_checkpoint_hash": null,
+            "gas_used": "12",
+            "success": success,
+            "vm_status": vm_status,
+            "accumulator_root_hash": hash,
+            "changes": [{
+                "type": "write_table_item",
+                "state_key_hash": hash,
+                "handle": "0x1b854694ae746cdbd8d44186ca4929b2b337df21d1c74633be19b2710552fdca",
+                "key": "0x0619dc29a0aac8fa146714058e8dd6d2d0f3bdf5f6331907bf91f3acd81e6935",
+                "value": "0x708f579252b9010000000000000000"
+            }],
+            "sender": "0x2",
+            "sequence_number": "18446744073709551615",
+            "max_gas_amount": "200000",
+            "gas_unit_price": "100",
+            "expiration_timestamp_secs": "1700000030",
+            "payload": {
+                "type": "entry_function_payload",
+                "function": "0x1::aptos_account::transfer",
+                "type_arguments": [],
+                "arguments": ["0x3", "100"]
+            },
+            "signature": {
+                "type": "fee_payer_signature",
+                "sender": {"type": "no_account_signature"},
+                "secondary_signer_addresses": [],
+                "secondary_signers": [],
+                "fee_payer_address": "0x0",
+                "fee_payer_signer": {"type": "no_account_signature"}
+            },
+            "events": [],
+            "timestamp": "1700000000000000"
+        }])
+    }
+
+    #[test]
+    fn test_simulation_result() {
+        let body = simulation_response(true, "Executed successfully").to_string();
+        let result = SimulationResult::from_json(body.as_bytes()).unwrap();
+        assert!(result.success);
+        assert_eq!(result.gas_used, 12);
+        assert_eq!(result.gas_fee(), 1_200);
+        assert_eq!(result.changes.len(), 1);
+        assert!(matches!(
+            result.changes[0],
+            WriteSetChange::WriteTableItem(_)
+        ));
+        assert!(result.into_result().is_ok());
+
+        let body = simulation_response(false, "Move abort in 0x1::coin: 0x10006").to_string();
+        let error = SimulationResult::from_json(body.as_bytes())
+            .unwrap()
+            .into_result()
+            .unwrap_err();
+        assert!(error.to_string().contains("0x10006"));
+
+        assert!(SimulationResult::from_json(b"[]").is_err());
+    }
+
+    #[test]
+    fn test_no_account_signature() {
+        let body = simulation_response(true, "Executed successfully");
+        let txns: Vec<UserTransaction> = serde_json::from_value(body).unwrap();
+        let signature = txns[0].request.signature.clone().unwrap();
+        let authenticator = TransactionAuthenticator::try_from(signature.clone()).unwrap();
+        assert_eq!(
+            authenticator,
+            TransactionAuthenticator::fee_payer(
+                AccountAuthenticator::NoAccountAuthenticator,
+                vec![],
+                vec![],
+                AccountAddress::ZERO,
+                AccountAuthenticator::NoAccountAuthenticator,
+            )
+        );
+        // The signature serializes back to the node's JSON.
+        let value = serde_json::to_value(&signature).unwrap();
+        assert_eq!(value["sender"], json!({"type": "no_account_signature"}));
+    }
+}
@@ -1805,21 +1805,21 @@ impl EntryFunctionCall {
     //     }
     // }
 
-    // /// Try to recognize an Aptos `TransactionPayload` and convert it into a structured object `EntryFunctionCall`.
-    // pub fn decode(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-    //     if let TransactionPayload::EntryFunction(script) = payload {
-    //         match SCRIPT_FUNCTION_DECODER_MAP.get(&format!(
-    //             "{}_{}",
-    //             script.module().name(),
-    //             script.function()
-    //         )) {
-    //             Some(decoder) => decoder(payload),
-    //             None => None,
-    //         }
-    //     } else {
-    //         None
-    //     }
-    // }
+    /// Try to recognize an Aptos `TransactionPayload` and convert it into a structured object `EntryFunctionCall`.
+    pub fn decode(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            match SCRIPT_FUNCTION_DECODER_MAP.get(&format!(
+                "{}_{}",
+                script.module().name(),
+                script.function()
+            )) {
+                Some(decoder) => decoder(payload),
+                None => None,
+            }
+        } else {
+            None
+        }
+    }
 }
 
 /// Offers rotation capability on behalf of `account` to the account at address `recipient_address`.
@@ -2647,386 +2647,386 @@ pub fn coin_transfer(coin_type: TypeTag, to: AccountAddress, amount: u64) -> Tra
 //     ))
 // }
 
-// /// Add `amount` of coins to the delegation pool `pool_address`.
-// pub fn delegation_pool_add_stake(pool_address: AccountAddress, amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("add_stake").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&amount).unwrap(),
-//         ],
-//     ))
-// }
+/// Add `amount` of coins to the delegation pool `pool_address`.
+pub fn delegation_pool_add_stake(pool_address: AccountAddress, amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("add_stake").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&amount).unwrap(),
+        ],
+    ))
+}
 
-// /// Allowlist a delegator as the pool owner.
-// pub fn delegation_pool_allowlist_delegator(
-//     delegator_address: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("allowlist_delegator").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&delegator_address).unwrap()],
-//     ))
-// }
+/// Allowlist a delegator as the pool owner.
+pub fn delegation_pool_allowlist_delegator(
+    delegator_address: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("allowlist_delegator").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&delegator_address).unwrap()],
+    ))
+}
 
-// /// A voter could create a governance proposal by this function. To successfully create a proposal, the voter's
-// /// voting power in THIS delegation pool must be not less than the minimum required voting power specified in
-// /// `aptos_governance.move`.
-// pub fn delegation_pool_create_proposal(
-//     pool_address: AccountAddress,
-//     execution_hash: Vec<u8>,
-//     metadata_location: Vec<u8>,
-//     metadata_hash: Vec<u8>,
-//     is_multi_step_proposal: bool,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("create_proposal").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&execution_hash).unwrap(),
-//             bcs::to_bytes(&metadata_location).unwrap(),
-//             bcs::to_bytes(&metadata_hash).unwrap(),
-//             bcs::to_bytes(&is_multi_step_proposal).unwrap(),
-//         ],
-//     ))
-// }
+/// A voter could create a governance proposal by this function. To successfully create a proposal, the voter's
+/// voting power in THIS delegation pool must be not less than the minimum required voting power specified in
+/// `aptos_governance.move`.
+pub fn delegation_pool_create_proposal(
+    pool_address: AccountAddress,
+    execution_hash: Vec<u8>,
+    metadata_location: Vec<u8>,
+    metadata_hash: Vec<u8>,
+    is_multi_step_proposal: bool,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("create_proposal").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&execution_hash).unwrap(),
+            bcs::to_bytes(&metadata_location).unwrap(),
+            bcs::to_bytes(&metadata_hash).unwrap(),
+            bcs::to_bytes(&is_multi_step_proposal).unwrap(),
+        ],
+    ))
+}
 
-// /// Allows a delegator to delegate its voting power to a voter. If this delegator already has a delegated voter,
-// /// this change won't take effects until the next lockup period.
-// pub fn delegation_pool_delegate_voting_power(
-//     pool_address: AccountAddress,
-//     new_voter: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("delegate_voting_power").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&new_voter).unwrap(),
-//         ],
-//     ))
-// }
+/// Allows a delegator to delegate its voting power to a voter. If this delegator already has a delegated voter,
+/// this change won't take effects until the next lockup period.
+pub fn delegation_pool_delegate_voting_power(
+    pool_address: AccountAddress,
+    new_voter: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("delegate_voting_power").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&new_voter).unwrap(),
+        ],
+    ))
+}
 
-// /// Disable delegators allowlisting as the pool owner. The existing allowlist will be emptied.
-// pub fn delegation_pool_disable_delegators_allowlisting() -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("disable_delegators_allowlisting").to_owned(),
-//         vec![],
-//         vec![],
-//     ))
-// }
+/// Disable delegators allowlisting as the pool owner. The existing allowlist will be emptied.
+pub fn delegation_pool_disable_delegators_allowlisting() -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("disable_delegators_allowlisting").to_owned(),
+        vec![],
+        vec![],
+    ))
+}
 
-// /// Enable delegators allowlisting as the pool owner.
-// pub fn delegation_pool_enable_delegators_allowlisting() -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("enable_delegators_allowlisting").to_owned(),
-//         vec![],
-//         vec![],
-//     ))
-// }
+/// Enable delegators allowlisting as the pool owner.
+pub fn delegation_pool_enable_delegators_allowlisting() -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("enable_delegators_allowlisting").to_owned(),
+        vec![],
+        vec![],
+    ))
+}
 
-// /// Enable partial governance voting on a stake pool. The voter of this stake pool will be managed by this module.
-// /// The existing voter will be replaced. The function is permissionless.
-// pub fn delegation_pool_enable_partial_governance_voting(
-//     pool_address: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("enable_partial_governance_voting").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&pool_address).unwrap()],
-//     ))
-// }
+/// Enable partial governance voting on a stake pool. The voter of this stake pool will be managed by this module.
+/// The existing voter will be replaced. The function is permissionless.
+pub fn delegation_pool_enable_partial_governance_voting(
+    pool_address: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("enable_partial_governance_voting").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&pool_address).unwrap()],
+    ))
+}
 
-// /// Evict a delegator that is not allowlisted by unlocking their entire stake.
-// pub fn delegation_pool_evict_delegator(delegator_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("evict_delegator").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&delegator_address).unwrap()],
-//     ))
-// }
+/// Evict a delegator that is not allowlisted by unlocking their entire stake.
+pub fn delegation_pool_evict_delegator(delegator_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("evict_delegator").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&delegator_address).unwrap()],
+    ))
+}
 
-// /// Initialize a delegation pool of custom fixed `operator_commission_percentage`.
-// /// A resource account is created from `owner` signer and its supplied `delegation_pool_creation_seed`
-// /// to host the delegation pool resource and own the underlying stake pool.
-// /// Ownership over setting the operator/voter is granted to `owner` who has both roles initially.
-// pub fn delegation_pool_initialize_delegation_pool(
-//     operator_commission_percentage: u64,
-//     delegation_pool_creation_seed: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("initialize_delegation_pool").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&operator_commission_percentage).unwrap(),
-//             bcs::to_bytes(&delegation_pool_creation_seed).unwrap(),
-//         ],
-//     ))
-// }
+/// Initialize a delegation pool of custom fixed `operator_commission_percentage`.
+/// A resource account is created from `owner` signer and its supplied `delegation_pool_creation_seed`
+/// to host the delegation pool resource and own the underlying stake pool.
+/// Ownership over setting the operator/voter is granted to `owner` who has both roles initially.
+pub fn delegation_pool_initialize_delegation_pool(
+    operator_commission_percentage: u64,
+    delegation_pool_creation_seed: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("initialize_delegation_pool").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&operator_commission_percentage).unwrap(),
+            bcs::to_bytes(&delegation_pool_creation_seed).unwrap(),
+        ],
+    ))
+}
 
-// /// Move `amount` of coins from pending_inactive to active.
-// pub fn delegation_pool_reactivate_stake(
-//     pool_address: AccountAddress,
-//     amount: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("reactivate_stake").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&amount).unwrap(),
-//         ],
-//     ))
-// }
+/// Move `amount` of coins from pending_inactive to active.
+pub fn delegation_pool_reactivate_stake(
+    pool_address: AccountAddress,
+    amount: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("reactivate_stake").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&amount).unwrap(),
+        ],
+    ))
+}
 
-// /// Remove a delegator from the allowlist as the pool owner, but do not unlock their stake.
-// pub fn delegation_pool_remove_delegator_from_allowlist(
-//     delegator_address: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("remove_delegator_from_allowlist").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&delegator_address).unwrap()],
-//     ))
-// }
+/// Remove a delegator from the allowlist as the pool owner, but do not unlock their stake.
+pub fn delegation_pool_remove_delegator_from_allowlist(
+    delegator_address: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("remove_delegator_from_allowlist").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&delegator_address).unwrap()],
+    ))
+}
 
-// /// Allows an operator to change its beneficiary. Any existing unpaid commission rewards will be paid to the new
-// /// beneficiary. To ensure payment to the current beneficiary, one should first call `synchronize_delegation_pool`
-// /// before switching the beneficiary. An operator can set one beneficiary for delegation pools, not a separate
-// /// one for each pool.
-// pub fn delegation_pool_set_beneficiary_for_operator(
-//     new_beneficiary: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("set_beneficiary_for_operator").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_beneficiary).unwrap()],
-//     ))
-// }
+/// Allows an operator to change its beneficiary. Any existing unpaid commission rewards will be paid to the new
+/// beneficiary. To ensure payment to the current beneficiary, one should first call `synchronize_delegation_pool`
+/// before switching the beneficiary. An operator can set one beneficiary for delegation pools, not a separate
+/// one for each pool.
+pub fn delegation_pool_set_beneficiary_for_operator(
+    new_beneficiary: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("set_beneficiary_for_operator").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_beneficiary).unwrap()],
+    ))
+}
 
-// /// Allows an owner to change the delegated voter of the underlying stake pool.
-// pub fn delegation_pool_set_delegated_voter(new_voter: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("set_delegated_voter").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_voter).unwrap()],
-//     ))
-// }
+/// Allows an owner to change the delegated voter of the underlying stake pool.
+pub fn delegation_pool_set_delegated_voter(new_voter: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("set_delegated_voter").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_voter).unwrap()],
+    ))
+}
 
-// /// Allows an owner to change the operator of the underlying stake pool.
-// pub fn delegation_pool_set_operator(new_operator: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("set_operator").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_operator).unwrap()],
-//     ))
-// }
+/// Allows an owner to change the operator of the underlying stake pool.
+pub fn delegation_pool_set_operator(new_operator: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("set_operator").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_operator).unwrap()],
+    ))
+}
 
-// /// Synchronize delegation and stake pools: distribute yet-undetected rewards to the corresponding internal
-// /// shares pools, assign commission to operator and eventually prepare delegation pool for a new lockup cycle.
-// pub fn delegation_pool_synchronize_delegation_pool(
-//     pool_address: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("synchronize_delegation_pool").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&pool_address).unwrap()],
-//     ))
-// }
+/// Synchronize delegation and stake pools: distribute yet-undetected rewards to the corresponding internal
+/// shares pools, assign commission to operator and eventually prepare delegation pool for a new lockup cycle.
+pub fn delegation_pool_synchronize_delegation_pool(
+    pool_address: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("synchronize_delegation_pool").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&pool_address).unwrap()],
+    ))
+}
 
-// /// Unlock `amount` from the active + pending_active stake of `delegator` or
-// /// at most how much active stake there is on the stake pool.
-// pub fn delegation_pool_unlock(pool_address: AccountAddress, amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("unlock").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&amount).unwrap(),
-//         ],
-//     ))
-// }
+/// Unlock `amount` from the active + pending_active stake of `delegator` or
+/// at most how much active stake there is on the stake pool.
+pub fn delegation_pool_unlock(pool_address: AccountAddress, amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("unlock").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&amount).unwrap(),
+        ],
+    ))
+}
 
-// /// Allows an owner to update the commission percentage for the operator of the underlying stake pool.
-// pub fn delegation_pool_update_commission_percentage(
-//     new_commission_percentage: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("update_commission_percentage").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_commission_percentage).unwrap()],
-//     ))
-// }
+/// Allows an owner to update the commission percentage for the operator of the underlying stake pool.
+pub fn delegation_pool_update_commission_percentage(
+    new_commission_percentage: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("update_commission_percentage").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_commission_percentage).unwrap()],
+    ))
+}
 
-// /// Vote on a proposal with a voter's voting power. To successfully vote, the following conditions must be met:
-// /// 1. The voting period of the proposal hasn't ended.
-// /// 2. The delegation pool's lockup period ends after the voting period of the proposal.
-// /// 3. The voter still has spare voting power on this proposal.
-// /// 4. The delegation pool never votes on the proposal before enabling partial governance voting.
-// pub fn delegation_pool_vote(
-//     pool_address: AccountAddress,
-//     proposal_id: u64,
-//     voting_power: u64,
-//     should_pass: bool,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("vote").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&proposal_id).unwrap(),
-//             bcs::to_bytes(&voting_power).unwrap(),
-//             bcs::to_bytes(&should_pass).unwrap(),
-//         ],
-//     ))
-// }
+/// Vote on a proposal with a voter's voting power. To successfully vote, the following conditions must be met:
+/// 1. The voting period of the proposal hasn't ended.
+/// 2. The delegation pool's lockup period ends after the voting period of the proposal.
+/// 3. The voter still has spare voting power on this proposal.
+/// 4. The delegation pool never votes on the proposal before enabling partial governance voting.
+pub fn delegation_pool_vote(
+    pool_address: AccountAddress,
+    proposal_id: u64,
+    voting_power: u64,
+    should_pass: bool,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("vote").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&proposal_id).unwrap(),
+            bcs::to_bytes(&voting_power).unwrap(),
+            bcs::to_bytes(&should_pass).unwrap(),
+        ],
+    ))
+}
 
-// /// Withdraw `amount` of owned inactive stake from the delegation pool at `pool_address`.
-// pub fn delegation_pool_withdraw(pool_address: AccountAddress, amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("delegation_pool").to_owned(),
-//         ),
-//         ident_str!("withdraw").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&amount).unwrap(),
-//         ],
-//     ))
-// }
+/// Withdraw `amount` of owned inactive stake from the delegation pool at `pool_address`.
+pub fn delegation_pool_withdraw(pool_address: AccountAddress, amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        ident_str!("withdraw").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&amount).unwrap(),
+        ],
+    ))
+}
 
 /// This can be called to install or update a set of JWKs for a federated OIDC provider.  This function should
 /// be invoked to intially install a set of JWKs or to update a set of JWKs when a keypair is rotated.
@@ -3208,654 +3208,654 @@ pub fn managed_coin_burn(coin_type: TypeTag, amount: u64) -> TransactionPayload
 //     ))
 // }
 
-// /// Similar to add_owners, but only allow adding one owner.
-// pub fn multisig_account_add_owner(new_owner: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("add_owner").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_owner).unwrap()],
-//     ))
-// }
-
-// /// Add new owners to the multisig account. This can only be invoked by the multisig account itself, through the
-// /// proposal flow.
-// ///
-// /// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
-// /// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
-// /// maliciously alter the owners list.
-// pub fn multisig_account_add_owners(new_owners: Vec<AccountAddress>) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("add_owners").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_owners).unwrap()],
-//     ))
-// }
+/// Similar to add_owners, but only allow adding one owner.
+pub fn multisig_account_add_owner(new_owner: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("add_owner").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_owner).unwrap()],
+    ))
+}
 
-// /// Add owners then update number of signatures required, in a single operation.
-// pub fn multisig_account_add_owners_and_update_signatures_required(
-//     new_owners: Vec<AccountAddress>,
-//     new_num_signatures_required: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("add_owners_and_update_signatures_required").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&new_owners).unwrap(),
-//             bcs::to_bytes(&new_num_signatures_required).unwrap(),
-//         ],
-//     ))
-// }
+/// Add new owners to the multisig account. This can only be invoked by the multisig account itself, through the
+/// proposal flow.
+///
+/// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
+/// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
+/// maliciously alter the owners list.
+pub fn multisig_account_add_owners(new_owners: Vec<AccountAddress>) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("add_owners").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_owners).unwrap()],
+    ))
+}
 
-// /// Approve a multisig transaction.
-// pub fn multisig_account_approve_transaction(
-//     multisig_account: AccountAddress,
-//     sequence_number: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("approve_transaction").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&sequence_number).unwrap(),
-//         ],
-//     ))
-// }
+/// Add owners then update number of signatures required, in a single operation.
+pub fn multisig_account_add_owners_and_update_signatures_required(
+    new_owners: Vec<AccountAddress>,
+    new_num_signatures_required: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("add_owners_and_update_signatures_required").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&new_owners).unwrap(),
+            bcs::to_bytes(&new_num_signatures_required).unwrap(),
+        ],
+    ))
+}
 
-// /// Creates a new multisig account and add the signer as a single owner.
-// pub fn multisig_account_create(
-//     num_signatures_required: u64,
-//     metadata_keys: Vec<Vec<u8>>,
-//     metadata_values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&num_signatures_required).unwrap(),
-//             bcs::to_bytes(&metadata_keys).unwrap(),
-//             bcs::to_bytes(&metadata_values).unwrap(),
-//         ],
-//     ))
-// }
+/// Approve a multisig transaction.
+pub fn multisig_account_approve_transaction(
+    multisig_account: AccountAddress,
+    sequence_number: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("approve_transaction").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&sequence_number).unwrap(),
+        ],
+    ))
+}
 
-// /// Create a multisig transaction, which will have one approval initially (from the creator).
-// pub fn multisig_account_create_transaction(
-//     multisig_account: AccountAddress,
-//     payload: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_transaction").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&payload).unwrap(),
-//         ],
-//     ))
-// }
+/// Creates a new multisig account and add the signer as a single owner.
+pub fn multisig_account_create(
+    num_signatures_required: u64,
+    metadata_keys: Vec<Vec<u8>>,
+    metadata_values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&num_signatures_required).unwrap(),
+            bcs::to_bytes(&metadata_keys).unwrap(),
+            bcs::to_bytes(&metadata_values).unwrap(),
+        ],
+    ))
+}
 
-// /// Create a multisig transaction with a transaction hash instead of the full payload.
-// /// This means the payload will be stored off chain for gas saving. Later, during execution, the executor will need
-// /// to provide the full payload, which will be validated against the hash stored on-chain.
-// pub fn multisig_account_create_transaction_with_hash(
-//     multisig_account: AccountAddress,
-//     payload_hash: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_transaction_with_hash").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&payload_hash).unwrap(),
-//         ],
-//     ))
-// }
+/// Create a multisig transaction, which will have one approval initially (from the creator).
+pub fn multisig_account_create_transaction(
+    multisig_account: AccountAddress,
+    payload: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_transaction").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&payload).unwrap(),
+        ],
+    ))
+}
 
-// /// Creates a new multisig account on top of an existing account.
-// ///
-// /// This offers a migration path for an existing account with a multi-ed25519 auth key (native multisig account).
-// /// In order to ensure a malicious module cannot obtain backdoor control over an existing account, a signed message
-// /// with a valid signature from the account's auth key is required.
-// ///
-// /// Note that this does not revoke auth key-based control over the account. Owners should separately rotate the auth
-// /// key after they are fully migrated to the new multisig account. Alternatively, they can call
-// /// create_with_existing_account_and_revoke_auth_key instead.
-// pub fn multisig_account_create_with_existing_account(
-//     multisig_address: AccountAddress,
-//     owners: Vec<AccountAddress>,
-//     num_signatures_required: u64,
-//     account_scheme: u8,
-//     account_public_key: Vec<u8>,
-//     create_multisig_account_signed_message: Vec<u8>,
-//     metadata_keys: Vec<Vec<u8>>,
-//     metadata_values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_with_existing_account").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_address).unwrap(),
-//             bcs::to_bytes(&owners).unwrap(),
-//             bcs::to_bytes(&num_signatures_required).unwrap(),
-//             bcs::to_bytes(&account_scheme).unwrap(),
-//             bcs::to_bytes(&account_public_key).unwrap(),
-//             bcs::to_bytes(&create_multisig_account_signed_message).unwrap(),
-//             bcs::to_bytes(&metadata_keys).unwrap(),
-//             bcs::to_bytes(&metadata_values).unwrap(),
-//         ],
-//     ))
-// }
+/// Create a multisig transaction with a transaction hash instead of the full payload.
+/// This means the payload will be stored off chain for gas saving. Later, during execution, the executor will need
+/// to provide the full payload, which will be validated against the hash stored on-chain.
+pub fn multisig_account_create_transaction_with_hash(
+    multisig_account: AccountAddress,
+    payload_hash: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_transaction_with_hash").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&payload_hash).unwrap(),
+        ],
+    ))
+}
 
-// /// Creates a new multisig account on top of an existing account and immediately rotate the origin auth key to 0x0.
-// ///
-// /// Note: If the original account is a resource account, this does not revoke all control over it as if any
-// /// SignerCapability of the resource account still exists, it can still be used to generate the signer for the
-// /// account.
-// pub fn multisig_account_create_with_existing_account_and_revoke_auth_key(
-//     multisig_address: AccountAddress,
-//     owners: Vec<AccountAddress>,
-//     num_signatures_required: u64,
-//     account_scheme: u8,
-//     account_public_key: Vec<u8>,
-//     create_multisig_account_signed_message: Vec<u8>,
-//     metadata_keys: Vec<Vec<u8>>,
-//     metadata_values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_with_existing_account_and_revoke_auth_key").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_address).unwrap(),
-//             bcs::to_bytes(&owners).unwrap(),
-//             bcs::to_bytes(&num_signatures_required).unwrap(),
-//             bcs::to_bytes(&account_scheme).unwrap(),
-//             bcs::to_bytes(&account_public_key).unwrap(),
-//             bcs::to_bytes(&create_multisig_account_signed_message).unwrap(),
-//             bcs::to_bytes(&metadata_keys).unwrap(),
-//             bcs::to_bytes(&metadata_values).unwrap(),
-//         ],
-//     ))
-// }
+/// Creates a new multisig account on top of an existing account.
+///
+/// This offers a migration path for an existing account with a multi-ed25519 auth key (native multisig account).
+/// In order to ensure a malicious module cannot obtain backdoor control over an existing account, a signed message
+/// with a valid signature from the account's auth key is required.
+///
+/// Note that this does not revoke auth key-based control over the account. Owners should separately rotate the auth
+/// key after they are fully migrated to the new multisig account. Alternatively, they can call
+/// create_with_existing_account_and_revoke_auth_key instead.
+pub fn multisig_account_create_with_existing_account(
+    multisig_address: AccountAddress,
+    owners: Vec<AccountAddress>,
+    num_signatures_required: u64,
+    account_scheme: u8,
+    account_public_key: Vec<u8>,
+    create_multisig_account_signed_message: Vec<u8>,
+    metadata_keys: Vec<Vec<u8>>,
+    metadata_values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_with_existing_account").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_address).unwrap(),
+            bcs::to_bytes(&owners).unwrap(),
+            bcs::to_bytes(&num_signatures_required).unwrap(),
+            bcs::to_bytes(&account_scheme).unwrap(),
+            bcs::to_bytes(&account_public_key).unwrap(),
+            bcs::to_bytes(&create_multisig_account_signed_message).unwrap(),
+            bcs::to_bytes(&metadata_keys).unwrap(),
+            bcs::to_bytes(&metadata_values).unwrap(),
+        ],
+    ))
+}
 
-// /// Private entry function that creates a new multisig account on top of an existing account and immediately rotate
-// /// the origin auth key to 0x0.
-// ///
-// /// Note: If the original account is a resource account, this does not revoke all control over it as if any
-// /// SignerCapability of the resource account still exists, it can still be used to generate the signer for the
-// /// account.
-// pub fn multisig_account_create_with_existing_account_and_revoke_auth_key_call(
-//     owners: Vec<AccountAddress>,
-//     num_signatures_required: u64,
-//     metadata_keys: Vec<Vec<u8>>,
-//     metadata_values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_with_existing_account_and_revoke_auth_key_call").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&owners).unwrap(),
-//             bcs::to_bytes(&num_signatures_required).unwrap(),
-//             bcs::to_bytes(&metadata_keys).unwrap(),
-//             bcs::to_bytes(&metadata_values).unwrap(),
-//         ],
-//     ))
-// }
+/// Creates a new multisig account on top of an existing account and immediately rotate the origin auth key to 0x0.
+///
+/// Note: If the original account is a resource account, this does not revoke all control over it as if any
+/// SignerCapability of the resource account still exists, it can still be used to generate the signer for the
+/// account.
+pub fn multisig_account_create_with_existing_account_and_revoke_auth_key(
+    multisig_address: AccountAddress,
+    owners: Vec<AccountAddress>,
+    num_signatures_required: u64,
+    account_scheme: u8,
+    account_public_key: Vec<u8>,
+    create_multisig_account_signed_message: Vec<u8>,
+    metadata_keys: Vec<Vec<u8>>,
+    metadata_values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_with_existing_account_and_revoke_auth_key").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_address).unwrap(),
+            bcs::to_bytes(&owners).unwrap(),
+            bcs::to_bytes(&num_signatures_required).unwrap(),
+            bcs::to_bytes(&account_scheme).unwrap(),
+            bcs::to_bytes(&account_public_key).unwrap(),
+            bcs::to_bytes(&create_multisig_account_signed_message).unwrap(),
+            bcs::to_bytes(&metadata_keys).unwrap(),
+            bcs::to_bytes(&metadata_values).unwrap(),
+        ],
+    ))
+}
 
-// /// Private entry function that creates a new multisig account on top of an existing account.
-// ///
-// /// This offers a migration path for an existing account with any type of auth key.
-// ///
-// /// Note that this does not revoke auth key-based control over the account. Owners should separately rotate the auth
-// /// key after they are fully migrated to the new multisig account. Alternatively, they can call
-// /// create_with_existing_account_and_revoke_auth_key_call instead.
-// pub fn multisig_account_create_with_existing_account_call(
-//     owners: Vec<AccountAddress>,
-//     num_signatures_required: u64,
-//     metadata_keys: Vec<Vec<u8>>,
-//     metadata_values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_with_existing_account_call").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&owners).unwrap(),
-//             bcs::to_bytes(&num_signatures_required).unwrap(),
-//             bcs::to_bytes(&metadata_keys).unwrap(),
-//             bcs::to_bytes(&metadata_values).unwrap(),
-//         ],
-//     ))
-// }
+/// Private entry function that creates a new multisig account on top of an existing account and immediately rotate
+/// the origin auth key to 0x0.
+///
+/// Note: If the original account is a resource account, this does not revoke all control over it as if any
+/// SignerCapability of the resource account still exists, it can still be used to generate the signer for the
+/// account.
+pub fn multisig_account_create_with_existing_account_and_revoke_auth_key_call(
+    owners: Vec<AccountAddress>,
+    num_signatures_required: u64,
+    metadata_keys: Vec<Vec<u8>>,
+    metadata_values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_with_existing_account_and_revoke_auth_key_call").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&owners).unwrap(),
+            bcs::to_bytes(&num_signatures_required).unwrap(),
+            bcs::to_bytes(&metadata_keys).unwrap(),
+            bcs::to_bytes(&metadata_values).unwrap(),
+        ],
+    ))
+}
 
-// /// Creates a new multisig account with the specified additional owner list and signatures required.
-// ///
-// /// @param additional_owners The owner account who calls this function cannot be in the additional_owners and there
-// /// cannot be any duplicate owners in the list.
-// /// @param num_signatures_required The number of signatures required to execute a transaction. Must be at least 1 and
-// /// at most the total number of owners.
-// pub fn multisig_account_create_with_owners(
-//     additional_owners: Vec<AccountAddress>,
-//     num_signatures_required: u64,
-//     metadata_keys: Vec<Vec<u8>>,
-//     metadata_values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_with_owners").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&additional_owners).unwrap(),
-//             bcs::to_bytes(&num_signatures_required).unwrap(),
-//             bcs::to_bytes(&metadata_keys).unwrap(),
-//             bcs::to_bytes(&metadata_values).unwrap(),
-//         ],
-//     ))
-// }
+/// Private entry function that creates a new multisig account on top of an existing account.
+///
+/// This offers a migration path for an existing account with any type of auth key.
+///
+/// Note that this does not revoke auth key-based control over the account. Owners should separately rotate the auth
+/// key after they are fully migrated to the new multisig account. Alternatively, they can call
+/// create_with_existing_account_and_revoke_auth_key_call instead.
+pub fn multisig_account_create_with_existing_account_call(
+    owners: Vec<AccountAddress>,
+    num_signatures_required: u64,
+    metadata_keys: Vec<Vec<u8>>,
+    metadata_values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_with_existing_account_call").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&owners).unwrap(),
+            bcs::to_bytes(&num_signatures_required).unwrap(),
+            bcs::to_bytes(&metadata_keys).unwrap(),
+            bcs::to_bytes(&metadata_values).unwrap(),
+        ],
+    ))
+}
 
-// /// Like `create_with_owners`, but removes the calling account after creation.
-// ///
-// /// This is for creating a vanity multisig account from a bootstrapping account that should not
-// /// be an owner after the vanity multisig address has been secured.
-// pub fn multisig_account_create_with_owners_then_remove_bootstrapper(
-//     owners: Vec<AccountAddress>,
-//     num_signatures_required: u64,
-//     metadata_keys: Vec<Vec<u8>>,
-//     metadata_values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("create_with_owners_then_remove_bootstrapper").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&owners).unwrap(),
-//             bcs::to_bytes(&num_signatures_required).unwrap(),
-//             bcs::to_bytes(&metadata_keys).unwrap(),
-//             bcs::to_bytes(&metadata_values).unwrap(),
-//         ],
-//     ))
-// }
+/// Creates a new multisig account with the specified additional owner list and signatures required.
+///
+/// @param additional_owners The owner account who calls this function cannot be in the additional_owners and there
+/// cannot be any duplicate owners in the list.
+/// @param num_signatures_required The number of signatures required to execute a transaction. Must be at least 1 and
+/// at most the total number of owners.
+pub fn multisig_account_create_with_owners(
+    additional_owners: Vec<AccountAddress>,
+    num_signatures_required: u64,
+    metadata_keys: Vec<Vec<u8>>,
+    metadata_values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_with_owners").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&additional_owners).unwrap(),
+            bcs::to_bytes(&num_signatures_required).unwrap(),
+            bcs::to_bytes(&metadata_keys).unwrap(),
+            bcs::to_bytes(&metadata_values).unwrap(),
+        ],
+    ))
+}
 
-// /// Remove the next transaction if it has sufficient owner rejections.
-// pub fn multisig_account_execute_rejected_transaction(
-//     multisig_account: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("execute_rejected_transaction").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&multisig_account).unwrap()],
-//     ))
-// }
+/// Like `create_with_owners`, but removes the calling account after creation.
+///
+/// This is for creating a vanity multisig account from a bootstrapping account that should not
+/// be an owner after the vanity multisig address has been secured.
+pub fn multisig_account_create_with_owners_then_remove_bootstrapper(
+    owners: Vec<AccountAddress>,
+    num_signatures_required: u64,
+    metadata_keys: Vec<Vec<u8>>,
+    metadata_values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("create_with_owners_then_remove_bootstrapper").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&owners).unwrap(),
+            bcs::to_bytes(&num_signatures_required).unwrap(),
+            bcs::to_bytes(&metadata_keys).unwrap(),
+            bcs::to_bytes(&metadata_values).unwrap(),
+        ],
+    ))
+}
 
-// /// Remove the next transactions until the final_sequence_number if they have sufficient owner rejections.
-// pub fn multisig_account_execute_rejected_transactions(
-//     multisig_account: AccountAddress,
-//     final_sequence_number: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("execute_rejected_transactions").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&final_sequence_number).unwrap(),
-//         ],
-//     ))
-// }
+/// Remove the next transaction if it has sufficient owner rejections.
+pub fn multisig_account_execute_rejected_transaction(
+    multisig_account: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("execute_rejected_transaction").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&multisig_account).unwrap()],
+    ))
+}
 
-// /// Reject a multisig transaction.
-// pub fn multisig_account_reject_transaction(
-//     multisig_account: AccountAddress,
-//     sequence_number: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("reject_transaction").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&sequence_number).unwrap(),
-//         ],
-//     ))
-// }
+/// Remove the next transactions until the final_sequence_number if they have sufficient owner rejections.
+pub fn multisig_account_execute_rejected_transactions(
+    multisig_account: AccountAddress,
+    final_sequence_number: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("execute_rejected_transactions").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&final_sequence_number).unwrap(),
+        ],
+    ))
+}
 
-// /// Similar to remove_owners, but only allow removing one owner.
-// pub fn multisig_account_remove_owner(owner_to_remove: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("remove_owner").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&owner_to_remove).unwrap()],
-//     ))
-// }
+/// Reject a multisig transaction.
+pub fn multisig_account_reject_transaction(
+    multisig_account: AccountAddress,
+    sequence_number: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("reject_transaction").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&sequence_number).unwrap(),
+        ],
+    ))
+}
 
-// /// Remove owners from the multisig account. This can only be invoked by the multisig account itself, through the
-// /// proposal flow.
-// ///
-// /// This function skips any owners who are not in the multisig account's list of owners.
-// /// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
-// /// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
-// /// maliciously alter the owners list.
-// pub fn multisig_account_remove_owners(owners_to_remove: Vec<AccountAddress>) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("remove_owners").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&owners_to_remove).unwrap()],
-//     ))
-// }
+/// Similar to remove_owners, but only allow removing one owner.
+pub fn multisig_account_remove_owner(owner_to_remove: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("remove_owner").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&owner_to_remove).unwrap()],
+    ))
+}
 
-// /// Swap an owner in for an old one, without changing required signatures.
-// pub fn multisig_account_swap_owner(
-//     to_swap_in: AccountAddress,
-//     to_swap_out: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("swap_owner").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&to_swap_in).unwrap(),
-//             bcs::to_bytes(&to_swap_out).unwrap(),
-//         ],
-//     ))
-// }
+/// Remove owners from the multisig account. This can only be invoked by the multisig account itself, through the
+/// proposal flow.
+///
+/// This function skips any owners who are not in the multisig account's list of owners.
+/// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
+/// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
+/// maliciously alter the owners list.
+pub fn multisig_account_remove_owners(owners_to_remove: Vec<AccountAddress>) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("remove_owners").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&owners_to_remove).unwrap()],
+    ))
+}
 
-// /// Swap owners in and out, without changing required signatures.
-// pub fn multisig_account_swap_owners(
-//     to_swap_in: Vec<AccountAddress>,
-//     to_swap_out: Vec<AccountAddress>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("swap_owners").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&to_swap_in).unwrap(),
-//             bcs::to_bytes(&to_swap_out).unwrap(),
-//         ],
-//     ))
-// }
+/// Swap an owner in for an old one, without changing required signatures.
+pub fn multisig_account_swap_owner(
+    to_swap_in: AccountAddress,
+    to_swap_out: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("swap_owner").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&to_swap_in).unwrap(),
+            bcs::to_bytes(&to_swap_out).unwrap(),
+        ],
+    ))
+}
 
-// /// Swap owners in and out, updating number of required signatures.
-// pub fn multisig_account_swap_owners_and_update_signatures_required(
-//     new_owners: Vec<AccountAddress>,
-//     owners_to_remove: Vec<AccountAddress>,
-//     new_num_signatures_required: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("swap_owners_and_update_signatures_required").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&new_owners).unwrap(),
-//             bcs::to_bytes(&owners_to_remove).unwrap(),
-//             bcs::to_bytes(&new_num_signatures_required).unwrap(),
-//         ],
-//     ))
-// }
+/// Swap owners in and out, without changing required signatures.
+pub fn multisig_account_swap_owners(
+    to_swap_in: Vec<AccountAddress>,
+    to_swap_out: Vec<AccountAddress>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("swap_owners").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&to_swap_in).unwrap(),
+            bcs::to_bytes(&to_swap_out).unwrap(),
+        ],
+    ))
+}
 
-// /// Allow the multisig account to update its own metadata. Note that this overrides the entire existing metadata.
-// /// If any attributes are not specified in the metadata, they will be removed!
-// ///
-// /// This can only be invoked by the multisig account itself, through the proposal flow.
-// /// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
-// /// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
-// /// maliciously alter the number of signatures required.
-// pub fn multisig_account_update_metadata(
-//     keys: Vec<Vec<u8>>,
-//     values: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("update_metadata").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&keys).unwrap(),
-//             bcs::to_bytes(&values).unwrap(),
-//         ],
-//     ))
-// }
+/// Swap owners in and out, updating number of required signatures.
+pub fn multisig_account_swap_owners_and_update_signatures_required(
+    new_owners: Vec<AccountAddress>,
+    owners_to_remove: Vec<AccountAddress>,
+    new_num_signatures_required: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("swap_owners_and_update_signatures_required").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&new_owners).unwrap(),
+            bcs::to_bytes(&owners_to_remove).unwrap(),
+            bcs::to_bytes(&new_num_signatures_required).unwrap(),
+        ],
+    ))
+}
 
-// /// Update the number of signatures required to execute transaction in the specified multisig account.
-// ///
-// /// This can only be invoked by the multisig account itself, through the proposal flow.
-// /// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
-// /// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
-// /// maliciously alter the number of signatures required.
-// pub fn multisig_account_update_signatures_required(
-//     new_num_signatures_required: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("update_signatures_required").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_num_signatures_required).unwrap()],
-//     ))
-// }
+/// Allow the multisig account to update its own metadata. Note that this overrides the entire existing metadata.
+/// If any attributes are not specified in the metadata, they will be removed!
+///
+/// This can only be invoked by the multisig account itself, through the proposal flow.
+/// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
+/// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
+/// maliciously alter the number of signatures required.
+pub fn multisig_account_update_metadata(
+    keys: Vec<Vec<u8>>,
+    values: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("update_metadata").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&keys).unwrap(),
+            bcs::to_bytes(&values).unwrap(),
+        ],
+    ))
+}
 
-// /// Generic function that can be used to either approve or reject a multisig transaction
-// pub fn multisig_account_vote_transaction(
-//     multisig_account: AccountAddress,
-//     sequence_number: u64,
-//     approved: bool,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("vote_transaction").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&sequence_number).unwrap(),
-//             bcs::to_bytes(&approved).unwrap(),
-//         ],
-//     ))
-// }
+/// Update the number of signatures required to execute transaction in the specified multisig account.
+///
+/// This can only be invoked by the multisig account itself, through the proposal flow.
+/// Note that this function is not public so it can only be invoked directly instead of via a module or script. This
+/// ensures that a multisig transaction cannot lead to another module obtaining the multisig signer and using it to
+/// maliciously alter the number of signatures required.
+pub fn multisig_account_update_signatures_required(
+    new_num_signatures_required: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("update_signatures_required").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_num_signatures_required).unwrap()],
+    ))
+}
 
-// /// Generic function that can be used to either approve or reject a batch of transactions within a specified range.
-// pub fn multisig_account_vote_transactions(
-//     multisig_account: AccountAddress,
-//     starting_sequence_number: u64,
-//     final_sequence_number: u64,
-//     approved: bool,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("vote_transactions").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&starting_sequence_number).unwrap(),
-//             bcs::to_bytes(&final_sequence_number).unwrap(),
-//             bcs::to_bytes(&approved).unwrap(),
-//         ],
-//     ))
-// }
+/// Generic function that can be used to either approve or reject a multisig transaction
+pub fn multisig_account_vote_transaction(
+    multisig_account: AccountAddress,
+    sequence_number: u64,
+    approved: bool,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("vote_transaction").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&sequence_number).unwrap(),
+            bcs::to_bytes(&approved).unwrap(),
+        ],
+    ))
+}
 
-// /// Generic function that can be used to either approve or reject a multisig transaction
-// /// Retained for backward compatibility: the function with the typographical error in its name
-// /// will continue to be an accessible entry point.
-// pub fn multisig_account_vote_transanction(
-//     multisig_account: AccountAddress,
-//     sequence_number: u64,
-//     approved: bool,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("multisig_account").to_owned(),
-//         ),
-//         ident_str!("vote_transanction").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&multisig_account).unwrap(),
-//             bcs::to_bytes(&sequence_number).unwrap(),
-//             bcs::to_bytes(&approved).unwrap(),
-//         ],
-//     ))
-// }
+/// Generic function that can be used to either approve or reject a batch of transactions within a specified range.
+pub fn multisig_account_vote_transactions(
+    multisig_account: AccountAddress,
+    starting_sequence_number: u64,
+    final_sequence_number: u64,
+    approved: bool,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("vote_transactions").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&starting_sequence_number).unwrap(),
+            bcs::to_bytes(&final_sequence_number).unwrap(),
+            bcs::to_bytes(&approved).unwrap(),
+        ],
+    ))
+}
+
+/// Generic function that can be used to either approve or reject a multisig transaction
+/// Retained for backward compatibility: the function with the typographical error in its name
+/// will continue to be an accessible entry point.
+pub fn multisig_account_vote_transanction(
+    multisig_account: AccountAddress,
+    sequence_number: u64,
+    approved: bool,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("multisig_account").to_owned(),
+        ),
+        ident_str!("vote_transanction").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&multisig_account).unwrap(),
+            bcs::to_bytes(&sequence_number).unwrap(),
+            bcs::to_bytes(&approved).unwrap(),
+        ],
+    ))
+}
 
 // /// Entry function that can be used to transfer, if allow_ungated_transfer is set true.
 // pub fn object_transfer_call(object: AccountAddress, to: AccountAddress) -> TransactionPayload {
@@ -3873,615 +3873,615 @@ pub fn managed_coin_burn(coin_type: TypeTag, amount: u64) -> TransactionPayload
 //     ))
 // }
 
-// /// Creates a new object with a unique address derived from the publisher address and the object seed.
-// /// Publishes the code passed in the function to the newly created object.
-// /// The caller must provide package metadata describing the package via `metadata_serialized` and
-// /// the code to be published via `code`. This contains a vector of modules to be deployed on-chain.
-// pub fn object_code_deployment_publish(
-//     metadata_serialized: Vec<u8>,
-//     code: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("object_code_deployment").to_owned(),
-//         ),
-//         ident_str!("publish").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&metadata_serialized).unwrap(),
-//             bcs::to_bytes(&code).unwrap(),
-//         ],
-//     ))
-// }
-
-// /// Creates a new resource account and rotates the authentication key to either
-// /// the optional auth key if it is non-empty (though auth keys are 32-bytes)
-// /// or the source accounts current auth key.
-// pub fn resource_account_create_resource_account(
-//     seed: Vec<u8>,
-//     optional_auth_key: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("resource_account").to_owned(),
-//         ),
-//         ident_str!("create_resource_account").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&seed).unwrap(),
-//             bcs::to_bytes(&optional_auth_key).unwrap(),
-//         ],
-//     ))
-// }
+/// Creates a new object with a unique address derived from the publisher address and the object seed.
+/// Publishes the code passed in the function to the newly created object.
+/// The caller must provide package metadata describing the package via `metadata_serialized` and
+/// the code to be published via `code`. This contains a vector of modules to be deployed on-chain.
+pub fn object_code_deployment_publish(
+    metadata_serialized: Vec<u8>,
+    code: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("object_code_deployment").to_owned(),
+        ),
+        ident_str!("publish").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&metadata_serialized).unwrap(),
+            bcs::to_bytes(&code).unwrap(),
+        ],
+    ))
+}
 
-// /// Creates a new resource account, transfer the amount of coins from the origin to the resource
-// /// account, and rotates the authentication key to either the optional auth key if it is
-// /// non-empty (though auth keys are 32-bytes) or the source accounts current auth key. Note,
-// /// this function adds additional resource ownership to the resource account and should only be
-// /// used for resource accounts that need access to `Coin<AptosCoin>`.
-// pub fn resource_account_create_resource_account_and_fund(
-//     seed: Vec<u8>,
-//     optional_auth_key: Vec<u8>,
-//     fund_amount: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("resource_account").to_owned(),
-//         ),
-//         ident_str!("create_resource_account_and_fund").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&seed).unwrap(),
-//             bcs::to_bytes(&optional_auth_key).unwrap(),
-//             bcs::to_bytes(&fund_amount).unwrap(),
-//         ],
-//     ))
-// }
+/// Creates a new resource account and rotates the authentication key to either
+/// the optional auth key if it is non-empty (though auth keys are 32-bytes)
+/// or the source accounts current auth key.
+pub fn resource_account_create_resource_account(
+    seed: Vec<u8>,
+    optional_auth_key: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("resource_account").to_owned(),
+        ),
+        ident_str!("create_resource_account").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&seed).unwrap(),
+            bcs::to_bytes(&optional_auth_key).unwrap(),
+        ],
+    ))
+}
 
-// /// Creates a new resource account, publishes the package under this account transaction under
-// /// this account and leaves the signer cap readily available for pickup.
-// pub fn resource_account_create_resource_account_and_publish_package(
-//     seed: Vec<u8>,
-//     metadata_serialized: Vec<u8>,
-//     code: Vec<Vec<u8>>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("resource_account").to_owned(),
-//         ),
-//         ident_str!("create_resource_account_and_publish_package").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&seed).unwrap(),
-//             bcs::to_bytes(&metadata_serialized).unwrap(),
-//             bcs::to_bytes(&code).unwrap(),
-//         ],
-//     ))
-// }
+/// Creates a new resource account, transfer the amount of coins from the origin to the resource
+/// account, and rotates the authentication key to either the optional auth key if it is
+/// non-empty (though auth keys are 32-bytes) or the source accounts current auth key. Note,
+/// this function adds additional resource ownership to the resource account and should only be
+/// used for resource accounts that need access to `Coin<AptosCoin>`.
+pub fn resource_account_create_resource_account_and_fund(
+    seed: Vec<u8>,
+    optional_auth_key: Vec<u8>,
+    fund_amount: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("resource_account").to_owned(),
+        ),
+        ident_str!("create_resource_account_and_fund").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&seed).unwrap(),
+            bcs::to_bytes(&optional_auth_key).unwrap(),
+            bcs::to_bytes(&fund_amount).unwrap(),
+        ],
+    ))
+}
 
-// /// Add `amount` of coins from the `account` owning the StakePool.
-// pub fn stake_add_stake(amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("add_stake").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&amount).unwrap()],
-//     ))
-// }
+/// Creates a new resource account, publishes the package under this account transaction under
+/// this account and leaves the signer cap readily available for pickup.
+pub fn resource_account_create_resource_account_and_publish_package(
+    seed: Vec<u8>,
+    metadata_serialized: Vec<u8>,
+    code: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("resource_account").to_owned(),
+        ),
+        ident_str!("create_resource_account_and_publish_package").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&seed).unwrap(),
+            bcs::to_bytes(&metadata_serialized).unwrap(),
+            bcs::to_bytes(&code).unwrap(),
+        ],
+    ))
+}
 
-// /// Similar to increase_lockup_with_cap but will use ownership capability from the signing account.
-// pub fn stake_increase_lockup() -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("increase_lockup").to_owned(),
-//         vec![],
-//         vec![],
-//     ))
-// }
+/// Add `amount` of coins from the `account` owning the StakePool.
+pub fn stake_add_stake(amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("add_stake").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&amount).unwrap()],
+    ))
+}
 
-// /// Initialize the validator account and give ownership to the signing account
-// /// except it leaves the ValidatorConfig to be set by another entity.
-// /// Note: this triggers setting the operator and owner, set it to the account's address
-// /// to set later.
-// pub fn stake_initialize_stake_owner(
-//     initial_stake_amount: u64,
-//     operator: AccountAddress,
-//     voter: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("initialize_stake_owner").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&initial_stake_amount).unwrap(),
-//             bcs::to_bytes(&operator).unwrap(),
-//             bcs::to_bytes(&voter).unwrap(),
-//         ],
-//     ))
-// }
+/// Similar to increase_lockup_with_cap but will use ownership capability from the signing account.
+pub fn stake_increase_lockup() -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("increase_lockup").to_owned(),
+        vec![],
+        vec![],
+    ))
+}
 
-// /// Initialize the validator account and give ownership to the signing account.
-// pub fn stake_initialize_validator(
-//     consensus_pubkey: Vec<u8>,
-//     proof_of_possession: Vec<u8>,
-//     network_addresses: Vec<u8>,
-//     fullnode_addresses: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("initialize_validator").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&consensus_pubkey).unwrap(),
-//             bcs::to_bytes(&proof_of_possession).unwrap(),
-//             bcs::to_bytes(&network_addresses).unwrap(),
-//             bcs::to_bytes(&fullnode_addresses).unwrap(),
-//         ],
-//     ))
-// }
+/// Initialize the validator account and give ownership to the signing account
+/// except it leaves the ValidatorConfig to be set by another entity.
+/// Note: this triggers setting the operator and owner, set it to the account's address
+/// to set later.
+pub fn stake_initialize_stake_owner(
+    initial_stake_amount: u64,
+    operator: AccountAddress,
+    voter: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("initialize_stake_owner").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&initial_stake_amount).unwrap(),
+            bcs::to_bytes(&operator).unwrap(),
+            bcs::to_bytes(&voter).unwrap(),
+        ],
+    ))
+}
 
-// /// This can only called by the operator of the validator/staking pool.
-// pub fn stake_join_validator_set(pool_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("join_validator_set").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&pool_address).unwrap()],
-//     ))
-// }
+/// Initialize the validator account and give ownership to the signing account.
+pub fn stake_initialize_validator(
+    consensus_pubkey: Vec<u8>,
+    proof_of_possession: Vec<u8>,
+    network_addresses: Vec<u8>,
+    fullnode_addresses: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("initialize_validator").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&consensus_pubkey).unwrap(),
+            bcs::to_bytes(&proof_of_possession).unwrap(),
+            bcs::to_bytes(&network_addresses).unwrap(),
+            bcs::to_bytes(&fullnode_addresses).unwrap(),
+        ],
+    ))
+}
 
-// /// Request to have `pool_address` leave the validator set. The validator is only actually removed from the set when
-// /// the next epoch starts.
-// /// The last validator in the set cannot leave. This is an edge case that should never happen as long as the network
-// /// is still operational.
-// ///
-// /// Can only be called by the operator of the validator/staking pool.
-// pub fn stake_leave_validator_set(pool_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("leave_validator_set").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&pool_address).unwrap()],
-//     ))
-// }
+/// This can only called by the operator of the validator/staking pool.
+pub fn stake_join_validator_set(pool_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("join_validator_set").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&pool_address).unwrap()],
+    ))
+}
 
-// /// Move `amount` of coins from pending_inactive to active.
-// pub fn stake_reactivate_stake(amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("reactivate_stake").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&amount).unwrap()],
-//     ))
-// }
+/// Request to have `pool_address` leave the validator set. The validator is only actually removed from the set when
+/// the next epoch starts.
+/// The last validator in the set cannot leave. This is an edge case that should never happen as long as the network
+/// is still operational.
+///
+/// Can only be called by the operator of the validator/staking pool.
+pub fn stake_leave_validator_set(pool_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("leave_validator_set").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&pool_address).unwrap()],
+    ))
+}
 
-// /// Rotate the consensus key of the validator, it'll take effect in next epoch.
-// pub fn stake_rotate_consensus_key(
-//     pool_address: AccountAddress,
-//     new_consensus_pubkey: Vec<u8>,
-//     proof_of_possession: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("rotate_consensus_key").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&new_consensus_pubkey).unwrap(),
-//             bcs::to_bytes(&proof_of_possession).unwrap(),
-//         ],
-//     ))
-// }
+/// Move `amount` of coins from pending_inactive to active.
+pub fn stake_reactivate_stake(amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("reactivate_stake").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&amount).unwrap()],
+    ))
+}
 
-// /// Allows an owner to change the delegated voter of the stake pool.
-// pub fn stake_set_delegated_voter(new_voter: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("set_delegated_voter").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_voter).unwrap()],
-//     ))
-// }
+/// Rotate the consensus key of the validator, it'll take effect in next epoch.
+pub fn stake_rotate_consensus_key(
+    pool_address: AccountAddress,
+    new_consensus_pubkey: Vec<u8>,
+    proof_of_possession: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("rotate_consensus_key").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&new_consensus_pubkey).unwrap(),
+            bcs::to_bytes(&proof_of_possession).unwrap(),
+        ],
+    ))
+}
 
-// /// Allows an owner to change the operator of the stake pool.
-// pub fn stake_set_operator(new_operator: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("set_operator").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_operator).unwrap()],
-//     ))
-// }
+/// Allows an owner to change the delegated voter of the stake pool.
+pub fn stake_set_delegated_voter(new_voter: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("set_delegated_voter").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_voter).unwrap()],
+    ))
+}
 
-// /// Similar to unlock_with_cap but will use ownership capability from the signing account.
-// pub fn stake_unlock(amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("unlock").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&amount).unwrap()],
-//     ))
-// }
+/// Allows an owner to change the operator of the stake pool.
+pub fn stake_set_operator(new_operator: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("set_operator").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_operator).unwrap()],
+    ))
+}
 
-// /// Update the network and full node addresses of the validator. This only takes effect in the next epoch.
-// pub fn stake_update_network_and_fullnode_addresses(
-//     pool_address: AccountAddress,
-//     new_network_addresses: Vec<u8>,
-//     new_fullnode_addresses: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("update_network_and_fullnode_addresses").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&pool_address).unwrap(),
-//             bcs::to_bytes(&new_network_addresses).unwrap(),
-//             bcs::to_bytes(&new_fullnode_addresses).unwrap(),
-//         ],
-//     ))
-// }
+/// Similar to unlock_with_cap but will use ownership capability from the signing account.
+pub fn stake_unlock(amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("unlock").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&amount).unwrap()],
+    ))
+}
 
-// /// Withdraw from `account`'s inactive stake.
-// pub fn stake_withdraw(withdraw_amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("stake").to_owned(),
-//         ),
-//         ident_str!("withdraw").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&withdraw_amount).unwrap()],
-//     ))
-// }
+/// Update the network and full node addresses of the validator. This only takes effect in the next epoch.
+pub fn stake_update_network_and_fullnode_addresses(
+    pool_address: AccountAddress,
+    new_network_addresses: Vec<u8>,
+    new_fullnode_addresses: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("update_network_and_fullnode_addresses").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&pool_address).unwrap(),
+            bcs::to_bytes(&new_network_addresses).unwrap(),
+            bcs::to_bytes(&new_fullnode_addresses).unwrap(),
+        ],
+    ))
+}
 
-// /// Add more stake to an existing staking contract.
-// pub fn staking_contract_add_stake(operator: AccountAddress, amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("add_stake").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&operator).unwrap(),
-//             bcs::to_bytes(&amount).unwrap(),
-//         ],
-//     ))
-// }
+/// Withdraw from `account`'s inactive stake.
+pub fn stake_withdraw(withdraw_amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("stake").to_owned(),
+        ),
+        ident_str!("withdraw").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&withdraw_amount).unwrap()],
+    ))
+}
 
-// /// Staker can call this function to create a simple staking contract with a specified operator.
-// pub fn staking_contract_create_staking_contract(
-//     operator: AccountAddress,
-//     voter: AccountAddress,
-//     amount: u64,
-//     commission_percentage: u64,
-//     contract_creation_seed: Vec<u8>,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("create_staking_contract").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&operator).unwrap(),
-//             bcs::to_bytes(&voter).unwrap(),
-//             bcs::to_bytes(&amount).unwrap(),
-//             bcs::to_bytes(&commission_percentage).unwrap(),
-//             bcs::to_bytes(&contract_creation_seed).unwrap(),
-//         ],
-//     ))
-// }
+/// Add more stake to an existing staking contract.
+pub fn staking_contract_add_stake(operator: AccountAddress, amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("add_stake").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&operator).unwrap(),
+            bcs::to_bytes(&amount).unwrap(),
+        ],
+    ))
+}
+
+/// Staker can call this function to create a simple staking contract with a specified operator.
+pub fn staking_contract_create_staking_contract(
+    operator: AccountAddress,
+    voter: AccountAddress,
+    amount: u64,
+    commission_percentage: u64,
+    contract_creation_seed: Vec<u8>,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("create_staking_contract").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&operator).unwrap(),
+            bcs::to_bytes(&voter).unwrap(),
+            bcs::to_bytes(&amount).unwrap(),
+            bcs::to_bytes(&commission_percentage).unwrap(),
+            bcs::to_bytes(&contract_creation_seed).unwrap(),
+        ],
+    ))
+}
 
-// /// Allow anyone to distribute already unlocked funds. This does not affect reward compounding and therefore does
-// /// not need to be restricted to just the staker or operator.
-// pub fn staking_contract_distribute(
-//     staker: AccountAddress,
-//     operator: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("distribute").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&staker).unwrap(),
-//             bcs::to_bytes(&operator).unwrap(),
-//         ],
-//     ))
-// }
+/// Allow anyone to distribute already unlocked funds. This does not affect reward compounding and therefore does
+/// not need to be restricted to just the staker or operator.
+pub fn staking_contract_distribute(
+    staker: AccountAddress,
+    operator: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("distribute").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&staker).unwrap(),
+            bcs::to_bytes(&operator).unwrap(),
+        ],
+    ))
+}
 
-// /// Unlock commission amount from the stake pool. Operator needs to wait for the amount to become withdrawable
-// /// at the end of the stake pool's lockup period before they can actually can withdraw_commission.
-// ///
-// /// Only staker, operator or beneficiary can call this.
-// pub fn staking_contract_request_commission(
-//     staker: AccountAddress,
-//     operator: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("request_commission").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&staker).unwrap(),
-//             bcs::to_bytes(&operator).unwrap(),
-//         ],
-//     ))
-// }
+/// Unlock commission amount from the stake pool. Operator needs to wait for the amount to become withdrawable
+/// at the end of the stake pool's lockup period before they can actually can withdraw_commission.
+///
+/// Only staker, operator or beneficiary can call this.
+pub fn staking_contract_request_commission(
+    staker: AccountAddress,
+    operator: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("request_commission").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&staker).unwrap(),
+            bcs::to_bytes(&operator).unwrap(),
+        ],
+    ))
+}
 
-// /// Convenient function to allow the staker to reset their stake pool's lockup period to start now.
-// pub fn staking_contract_reset_lockup(operator: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("reset_lockup").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&operator).unwrap()],
-//     ))
-// }
+/// Convenient function to allow the staker to reset their stake pool's lockup period to start now.
+pub fn staking_contract_reset_lockup(operator: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("reset_lockup").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&operator).unwrap()],
+    ))
+}
 
-// /// Allows an operator to change its beneficiary. Any existing unpaid commission rewards will be paid to the new
-// /// beneficiary. To ensures payment to the current beneficiary, one should first call `distribute` before switching
-// /// the beneficiary. An operator can set one beneficiary for staking contract pools, not a separate one for each pool.
-// pub fn staking_contract_set_beneficiary_for_operator(
-//     new_beneficiary: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("set_beneficiary_for_operator").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_beneficiary).unwrap()],
-//     ))
-// }
+/// Allows an operator to change its beneficiary. Any existing unpaid commission rewards will be paid to the new
+/// beneficiary. To ensures payment to the current beneficiary, one should first call `distribute` before switching
+/// the beneficiary. An operator can set one beneficiary for staking contract pools, not a separate one for each pool.
+pub fn staking_contract_set_beneficiary_for_operator(
+    new_beneficiary: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("set_beneficiary_for_operator").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_beneficiary).unwrap()],
+    ))
+}
 
-// /// Allows staker to switch operator without going through the lenghthy process to unstake.
-// pub fn staking_contract_switch_operator(
-//     old_operator: AccountAddress,
-//     new_operator: AccountAddress,
-//     new_commission_percentage: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("switch_operator").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&old_operator).unwrap(),
-//             bcs::to_bytes(&new_operator).unwrap(),
-//             bcs::to_bytes(&new_commission_percentage).unwrap(),
-//         ],
-//     ))
-// }
+/// Allows staker to switch operator without going through the lenghthy process to unstake.
+pub fn staking_contract_switch_operator(
+    old_operator: AccountAddress,
+    new_operator: AccountAddress,
+    new_commission_percentage: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("switch_operator").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&old_operator).unwrap(),
+            bcs::to_bytes(&new_operator).unwrap(),
+            bcs::to_bytes(&new_commission_percentage).unwrap(),
+        ],
+    ))
+}
 
-// /// Allows staker to switch operator without going through the lenghthy process to unstake, without resetting commission.
-// pub fn staking_contract_switch_operator_with_same_commission(
-//     old_operator: AccountAddress,
-//     new_operator: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("switch_operator_with_same_commission").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&old_operator).unwrap(),
-//             bcs::to_bytes(&new_operator).unwrap(),
-//         ],
-//     ))
-// }
+/// Allows staker to switch operator without going through the lenghthy process to unstake, without resetting commission.
+pub fn staking_contract_switch_operator_with_same_commission(
+    old_operator: AccountAddress,
+    new_operator: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("switch_operator_with_same_commission").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&old_operator).unwrap(),
+            bcs::to_bytes(&new_operator).unwrap(),
+        ],
+    ))
+}
 
-// /// Unlock all accumulated rewards since the last recorded principals.
-// pub fn staking_contract_unlock_rewards(operator: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("unlock_rewards").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&operator).unwrap()],
-//     ))
-// }
+/// Unlock all accumulated rewards since the last recorded principals.
+pub fn staking_contract_unlock_rewards(operator: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("unlock_rewards").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&operator).unwrap()],
+    ))
+}
 
-// /// Staker can call this to request withdrawal of part or all of their staking_contract.
-// /// This also triggers paying commission to the operator for accounting simplicity.
-// pub fn staking_contract_unlock_stake(operator: AccountAddress, amount: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("unlock_stake").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&operator).unwrap(),
-//             bcs::to_bytes(&amount).unwrap(),
-//         ],
-//     ))
-// }
+/// Staker can call this to request withdrawal of part or all of their staking_contract.
+/// This also triggers paying commission to the operator for accounting simplicity.
+pub fn staking_contract_unlock_stake(operator: AccountAddress, amount: u64) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("unlock_stake").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&operator).unwrap(),
+            bcs::to_bytes(&amount).unwrap(),
+        ],
+    ))
+}
 
-// /// Convenience function to allow a staker to update the commission percentage paid to the operator.
-// /// TODO: fix the typo in function name. commision -> commission
-// pub fn staking_contract_update_commision(
-//     operator: AccountAddress,
-//     new_commission_percentage: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("update_commision").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&operator).unwrap(),
-//             bcs::to_bytes(&new_commission_percentage).unwrap(),
-//         ],
-//     ))
-// }
+/// Convenience function to allow a staker to update the commission percentage paid to the operator.
+/// TODO: fix the typo in function name. commision -> commission
+pub fn staking_contract_update_commision(
+    operator: AccountAddress,
+    new_commission_percentage: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("update_commision").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&operator).unwrap(),
+            bcs::to_bytes(&new_commission_percentage).unwrap(),
+        ],
+    ))
+}
 
-// /// Convenient function to allow the staker to update the voter address in a staking contract they made.
-// pub fn staking_contract_update_voter(
-//     operator: AccountAddress,
-//     new_voter: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("staking_contract").to_owned(),
-//         ),
-//         ident_str!("update_voter").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&operator).unwrap(),
-//             bcs::to_bytes(&new_voter).unwrap(),
-//         ],
-//     ))
-// }
+/// Convenient function to allow the staker to update the voter address in a staking contract they made.
+pub fn staking_contract_update_voter(
+    operator: AccountAddress,
+    new_voter: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("staking_contract").to_owned(),
+        ),
+        ident_str!("update_voter").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&operator).unwrap(),
+            bcs::to_bytes(&new_voter).unwrap(),
+        ],
+    ))
+}
 
 // pub fn staking_proxy_set_operator(
 //     old_operator: AccountAddress,
@@ -4628,2825 +4628,2826 @@ pub fn managed_coin_burn(coin_type: TypeTag, amount: u64) -> TransactionPayload
 //                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 //                 0, 0, 0, 1,
 //             ]),
-//             ident_str!("staking_proxy").to_owned(),
-//         ),
-//         ident_str!("set_voter").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&operator).unwrap(),
-//             bcs::to_bytes(&new_voter).unwrap(),
-//         ],
-//     ))
-// }
-
-// pub fn transaction_fee_convert_to_aptos_fa_burn_ref() -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("transaction_fee").to_owned(),
-//         ),
-//         ident_str!("convert_to_aptos_fa_burn_ref").to_owned(),
-//         vec![],
-//         vec![],
-//     ))
-// }
-
-// /// Used in on-chain governances to update the major version for the next epoch.
-// /// Example usage:
-// /// - `aptos_framework::version::set_for_next_epoch(&framework_signer, new_version);`
-// /// - `aptos_framework::aptos_governance::reconfigure(&framework_signer);`
-// pub fn version_set_for_next_epoch(major: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("version").to_owned(),
-//         ),
-//         ident_str!("set_for_next_epoch").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&major).unwrap()],
-//     ))
-// }
-
-// /// Deprecated by `set_for_next_epoch()`.
-// ///
-// /// WARNING: calling this while randomness is enabled will trigger a new epoch without randomness!
-// ///
-// /// TODO: update all the tests that reference this function, then disable this function.
-// pub fn version_set_version(major: u64) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("version").to_owned(),
-//         ),
-//         ident_str!("set_version").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&major).unwrap()],
-//     ))
-// }
-
-// /// Withdraw all funds to the preset vesting contract's withdrawal address. This can only be called if the contract
-// /// has already been terminated.
-// pub fn vesting_admin_withdraw(contract_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("admin_withdraw").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&contract_address).unwrap()],
-//     ))
-// }
-
-// /// Distribute any withdrawable stake from the stake pool.
-// pub fn vesting_distribute(contract_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("distribute").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&contract_address).unwrap()],
-//     ))
-// }
-
-// /// Call `distribute` for many vesting contracts.
-// pub fn vesting_distribute_many(contract_addresses: Vec<AccountAddress>) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("distribute_many").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&contract_addresses).unwrap()],
-//     ))
-// }
-
-// /// Remove the beneficiary for the given shareholder. All distributions will sent directly to the shareholder
-// /// account.
-// pub fn vesting_reset_beneficiary(
-//     contract_address: AccountAddress,
-//     shareholder: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("reset_beneficiary").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&shareholder).unwrap(),
-//         ],
-//     ))
-// }
-
-// pub fn vesting_reset_lockup(contract_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("reset_lockup").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&contract_address).unwrap()],
-//     ))
-// }
-
-// pub fn vesting_set_beneficiary(
-//     contract_address: AccountAddress,
-//     shareholder: AccountAddress,
-//     new_beneficiary: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("set_beneficiary").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&shareholder).unwrap(),
-//             bcs::to_bytes(&new_beneficiary).unwrap(),
-//         ],
-//     ))
-// }
-
-// /// Set the beneficiary for the operator.
-// pub fn vesting_set_beneficiary_for_operator(new_beneficiary: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("set_beneficiary_for_operator").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&new_beneficiary).unwrap()],
-//     ))
-// }
-
-// pub fn vesting_set_beneficiary_resetter(
-//     contract_address: AccountAddress,
-//     beneficiary_resetter: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("set_beneficiary_resetter").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&beneficiary_resetter).unwrap(),
-//         ],
-//     ))
-// }
-
-// pub fn vesting_set_management_role(
-//     contract_address: AccountAddress,
-//     role: Vec<u8>,
-//     role_holder: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("set_management_role").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&role).unwrap(),
-//             bcs::to_bytes(&role_holder).unwrap(),
-//         ],
-//     ))
-// }
-
-// /// Terminate the vesting contract and send all funds back to the withdrawal address.
-// pub fn vesting_terminate_vesting_contract(contract_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("terminate_vesting_contract").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&contract_address).unwrap()],
-//     ))
-// }
-
-// /// Unlock any accumulated rewards.
-// pub fn vesting_unlock_rewards(contract_address: AccountAddress) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("unlock_rewards").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&contract_address).unwrap()],
-//     ))
-// }
-
-// /// Call `unlock_rewards` for many vesting contracts.
-// pub fn vesting_unlock_rewards_many(contract_addresses: Vec<AccountAddress>) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("unlock_rewards_many").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&contract_addresses).unwrap()],
-//     ))
-// }
-
-// pub fn vesting_update_commission_percentage(
-//     contract_address: AccountAddress,
-//     new_commission_percentage: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("update_commission_percentage").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&new_commission_percentage).unwrap(),
-//         ],
-//     ))
-// }
-
-// pub fn vesting_update_operator(
-//     contract_address: AccountAddress,
-//     new_operator: AccountAddress,
-//     commission_percentage: u64,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
-//         ),
-//         ident_str!("update_operator").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&new_operator).unwrap(),
-//             bcs::to_bytes(&commission_percentage).unwrap(),
-//         ],
-//     ))
-// }
-
-// pub fn vesting_update_operator_with_same_commission(
-//     contract_address: AccountAddress,
-//     new_operator: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("vesting").to_owned(),
+//             ident_str!("staking_proxy").to_owned(),
 //         ),
-//         ident_str!("update_operator_with_same_commission").to_owned(),
+//         ident_str!("set_voter").to_owned(),
 //         vec![],
 //         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&new_operator).unwrap(),
+//             bcs::to_bytes(&operator).unwrap(),
+//             bcs::to_bytes(&new_voter).unwrap(),
 //         ],
 //     ))
 // }
 
-// pub fn vesting_update_voter(
-//     contract_address: AccountAddress,
-//     new_voter: AccountAddress,
-// ) -> TransactionPayload {
+// pub fn transaction_fee_convert_to_aptos_fa_burn_ref() -> TransactionPayload {
 //     TransactionPayload::EntryFunction(EntryFunction::new(
 //         ModuleId::new(
 //             AccountAddress::new([
 //                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 //                 0, 0, 0, 1,
 //             ]),
-//             ident_str!("vesting").to_owned(),
+//             ident_str!("transaction_fee").to_owned(),
 //         ),
-//         ident_str!("update_voter").to_owned(),
+//         ident_str!("convert_to_aptos_fa_burn_ref").to_owned(),
+//         vec![],
 //         vec![],
-//         vec![
-//             bcs::to_bytes(&contract_address).unwrap(),
-//             bcs::to_bytes(&new_voter).unwrap(),
-//         ],
 //     ))
 // }
 
-// /// Unlock any vested portion of the grant.
-// pub fn vesting_vest(contract_address: AccountAddress) -> TransactionPayload {
+// /// Used in on-chain governances to update the major version for the next epoch.
+// /// Example usage:
+// /// - `aptos_framework::version::set_for_next_epoch(&framework_signer, new_version);`
+// /// - `aptos_framework::aptos_governance::reconfigure(&framework_signer);`
+// pub fn version_set_for_next_epoch(major: u64) -> TransactionPayload {
 //     TransactionPayload::EntryFunction(EntryFunction::new(
 //         ModuleId::new(
 //             AccountAddress::new([
 //                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 //                 0, 0, 0, 1,
 //             ]),
-//             ident_str!("vesting").to_owned(),
+//             ident_str!("version").to_owned(),
 //         ),
-//         ident_str!("vest").to_owned(),
+//         ident_str!("set_for_next_epoch").to_owned(),
 //         vec![],
-//         vec![bcs::to_bytes(&contract_address).unwrap()],
+//         vec![bcs::to_bytes(&major).unwrap()],
 //     ))
 // }
 
-// /// Call `vest` for many vesting contracts.
-// pub fn vesting_vest_many(contract_addresses: Vec<AccountAddress>) -> TransactionPayload {
+// /// Deprecated by `set_for_next_epoch()`.
+// ///
+// /// WARNING: calling this while randomness is enabled will trigger a new epoch without randomness!
+// ///
+// /// TODO: update all the tests that reference this function, then disable this function.
+// pub fn version_set_version(major: u64) -> TransactionPayload {
 //     TransactionPayload::EntryFunction(EntryFunction::new(
 //         ModuleId::new(
 //             AccountAddress::new([
 //                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 //                 0, 0, 0, 1,
 //             ]),
-//             ident_str!("vesting").to_owned(),
+//             ident_str!("version").to_owned(),
 //         ),
-//         ident_str!("vest_many").to_owned(),
+//         ident_str!("set_version").to_owned(),
 //         vec![],
-//         vec![bcs::to_bytes(&contract_addresses).unwrap()],
+//         vec![bcs::to_bytes(&major).unwrap()],
 //     ))
 // }
-// mod decoder {
-//     use super::*;
-//     pub fn account_offer_rotation_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AccountOfferRotationCapability {
-//                 rotation_capability_sig_bytes: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 account_scheme: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 account_public_key_bytes: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 recipient_address: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_offer_signer_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AccountOfferSignerCapability {
-//                 signer_capability_sig_bytes: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 account_scheme: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 account_public_key_bytes: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 recipient_address: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_revoke_any_rotation_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::AccountRevokeAnyRotationCapability {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_revoke_any_signer_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::AccountRevokeAnySignerCapability {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_revoke_rotation_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AccountRevokeRotationCapability {
-//                 to_be_revoked_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_revoke_signer_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AccountRevokeSignerCapability {
-//                 to_be_revoked_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_rotate_authentication_key(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AccountRotateAuthenticationKey {
-//                 from_scheme: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 from_public_key_bytes: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 to_scheme: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 to_public_key_bytes: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 cap_rotate_key: bcs::from_bytes(script.args().get(4)?).ok()?,
-//                 cap_update_table: bcs::from_bytes(script.args().get(5)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_rotate_authentication_key_call(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AccountRotateAuthenticationKeyCall {
-//                 new_auth_key: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_rotate_authentication_key_with_rotation_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::AccountRotateAuthenticationKeyWithRotationCapability {
-//                     rotation_cap_offerer_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     new_scheme: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     new_public_key_bytes: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                     cap_update_table: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn account_set_originating_address(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::AccountSetOriginatingAddress {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_account_batch_transfer(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosAccountBatchTransfer {
-//                 recipients: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amounts: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_account_batch_transfer_coins(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosAccountBatchTransferCoins {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//                 recipients: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amounts: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_account_create_account(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosAccountCreateAccount {
-//                 auth_key: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_account_fungible_transfer_only(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosAccountFungibleTransferOnly {
-//                 to: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_account_set_allow_direct_coin_transfers(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosAccountSetAllowDirectCoinTransfers {
-//                 allow: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_account_transfer(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosAccountTransfer {
-//                 to: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_account_transfer_coins(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosAccountTransferCoins {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//                 to: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_coin_claim_mint_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::AptosCoinClaimMintCapability {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_coin_delegate_mint_capability(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosCoinDelegateMintCapability {
-//                 to: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_coin_mint(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosCoinMint {
-//                 dst_addr: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_add_approved_script_hash_script(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::AptosGovernanceAddApprovedScriptHashScript {
-//                     proposal_id: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_batch_partial_vote(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceBatchPartialVote {
-//                 stake_pools: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 voting_power: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 should_pass: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_batch_vote(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceBatchVote {
-//                 stake_pools: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 should_pass: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_create_proposal(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceCreateProposal {
-//                 stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 execution_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 metadata_location: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 metadata_hash: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_create_proposal_v2(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceCreateProposalV2 {
-//                 stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 execution_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 metadata_location: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 metadata_hash: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 is_multi_step_proposal: bcs::from_bytes(script.args().get(4)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_force_end_epoch(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceForceEndEpoch {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_force_end_epoch_test_only(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceForceEndEpochTestOnly {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_partial_vote(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosGovernancePartialVote {
-//                 stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 voting_power: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 should_pass: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_reconfigure(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceReconfigure {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn aptos_governance_vote(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::AptosGovernanceVote {
-//                 stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 should_pass: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn code_publish_package_txn(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::CodePublishPackageTxn {
-//                 metadata_serialized: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 code: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn coin_create_coin_conversion_map(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::CoinCreateCoinConversionMap {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn coin_create_pairing(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::CoinCreatePairing {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn coin_migrate_coin_store_to_fungible_store(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::CoinMigrateCoinStoreToFungibleStore {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//                 accounts: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn coin_migrate_to_fungible_store(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::CoinMigrateToFungibleStore {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn coin_transfer(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::CoinTransfer {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//                 to: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn coin_upgrade_supply(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::CoinUpgradeSupply {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_add_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolAddStake {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_allowlist_delegator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolAllowlistDelegator {
-//                 delegator_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_create_proposal(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolCreateProposal {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 execution_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 metadata_location: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 metadata_hash: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 is_multi_step_proposal: bcs::from_bytes(script.args().get(4)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_delegate_voting_power(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolDelegateVotingPower {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_disable_delegators_allowlisting(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolDisableDelegatorsAllowlisting {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_enable_delegators_allowlisting(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolEnableDelegatorsAllowlisting {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_enable_partial_governance_voting(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::DelegationPoolEnablePartialGovernanceVoting {
-//                     pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_evict_delegator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolEvictDelegator {
-//                 delegator_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_initialize_delegation_pool(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolInitializeDelegationPool {
-//                 operator_commission_percentage: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 delegation_pool_creation_seed: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_reactivate_stake(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolReactivateStake {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_remove_delegator_from_allowlist(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::DelegationPoolRemoveDelegatorFromAllowlist {
-//                     delegator_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_set_beneficiary_for_operator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolSetBeneficiaryForOperator {
-//                 new_beneficiary: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_set_delegated_voter(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolSetDelegatedVoter {
-//                 new_voter: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_set_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolSetOperator {
-//                 new_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_synchronize_delegation_pool(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolSynchronizeDelegationPool {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_unlock(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolUnlock {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_update_commission_percentage(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::DelegationPoolUpdateCommissionPercentage {
-//                     new_commission_percentage: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_vote(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolVote {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 voting_power: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 should_pass: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn delegation_pool_withdraw(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::DelegationPoolWithdraw {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn jwks_update_federated_jwk_set(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::JwksUpdateFederatedJwkSet {
-//                 iss: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 kid_vec: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 alg_vec: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 e_vec: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 n_vec: bcs::from_bytes(script.args().get(4)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn managed_coin_burn(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ManagedCoinBurn {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//                 amount: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn managed_coin_destroy_caps(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ManagedCoinDestroyCaps {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn managed_coin_initialize(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ManagedCoinInitialize {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//                 name: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 symbol: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 decimals: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 monitor_supply: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn managed_coin_mint(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ManagedCoinMint {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//                 dst_addr: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn managed_coin_register(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ManagedCoinRegister {
-//                 coin_type: script.ty_args().get(0)?.clone(),
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_add_owner(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountAddOwner {
-//                 new_owner: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_add_owners(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountAddOwners {
-//                 new_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_add_owners_and_update_signatures_required(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountAddOwnersAndUpdateSignaturesRequired {
-//                     new_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     new_num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_approve_transaction(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountApproveTransaction {
-//                 multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountCreate {
-//                 num_signatures_required: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 metadata_keys: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 metadata_values: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_transaction(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountCreateTransaction {
-//                 multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 payload: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_transaction_with_hash(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountCreateTransactionWithHash {
-//                     multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     payload_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_with_existing_account(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountCreateWithExistingAccount {
-//                     multisig_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     owners: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     num_signatures_required: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                     account_scheme: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                     account_public_key: bcs::from_bytes(script.args().get(4)?).ok()?,
-//                     create_multisig_account_signed_message: bcs::from_bytes(script.args().get(5)?)
-//                         .ok()?,
-//                     metadata_keys: bcs::from_bytes(script.args().get(6)?).ok()?,
-//                     metadata_values: bcs::from_bytes(script.args().get(7)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_with_existing_account_and_revoke_auth_key(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountCreateWithExistingAccountAndRevokeAuthKey {
-//                     multisig_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     owners: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     num_signatures_required: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                     account_scheme: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                     account_public_key: bcs::from_bytes(script.args().get(4)?).ok()?,
-//                     create_multisig_account_signed_message: bcs::from_bytes(script.args().get(5)?)
-//                         .ok()?,
-//                     metadata_keys: bcs::from_bytes(script.args().get(6)?).ok()?,
-//                     metadata_values: bcs::from_bytes(script.args().get(7)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_with_existing_account_and_revoke_auth_key_call(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountCreateWithExistingAccountAndRevokeAuthKeyCall {
-//                     owners: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                     metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_with_existing_account_call(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountCreateWithExistingAccountCall {
-//                     owners: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                     metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_with_owners(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountCreateWithOwners {
-//                 additional_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_create_with_owners_then_remove_bootstrapper(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountCreateWithOwnersThenRemoveBootstrapper {
-//                     owners: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                     metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_execute_rejected_transaction(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountExecuteRejectedTransaction {
-//                     multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_execute_rejected_transactions(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountExecuteRejectedTransactions {
-//                     multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     final_sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_reject_transaction(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountRejectTransaction {
-//                 multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_remove_owner(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountRemoveOwner {
-//                 owner_to_remove: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_remove_owners(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountRemoveOwners {
-//                 owners_to_remove: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_swap_owner(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountSwapOwner {
-//                 to_swap_in: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 to_swap_out: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_swap_owners(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountSwapOwners {
-//                 to_swap_in: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 to_swap_out: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_swap_owners_and_update_signatures_required(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::MultisigAccountSwapOwnersAndUpdateSignaturesRequired {
-//                     new_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     owners_to_remove: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     new_num_signatures_required: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_update_metadata(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountUpdateMetadata {
-//                 keys: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 values: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_update_signatures_required(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountUpdateSignaturesRequired {
-//                 new_num_signatures_required: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_vote_transaction(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountVoteTransaction {
-//                 multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 approved: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_vote_transactions(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountVoteTransactions {
-//                 multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 starting_sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 final_sequence_number: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 approved: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn multisig_account_vote_transanction(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::MultisigAccountVoteTransanction {
-//                 multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 approved: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn object_transfer_call(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ObjectTransferCall {
-//                 object: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 to: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn object_code_deployment_publish(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ObjectCodeDeploymentPublish {
-//                 metadata_serialized: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 code: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn resource_account_create_resource_account(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::ResourceAccountCreateResourceAccount {
-//                 seed: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 optional_auth_key: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn resource_account_create_resource_account_and_fund(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::ResourceAccountCreateResourceAccountAndFund {
-//                     seed: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     optional_auth_key: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     fund_amount: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn resource_account_create_resource_account_and_publish_package(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::ResourceAccountCreateResourceAccountAndPublishPackage {
-//                     seed: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     metadata_serialized: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                     code: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_add_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeAddStake {
-//                 amount: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_increase_lockup(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::StakeIncreaseLockup {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_initialize_stake_owner(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeInitializeStakeOwner {
-//                 initial_stake_amount: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 voter: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_initialize_validator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeInitializeValidator {
-//                 consensus_pubkey: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 proof_of_possession: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 network_addresses: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 fullnode_addresses: bcs::from_bytes(script.args().get(3)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_join_validator_set(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeJoinValidatorSet {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_leave_validator_set(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeLeaveValidatorSet {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_reactivate_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeReactivateStake {
-//                 amount: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_rotate_consensus_key(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeRotateConsensusKey {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_consensus_pubkey: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 proof_of_possession: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_set_delegated_voter(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeSetDelegatedVoter {
-//                 new_voter: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_set_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeSetOperator {
-//                 new_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_unlock(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeUnlock {
-//                 amount: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_update_network_and_fullnode_addresses(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeUpdateNetworkAndFullnodeAddresses {
-//                 pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_network_addresses: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 new_fullnode_addresses: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn stake_withdraw(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakeWithdraw {
-//                 withdraw_amount: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_add_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractAddStake {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_create_staking_contract(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractCreateStakingContract {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 voter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(2)?).ok()?,
-//                 commission_percentage: bcs::from_bytes(script.args().get(3)?).ok()?,
-//                 contract_creation_seed: bcs::from_bytes(script.args().get(4)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_distribute(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractDistribute {
-//                 staker: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_request_commission(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractRequestCommission {
-//                 staker: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_reset_lockup(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractResetLockup {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_set_beneficiary_for_operator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::StakingContractSetBeneficiaryForOperator {
-//                     new_beneficiary: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_switch_operator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractSwitchOperator {
-//                 old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 new_commission_percentage: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_switch_operator_with_same_commission(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(
-//                 EntryFunctionCall::StakingContractSwitchOperatorWithSameCommission {
-//                     old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                     new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 },
-//             )
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_unlock_rewards(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractUnlockRewards {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_unlock_stake(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractUnlockStake {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 amount: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_update_commision(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractUpdateCommision {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_commission_percentage: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_contract_update_voter(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingContractUpdateVoter {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetOperator {
-//                 old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_stake_pool_operator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetStakePoolOperator {
-//                 new_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_stake_pool_voter(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetStakePoolVoter {
-//                 new_voter: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_staking_contract_operator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetStakingContractOperator {
-//                 old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_staking_contract_voter(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetStakingContractVoter {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_vesting_contract_operator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetVestingContractOperator {
-//                 old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_vesting_contract_voter(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetVestingContractVoter {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn staking_proxy_set_voter(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::StakingProxySetVoter {
-//                 operator: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn transaction_fee_convert_to_aptos_fa_burn_ref(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(_script) = payload {
-//             Some(EntryFunctionCall::TransactionFeeConvertToAptosFaBurnRef {})
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn version_set_for_next_epoch(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VersionSetForNextEpoch {
-//                 major: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn version_set_version(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VersionSetVersion {
-//                 major: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_admin_withdraw(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingAdminWithdraw {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_distribute(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingDistribute {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_distribute_many(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingDistributeMany {
-//                 contract_addresses: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_reset_beneficiary(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingResetBeneficiary {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 shareholder: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_reset_lockup(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingResetLockup {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_set_beneficiary(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingSetBeneficiary {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 shareholder: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 new_beneficiary: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_set_beneficiary_for_operator(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingSetBeneficiaryForOperator {
-//                 new_beneficiary: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_set_beneficiary_resetter(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingSetBeneficiaryResetter {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 beneficiary_resetter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_set_management_role(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingSetManagementRole {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 role: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 role_holder: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_terminate_vesting_contract(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingTerminateVestingContract {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_unlock_rewards(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingUnlockRewards {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_unlock_rewards_many(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingUnlockRewardsMany {
-//                 contract_addresses: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_update_commission_percentage(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingUpdateCommissionPercentage {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_commission_percentage: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_update_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingUpdateOperator {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//                 commission_percentage: bcs::from_bytes(script.args().get(2)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_update_operator_with_same_commission(
-//         payload: &TransactionPayload,
-//     ) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingUpdateOperatorWithSameCommission {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_update_voter(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingUpdateVoter {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//                 new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_vest(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingVest {
-//                 contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-
-//     pub fn vesting_vest_many(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
-//         if let TransactionPayload::EntryFunction(script) = payload {
-//             Some(EntryFunctionCall::VestingVestMany {
-//                 contract_addresses: bcs::from_bytes(script.args().get(0)?).ok()?,
-//             })
-//         } else {
-//             None
-//         }
-//     }
-// }
 
-// type EntryFunctionDecoderMap = std::collections::HashMap<
-//     String,
-//     Box<
-//         dyn Fn(&TransactionPayload) -> Option<EntryFunctionCall>
-//             + std::marker::Sync
-//             + std::marker::Send,
-//     >,
-// >;
-
-// static SCRIPT_FUNCTION_DECODER_MAP: once_cell::sync::Lazy<EntryFunctionDecoderMap> =
-//     once_cell::sync::Lazy::new(|| {
-//         let mut map: EntryFunctionDecoderMap = std::collections::HashMap::new();
-//         map.insert(
-//             "account_offer_rotation_capability".to_string(),
-//             Box::new(decoder::account_offer_rotation_capability),
-//         );
-//         map.insert(
-//             "account_offer_signer_capability".to_string(),
-//             Box::new(decoder::account_offer_signer_capability),
-//         );
-//         map.insert(
-//             "account_revoke_any_rotation_capability".to_string(),
-//             Box::new(decoder::account_revoke_any_rotation_capability),
-//         );
-//         map.insert(
-//             "account_revoke_any_signer_capability".to_string(),
-//             Box::new(decoder::account_revoke_any_signer_capability),
-//         );
-//         map.insert(
-//             "account_revoke_rotation_capability".to_string(),
-//             Box::new(decoder::account_revoke_rotation_capability),
-//         );
-//         map.insert(
-//             "account_revoke_signer_capability".to_string(),
-//             Box::new(decoder::account_revoke_signer_capability),
-//         );
-//         map.insert(
-//             "account_rotate_authentication_key".to_string(),
-//             Box::new(decoder::account_rotate_authentication_key),
-//         );
-//         map.insert(
-//             "account_rotate_authentication_key_call".to_string(),
-//             Box::new(decoder::account_rotate_authentication_key_call),
-//         );
-//         map.insert(
-//             "account_rotate_authentication_key_with_rotation_capability".to_string(),
-//             Box::new(decoder::account_rotate_authentication_key_with_rotation_capability),
-//         );
-//         map.insert(
-//             "account_set_originating_address".to_string(),
-//             Box::new(decoder::account_set_originating_address),
-//         );
-//         map.insert(
-//             "aptos_account_batch_transfer".to_string(),
-//             Box::new(decoder::aptos_account_batch_transfer),
-//         );
-//         map.insert(
-//             "aptos_account_batch_transfer_coins".to_string(),
-//             Box::new(decoder::aptos_account_batch_transfer_coins),
-//         );
-//         map.insert(
-//             "aptos_account_create_account".to_string(),
-//             Box::new(decoder::aptos_account_create_account),
-//         );
-//         map.insert(
-//             "aptos_account_fungible_transfer_only".to_string(),
-//             Box::new(decoder::aptos_account_fungible_transfer_only),
-//         );
-//         map.insert(
-//             "aptos_account_set_allow_direct_coin_transfers".to_string(),
-//             Box::new(decoder::aptos_account_set_allow_direct_coin_transfers),
-//         );
-//         map.insert(
-//             "aptos_account_transfer".to_string(),
-//             Box::new(decoder::aptos_account_transfer),
-//         );
-//         map.insert(
-//             "aptos_account_transfer_coins".to_string(),
-//             Box::new(decoder::aptos_account_transfer_coins),
-//         );
-//         map.insert(
-//             "aptos_coin_claim_mint_capability".to_string(),
-//             Box::new(decoder::aptos_coin_claim_mint_capability),
-//         );
-//         map.insert(
-//             "aptos_coin_delegate_mint_capability".to_string(),
-//             Box::new(decoder::aptos_coin_delegate_mint_capability),
-//         );
-//         map.insert(
-//             "aptos_coin_mint".to_string(),
-//             Box::new(decoder::aptos_coin_mint),
-//         );
-//         map.insert(
-//             "aptos_governance_add_approved_script_hash_script".to_string(),
-//             Box::new(decoder::aptos_governance_add_approved_script_hash_script),
-//         );
-//         map.insert(
-//             "aptos_governance_batch_partial_vote".to_string(),
-//             Box::new(decoder::aptos_governance_batch_partial_vote),
-//         );
-//         map.insert(
-//             "aptos_governance_batch_vote".to_string(),
-//             Box::new(decoder::aptos_governance_batch_vote),
-//         );
-//         map.insert(
-//             "aptos_governance_create_proposal".to_string(),
-//             Box::new(decoder::aptos_governance_create_proposal),
-//         );
-//         map.insert(
-//             "aptos_governance_create_proposal_v2".to_string(),
-//             Box::new(decoder::aptos_governance_create_proposal_v2),
-//         );
-//         map.insert(
-//             "aptos_governance_force_end_epoch".to_string(),
-//             Box::new(decoder::aptos_governance_force_end_epoch),
-//         );
-//         map.insert(
-//             "aptos_governance_force_end_epoch_test_only".to_string(),
-//             Box::new(decoder::aptos_governance_force_end_epoch_test_only),
-//         );
-//         map.insert(
-//             "aptos_governance_partial_vote".to_string(),
-//             Box::new(decoder::aptos_governance_partial_vote),
-//         );
-//         map.insert(
-//             "aptos_governance_reconfigure".to_string(),
-//             Box::new(decoder::aptos_governance_reconfigure),
-//         );
-//         map.insert(
-//             "aptos_governance_vote".to_string(),
-//             Box::new(decoder::aptos_governance_vote),
-//         );
-//         map.insert(
-//             "code_publish_package_txn".to_string(),
-//             Box::new(decoder::code_publish_package_txn),
-//         );
-//         map.insert(
-//             "coin_create_coin_conversion_map".to_string(),
-//             Box::new(decoder::coin_create_coin_conversion_map),
-//         );
-//         map.insert(
-//             "coin_create_pairing".to_string(),
-//             Box::new(decoder::coin_create_pairing),
-//         );
-//         map.insert(
-//             "coin_migrate_coin_store_to_fungible_store".to_string(),
-//             Box::new(decoder::coin_migrate_coin_store_to_fungible_store),
-//         );
-//         map.insert(
-//             "coin_migrate_to_fungible_store".to_string(),
-//             Box::new(decoder::coin_migrate_to_fungible_store),
-//         );
-//         map.insert(
-//             "coin_transfer".to_string(),
-//             Box::new(decoder::coin_transfer),
-//         );
-//         map.insert(
-//             "coin_upgrade_supply".to_string(),
-//             Box::new(decoder::coin_upgrade_supply),
-//         );
-//         map.insert(
-//             "delegation_pool_add_stake".to_string(),
-//             Box::new(decoder::delegation_pool_add_stake),
-//         );
-//         map.insert(
-//             "delegation_pool_allowlist_delegator".to_string(),
-//             Box::new(decoder::delegation_pool_allowlist_delegator),
-//         );
-//         map.insert(
-//             "delegation_pool_create_proposal".to_string(),
-//             Box::new(decoder::delegation_pool_create_proposal),
-//         );
-//         map.insert(
-//             "delegation_pool_delegate_voting_power".to_string(),
-//             Box::new(decoder::delegation_pool_delegate_voting_power),
-//         );
-//         map.insert(
-//             "delegation_pool_disable_delegators_allowlisting".to_string(),
-//             Box::new(decoder::delegation_pool_disable_delegators_allowlisting),
-//         );
-//         map.insert(
-//             "delegation_pool_enable_delegators_allowlisting".to_string(),
-//             Box::new(decoder::delegation_pool_enable_delegators_allowlisting),
-//         );
-//         map.insert(
-//             "delegation_pool_enable_partial_governance_voting".to_string(),
-//             Box::new(decoder::delegation_pool_enable_partial_governance_voting),
-//         );
-//         map.insert(
-//             "delegation_pool_evict_delegator".to_string(),
-//             Box::new(decoder::delegation_pool_evict_delegator),
-//         );
-//         map.insert(
-//             "delegation_pool_initialize_delegation_pool".to_string(),
-//             Box::new(decoder::delegation_pool_initialize_delegation_pool),
-//         );
-//         map.insert(
-//             "delegation_pool_reactivate_stake".to_string(),
-//             Box::new(decoder::delegation_pool_reactivate_stake),
-//         );
-//         map.insert(
-//             "delegation_pool_remove_delegator_from_allowlist".to_string(),
-//             Box::new(decoder::delegation_pool_remove_delegator_from_allowlist),
-//         );
-//         map.insert(
-//             "delegation_pool_set_beneficiary_for_operator".to_string(),
-//             Box::new(decoder::delegation_pool_set_beneficiary_for_operator),
-//         );
-//         map.insert(
-//             "delegation_pool_set_delegated_voter".to_string(),
-//             Box::new(decoder::delegation_pool_set_delegated_voter),
-//         );
-//         map.insert(
-//             "delegation_pool_set_operator".to_string(),
-//             Box::new(decoder::delegation_pool_set_operator),
-//         );
-//         map.insert(
-//             "delegation_pool_synchronize_delegation_pool".to_string(),
-//             Box::new(decoder::delegation_pool_synchronize_delegation_pool),
-//         );
-//         map.insert(
-//             "delegation_pool_unlock".to_string(),
-//             Box::new(decoder::delegation_pool_unlock),
-//         );
-//         map.insert(
-//             "delegation_pool_update_commission_percentage".to_string(),
-//             Box::new(decoder::delegation_pool_update_commission_percentage),
-//         );
-//         map.insert(
-//             "delegation_pool_vote".to_string(),
-//             Box::new(decoder::delegation_pool_vote),
-//         );
-//         map.insert(
-//             "delegation_pool_withdraw".to_string(),
-//             Box::new(decoder::delegation_pool_withdraw),
-//         );
-//         map.insert(
-//             "jwks_update_federated_jwk_set".to_string(),
-//             Box::new(decoder::jwks_update_federated_jwk_set),
-//         );
-//         map.insert(
-//             "managed_coin_burn".to_string(),
-//             Box::new(decoder::managed_coin_burn),
-//         );
-//         map.insert(
-//             "managed_coin_destroy_caps".to_string(),
-//             Box::new(decoder::managed_coin_destroy_caps),
-//         );
-//         map.insert(
-//             "managed_coin_initialize".to_string(),
-//             Box::new(decoder::managed_coin_initialize),
-//         );
-//         map.insert(
-//             "managed_coin_mint".to_string(),
-//             Box::new(decoder::managed_coin_mint),
-//         );
-//         map.insert(
-//             "managed_coin_register".to_string(),
-//             Box::new(decoder::managed_coin_register),
-//         );
-//         map.insert(
-//             "multisig_account_add_owner".to_string(),
-//             Box::new(decoder::multisig_account_add_owner),
-//         );
-//         map.insert(
-//             "multisig_account_add_owners".to_string(),
-//             Box::new(decoder::multisig_account_add_owners),
-//         );
-//         map.insert(
-//             "multisig_account_add_owners_and_update_signatures_required".to_string(),
-//             Box::new(decoder::multisig_account_add_owners_and_update_signatures_required),
-//         );
-//         map.insert(
-//             "multisig_account_approve_transaction".to_string(),
-//             Box::new(decoder::multisig_account_approve_transaction),
-//         );
-//         map.insert(
-//             "multisig_account_create".to_string(),
-//             Box::new(decoder::multisig_account_create),
-//         );
-//         map.insert(
-//             "multisig_account_create_transaction".to_string(),
-//             Box::new(decoder::multisig_account_create_transaction),
-//         );
-//         map.insert(
-//             "multisig_account_create_transaction_with_hash".to_string(),
-//             Box::new(decoder::multisig_account_create_transaction_with_hash),
-//         );
-//         map.insert(
-//             "multisig_account_create_with_existing_account".to_string(),
-//             Box::new(decoder::multisig_account_create_with_existing_account),
-//         );
-//         map.insert(
-//             "multisig_account_create_with_existing_account_and_revoke_auth_key".to_string(),
-//             Box::new(decoder::multisig_account_create_with_existing_account_and_revoke_auth_key),
-//         );
-//         map.insert(
-//             "multisig_account_create_with_existing_account_and_revoke_auth_key_call".to_string(),
-//             Box::new(
-//                 decoder::multisig_account_create_with_existing_account_and_revoke_auth_key_call,
-//             ),
-//         );
-//         map.insert(
-//             "multisig_account_create_with_existing_account_call".to_string(),
-//             Box::new(decoder::multisig_account_create_with_existing_account_call),
-//         );
-//         map.insert(
-//             "multisig_account_create_with_owners".to_string(),
-//             Box::new(decoder::multisig_account_create_with_owners),
-//         );
-//         map.insert(
-//             "multisig_account_create_with_owners_then_remove_bootstrapper".to_string(),
-//             Box::new(decoder::multisig_account_create_with_owners_then_remove_bootstrapper),
-//         );
-//         map.insert(
-//             "multisig_account_execute_rejected_transaction".to_string(),
-//             Box::new(decoder::multisig_account_execute_rejected_transaction),
-//         );
-//         map.insert(
-//             "multisig_account_execute_rejected_transactions".to_string(),
-//             Box::new(decoder::multisig_account_execute_rejected_transactions),
-//         );
-//         map.insert(
-//             "multisig_account_reject_transaction".to_string(),
-//             Box::new(decoder::multisig_account_reject_transaction),
-//         );
-//         map.insert(
-//             "multisig_account_remove_owner".to_string(),
-//             Box::new(decoder::multisig_account_remove_owner),
-//         );
-//         map.insert(
-//             "multisig_account_remove_owners".to_string(),
-//             Box::new(decoder::multisig_account_remove_owners),
-//         );
-//         map.insert(
-//             "multisig_account_swap_owner".to_string(),
-//             Box::new(decoder::multisig_account_swap_owner),
-//         );
-//         map.insert(
-//             "multisig_account_swap_owners".to_string(),
-//             Box::new(decoder::multisig_account_swap_owners),
-//         );
-//         map.insert(
-//             "multisig_account_swap_owners_and_update_signatures_required".to_string(),
-//             Box::new(decoder::multisig_account_swap_owners_and_update_signatures_required),
-//         );
-//         map.insert(
-//             "multisig_account_update_metadata".to_string(),
-//             Box::new(decoder::multisig_account_update_metadata),
-//         );
-//         map.insert(
-//             "multisig_account_update_signatures_required".to_string(),
-//             Box::new(decoder::multisig_account_update_signatures_required),
-//         );
-//         map.insert(
-//             "multisig_account_vote_transaction".to_string(),
-//             Box::new(decoder::multisig_account_vote_transaction),
-//         );
-//         map.insert(
-//             "multisig_account_vote_transactions".to_string(),
-//             Box::new(decoder::multisig_account_vote_transactions),
-//         );
-//         map.insert(
-//             "multisig_account_vote_transanction".to_string(),
-//             Box::new(decoder::multisig_account_vote_transanction),
-//         );
-//         map.insert(
-//             "object_transfer_call".to_string(),
-//             Box::new(decoder::object_transfer_call),
-//         );
-//         map.insert(
-//             "object_code_deployment_publish".to_string(),
-//             Box::new(decoder::object_code_deployment_publish),
-//         );
-//         map.insert(
-//             "resource_account_create_resource_account".to_string(),
-//             Box::new(decoder::resource_account_create_resource_account),
-//         );
-//         map.insert(
-//             "resource_account_create_resource_account_and_fund".to_string(),
-//             Box::new(decoder::resource_account_create_resource_account_and_fund),
-//         );
-//         map.insert(
-//             "resource_account_create_resource_account_and_publish_package".to_string(),
-//             Box::new(decoder::resource_account_create_resource_account_and_publish_package),
-//         );
-//         map.insert(
-//             "stake_add_stake".to_string(),
-//             Box::new(decoder::stake_add_stake),
-//         );
-//         map.insert(
-//             "stake_increase_lockup".to_string(),
-//             Box::new(decoder::stake_increase_lockup),
-//         );
-//         map.insert(
-//             "stake_initialize_stake_owner".to_string(),
-//             Box::new(decoder::stake_initialize_stake_owner),
-//         );
-//         map.insert(
-//             "stake_initialize_validator".to_string(),
-//             Box::new(decoder::stake_initialize_validator),
-//         );
-//         map.insert(
-//             "stake_join_validator_set".to_string(),
-//             Box::new(decoder::stake_join_validator_set),
-//         );
-//         map.insert(
-//             "stake_leave_validator_set".to_string(),
-//             Box::new(decoder::stake_leave_validator_set),
-//         );
-//         map.insert(
-//             "stake_reactivate_stake".to_string(),
-//             Box::new(decoder::stake_reactivate_stake),
-//         );
-//         map.insert(
-//             "stake_rotate_consensus_key".to_string(),
-//             Box::new(decoder::stake_rotate_consensus_key),
-//         );
-//         map.insert(
-//             "stake_set_delegated_voter".to_string(),
-//             Box::new(decoder::stake_set_delegated_voter),
-//         );
-//         map.insert(
-//             "stake_set_operator".to_string(),
-//             Box::new(decoder::stake_set_operator),
-//         );
-//         map.insert("stake_unlock".to_string(), Box::new(decoder::stake_unlock));
-//         map.insert(
-//             "stake_update_network_and_fullnode_addresses".to_string(),
-//             Box::new(decoder::stake_update_network_and_fullnode_addresses),
-//         );
-//         map.insert(
-//             "stake_withdraw".to_string(),
-//             Box::new(decoder::stake_withdraw),
-//         );
-//         map.insert(
-//             "staking_contract_add_stake".to_string(),
-//             Box::new(decoder::staking_contract_add_stake),
-//         );
-//         map.insert(
-//             "staking_contract_create_staking_contract".to_string(),
-//             Box::new(decoder::staking_contract_create_staking_contract),
-//         );
-//         map.insert(
-//             "staking_contract_distribute".to_string(),
-//             Box::new(decoder::staking_contract_distribute),
-//         );
-//         map.insert(
-//             "staking_contract_request_commission".to_string(),
-//             Box::new(decoder::staking_contract_request_commission),
-//         );
-//         map.insert(
-//             "staking_contract_reset_lockup".to_string(),
-//             Box::new(decoder::staking_contract_reset_lockup),
-//         );
-//         map.insert(
-//             "staking_contract_set_beneficiary_for_operator".to_string(),
-//             Box::new(decoder::staking_contract_set_beneficiary_for_operator),
-//         );
-//         map.insert(
-//             "staking_contract_switch_operator".to_string(),
-//             Box::new(decoder::staking_contract_switch_operator),
-//         );
-//         map.insert(
-//             "staking_contract_switch_operator_with_same_commission".to_string(),
-//             Box::new(decoder::staking_contract_switch_operator_with_same_commission),
-//         );
-//         map.insert(
-//             "staking_contract_unlock_rewards".to_string(),
-//             Box::new(decoder::staking_contract_unlock_rewards),
-//         );
-//         map.insert(
-//             "staking_contract_unlock_stake".to_string(),
-//             Box::new(decoder::staking_contract_unlock_stake),
-//         );
-//         map.insert(
-//             "staking_contract_update_commision".to_string(),
-//             Box::new(decoder::staking_contract_update_commision),
-//         );
-//         map.insert(
-//             "staking_contract_update_voter".to_string(),
-//             Box::new(decoder::staking_contract_update_voter),
-//         );
-//         map.insert(
-//             "staking_proxy_set_operator".to_string(),
-//             Box::new(decoder::staking_proxy_set_operator),
-//         );
-//         map.insert(
-//             "staking_proxy_set_stake_pool_operator".to_string(),
-//             Box::new(decoder::staking_proxy_set_stake_pool_operator),
-//         );
-//         map.insert(
-//             "staking_proxy_set_stake_pool_voter".to_string(),
-//             Box::new(decoder::staking_proxy_set_stake_pool_voter),
-//         );
-//         map.insert(
-//             "staking_proxy_set_staking_contract_operator".to_string(),
-//             Box::new(decoder::staking_proxy_set_staking_contract_operator),
-//         );
-//         map.insert(
-//             "staking_proxy_set_staking_contract_voter".to_string(),
-//             Box::new(decoder::staking_proxy_set_staking_contract_voter),
-//         );
-//         map.insert(
-//             "staking_proxy_set_vesting_contract_operator".to_string(),
-//             Box::new(decoder::staking_proxy_set_vesting_contract_operator),
-//         );
-//         map.insert(
-//             "staking_proxy_set_vesting_contract_voter".to_string(),
-//             Box::new(decoder::staking_proxy_set_vesting_contract_voter),
-//         );
-//         map.insert(
-//             "staking_proxy_set_voter".to_string(),
-//             Box::new(decoder::staking_proxy_set_voter),
-//         );
-//         map.insert(
-//             "transaction_fee_convert_to_aptos_fa_burn_ref".to_string(),
-//             Box::new(decoder::transaction_fee_convert_to_aptos_fa_burn_ref),
-//         );
-//         map.insert(
-//             "version_set_for_next_epoch".to_string(),
-//             Box::new(decoder::version_set_for_next_epoch),
-//         );
-//         map.insert(
-//             "version_set_version".to_string(),
-//             Box::new(decoder::version_set_version),
-//         );
-//         map.insert(
-//             "vesting_admin_withdraw".to_string(),
-//             Box::new(decoder::vesting_admin_withdraw),
-//         );
-//         map.insert(
-//             "vesting_distribute".to_string(),
-//             Box::new(decoder::vesting_distribute),
-//         );
-//         map.insert(
-//             "vesting_distribute_many".to_string(),
-//             Box::new(decoder::vesting_distribute_many),
-//         );
-//         map.insert(
-//             "vesting_reset_beneficiary".to_string(),
-//             Box::new(decoder::vesting_reset_beneficiary),
-//         );
-//         map.insert(
-//             "vesting_reset_lockup".to_string(),
-//             Box::new(decoder::vesting_reset_lockup),
-//         );
-//         map.insert(
-//             "vesting_set_beneficiary".to_string(),
-//             Box::new(decoder::vesting_set_beneficiary),
-//         );
-//         map.insert(
-//             "vesting_set_beneficiary_for_operator".to_string(),
-//             Box::new(decoder::vesting_set_beneficiary_for_operator),
-//         );
-//         map.insert(
-//             "vesting_set_beneficiary_resetter".to_string(),
-//             Box::new(decoder::vesting_set_beneficiary_resetter),
-//         );
-//         map.insert(
-//             "vesting_set_management_role".to_string(),
-//             Box::new(decoder::vesting_set_management_role),
-//         );
-//         map.insert(
-//             "vesting_terminate_vesting_contract".to_string(),
-//             Box::new(decoder::vesting_terminate_vesting_contract),
-//         );
-//         map.insert(
-//             "vesting_unlock_rewards".to_string(),
-//             Box::new(decoder::vesting_unlock_rewards),
-//         );
-//         map.insert(
-//             "vesting_unlock_rewards_many".to_string(),
-//             Box::new(decoder::vesting_unlock_rewards_many),
-//         );
-//         map.insert(
-//             "vesting_update_commission_percentage".to_string(),
-//             Box::new(decoder::vesting_update_commission_percentage),
-//         );
-//         map.insert(
-//             "vesting_update_operator".to_string(),
-//             Box::new(decoder::vesting_update_operator),
-//         );
-//         map.insert(
-//             "vesting_update_operator_with_same_commission".to_string(),
-//             Box::new(decoder::vesting_update_operator_with_same_commission),
-//         );
-//         map.insert(
-//             "vesting_update_voter".to_string(),
-//             Box::new(decoder::vesting_update_voter),
-//         );
-//         map.insert("vesting_vest".to_string(), Box::new(decoder::vesting_vest));
-//         map.insert(
-//             "vesting_vest_many".to_string(),
-//             Box::new(decoder::vesting_vest_many),
-//         );
-//         map
-//     });
+/// Withdraw all funds to the preset vesting contract's withdrawal address. This can only be called if the contract
+/// has already been terminated.
+pub fn vesting_admin_withdraw(contract_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("admin_withdraw").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_address).unwrap()],
+    ))
+}
+
+/// Distribute any withdrawable stake from the stake pool.
+pub fn vesting_distribute(contract_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("distribute").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_address).unwrap()],
+    ))
+}
+
+/// Call `distribute` for many vesting contracts.
+pub fn vesting_distribute_many(contract_addresses: Vec<AccountAddress>) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("distribute_many").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_addresses).unwrap()],
+    ))
+}
+
+/// Remove the beneficiary for the given shareholder. All distributions will sent directly to the shareholder
+/// account.
+pub fn vesting_reset_beneficiary(
+    contract_address: AccountAddress,
+    shareholder: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("reset_beneficiary").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&shareholder).unwrap(),
+        ],
+    ))
+}
+
+pub fn vesting_reset_lockup(contract_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("reset_lockup").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_address).unwrap()],
+    ))
+}
+
+pub fn vesting_set_beneficiary(
+    contract_address: AccountAddress,
+    shareholder: AccountAddress,
+    new_beneficiary: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("set_beneficiary").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&shareholder).unwrap(),
+            bcs::to_bytes(&new_beneficiary).unwrap(),
+        ],
+    ))
+}
+
+/// Set the beneficiary for the operator.
+pub fn vesting_set_beneficiary_for_operator(new_beneficiary: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("set_beneficiary_for_operator").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&new_beneficiary).unwrap()],
+    ))
+}
+
+pub fn vesting_set_beneficiary_resetter(
+    contract_address: AccountAddress,
+    beneficiary_resetter: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("set_beneficiary_resetter").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&beneficiary_resetter).unwrap(),
+        ],
+    ))
+}
+
+pub fn vesting_set_management_role(
+    contract_address: AccountAddress,
+    role: Vec<u8>,
+    role_holder: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("set_management_role").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&role).unwrap(),
+            bcs::to_bytes(&role_holder).unwrap(),
+        ],
+    ))
+}
+
+/// Terminate the vesting contract and send all funds back to the withdrawal address.
+pub fn vesting_terminate_vesting_contract(contract_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("terminate_vesting_contract").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_address).unwrap()],
+    ))
+}
+
+/// Unlock any accumulated rewards.
+pub fn vesting_unlock_rewards(contract_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("unlock_rewards").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_address).unwrap()],
+    ))
+}
+
+/// Call `unlock_rewards` for many vesting contracts.
+pub fn vesting_unlock_rewards_many(contract_addresses: Vec<AccountAddress>) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("unlock_rewards_many").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_addresses).unwrap()],
+    ))
+}
+
+pub fn vesting_update_commission_percentage(
+    contract_address: AccountAddress,
+    new_commission_percentage: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("update_commission_percentage").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&new_commission_percentage).unwrap(),
+        ],
+    ))
+}
+
+pub fn vesting_update_operator(
+    contract_address: AccountAddress,
+    new_operator: AccountAddress,
+    commission_percentage: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("update_operator").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&new_operator).unwrap(),
+            bcs::to_bytes(&commission_percentage).unwrap(),
+        ],
+    ))
+}
+
+pub fn vesting_update_operator_with_same_commission(
+    contract_address: AccountAddress,
+    new_operator: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("update_operator_with_same_commission").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&new_operator).unwrap(),
+        ],
+    ))
+}
+
+pub fn vesting_update_voter(
+    contract_address: AccountAddress,
+    new_voter: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("update_voter").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&contract_address).unwrap(),
+            bcs::to_bytes(&new_voter).unwrap(),
+        ],
+    ))
+}
+
+/// Unlock any vested portion of the grant.
+pub fn vesting_vest(contract_address: AccountAddress) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("vest").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_address).unwrap()],
+    ))
+}
+
+/// Call `vest` for many vesting contracts.
+pub fn vesting_vest_many(contract_addresses: Vec<AccountAddress>) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("vesting").to_owned(),
+        ),
+        ident_str!("vest_many").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&contract_addresses).unwrap()],
+    ))
+}
+
+mod decoder {
+    use super::*;
+    pub fn account_offer_rotation_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AccountOfferRotationCapability {
+                rotation_capability_sig_bytes: bcs::from_bytes(script.args().get(0)?).ok()?,
+                account_scheme: bcs::from_bytes(script.args().get(1)?).ok()?,
+                account_public_key_bytes: bcs::from_bytes(script.args().get(2)?).ok()?,
+                recipient_address: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn account_offer_signer_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AccountOfferSignerCapability {
+                signer_capability_sig_bytes: bcs::from_bytes(script.args().get(0)?).ok()?,
+                account_scheme: bcs::from_bytes(script.args().get(1)?).ok()?,
+                account_public_key_bytes: bcs::from_bytes(script.args().get(2)?).ok()?,
+                recipient_address: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn account_revoke_any_rotation_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::AccountRevokeAnyRotationCapability {})
+        } else {
+            None
+        }
+    }
+
+    pub fn account_revoke_any_signer_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::AccountRevokeAnySignerCapability {})
+        } else {
+            None
+        }
+    }
+
+    pub fn account_revoke_rotation_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AccountRevokeRotationCapability {
+                to_be_revoked_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn account_revoke_signer_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AccountRevokeSignerCapability {
+                to_be_revoked_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn account_rotate_authentication_key(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AccountRotateAuthenticationKey {
+                from_scheme: bcs::from_bytes(script.args().get(0)?).ok()?,
+                from_public_key_bytes: bcs::from_bytes(script.args().get(1)?).ok()?,
+                to_scheme: bcs::from_bytes(script.args().get(2)?).ok()?,
+                to_public_key_bytes: bcs::from_bytes(script.args().get(3)?).ok()?,
+                cap_rotate_key: bcs::from_bytes(script.args().get(4)?).ok()?,
+                cap_update_table: bcs::from_bytes(script.args().get(5)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn account_rotate_authentication_key_call(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AccountRotateAuthenticationKeyCall {
+                new_auth_key: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn account_rotate_authentication_key_with_rotation_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::AccountRotateAuthenticationKeyWithRotationCapability {
+                    rotation_cap_offerer_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    new_scheme: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    new_public_key_bytes: bcs::from_bytes(script.args().get(2)?).ok()?,
+                    cap_update_table: bcs::from_bytes(script.args().get(3)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn account_set_originating_address(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::AccountSetOriginatingAddress {})
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_account_batch_transfer(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosAccountBatchTransfer {
+                recipients: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amounts: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_account_batch_transfer_coins(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosAccountBatchTransferCoins {
+                coin_type: script.ty_args().get(0)?.clone(),
+                recipients: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amounts: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_account_create_account(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosAccountCreateAccount {
+                auth_key: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_account_fungible_transfer_only(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosAccountFungibleTransferOnly {
+                to: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_account_set_allow_direct_coin_transfers(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosAccountSetAllowDirectCoinTransfers {
+                allow: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_account_transfer(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosAccountTransfer {
+                to: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_account_transfer_coins(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosAccountTransferCoins {
+                coin_type: script.ty_args().get(0)?.clone(),
+                to: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_coin_claim_mint_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::AptosCoinClaimMintCapability {})
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_coin_delegate_mint_capability(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosCoinDelegateMintCapability {
+                to: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_coin_mint(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosCoinMint {
+                dst_addr: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_add_approved_script_hash_script(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::AptosGovernanceAddApprovedScriptHashScript {
+                    proposal_id: bcs::from_bytes(script.args().get(0)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_batch_partial_vote(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceBatchPartialVote {
+                stake_pools: bcs::from_bytes(script.args().get(0)?).ok()?,
+                proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
+                voting_power: bcs::from_bytes(script.args().get(2)?).ok()?,
+                should_pass: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_batch_vote(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceBatchVote {
+                stake_pools: bcs::from_bytes(script.args().get(0)?).ok()?,
+                proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
+                should_pass: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_create_proposal(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceCreateProposal {
+                stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
+                execution_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
+                metadata_location: bcs::from_bytes(script.args().get(2)?).ok()?,
+                metadata_hash: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_create_proposal_v2(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceCreateProposalV2 {
+                stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
+                execution_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
+                metadata_location: bcs::from_bytes(script.args().get(2)?).ok()?,
+                metadata_hash: bcs::from_bytes(script.args().get(3)?).ok()?,
+                is_multi_step_proposal: bcs::from_bytes(script.args().get(4)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_force_end_epoch(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceForceEndEpoch {})
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_force_end_epoch_test_only(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceForceEndEpochTestOnly {})
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_partial_vote(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosGovernancePartialVote {
+                stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
+                proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
+                voting_power: bcs::from_bytes(script.args().get(2)?).ok()?,
+                should_pass: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_reconfigure(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceReconfigure {})
+        } else {
+            None
+        }
+    }
+
+    pub fn aptos_governance_vote(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::AptosGovernanceVote {
+                stake_pool: bcs::from_bytes(script.args().get(0)?).ok()?,
+                proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
+                should_pass: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn code_publish_package_txn(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::CodePublishPackageTxn {
+                metadata_serialized: bcs::from_bytes(script.args().get(0)?).ok()?,
+                code: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn coin_create_coin_conversion_map(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::CoinCreateCoinConversionMap {})
+        } else {
+            None
+        }
+    }
+
+    pub fn coin_create_pairing(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::CoinCreatePairing {
+                coin_type: script.ty_args().get(0)?.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn coin_migrate_coin_store_to_fungible_store(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::CoinMigrateCoinStoreToFungibleStore {
+                coin_type: script.ty_args().get(0)?.clone(),
+                accounts: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn coin_migrate_to_fungible_store(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::CoinMigrateToFungibleStore {
+                coin_type: script.ty_args().get(0)?.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn coin_transfer(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::CoinTransfer {
+                coin_type: script.ty_args().get(0)?.clone(),
+                to: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn coin_upgrade_supply(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::CoinUpgradeSupply {
+                coin_type: script.ty_args().get(0)?.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_add_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolAddStake {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_allowlist_delegator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolAllowlistDelegator {
+                delegator_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_create_proposal(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolCreateProposal {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                execution_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
+                metadata_location: bcs::from_bytes(script.args().get(2)?).ok()?,
+                metadata_hash: bcs::from_bytes(script.args().get(3)?).ok()?,
+                is_multi_step_proposal: bcs::from_bytes(script.args().get(4)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_delegate_voting_power(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolDelegateVotingPower {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_disable_delegators_allowlisting(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::DelegationPoolDisableDelegatorsAllowlisting {})
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_enable_delegators_allowlisting(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::DelegationPoolEnableDelegatorsAllowlisting {})
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_enable_partial_governance_voting(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::DelegationPoolEnablePartialGovernanceVoting {
+                    pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_evict_delegator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolEvictDelegator {
+                delegator_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_initialize_delegation_pool(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolInitializeDelegationPool {
+                operator_commission_percentage: bcs::from_bytes(script.args().get(0)?).ok()?,
+                delegation_pool_creation_seed: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_reactivate_stake(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolReactivateStake {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_remove_delegator_from_allowlist(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::DelegationPoolRemoveDelegatorFromAllowlist {
+                    delegator_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_set_beneficiary_for_operator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolSetBeneficiaryForOperator {
+                new_beneficiary: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_set_delegated_voter(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolSetDelegatedVoter {
+                new_voter: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_set_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolSetOperator {
+                new_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_synchronize_delegation_pool(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolSynchronizeDelegationPool {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_unlock(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolUnlock {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_update_commission_percentage(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::DelegationPoolUpdateCommissionPercentage {
+                    new_commission_percentage: bcs::from_bytes(script.args().get(0)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_vote(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolVote {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                proposal_id: bcs::from_bytes(script.args().get(1)?).ok()?,
+                voting_power: bcs::from_bytes(script.args().get(2)?).ok()?,
+                should_pass: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn delegation_pool_withdraw(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::DelegationPoolWithdraw {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn jwks_update_federated_jwk_set(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::JwksUpdateFederatedJwkSet {
+                iss: bcs::from_bytes(script.args().get(0)?).ok()?,
+                kid_vec: bcs::from_bytes(script.args().get(1)?).ok()?,
+                alg_vec: bcs::from_bytes(script.args().get(2)?).ok()?,
+                e_vec: bcs::from_bytes(script.args().get(3)?).ok()?,
+                n_vec: bcs::from_bytes(script.args().get(4)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn managed_coin_burn(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ManagedCoinBurn {
+                coin_type: script.ty_args().get(0)?.clone(),
+                amount: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn managed_coin_destroy_caps(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ManagedCoinDestroyCaps {
+                coin_type: script.ty_args().get(0)?.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn managed_coin_initialize(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ManagedCoinInitialize {
+                coin_type: script.ty_args().get(0)?.clone(),
+                name: bcs::from_bytes(script.args().get(0)?).ok()?,
+                symbol: bcs::from_bytes(script.args().get(1)?).ok()?,
+                decimals: bcs::from_bytes(script.args().get(2)?).ok()?,
+                monitor_supply: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn managed_coin_mint(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ManagedCoinMint {
+                coin_type: script.ty_args().get(0)?.clone(),
+                dst_addr: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn managed_coin_register(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ManagedCoinRegister {
+                coin_type: script.ty_args().get(0)?.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_add_owner(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountAddOwner {
+                new_owner: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_add_owners(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountAddOwners {
+                new_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_add_owners_and_update_signatures_required(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountAddOwnersAndUpdateSignaturesRequired {
+                    new_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    new_num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_approve_transaction(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountApproveTransaction {
+                multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountCreate {
+                num_signatures_required: bcs::from_bytes(script.args().get(0)?).ok()?,
+                metadata_keys: bcs::from_bytes(script.args().get(1)?).ok()?,
+                metadata_values: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_transaction(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountCreateTransaction {
+                multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                payload: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_transaction_with_hash(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountCreateTransactionWithHash {
+                    multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    payload_hash: bcs::from_bytes(script.args().get(1)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_with_existing_account(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountCreateWithExistingAccount {
+                    multisig_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    owners: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    num_signatures_required: bcs::from_bytes(script.args().get(2)?).ok()?,
+                    account_scheme: bcs::from_bytes(script.args().get(3)?).ok()?,
+                    account_public_key: bcs::from_bytes(script.args().get(4)?).ok()?,
+                    create_multisig_account_signed_message: bcs::from_bytes(script.args().get(5)?)
+                        .ok()?,
+                    metadata_keys: bcs::from_bytes(script.args().get(6)?).ok()?,
+                    metadata_values: bcs::from_bytes(script.args().get(7)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_with_existing_account_and_revoke_auth_key(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountCreateWithExistingAccountAndRevokeAuthKey {
+                    multisig_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    owners: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    num_signatures_required: bcs::from_bytes(script.args().get(2)?).ok()?,
+                    account_scheme: bcs::from_bytes(script.args().get(3)?).ok()?,
+                    account_public_key: bcs::from_bytes(script.args().get(4)?).ok()?,
+                    create_multisig_account_signed_message: bcs::from_bytes(script.args().get(5)?)
+                        .ok()?,
+                    metadata_keys: bcs::from_bytes(script.args().get(6)?).ok()?,
+                    metadata_values: bcs::from_bytes(script.args().get(7)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_with_existing_account_and_revoke_auth_key_call(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountCreateWithExistingAccountAndRevokeAuthKeyCall {
+                    owners: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
+                    metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_with_existing_account_call(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountCreateWithExistingAccountCall {
+                    owners: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
+                    metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_with_owners(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountCreateWithOwners {
+                additional_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
+                num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
+                metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
+                metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_create_with_owners_then_remove_bootstrapper(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountCreateWithOwnersThenRemoveBootstrapper {
+                    owners: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    num_signatures_required: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    metadata_keys: bcs::from_bytes(script.args().get(2)?).ok()?,
+                    metadata_values: bcs::from_bytes(script.args().get(3)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_execute_rejected_transaction(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountExecuteRejectedTransaction {
+                    multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_execute_rejected_transactions(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountExecuteRejectedTransactions {
+                    multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    final_sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_reject_transaction(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountRejectTransaction {
+                multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_remove_owner(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountRemoveOwner {
+                owner_to_remove: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_remove_owners(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountRemoveOwners {
+                owners_to_remove: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_swap_owner(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountSwapOwner {
+                to_swap_in: bcs::from_bytes(script.args().get(0)?).ok()?,
+                to_swap_out: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_swap_owners(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountSwapOwners {
+                to_swap_in: bcs::from_bytes(script.args().get(0)?).ok()?,
+                to_swap_out: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_swap_owners_and_update_signatures_required(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::MultisigAccountSwapOwnersAndUpdateSignaturesRequired {
+                    new_owners: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    owners_to_remove: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    new_num_signatures_required: bcs::from_bytes(script.args().get(2)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_update_metadata(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountUpdateMetadata {
+                keys: bcs::from_bytes(script.args().get(0)?).ok()?,
+                values: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_update_signatures_required(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountUpdateSignaturesRequired {
+                new_num_signatures_required: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_vote_transaction(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountVoteTransaction {
+                multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
+                approved: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_vote_transactions(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountVoteTransactions {
+                multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                starting_sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
+                final_sequence_number: bcs::from_bytes(script.args().get(2)?).ok()?,
+                approved: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn multisig_account_vote_transanction(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::MultisigAccountVoteTransanction {
+                multisig_account: bcs::from_bytes(script.args().get(0)?).ok()?,
+                sequence_number: bcs::from_bytes(script.args().get(1)?).ok()?,
+                approved: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn object_transfer_call(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ObjectTransferCall {
+                object: bcs::from_bytes(script.args().get(0)?).ok()?,
+                to: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn object_code_deployment_publish(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ObjectCodeDeploymentPublish {
+                metadata_serialized: bcs::from_bytes(script.args().get(0)?).ok()?,
+                code: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn resource_account_create_resource_account(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::ResourceAccountCreateResourceAccount {
+                seed: bcs::from_bytes(script.args().get(0)?).ok()?,
+                optional_auth_key: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn resource_account_create_resource_account_and_fund(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::ResourceAccountCreateResourceAccountAndFund {
+                    seed: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    optional_auth_key: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    fund_amount: bcs::from_bytes(script.args().get(2)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn resource_account_create_resource_account_and_publish_package(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::ResourceAccountCreateResourceAccountAndPublishPackage {
+                    seed: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    metadata_serialized: bcs::from_bytes(script.args().get(1)?).ok()?,
+                    code: bcs::from_bytes(script.args().get(2)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_add_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeAddStake {
+                amount: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_increase_lockup(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::StakeIncreaseLockup {})
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_initialize_stake_owner(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeInitializeStakeOwner {
+                initial_stake_amount: bcs::from_bytes(script.args().get(0)?).ok()?,
+                operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+                voter: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_initialize_validator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeInitializeValidator {
+                consensus_pubkey: bcs::from_bytes(script.args().get(0)?).ok()?,
+                proof_of_possession: bcs::from_bytes(script.args().get(1)?).ok()?,
+                network_addresses: bcs::from_bytes(script.args().get(2)?).ok()?,
+                fullnode_addresses: bcs::from_bytes(script.args().get(3)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_join_validator_set(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeJoinValidatorSet {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_leave_validator_set(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeLeaveValidatorSet {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_reactivate_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeReactivateStake {
+                amount: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_rotate_consensus_key(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeRotateConsensusKey {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_consensus_pubkey: bcs::from_bytes(script.args().get(1)?).ok()?,
+                proof_of_possession: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_set_delegated_voter(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeSetDelegatedVoter {
+                new_voter: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_set_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeSetOperator {
+                new_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_unlock(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeUnlock {
+                amount: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_update_network_and_fullnode_addresses(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeUpdateNetworkAndFullnodeAddresses {
+                pool_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_network_addresses: bcs::from_bytes(script.args().get(1)?).ok()?,
+                new_fullnode_addresses: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn stake_withdraw(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakeWithdraw {
+                withdraw_amount: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_add_stake(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractAddStake {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_create_staking_contract(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractCreateStakingContract {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                voter: bcs::from_bytes(script.args().get(1)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(2)?).ok()?,
+                commission_percentage: bcs::from_bytes(script.args().get(3)?).ok()?,
+                contract_creation_seed: bcs::from_bytes(script.args().get(4)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_distribute(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractDistribute {
+                staker: bcs::from_bytes(script.args().get(0)?).ok()?,
+                operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_request_commission(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractRequestCommission {
+                staker: bcs::from_bytes(script.args().get(0)?).ok()?,
+                operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_reset_lockup(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractResetLockup {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_set_beneficiary_for_operator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::StakingContractSetBeneficiaryForOperator {
+                    new_beneficiary: bcs::from_bytes(script.args().get(0)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_switch_operator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractSwitchOperator {
+                old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+                new_commission_percentage: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_switch_operator_with_same_commission(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(
+                EntryFunctionCall::StakingContractSwitchOperatorWithSameCommission {
+                    old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                    new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+                },
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_unlock_rewards(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractUnlockRewards {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_unlock_stake(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractUnlockStake {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                amount: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_update_commision(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractUpdateCommision {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_commission_percentage: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_contract_update_voter(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingContractUpdateVoter {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetOperator {
+                old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_stake_pool_operator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetStakePoolOperator {
+                new_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_stake_pool_voter(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetStakePoolVoter {
+                new_voter: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_staking_contract_operator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetStakingContractOperator {
+                old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_staking_contract_voter(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetStakingContractVoter {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_vesting_contract_operator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetVestingContractOperator {
+                old_operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_vesting_contract_voter(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetVestingContractVoter {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn staking_proxy_set_voter(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::StakingProxySetVoter {
+                operator: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn transaction_fee_convert_to_aptos_fa_burn_ref(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(_script) = payload {
+            Some(EntryFunctionCall::TransactionFeeConvertToAptosFaBurnRef {})
+        } else {
+            None
+        }
+    }
+
+    pub fn version_set_for_next_epoch(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VersionSetForNextEpoch {
+                major: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn version_set_version(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VersionSetVersion {
+                major: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_admin_withdraw(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingAdminWithdraw {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_distribute(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingDistribute {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_distribute_many(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingDistributeMany {
+                contract_addresses: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_reset_beneficiary(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingResetBeneficiary {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                shareholder: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_reset_lockup(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingResetLockup {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_set_beneficiary(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingSetBeneficiary {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                shareholder: bcs::from_bytes(script.args().get(1)?).ok()?,
+                new_beneficiary: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_set_beneficiary_for_operator(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingSetBeneficiaryForOperator {
+                new_beneficiary: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_set_beneficiary_resetter(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingSetBeneficiaryResetter {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                beneficiary_resetter: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_set_management_role(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingSetManagementRole {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                role: bcs::from_bytes(script.args().get(1)?).ok()?,
+                role_holder: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_terminate_vesting_contract(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingTerminateVestingContract {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_unlock_rewards(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingUnlockRewards {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_unlock_rewards_many(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingUnlockRewardsMany {
+                contract_addresses: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_update_commission_percentage(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingUpdateCommissionPercentage {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_commission_percentage: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_update_operator(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingUpdateOperator {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+                commission_percentage: bcs::from_bytes(script.args().get(2)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_update_operator_with_same_commission(
+        payload: &TransactionPayload,
+    ) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingUpdateOperatorWithSameCommission {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_operator: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_update_voter(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingUpdateVoter {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+                new_voter: bcs::from_bytes(script.args().get(1)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_vest(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingVest {
+                contract_address: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn vesting_vest_many(payload: &TransactionPayload) -> Option<EntryFunctionCall> {
+        if let TransactionPayload::EntryFunction(script) = payload {
+            Some(EntryFunctionCall::VestingVestMany {
+                contract_addresses: bcs::from_bytes(script.args().get(0)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+type EntryFunctionDecoderMap = std::collections::HashMap<
+    String,
+    Box<
+        dyn Fn(&TransactionPayload) -> Option<EntryFunctionCall>
+            + std::marker::Sync
+            + std::marker::Send,
+    >,
+>;
+
+static SCRIPT_FUNCTION_DECODER_MAP: once_cell::sync::Lazy<EntryFunctionDecoderMap> =
+    once_cell::sync::Lazy::new(|| {
+        let mut map: EntryFunctionDecoderMap = std::collections::HashMap::new();
+        map.insert(
+            "account_offer_rotation_capability".to_string(),
+            Box::new(decoder::account_offer_rotation_capability),
+        );
+        map.insert(
+            "account_offer_signer_capability".to_string(),
+            Box::new(decoder::account_offer_signer_capability),
+        );
+        map.insert(
+            "account_revoke_any_rotation_capability".to_string(),
+            Box::new(decoder::account_revoke_any_rotation_capability),
+        );
+        map.insert(
+            "account_revoke_any_signer_capability".to_string(),
+            Box::new(decoder::account_revoke_any_signer_capability),
+        );
+        map.insert(
+            "account_revoke_rotation_capability".to_string(),
+            Box::new(decoder::account_revoke_rotation_capability),
+        );
+        map.insert(
+            "account_revoke_signer_capability".to_string(),
+            Box::new(decoder::account_revoke_signer_capability),
+        );
+        map.insert(
+            "account_rotate_authentication_key".to_string(),
+            Box::new(decoder::account_rotate_authentication_key),
+        );
+        map.insert(
+            "account_rotate_authentication_key_call".to_string(),
+            Box::new(decoder::account_rotate_authentication_key_call),
+        );
+        map.insert(
+            "account_rotate_authentication_key_with_rotation_capability".to_string(),
+            Box::new(decoder::account_rotate_authentication_key_with_rotation_capability),
+        );
+        map.insert(
+            "account_set_originating_address".to_string(),
+            Box::new(decoder::account_set_originating_address),
+        );
+        map.insert(
+            "aptos_account_batch_transfer".to_string(),
+            Box::new(decoder::aptos_account_batch_transfer),
+        );
+        map.insert(
+            "aptos_account_batch_transfer_coins".to_string(),
+            Box::new(decoder::aptos_account_batch_transfer_coins),
+        );
+        map.insert(
+            "aptos_account_create_account".to_string(),
+            Box::new(decoder::aptos_account_create_account),
+        );
+        map.insert(
+            "aptos_account_fungible_transfer_only".to_string(),
+            Box::new(decoder::aptos_account_fungible_transfer_only),
+        );
+        map.insert(
+            "aptos_account_set_allow_direct_coin_transfers".to_string(),
+            Box::new(decoder::aptos_account_set_allow_direct_coin_transfers),
+        );
+        map.insert(
+            "aptos_account_transfer".to_string(),
+            Box::new(decoder::aptos_account_transfer),
+        );
+        map.insert(
+            "aptos_account_transfer_coins".to_string(),
+            Box::new(decoder::aptos_account_transfer_coins),
+        );
+        map.insert(
+            "aptos_coin_claim_mint_capability".to_string(),
+            Box::new(decoder::aptos_coin_claim_mint_capability),
+        );
+        map.insert(
+            "aptos_coin_delegate_mint_capability".to_string(),
+            Box::new(decoder::aptos_coin_delegate_mint_capability),
+        );
+        map.insert(
+            "aptos_coin_mint".to_string(),
+            Box::new(decoder::aptos_coin_mint),
+        );
+        map.insert(
+            "aptos_governance_add_approved_script_hash_script".to_string(),
+            Box::new(decoder::aptos_governance_add_approved_script_hash_script),
+        );
+        map.insert(
+            "aptos_governance_batch_partial_vote".to_string(),
+            Box::new(decoder::aptos_governance_batch_partial_vote),
+        );
+        map.insert(
+            "aptos_governance_batch_vote".to_string(),
+            Box::new(decoder::aptos_governance_batch_vote),
+        );
+        map.insert(
+            "aptos_governance_create_proposal".to_string(),
+            Box::new(decoder::aptos_governance_create_proposal),
+        );
+        map.insert(
+            "aptos_governance_create_proposal_v2".to_string(),
+            Box::new(decoder::aptos_governance_create_proposal_v2),
+        );
+        map.insert(
+            "aptos_governance_force_end_epoch".to_string(),
+            Box::new(decoder::aptos_governance_force_end_epoch),
+        );
+        map.insert(
+            "aptos_governance_force_end_epoch_test_only".to_string(),
+            Box::new(decoder::aptos_governance_force_end_epoch_test_only),
+        );
+        map.insert(
+            "aptos_governance_partial_vote".to_string(),
+            Box::new(decoder::aptos_governance_partial_vote),
+        );
+        map.insert(
+            "aptos_governance_reconfigure".to_string(),
+            Box::new(decoder::aptos_governance_reconfigure),
+        );
+        map.insert(
+            "aptos_governance_vote".to_string(),
+            Box::new(decoder::aptos_governance_vote),
+        );
+        map.insert(
+            "code_publish_package_txn".to_string(),
+            Box::new(decoder::code_publish_package_txn),
+        );
+        map.insert(
+            "coin_create_coin_conversion_map".to_string(),
+            Box::new(decoder::coin_create_coin_conversion_map),
+        );
+        map.insert(
+            "coin_create_pairing".to_string(),
+            Box::new(decoder::coin_create_pairing),
+        );
+        map.insert(
+            "coin_migrate_coin_store_to_fungible_store".to_string(),
+            Box::new(decoder::coin_migrate_coin_store_to_fungible_store),
+        );
+        map.insert(
+            "coin_migrate_to_fungible_store".to_string(),
+            Box::new(decoder::coin_migrate_to_fungible_store),
+        );
+        map.insert(
+            "coin_transfer".to_string(),
+            Box::new(decoder::coin_transfer),
+        );
+        map.insert(
+            "coin_upgrade_supply".to_string(),
+            Box::new(decoder::coin_upgrade_supply),
+        );
+        map.insert(
+            "delegation_pool_add_stake".to_string(),
+            Box::new(decoder::delegation_pool_add_stake),
+        );
+        map.insert(
+            "delegation_pool_allowlist_delegator".to_string(),
+            Box::new(decoder::delegation_pool_allowlist_delegator),
+        );
+        map.insert(
+            "delegation_pool_create_proposal".to_string(),
+            Box::new(decoder::delegation_pool_create_proposal),
+        );
+        map.insert(
+            "delegation_pool_delegate_voting_power".to_string(),
+            Box::new(decoder::delegation_pool_delegate_voting_power),
+        );
+        map.insert(
+            "delegation_pool_disable_delegators_allowlisting".to_string(),
+            Box::new(decoder::delegation_pool_disable_delegators_allowlisting),
+        );
+        map.insert(
+            "delegation_pool_enable_delegators_allowlisting".to_string(),
+            Box::new(decoder::delegation_pool_enable_delegators_allowlisting),
+        );
+        map.insert(
+            "delegation_pool_enable_partial_governance_voting".to_string(),
+            Box::new(decoder::delegation_pool_enable_partial_governance_voting),
+        );
+        map.insert(
+            "delegation_pool_evict_delegator".to_string(),
+            Box::new(decoder::delegation_pool_evict_delegator),
+        );
+        map.insert(
+            "delegation_pool_initialize_delegation_pool".to_string(),
+            Box::new(decoder::delegation_pool_initialize_delegation_pool),
+        );
+        map.insert(
+            "delegation_pool_reactivate_stake".to_string(),
+            Box::new(decoder::delegation_pool_reactivate_stake),
+        );
+        map.insert(
+            "delegation_pool_remove_delegator_from_allowlist".to_string(),
+            Box::new(decoder::delegation_pool_remove_delegator_from_allowlist),
+        );
+        map.insert(
+            "delegation_pool_set_beneficiary_for_operator".to_string(),
+            Box::new(decoder::delegation_pool_set_beneficiary_for_operator),
+        );
+        map.insert(
+            "delegation_pool_set_delegated_voter".to_string(),
+            Box::new(decoder::delegation_pool_set_delegated_voter),
+        );
+        map.insert(
+            "delegation_pool_set_operator".to_string(),
+            Box::new(decoder::delegation_pool_set_operator),
+        );
+        map.insert(
+            "delegation_pool_synchronize_delegation_pool".to_string(),
+            Box::new(decoder::delegation_pool_synchronize_delegation_pool),
+        );
+        map.insert(
+            "delegation_pool_unlock".to_string(),
+            Box::new(decoder::delegation_pool_unlock),
+        );
+        map.insert(
+            "delegation_pool_update_commission_percentage".to_string(),
+            Box::new(decoder::delegation_pool_update_commission_percentage),
+        );
+        map.insert(
+            "delegation_pool_vote".to_string(),
+            Box::new(decoder::delegation_pool_vote),
+        );
+        map.insert(
+            "delegation_pool_withdraw".to_string(),
+            Box::new(decoder::delegation_pool_withdraw),
+        );
+        map.insert(
+            "jwks_update_federated_jwk_set".to_string(),
+            Box::new(decoder::jwks_update_federated_jwk_set),
+        );
+        map.insert(
+            "managed_coin_burn".to_string(),
+            Box::new(decoder::managed_coin_burn),
+        );
+        map.insert(
+            "managed_coin_destroy_caps".to_string(),
+            Box::new(decoder::managed_coin_destroy_caps),
+        );
+        map.insert(
+            "managed_coin_initialize".to_string(),
+            Box::new(decoder::managed_coin_initialize),
+        );
+        map.insert(
+            "managed_coin_mint".to_string(),
+            Box::new(decoder::managed_coin_mint),
+        );
+        map.insert(
+            "managed_coin_register".to_string(),
+            Box::new(decoder::managed_coin_register),
+        );
+        map.insert(
+            "multisig_account_add_owner".to_string(),
+            Box::new(decoder::multisig_account_add_owner),
+        );
+        map.insert(
+            "multisig_account_add_owners".to_string(),
+            Box::new(decoder::multisig_account_add_owners),
+        );
+        map.insert(
+            "multisig_account_add_owners_and_update_signatures_required".to_string(),
+            Box::new(decoder::multisig_account_add_owners_and_update_signatures_required),
+        );
+        map.insert(
+            "multisig_account_approve_transaction".to_string(),
+            Box::new(decoder::multisig_account_approve_transaction),
+        );
+        map.insert(
+            "multisig_account_create".to_string(),
+            Box::new(decoder::multisig_account_create),
+        );
+        map.insert(
+            "multisig_account_create_transaction".to_string(),
+            Box::new(decoder::multisig_account_create_transaction),
+        );
+        map.insert(
+            "multisig_account_create_transaction_with_hash".to_string(),
+            Box::new(decoder::multisig_account_create_transaction_with_hash),
+        );
+        map.insert(
+            "multisig_account_create_with_existing_account".to_string(),
+            Box::new(decoder::multisig_account_create_with_existing_account),
+        );
+        map.insert(
+            "multisig_account_create_with_existing_account_and_revoke_auth_key".to_string(),
+            Box::new(decoder::multisig_account_create_with_existing_account_and_revoke_auth_key),
+        );
+        map.insert(
+            "multisig_account_create_with_existing_account_and_revoke_auth_key_call".to_string(),
+            Box::new(
+                decoder::multisig_account_create_with_existing_account_and_revoke_auth_key_call,
+            ),
+        );
+        map.insert(
+            "multisig_account_create_with_existing_account_call".to_string(),
+            Box::new(decoder::multisig_account_create_with_existing_account_call),
+        );
+        map.insert(
+            "multisig_account_create_with_owners".to_string(),
+            Box::new(decoder::multisig_account_create_with_owners),
+        );
+        map.insert(
+            "multisig_account_create_with_owners_then_remove_bootstrapper".to_string(),
+            Box::new(decoder::multisig_account_create_with_owners_then_remove_bootstrapper),
+        );
+        map.insert(
+            "multisig_account_execute_rejected_transaction".to_string(),
+            Box::new(decoder::multisig_account_execute_rejected_transaction),
+        );
+        map.insert(
+            "multisig_account_execute_rejected_transactions".to_string(),
+            Box::new(decoder::multisig_account_execute_rejected_transactions),
+        );
+        map.insert(
+            "multisig_account_reject_transaction".to_string(),
+            Box::new(decoder::multisig_account_reject_transaction),
+        );
+        map.insert(
+            "multisig_account_remove_owner".to_string(),
+            Box::new(decoder::multisig_account_remove_owner),
+        );
+        map.insert(
+            "multisig_account_remove_owners".to_string(),
+            Box::new(decoder::multisig_account_remove_owners),
+        );
+        map.insert(
+            "multisig_account_swap_owner".to_string(),
+            Box::new(decoder::multisig_account_swap_owner),
+        );
+        map.insert(
+            "multisig_account_swap_owners".to_string(),
+            Box::new(decoder::multisig_account_swap_owners),
+        );
+        map.insert(
+            "multisig_account_swap_owners_and_update_signatures_required".to_string(),
+            Box::new(decoder::multisig_account_swap_owners_and_update_signatures_required),
+        );
+        map.insert(
+            "multisig_account_update_metadata".to_string(),
+            Box::new(decoder::multisig_account_update_metadata),
+        );
+        map.insert(
+            "multisig_account_update_signatures_required".to_string(),
+            Box::new(decoder::multisig_account_update_signatures_required),
+        );
+        map.insert(
+            "multisig_account_vote_transaction".to_string(),
+            Box::new(decoder::multisig_account_vote_transaction),
+        );
+        map.insert(
+            "multisig_account_vote_transactions".to_string(),
+            Box::new(decoder::multisig_account_vote_transactions),
+        );
+        map.insert(
+            "multisig_account_vote_transanction".to_string(),
+            Box::new(decoder::multisig_account_vote_transanction),
+        );
+        map.insert(
+            "object_transfer_call".to_string(),
+            Box::new(decoder::object_transfer_call),
+        );
+        map.insert(
+            "object_code_deployment_publish".to_string(),
+            Box::new(decoder::object_code_deployment_publish),
+        );
+        map.insert(
+            "resource_account_create_resource_account".to_string(),
+            Box::new(decoder::resource_account_create_resource_account),
+        );
+        map.insert(
+            "resource_account_create_resource_account_and_fund".to_string(),
+            Box::new(decoder::resource_account_create_resource_account_and_fund),
+        );
+        map.insert(
+            "resource_account_create_resource_account_and_publish_package".to_string(),
+            Box::new(decoder::resource_account_create_resource_account_and_publish_package),
+        );
+        map.insert(
+            "stake_add_stake".to_string(),
+            Box::new(decoder::stake_add_stake),
+        );
+        map.insert(
+            "stake_increase_lockup".to_string(),
+            Box::new(decoder::stake_increase_lockup),
+        );
+        map.insert(
+            "stake_initialize_stake_owner".to_string(),
+            Box::new(decoder::stake_initialize_stake_owner),
+        );
+        map.insert(
+            "stake_initialize_validator".to_string(),
+            Box::new(decoder::stake_initialize_validator),
+        );
+        map.insert(
+            "stake_join_validator_set".to_string(),
+            Box::new(decoder::stake_join_validator_set),
+        );
+        map.insert(
+            "stake_leave_validator_set".to_string(),
+            Box::new(decoder::stake_leave_validator_set),
+        );
+        map.insert(
+            "stake_reactivate_stake".to_string(),
+            Box::new(decoder::stake_reactivate_stake),
+        );
+        map.insert(
+            "stake_rotate_consensus_key".to_string(),
+            Box::new(decoder::stake_rotate_consensus_key),
+        );
+        map.insert(
+            "stake_set_delegated_voter".to_string(),
+            Box::new(decoder::stake_set_delegated_voter),
+        );
+        map.insert(
+            "stake_set_operator".to_string(),
+            Box::new(decoder::stake_set_operator),
+        );
+        map.insert("stake_unlock".to_string(), Box::new(decoder::stake_unlock));
+        map.insert(
+            "stake_update_network_and_fullnode_addresses".to_string(),
+            Box::new(decoder::stake_update_network_and_fullnode_addresses),
+        );
+        map.insert(
+            "stake_withdraw".to_string(),
+            Box::new(decoder::stake_withdraw),
+        );
+        map.insert(
+            "staking_contract_add_stake".to_string(),
+            Box::new(decoder::staking_contract_add_stake),
+        );
+        map.insert(
+            "staking_contract_create_staking_contract".to_string(),
+            Box::new(decoder::staking_contract_create_staking_contract),
+        );
+        map.insert(
+            "staking_contract_distribute".to_string(),
+            Box::new(decoder::staking_contract_distribute),
+        );
+        map.insert(
+            "staking_contract_request_commission".to_string(),
+            Box::new(decoder::staking_contract_request_commission),
+        );
+        map.insert(
+            "staking_contract_reset_lockup".to_string(),
+            Box::new(decoder::staking_contract_reset_lockup),
+        );
+        map.insert(
+            "staking_contract_set_beneficiary_for_operator".to_string(),
+            Box::new(decoder::staking_contract_set_beneficiary_for_operator),
+        );
+        map.insert(
+            "staking_contract_switch_operator".to_string(),
+            Box::new(decoder::staking_contract_switch_operator),
+        );
+        map.insert(
+            "staking_contract_switch_operator_with_same_commission".to_string(),
+            Box::new(decoder::staking_contract_switch_operator_with_same_commission),
+        );
+        map.insert(
+            "staking_contract_unlock_rewards".to_string(),
+            Box::new(decoder::staking_contract_unlock_rewards),
+        );
+        map.insert(
+            "staking_contract_unlock_stake".to_string(),
+            Box::new(decoder::staking_contract_unlock_stake),
+        );
+        map.insert(
+            "staking_contract_update_commision".to_string(),
+            Box::new(decoder::staking_contract_update_commision),
+        );
+        map.insert(
+            "staking_contract_update_voter".to_string(),
+            Box::new(decoder::staking_contract_update_voter),
+        );
+        map.insert(
+            "staking_proxy_set_operator".to_string(),
+            Box::new(decoder::staking_proxy_set_operator),
+        );
+        map.insert(
+            "staking_proxy_set_stake_pool_operator".to_string(),
+            Box::new(decoder::staking_proxy_set_stake_pool_operator),
+        );
+        map.insert(
+            "staking_proxy_set_stake_pool_voter".to_string(),
+            Box::new(decoder::staking_proxy_set_stake_pool_voter),
+        );
+        map.insert(
+            "staking_proxy_set_staking_contract_operator".to_string(),
+            Box::new(decoder::staking_proxy_set_staking_contract_operator),
+        );
+        map.insert(
+            "staking_proxy_set_staking_contract_voter".to_string(),
+            Box::new(decoder::staking_proxy_set_staking_contract_voter),
+        );
+        map.insert(
+            "staking_proxy_set_vesting_contract_operator".to_string(),
+            Box::new(decoder::staking_proxy_set_vesting_contract_operator),
+        );
+        map.insert(
+            "staking_proxy_set_vesting_contract_voter".to_string(),
+            Box::new(decoder::staking_proxy_set_vesting_contract_voter),
+        );
+        map.insert(
+            "staking_proxy_set_voter".to_string(),
+            Box::new(decoder::staking_proxy_set_voter),
+        );
+        map.insert(
+            "transaction_fee_convert_to_aptos_fa_burn_ref".to_string(),
+            Box::new(decoder::transaction_fee_convert_to_aptos_fa_burn_ref),
+        );
+        map.insert(
+            "version_set_for_next_epoch".to_string(),
+            Box::new(decoder::version_set_for_next_epoch),
+        );
+        map.insert(
+            "version_set_version".to_string(),
+            Box::new(decoder::version_set_version),
+        );
+        map.insert(
+            "vesting_admin_withdraw".to_string(),
+            Box::new(decoder::vesting_admin_withdraw),
+        );
+        map.insert(
+            "vesting_distribute".to_string(),
+            Box::new(decoder::vesting_distribute),
+        );
+        map.insert(
+            "vesting_distribute_many".to_string(),
+            Box::new(decoder::vesting_distribute_many),
+        );
+        map.insert(
+            "vesting_reset_beneficiary".to_string(),
+            Box::new(decoder::vesting_reset_beneficiary),
+        );
+        map.insert(
+            "vesting_reset_lockup".to_string(),
+            Box::new(decoder::vesting_reset_lockup),
+        );
+        map.insert(
+            "vesting_set_beneficiary".to_string(),
+            Box::new(decoder::vesting_set_beneficiary),
+        );
+        map.insert(
+            "vesting_set_beneficiary_for_operator".to_string(),
+            Box::new(decoder::vesting_set_beneficiary_for_operator),
+        );
+        map.insert(
+            "vesting_set_beneficiary_resetter".to_string(),
+            Box::new(decoder::vesting_set_beneficiary_resetter),
+        );
+        map.insert(
+            "vesting_set_management_role".to_string(),
+            Box::new(decoder::vesting_set_management_role),
+        );
+        map.insert(
+            "vesting_terminate_vesting_contract".to_string(),
+            Box::new(decoder::vesting_terminate_vesting_contract),
+        );
+        map.insert(
+            "vesting_unlock_rewards".to_string(),
+            Box::new(decoder::vesting_unlock_rewards),
+        );
+        map.insert(
+            "vesting_unlock_rewards_many".to_string(),
+            Box::new(decoder::vesting_unlock_rewards_many),
+        );
+        map.insert(
+            "vesting_update_commission_percentage".to_string(),
+            Box::new(decoder::vesting_update_commission_percentage),
+        );
+        map.insert(
+            "vesting_update_operator".to_string(),
+            Box::new(decoder::vesting_update_operator),
+        );
+        map.insert(
+            "vesting_update_operator_with_same_commission".to_string(),
+            Box::new(decoder::vesting_update_operator_with_same_commission),
+        );
+        map.insert(
+            "vesting_update_voter".to_string(),
+            Box::new(decoder::vesting_update_voter),
+        );
+        map.insert("vesting_vest".to_string(), Box::new(decoder::vesting_vest));
+        map.insert(
+            "vesting_vest_many".to_string(),
+            Box::new(decoder::vesting_vest_many),
+        );
+        map
+    });
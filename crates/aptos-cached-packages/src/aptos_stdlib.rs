@@ -16,11 +16,20 @@
 // };
 // use move_core_types::{ident_str, language_storage::ModuleId};
 
+use anyhow::Result;
 use aptos_types::{
-    transaction::TransactionPayload,
+    account_config::{
+        AccountResource, CapabilityOfferProof, PackageMetadata, RotationKey, RotationProof,
+    },
+    chain_id::ChainId,
+    governance::{proposal_metadata_hash, ProposalScripts},
+    transaction::{EntryFunction, Multisig, MultisigTransactionPayload, TransactionPayload},
     utility_coin::{AptosCoinType, CoinType},
 };
-use move_core_types::account_address::AccountAddress;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, language_storage::ModuleId,
+    move_resource::MoveTypeTag,
+};
 
 use super::aptos_framework_sdk_builder::*;
 
@@ -28,6 +37,169 @@ pub fn aptos_coin_transfer(to: AccountAddress, amount: u64) -> TransactionPayloa
     coin_transfer(AptosCoinType::type_tag(), to, amount)
 }
 
+/// `0x1::coin::transfer` of the coin `C`
+pub fn coin_transfer_typed<C: CoinType>(to: AccountAddress, amount: u64) -> TransactionPayload {
+    coin_transfer(C::type_tag(), to, amount)
+}
+
+/// `0x1::aptos_account::transfer_coins` of the coin `C`
+pub fn aptos_account_transfer_coins_typed<C: CoinType>(
+    to: AccountAddress,
+    amount: u64,
+) -> TransactionPayload {
+    aptos_account_transfer_coins(C::type_tag(), to, amount)
+}
+
+/// `0x1::aptos_account::batch_transfer_coins` of the coin `C`
+pub fn aptos_account_batch_transfer_coins_typed<C: CoinType>(
+    recipients: Vec<AccountAddress>,
+    amounts: Vec<u64>,
+) -> TransactionPayload {
+    aptos_account_batch_transfer_coins(C::type_tag(), recipients, amounts)
+}
+
+/// `0x1::coin::migrate_to_fungible_store` of the coin `C`
+pub fn coin_migrate_to_fungible_store_typed<C: CoinType>() -> TransactionPayload {
+    coin_migrate_to_fungible_store(C::type_tag())
+}
+
+/// Rotates the authentication key of `originator`, whose current on-chain state is `account`,
+/// from `current_key` to `new_key`. Both keys sign the `RotationProofChallenge`, and the result
+/// is the complete `0x1::account::rotate_authentication_key` payload.
+pub fn account_rotate_authentication_key_signed(
+    originator: AccountAddress,
+    account: &AccountResource,
+    current_key: RotationKey,
+    new_key: RotationKey,
+) -> Result<TransactionPayload> {
+    let proof = RotationProof::new(originator, account, current_key, new_key)?;
+    Ok(account_rotate_authentication_key(
+        proof.from_scheme,
+        proof.from_public_key_bytes,
+        proof.to_scheme,
+        proof.to_public_key_bytes,
+        proof.cap_rotate_key,
+        proof.cap_update_table,
+    ))
+}
+
+/// Offers the signer capability of `source_address`, whose current on-chain state is `account`,
+/// to `recipient_address`. `key` signs the `SignerCapabilityOfferProofChallengeV2`.
+pub fn account_offer_signer_capability_signed(
+    source_address: AccountAddress,
+    account: &AccountResource,
+    key: RotationKey,
+    recipient_address: AccountAddress,
+) -> Result<TransactionPayload> {
+    let proof =
+        CapabilityOfferProof::signer_capability(source_address, account, key, recipient_address)?;
+    Ok(account_offer_signer_capability(
+        proof.signature,
+        proof.account_scheme,
+        proof.account_public_key_bytes,
+        proof.recipient_address,
+    ))
+}
+
+/// Offers the rotation capability of `source_address`, whose current on-chain state is
+/// `account`, to `recipient_address` on `chain_id`. `key` signs the
+/// `RotationCapabilityOfferProofChallengeV2`.
+pub fn account_offer_rotation_capability_signed(
+    chain_id: ChainId,
+    source_address: AccountAddress,
+    account: &AccountResource,
+    key: RotationKey,
+    recipient_address: AccountAddress,
+) -> Result<TransactionPayload> {
+    let proof = CapabilityOfferProof::rotation_capability(
+        chain_id,
+        source_address,
+        account,
+        key,
+        recipient_address,
+    )?;
+    Ok(account_offer_rotation_capability(
+        proof.signature,
+        proof.account_scheme,
+        proof.account_public_key_bytes,
+        proof.recipient_address,
+    ))
+}
+
+/// Proposes `payload` to `multisig_account`, storing only its hash on chain. The full payload
+/// has to be provided again when the transaction is executed.
+pub fn multisig_account_create_transaction_with_payload_hash(
+    multisig_account: AccountAddress,
+    payload: &MultisigTransactionPayload,
+) -> TransactionPayload {
+    multisig_account_create_transaction_with_hash(multisig_account, payload.payload_hash().to_vec())
+}
+
+/// Executes the next approved transaction of `multisig_address`. `payload` can be omitted if it
+/// was stored on chain when the transaction was created.
+pub fn multisig_execute(
+    multisig_address: AccountAddress,
+    payload: Option<MultisigTransactionPayload>,
+) -> TransactionPayload {
+    TransactionPayload::Multisig(Multisig::new(multisig_address, payload))
+}
+
+/// Creates a proposal resolved by `scripts`, backed by `stake_pool`. `metadata` is the content
+/// found at `metadata_location`, which the proposal commits to by its hash.
+pub fn aptos_governance_create_proposal_for_scripts(
+    stake_pool: AccountAddress,
+    scripts: &ProposalScripts,
+    metadata_location: &str,
+    metadata: &[u8],
+) -> TransactionPayload {
+    aptos_governance_create_proposal_v2(
+        stake_pool,
+        scripts.execution_hash().to_vec(),
+        metadata_location.as_bytes().to_vec(),
+        proposal_metadata_hash(metadata),
+        scripts.is_multi_step(),
+    )
+}
+
+/// `0x1::code::publish_package_txn` of the package described by `metadata`, whose modules are
+/// `code`, under the sender's account
+pub fn code_publish_package_txn_with_metadata(
+    metadata: &PackageMetadata,
+    code: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    code_publish_package_txn(metadata.to_bytes(), code)
+}
+
+/// `0x1::object_code_deployment::publish` of the package described by `metadata`. The package
+/// is deployed to a new object, whose address `create_object_code_deployment_address` predicts
+/// from the sender and the sequence number of the transaction.
+pub fn object_code_deployment_publish_with_metadata(
+    metadata: &PackageMetadata,
+    code: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    object_code_deployment_publish(metadata.to_bytes(), code)
+}
+
+/// `0x1::object_code_deployment::upgrade` of the package deployed to `code_object`
+pub fn object_code_deployment_upgrade_with_metadata(
+    metadata: &PackageMetadata,
+    code: Vec<Vec<u8>>,
+    code_object: AccountAddress,
+) -> TransactionPayload {
+    object_code_deployment_upgrade(metadata.to_bytes(), code, code_object)
+}
+
+/// `0x1::resource_account::create_resource_account_and_publish_package` of the package described
+/// by `metadata`. The package is published under the resource account at
+/// `create_resource_address(sender, seed)`.
+pub fn resource_account_create_resource_account_and_publish_package_with_metadata(
+    seed: Vec<u8>,
+    metadata: &PackageMetadata,
+    code: Vec<Vec<u8>>,
+) -> TransactionPayload {
+    resource_account_create_resource_account_and_publish_package(seed, metadata.to_bytes(), code)
+}
+
 // pub fn publish_module_source(module_name: &str, module_src: &str) -> TransactionPayload {
 //     let mut builder = PackageBuilder::new("tmp");
 //     builder.add_source(module_name, module_src);
@@ -44,46 +216,94 @@ pub fn aptos_coin_transfer(to: AccountAddress, amount: u64) -> TransactionPayloa
 //     code_publish_package_txn(metadata_serialized, code)
 // }
 
-// /// Temporary workaround as `Object<T>` as a function argument is not recognised
-// /// when auto generating move transaction payloads. Will address in separate PR.
-// pub fn object_code_deployment_upgrade(
-//     metadata_serialized: Vec<u8>,
-//     code: Vec<Vec<u8>>,
-//     code_object: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("object_code_deployment").to_owned(),
-//         ),
-//         ident_str!("upgrade").to_owned(),
-//         vec![],
-//         vec![
-//             bcs::to_bytes(&metadata_serialized).unwrap(),
-//             bcs::to_bytes(&code).unwrap(),
-//             bcs::to_bytes(&code_object).unwrap(),
-//         ],
-//     ))
-// }
+/// Temporary workaround as `Object<T>` as a function argument is not recognised
+/// when auto generating move transaction payloads. Will address in separate PR.
+pub fn object_code_deployment_upgrade(
+    metadata_serialized: Vec<u8>,
+    code: Vec<Vec<u8>>,
+    code_object: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("object_code_deployment").to_owned(),
+        ),
+        ident_str!("upgrade").to_owned(),
+        vec![],
+        vec![
+            bcs::to_bytes(&metadata_serialized).unwrap(),
+            bcs::to_bytes(&code).unwrap(),
+            bcs::to_bytes(&code_object).unwrap(),
+        ],
+    ))
+}
 
-// /// Temporary workaround as `Object<T>` as a function argument is not recognised
-// /// when auto generating move transaction payloads. Will address in separate PR.
-// pub fn object_code_deployment_freeze_code_object(
-//     code_object: AccountAddress,
-// ) -> TransactionPayload {
-//     TransactionPayload::EntryFunction(EntryFunction::new(
-//         ModuleId::new(
-//             AccountAddress::new([
-//                 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-//                 0, 0, 0, 1,
-//             ]),
-//             ident_str!("object_code_deployment").to_owned(),
-//         ),
-//         ident_str!("freeze_code_object").to_owned(),
-//         vec![],
-//         vec![bcs::to_bytes(&code_object).unwrap()],
-//     ))
-// }
+/// Temporary workaround as `Object<T>` as a function argument is not recognised
+/// when auto generating move transaction payloads. Will address in separate PR.
+pub fn object_code_deployment_freeze_code_object(
+    code_object: AccountAddress,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("object_code_deployment").to_owned(),
+        ),
+        ident_str!("freeze_code_object").to_owned(),
+        vec![],
+        vec![bcs::to_bytes(&code_object).unwrap()],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::{identifier::IdentStr, move_resource::MoveStructType};
+
+    struct UsdCoin;
+
+    impl MoveStructType for UsdCoin {
+        const ADDRESS: AccountAddress = AccountAddress::TWO;
+        const MODULE_NAME: &'static IdentStr = ident_str!("usd");
+        const STRUCT_NAME: &'static IdentStr = ident_str!("Usd");
+    }
+
+    impl CoinType for UsdCoin {
+        fn coin_info_address() -> AccountAddress {
+            AccountAddress::TWO
+        }
+    }
+
+    #[test]
+    fn test_typed_coin_builders() {
+        let coin_type = UsdCoin::type_tag();
+        assert_eq!(coin_type.to_string(), "0x2::usd::Usd");
+        let to = AccountAddress::THREE;
+
+        assert_eq!(
+            coin_transfer_typed::<UsdCoin>(to, 5),
+            coin_transfer(coin_type.clone(), to, 5)
+        );
+        assert_eq!(
+            aptos_account_transfer_coins_typed::<UsdCoin>(to, 5),
+            aptos_account_transfer_coins(coin_type.clone(), to, 5)
+        );
+        assert_eq!(
+            aptos_account_batch_transfer_coins_typed::<UsdCoin>(vec![to], vec![5]),
+            aptos_account_batch_transfer_coins(coin_type.clone(), vec![to], vec![5])
+        );
+        assert_eq!(
+            coin_migrate_to_fungible_store_typed::<UsdCoin>(),
+            coin_migrate_to_fungible_store(coin_type)
+        );
+        assert_eq!(
+            aptos_coin_transfer(to, 5),
+            coin_transfer_typed::<AptosCoinType>(to, 5)
+        );
+    }
+}
@@ -34,8 +34,8 @@ type Bytes = Vec<u8>;
 /// }
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "fuzzing", derive(proptest_derive::Arbitrary))]
-#[cfg_attr(feature = "fuzzing", proptest(no_params))]
+// #[cfg_attr(feature = "fuzzing", derive(proptest_derive::Arbitrary))]
+// #[cfg_attr(feature = "fuzzing", proptest(no_params))]
 pub enum EntryFunctionCall {
     /// Create a new collection
     AptosTokenCreateCollection {
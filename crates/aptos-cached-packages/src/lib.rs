@@ -6,5 +6,6 @@
 
 pub mod aptos_framework_sdk_builder;
 pub mod aptos_stdlib;
-// pub mod aptos_token_objects_sdk_builder;
-// pub mod aptos_token_sdk_builder;
+pub mod aptos_token_objects_sdk_builder;
+pub mod aptos_token_sdk_builder;
+pub mod preview;
@@ -0,0 +1,755 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Human readable previews of transactions ("clear signing"), to show or log what a transaction
+//! does before it is signed, e.g.
+//! `Transfer 1.5 APT to 0xabc (expires 2024-01-01 12:00:00 UTC, max fee 0.002 APT)`.
+
+use crate::{
+    aptos_framework_sdk_builder as framework, aptos_token_objects_sdk_builder as token_objects,
+    aptos_token_sdk_builder as token,
+};
+use anyhow::Result;
+use aptos_types::{
+    account_config::{CoinInfoResource, TokenDataId, TokenId},
+    chain_id::ChainId,
+    transaction::{
+        EntryFunction, Multisig, MultisigTransactionPayload, RawTransaction, ReplayProtector,
+        TransactionExecutable, TransactionPayload, TransactionPayloadInner,
+    },
+    utility_coin::{AptosCoinType, CoinType},
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    move_resource::MoveTypeTag,
+};
+use std::{collections::BTreeMap, fmt};
+
+/// Arguments longer than this are shortened in the text form.
+const MAX_DISPLAYED_ARGUMENT_BYTES: usize = 32;
+
+/// The asset an amount is denominated in
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Asset {
+    /// A coin, identified by its coin type
+    Coin(TypeTag),
+    /// A fungible asset, identified by the address of its metadata object
+    FungibleAsset(AccountAddress),
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Asset::Coin(coin_type) => write!(f, "{}", coin_type),
+            Asset::FungibleAsset(metadata) => {
+                write!(f, "fungible asset {}", metadata.to_hex_literal())
+            },
+        }
+    }
+}
+
+/// How amounts of an asset are shown
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetInfo {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl AssetInfo {
+    pub fn new(symbol: impl Into<String>, decimals: u8) -> Self {
+        Self {
+            symbol: symbol.into(),
+            decimals,
+        }
+    }
+}
+
+/// An amount of an asset, in its smallest unit. It is shown with the decimals and symbol of the
+/// asset when they are known, and in raw units otherwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Amount {
+    pub asset: Asset,
+    pub value: u128,
+    pub info: Option<AssetInfo>,
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.info {
+            Some(info) => write!(
+                f,
+                "{} {}",
+                format_units(self.value, info.decimals),
+                info.symbol
+            ),
+            None => write!(f, "{} units of {}", self.value, self.asset),
+        }
+    }
+}
+
+/// A call of an entry function, shown as `module::function<type_arguments>(arguments)` with the
+/// arguments as BCS bytes in hex
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCall {
+    pub module: ModuleId,
+    pub function: Identifier,
+    pub type_arguments: Vec<TypeTag>,
+    pub arguments: Vec<Vec<u8>>,
+}
+
+impl From<&EntryFunction> for FunctionCall {
+    fn from(entry_function: &EntryFunction) -> Self {
+        Self {
+            module: entry_function.module().clone(),
+            function: entry_function.function().to_owned(),
+            type_arguments: entry_function.ty_args().to_vec(),
+            arguments: entry_function.args().to_vec(),
+        }
+    }
+}
+
+impl fmt::Display for FunctionCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.module.short_str_lossless(), self.function)?;
+        if !self.type_arguments.is_empty() {
+            let type_arguments = self
+                .type_arguments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            write!(f, "<{}>", type_arguments.join(", "))?;
+        }
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|arg| format_bytes(arg))
+            .collect::<Vec<_>>();
+        write!(f, "({})", arguments.join(", "))
+    }
+}
+
+/// A call decoded by one of the framework or token builders
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedCall {
+    Framework(framework::EntryFunctionCall),
+    Token(token::EntryFunctionCall),
+    TokenObjects(token_objects::EntryFunctionCall),
+}
+
+/// What a transaction does
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Transfer of `amount` to `to`
+    Transfer { to: AccountAddress, amount: Amount },
+    /// Transfers of the same asset to several recipients
+    BatchTransfer {
+        transfers: Vec<(AccountAddress, Amount)>,
+    },
+    /// Transfer of the object at `object` to `to`
+    ObjectTransfer {
+        object: AccountAddress,
+        to: AccountAddress,
+    },
+    /// Transfer of `amount` of a token v1. The receiver of a direct transfer is a secondary
+    /// signer of the transaction, and so is not known from the payload.
+    TokenTransfer {
+        token: TokenId,
+        amount: u64,
+        to: Option<AccountAddress>,
+    },
+    /// Offer of `amount` of a token v1 to `receiver`, who has to claim it
+    TokenOffer {
+        token: TokenId,
+        amount: u64,
+        receiver: AccountAddress,
+    },
+    /// Mint of a digital asset token, owned by the sender or soul bound to `to`
+    TokenMint {
+        collection: String,
+        name: String,
+        to: Option<AccountAddress>,
+    },
+    /// Any other entry function call, with its arguments decoded when the function is known
+    Call {
+        call: FunctionCall,
+        decoded: Option<DecodedCall>,
+    },
+    /// Execution of a script
+    Script {
+        code_size: usize,
+        type_arguments: Vec<TypeTag>,
+        num_arguments: usize,
+    },
+    /// Execution of a multisig transaction whose payload is stored on chain
+    StoredMultisigTransaction,
+    /// The deprecated module bundle payload, which can no longer be executed
+    ModuleBundle,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::Transfer { to, amount } => {
+                write!(f, "Transfer {} to {}", amount, to.to_hex_literal())
+            },
+            Action::BatchTransfer { transfers } => {
+                let transfers = transfers
+                    .iter()
+                    .map(|(to, amount)| format!("{} to {}", amount, to.to_hex_literal()))
+                    .collect::<Vec<_>>();
+                write!(f, "Transfer {}", transfers.join(", "))
+            },
+            Action::ObjectTransfer { object, to } => write!(
+                f,
+                "Transfer object {} to {}",
+                object.to_hex_literal(),
+                to.to_hex_literal()
+            ),
+            Action::TokenTransfer { token, amount, to } => {
+                write!(f, "Transfer {} of token {}", amount, format_token(token))?;
+                match to {
+                    Some(to) => write!(f, " to {}", to.to_hex_literal()),
+                    None => write!(f, " to the secondary signer"),
+                }
+            },
+            Action::TokenOffer {
+                token,
+                amount,
+                receiver,
+            } => write!(
+                f,
+                "Offer {} of token {} to {}",
+                amount,
+                format_token(token),
+                receiver.to_hex_literal()
+            ),
+            Action::TokenMint {
+                collection,
+                name,
+                to,
+            } => {
+                write!(
+                    f,
+                    "Mint token \"{}\" of collection \"{}\"",
+                    name, collection
+                )?;
+                match to {
+                    Some(to) => write!(f, " soul bound to {}", to.to_hex_literal()),
+                    None => Ok(()),
+                }
+            },
+            Action::Call { call, .. } => write!(f, "Call {}", call),
+            Action::Script {
+                code_size,
+                type_arguments,
+                num_arguments,
+            } => write!(
+                f,
+                "Run a script of {} bytes with {} type arguments and {} arguments",
+                code_size,
+                type_arguments.len(),
+                num_arguments
+            ),
+            Action::StoredMultisigTransaction => {
+                write!(f, "Execute the stored multisig transaction")
+            },
+            Action::ModuleBundle => write!(f, "Publish a deprecated module bundle"),
+        }
+    }
+}
+
+/// The preview of a transaction. Its `Display` implementation gives the text form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionPreview {
+    pub sender: AccountAddress,
+    /// The multisig account the payload is executed as, if any
+    pub multisig_address: Option<AccountAddress>,
+    pub chain_id: ChainId,
+    pub replay_protector: ReplayProtector,
+    pub expiration_timestamp_secs: u64,
+    /// The highest gas fee the transaction can be charged, `max_gas_amount * gas_unit_price`
+    pub max_fee: Amount,
+    pub action: Action,
+}
+
+impl fmt::Display for TransactionPreview {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.action)?;
+        if let Some(multisig_address) = self.multisig_address {
+            write!(
+                f,
+                " as multisig account {}",
+                multisig_address.to_hex_literal()
+            )?;
+        }
+        write!(
+            f,
+            " (expires {}, max fee {})",
+            format_timestamp(self.expiration_timestamp_secs),
+            self.max_fee
+        )
+    }
+}
+
+/// Renders transaction previews, with the symbols and decimals of the assets it knows about.
+/// APT is always known, as a coin and as a fungible asset.
+#[derive(Clone, Debug)]
+pub struct TransactionPreviewer {
+    coins: BTreeMap<TypeTag, AssetInfo>,
+    fungible_assets: BTreeMap<AccountAddress, AssetInfo>,
+}
+
+impl Default for TransactionPreviewer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransactionPreviewer {
+    pub fn new() -> Self {
+        let apt = AssetInfo::new("APT", 8);
+        Self {
+            coins: BTreeMap::from([(AptosCoinType::type_tag(), apt.clone())]),
+            fungible_assets: BTreeMap::from([(AccountAddress::TEN, apt)]),
+        }
+    }
+
+    /// Shows amounts of the coin `C` with the symbol and decimals of its `CoinInfo`
+    pub fn add_coin<C: CoinType>(&mut self, coin_info: &CoinInfoResource<C>) -> Result<()> {
+        let info = AssetInfo::new(coin_info.symbol()?, coin_info.decimals());
+        self.coins.insert(C::type_tag(), info);
+        Ok(())
+    }
+
+    /// Shows amounts of the coin of type `coin_type` with `info`
+    pub fn add_coin_info(&mut self, coin_type: TypeTag, info: AssetInfo) {
+        self.coins.insert(coin_type, info);
+    }
+
+    /// Shows amounts of the fungible asset whose metadata is at `metadata` with `info`
+    pub fn add_fungible_asset(&mut self, metadata: AccountAddress, info: AssetInfo) {
+        self.fungible_assets.insert(metadata, info);
+    }
+
+    pub fn preview(&self, txn: &RawTransaction) -> TransactionPreview {
+        let max_fee = txn.max_gas_amount() as u128 * txn.gas_unit_price() as u128;
+        TransactionPreview {
+            sender: txn.sender(),
+            multisig_address: txn.payload().multisig_address(),
+            chain_id: txn.chain_id(),
+            replay_protector: txn.replay_protector(),
+            expiration_timestamp_secs: txn.expiration_timestamp_secs(),
+            max_fee: self.amount(Asset::Coin(AptosCoinType::type_tag()), max_fee),
+            action: self.action(txn.payload()),
+        }
+    }
+
+    fn amount(&self, asset: Asset, value: impl Into<u128>) -> Amount {
+        let info = match &asset {
+            Asset::Coin(coin_type) => self.coins.get(coin_type),
+            Asset::FungibleAsset(metadata) => self.fungible_assets.get(metadata),
+        };
+        Amount {
+            info: info.cloned(),
+            asset,
+            value: value.into(),
+        }
+    }
+
+    fn action(&self, payload: &TransactionPayload) -> Action {
+        let entry_function = match payload {
+            TransactionPayload::EntryFunction(entry_function)
+            | TransactionPayload::Multisig(Multisig {
+                transaction_payload: Some(MultisigTransactionPayload::EntryFunction(entry_function)),
+                ..
+            })
+            | TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::EntryFunction(entry_function),
+                ..
+            }) => entry_function,
+            TransactionPayload::Script(script)
+            | TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::Script(script),
+                ..
+            }) => {
+                return Action::Script {
+                    code_size: script.code().len(),
+                    type_arguments: script.ty_args().to_vec(),
+                    num_arguments: script.args().len(),
+                }
+            },
+            TransactionPayload::Multisig(Multisig {
+                transaction_payload: None,
+                ..
+            })
+            | TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::Empty,
+                ..
+            }) => return Action::StoredMultisigTransaction,
+            TransactionPayload::ModuleBundle(_) => return Action::ModuleBundle,
+        };
+
+        let decoded = decode(entry_function);
+        decoded
+            .as_ref()
+            .and_then(|decoded| self.decoded_action(decoded))
+            .or_else(|| self.fungible_asset_action(entry_function))
+            .unwrap_or_else(|| Action::Call {
+                call: FunctionCall::from(entry_function),
+                decoded,
+            })
+    }
+
+    fn decoded_action(&self, decoded: &DecodedCall) -> Option<Action> {
+        use framework::EntryFunctionCall as F;
+        use token::EntryFunctionCall as T;
+        use token_objects::EntryFunctionCall as O;
+
+        let apt = || Asset::Coin(AptosCoinType::type_tag());
+        Some(match decoded.clone() {
+            DecodedCall::Framework(
+                F::CoinTransfer {
+                    coin_type,
+                    to,
+                    amount,
+                }
+                | F::AptosAccountTransferCoins {
+                    coin_type,
+                    to,
+                    amount,
+                },
+            ) => Action::Transfer {
+                to,
+                amount: self.amount(Asset::Coin(coin_type), amount),
+            },
+            DecodedCall::Framework(F::AptosAccountTransfer { to, amount }) => Action::Transfer {
+                to,
+                amount: self.amount(apt(), amount),
+            },
+            DecodedCall::Framework(F::AptosAccountFungibleTransferOnly { to, amount }) => {
+                Action::Transfer {
+                    to,
+                    amount: self.amount(Asset::FungibleAsset(AccountAddress::TEN), amount),
+                }
+            },
+            DecodedCall::Framework(F::AptosAccountBatchTransfer {
+                recipients,
+                amounts,
+            }) => self.batch_transfer(apt(), recipients, amounts)?,
+            DecodedCall::Framework(F::AptosAccountBatchTransferCoins {
+                coin_type,
+                recipients,
+                amounts,
+            }) => self.batch_transfer(Asset::Coin(coin_type), recipients, amounts)?,
+            DecodedCall::Framework(F::ObjectTransferCall { object, to }) => {
+                Action::ObjectTransfer { object, to }
+            },
+            DecodedCall::Token(T::TokenDirectTransferScript {
+                creators_address,
+                collection,
+                name,
+                property_version,
+                amount,
+            }) => Action::TokenTransfer {
+                token: token_id(creators_address, collection, name, property_version),
+                amount,
+                to: None,
+            },
+            DecodedCall::Token(T::TokenTransferWithOptIn {
+                creator,
+                collection_name,
+                token_name,
+                token_property_version,
+                to,
+                amount,
+            }) => Action::TokenTransfer {
+                token: token_id(creator, collection_name, token_name, token_property_version),
+                amount,
+                to: Some(to),
+            },
+            DecodedCall::Token(T::TokenTransfersOfferScript {
+                receiver,
+                creator,
+                collection,
+                name,
+                property_version,
+                amount,
+            }) => Action::TokenOffer {
+                token: token_id(creator, collection, name, property_version),
+                amount,
+                receiver,
+            },
+            DecodedCall::TokenObjects(O::AptosTokenMint {
+                collection, name, ..
+            }) => Action::TokenMint {
+                collection: lossy_string(collection),
+                name: lossy_string(name),
+                to: None,
+            },
+            DecodedCall::TokenObjects(O::AptosTokenMintSoulBound {
+                collection,
+                name,
+                soul_bound_to,
+                ..
+            }) => Action::TokenMint {
+                collection: lossy_string(collection),
+                name: lossy_string(name),
+                to: Some(soul_bound_to),
+            },
+            _ => return None,
+        })
+    }
+
+    /// Transfers of fungible assets and objects, which take `Object<T>` arguments that the
+    /// framework builders do not cover
+    fn fungible_asset_action(&self, entry_function: &EntryFunction) -> Option<Action> {
+        if entry_function.module().address() != &AccountAddress::ONE {
+            return None;
+        }
+        let args = entry_function.args();
+        let arg = |i: usize| args.get(i).map(Vec::as_slice);
+        let module = entry_function.module().name().as_str();
+        let function = entry_function.function().as_str();
+        match (module, function, args.len()) {
+            ("primary_fungible_store", "transfer", 3)
+            | ("aptos_account", "transfer_fungible_assets", 3) => {
+                let metadata = bcs::from_bytes(arg(0)?).ok()?;
+                Some(Action::Transfer {
+                    to: bcs::from_bytes(arg(1)?).ok()?,
+                    amount: self.amount(
+                        Asset::FungibleAsset(metadata),
+                        bcs::from_bytes::<u64>(arg(2)?).ok()?,
+                    ),
+                })
+            },
+            ("object", "transfer", 2) => Some(Action::ObjectTransfer {
+                object: bcs::from_bytes(arg(0)?).ok()?,
+                to: bcs::from_bytes(arg(1)?).ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    fn batch_transfer(
+        &self,
+        asset: Asset,
+        recipients: Vec<AccountAddress>,
+        amounts: Vec<u64>,
+    ) -> Option<Action> {
+        if recipients.len() != amounts.len() {
+            return None;
+        }
+        let transfers = recipients
+            .into_iter()
+            .zip(amounts)
+            .map(|(to, amount)| (to, self.amount(asset.clone(), amount)))
+            .collect();
+        Some(Action::BatchTransfer { transfers })
+    }
+}
+
+/// Decodes calls of the framework and token packages, only at their own addresses as the
+/// builders match functions by module and function name.
+fn decode(entry_function: &EntryFunction) -> Option<DecodedCall> {
+    let address = *entry_function.module().address();
+    let payload = TransactionPayload::EntryFunction(entry_function.clone());
+    if address == AccountAddress::ONE {
+        framework::EntryFunctionCall::decode(&payload).map(DecodedCall::Framework)
+    } else if address == AccountAddress::THREE {
+        token::EntryFunctionCall::decode(&payload).map(DecodedCall::Token)
+    } else if address == AccountAddress::FOUR {
+        token_objects::EntryFunctionCall::decode(&payload).map(DecodedCall::TokenObjects)
+    } else {
+        None
+    }
+}
+
+fn token_id(
+    creator: AccountAddress,
+    collection: Vec<u8>,
+    name: Vec<u8>,
+    property_version: u64,
+) -> TokenId {
+    TokenId::new(
+        TokenDataId::new(creator, lossy_string(collection), lossy_string(name)),
+        property_version,
+    )
+}
+
+fn lossy_string(bytes: Vec<u8>) -> String {
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn format_token(token: &TokenId) -> String {
+    let data_id = token.token_data_id();
+    format!(
+        "\"{}\" of collection \"{}\" by {}",
+        data_id.name(),
+        data_id.collection(),
+        data_id.creator().to_hex_literal()
+    )
+}
+
+/// Formats `value` smallest units of an asset with `decimals` decimals, e.g. 150000000 with 8
+/// decimals as 1.5
+fn format_units(value: u128, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", value, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+/// Formats seconds since the Unix Epoch as a UTC date and time
+fn format_timestamp(secs: u64) -> String {
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let (days, secs_of_day) = (secs / 86400, secs % 86400);
+    let z = days + 719468;
+    let era = z / 146097;
+    let doe = z % 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+fn format_bytes(bytes: &[u8]) -> String {
+    if bytes.len() > MAX_DISPLAYED_ARGUMENT_BYTES {
+        format!(
+            "0x{}… ({} bytes)",
+            hex::encode(&bytes[..MAX_DISPLAYED_ARGUMENT_BYTES]),
+            bytes.len()
+        )
+    } else {
+        format!("0x{}", hex::encode(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aptos_stdlib;
+    use move_core_types::{ident_str, language_storage::StructTag};
+    use std::str::FromStr;
+
+    fn raw_transaction(payload: TransactionPayload) -> RawTransaction {
+        RawTransaction::new(
+            AccountAddress::ONE,
+            0,
+            payload,
+            2_000,
+            100,
+            1_700_000_000,
+            ChainId::mainnet(),
+        )
+    }
+
+    #[test]
+    fn test_preview_coin_transfer() {
+        let to = AccountAddress::from_str("0xabc").unwrap();
+        let txn = raw_transaction(aptos_stdlib::aptos_coin_transfer(to, 150_000_000));
+        let preview = TransactionPreviewer::new().preview(&txn);
+        assert_eq!(
+            preview.action,
+            Action::Transfer {
+                to,
+                amount: Amount {
+                    asset: Asset::Coin(AptosCoinType::type_tag()),
+                    value: 150_000_000,
+                    info: Some(AssetInfo::new("APT", 8)),
+                },
+            }
+        );
+        assert_eq!(
+            preview.to_string(),
+            "Transfer 1.5 APT to 0xabc (expires 2023-11-14 22:13:20 UTC, max fee 0.002 APT)"
+        );
+    }
+
+    #[test]
+    fn test_preview_unknown_coin_and_function() {
+        let coin_type = TypeTag::from_str("0xcafe::usd::USD").unwrap();
+        let to = AccountAddress::TWO;
+        let payload = framework::coin_transfer(coin_type.clone(), to, 1_234_500);
+
+        let mut previewer = TransactionPreviewer::new();
+        assert_eq!(
+            previewer
+                .preview(&raw_transaction(payload.clone()))
+                .action
+                .to_string(),
+            "Transfer 1234500 units of 0xcafe::usd::USD to 0x2"
+        );
+        previewer.add_coin_info(coin_type, AssetInfo::new("USD", 6));
+        assert_eq!(
+            previewer
+                .preview(&raw_transaction(payload))
+                .action
+                .to_string(),
+            "Transfer 1.2345 USD to 0x2"
+        );
+
+        let payload = TransactionPayload::EntryFunction(EntryFunction::new(
+            ModuleId::new(AccountAddress::TWO, ident_str!("coin").to_owned()),
+            ident_str!("transfer").to_owned(),
+            vec![TypeTag::Struct(Box::new(
+                StructTag::from_str("0x2::usd::USD").unwrap(),
+            ))],
+            vec![bcs::to_bytes(&to).unwrap(), bcs::to_bytes(&7u64).unwrap()],
+        ));
+        let action = previewer.preview(&raw_transaction(payload)).action;
+        assert!(matches!(action, Action::Call { decoded: None, .. }));
+        assert_eq!(
+            action.to_string(),
+            "Call 0x2::coin::transfer<0x2::usd::USD>(0x0000000000000000000000000000000000000000000000000000000000000002, 0x0700000000000000)"
+        );
+    }
+
+    #[test]
+    fn test_format_units() {
+        assert_eq!(format_units(0, 8), "0");
+        assert_eq!(format_units(1, 8), "0.00000001");
+        assert_eq!(format_units(100_000_000, 8), "1");
+        assert_eq!(format_units(123, 0), "123");
+        assert_eq!(
+            format_units(u64::MAX.into(), 30),
+            "0.000000000018446744073709551615"
+        );
+        assert_eq!(
+            format_units(u128::MAX, 30),
+            "340282366.920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn test_preview_max_fee_does_not_overflow() {
+        let txn = RawTransaction::new(
+            AccountAddress::ONE,
+            0,
+            aptos_stdlib::aptos_coin_transfer(AccountAddress::TWO, 1),
+            u64::MAX,
+            u64::MAX,
+            1_700_000_000,
+            ChainId::mainnet(),
+        );
+        let preview = TransactionPreviewer::new().preview(&txn);
+        assert_eq!(preview.max_fee.value, u64::MAX as u128 * u64::MAX as u128);
+    }
+}
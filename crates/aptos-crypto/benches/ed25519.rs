@@ -0,0 +1,48 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compares verifying N Ed25519 signatures one at a time with verifying them as one batch.
+
+use aptos_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature},
+    traits::{Signature, SigningKey},
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::convert::TryFrom;
+
+fn signed_messages(n: usize) -> Vec<(Vec<u8>, Ed25519PublicKey, Ed25519Signature)> {
+    (0..n)
+        .map(|i| {
+            let private_key = Ed25519PrivateKey::try_from(&[i as u8 + 1; 32][..]).unwrap();
+            let message = format!("message #{}", i).into_bytes();
+            let signature = private_key.sign_arbitrary_message(&message);
+            (message, Ed25519PublicKey::from(&private_key), signature)
+        })
+        .collect()
+}
+
+fn verify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ed25519_verify");
+    for n in [1, 16, 64, 256] {
+        let items = signed_messages(n);
+        let triples: Vec<_> = items
+            .iter()
+            .map(|(message, key, signature)| (&message[..], key, signature))
+            .collect();
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("single", n), &triples, |b, triples| {
+            b.iter(|| {
+                for (message, key, signature) in triples {
+                    signature.verify_arbitrary_msg(message, key).unwrap();
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", n), &triples, |b, triples| {
+            b.iter(|| Ed25519Signature::batch_verify_arbitrary_msgs(triples).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, verify);
+criterion_main!(benches);
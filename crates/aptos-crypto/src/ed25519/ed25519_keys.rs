@@ -176,20 +176,19 @@ impl SigningKey for Ed25519PrivateKey {
         ))
     }
 
-    // #[cfg(any(test, feature = "fuzzing"))]
-    // fn sign_arbitrary_message(&self, message: &[u8]) -> Ed25519Signature {
-    //     Ed25519PrivateKey::sign_arbitrary_message(self, message)
-    // }
+    fn sign_arbitrary_message(&self, message: &[u8]) -> Ed25519Signature {
+        Ed25519PrivateKey::sign_arbitrary_message(self, message)
+    }
 }
 
-// impl Uniform for Ed25519PrivateKey {
-//     fn generate<R>(rng: &mut R) -> Self
-//     where
-//         R: ::rand::RngCore + ::rand::CryptoRng + ::rand_core::CryptoRng + ::rand_core::RngCore,
-//     {
-//         Ed25519PrivateKey(ed25519_dalek::SecretKey::generate(rng))
-//     }
-// }
+impl Uniform for Ed25519PrivateKey {
+    fn generate<R>(rng: &mut R) -> Self
+    where
+        R: ::rand_core::RngCore + ::rand_core::CryptoRng,
+    {
+        Ed25519PrivateKey(ed25519_dalek::SecretKey::generate(rng))
+    }
+}
 
 impl PartialEq<Self> for Ed25519PrivateKey {
     fn eq(&self, other: &Self) -> bool {
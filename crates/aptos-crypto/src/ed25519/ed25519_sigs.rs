@@ -11,6 +11,12 @@ use crate::{
 use anyhow::{anyhow, Result};
 use aptos_crypto_derive::{DeserializeKey, SerializeKey};
 use core::convert::TryFrom;
+use curve25519_dalek::{
+    constants::{BASEPOINT_ORDER, EIGHT_TORSION},
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    traits::{IsIdentity, VartimeMultiscalarMul},
+};
+use once_cell::sync::Lazy;
 use serde::Serialize;
 use std::{cmp::Ordering, fmt};
 
@@ -47,11 +53,11 @@ impl Ed25519Signature {
         }
     }
 
-    // /// return an all-zero signature (for test only)
-    // #[cfg(any(test, feature = "fuzzing"))]
-    // pub fn dummy_signature() -> Self {
-    //     Self::from_bytes_unchecked(&[0u8; Self::LENGTH]).unwrap()
-    // }
+    /// return an all-zero signature, e.g. to fill in the signatures of a transaction that is
+    /// only simulated
+    pub fn dummy_signature() -> Self {
+        Self::from_bytes_unchecked(&[0u8; Self::LENGTH]).unwrap()
+    }
 
     /// Check for correct size and third-party based signature malleability issues.
     /// This method is required to ensure that given a valid signature for some message under some
@@ -92,6 +98,82 @@ impl Ed25519Signature {
         // As this stage S == L which implies a non canonical S.
         false
     }
+
+    /// Verifies a batch of `(message, public_key, signature)` triples at once, where each message
+    /// is an arbitrary byte string (e.g., the output of [`signing_message`]). Accepts exactly the
+    /// batches in which every signature passes [`Signature::verify_arbitrary_msg`].
+    pub fn batch_verify_arbitrary_msgs(
+        triples: &[(&[u8], &Ed25519PublicKey, &Ed25519Signature)],
+    ) -> Result<()> {
+        let mut batch = Vec::with_capacity(triples.len());
+        for triple @ (message, public_key, signature) in triples {
+            let signature_bytes = signature.to_bytes();
+            Ed25519Signature::check_s_malleability(&signature_bytes)?;
+            let mut r_bytes = [0u8; 32];
+            r_bytes.copy_from_slice(&signature_bytes[..32]);
+            let public_key_bytes = public_key.to_bytes();
+            if has_small_order(&r_bytes) || has_small_order(&public_key_bytes) {
+                return Err(anyhow!("{:?}", CryptoMaterialError::SmallSubgroupError));
+            }
+            // The batch equation does not see a torsion component in R or in the public key, so
+            // such signatures are verified on their own.
+            if is_torsion_free(&r_bytes) && is_torsion_free(&public_key_bytes) {
+                batch.push(*triple);
+            } else {
+                signature.verify_arbitrary_msg(message, public_key)?;
+            }
+        }
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let messages: Vec<&[u8]> = batch.iter().map(|(message, _, _)| *message).collect();
+        let dalek_public_keys: Vec<_> = batch.iter().map(|(_, key, _)| key.0).collect();
+        let dalek_signatures: Vec<_> = batch.iter().map(|(_, _, sig)| sig.0).collect();
+        ed25519_dalek::verify_batch(&messages[..], &dalek_signatures[..], &dalek_public_keys[..])
+            .map_err(|e| anyhow!("{}", e))
+    }
+}
+
+/// The encodings, with the sign bit cleared, of the y-coordinates of all points of small order:
+/// the canonical encodings of the y-coordinates of the eight torsion points, plus the
+/// non-canonical encodings `p` and `p + 1` of 0 and 1.
+static SMALL_ORDER_ENCODINGS: Lazy<Vec<[u8; 32]>> = Lazy::new(|| {
+    let mut encodings: Vec<[u8; 32]> = EIGHT_TORSION
+        .iter()
+        .map(|point| {
+            let mut bytes = point.compress().to_bytes();
+            bytes[31] &= 0x7f;
+            bytes
+        })
+        .collect();
+    let mut p = [0xff; 32];
+    p[0] = 0xed;
+    p[31] = 0x7f;
+    let mut p_plus_one = p;
+    p_plus_one[0] = 0xee;
+    encodings.extend([p, p_plus_one]);
+    encodings.sort_unstable();
+    encodings.dedup();
+    encodings
+});
+
+/// Returns true if the compressed point `bytes` decompresses to a point of small order. This only
+/// compares encodings, so it is much cheaper than decompressing the point.
+fn has_small_order(bytes: &[u8; 32]) -> bool {
+    let mut masked = *bytes;
+    masked[31] &= 0x7f;
+    SMALL_ORDER_ENCODINGS.contains(&masked)
+}
+
+/// Returns true if the compressed point `bytes` decompresses to a point in the prime-order
+/// subgroup, i.e., one without a torsion component.
+fn is_torsion_free(bytes: &[u8; 32]) -> bool {
+    CompressedEdwardsY(*bytes)
+        .decompress()
+        .is_some_and(|point| {
+            EdwardsPoint::vartime_multiscalar_mul([BASEPOINT_ORDER], [point]).is_identity()
+        })
 }
 
 //////////////////////
@@ -142,6 +224,22 @@ impl Signature for Ed25519Signature {
     fn to_bytes(&self) -> Vec<u8> {
         self.0.to_bytes().to_vec()
     }
+
+    /// Batch signature verification as described in the original EdDSA article
+    /// by Bernstein et al. "High-speed high-security signatures". This works for signatures on
+    /// the same message; see [`Ed25519Signature::batch_verify_arbitrary_msgs`] for signatures on
+    /// distinct messages.
+    fn batch_verify<T: CryptoHash + Serialize>(
+        message: &T,
+        keys_and_signatures: Vec<(Self::VerifyingKeyMaterial, Self)>,
+    ) -> Result<()> {
+        let message_bytes = signing_message(message)?;
+        let triples: Vec<_> = keys_and_signatures
+            .iter()
+            .map(|(key, signature)| (&message_bytes[..], key, signature))
+            .collect();
+        Ed25519Signature::batch_verify_arbitrary_msgs(&triples)
+    }
 }
 
 impl Length for Ed25519Signature {
@@ -195,3 +293,224 @@ impl fmt::Debug for Ed25519Signature {
         write!(f, "Ed25519Signature({})", self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ed25519::{ED25519_PRIVATE_KEY_LENGTH, ED25519_PUBLIC_KEY_LENGTH};
+    use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, scalar::Scalar};
+    use sha2::{Digest, Sha512};
+
+    fn signed_messages(n: usize) -> Vec<(Vec<u8>, Ed25519PublicKey, Ed25519Signature)> {
+        (0..n)
+            .map(|i| {
+                let mut seed = [0u8; ED25519_PRIVATE_KEY_LENGTH];
+                seed[..8].copy_from_slice(&(i as u64 + 1).to_le_bytes());
+                let private_key = Ed25519PrivateKey::try_from(&seed[..]).unwrap();
+                let public_key = Ed25519PublicKey::from(&private_key);
+                let message = format!("message #{}", i).into_bytes();
+                let signature = ed25519_dalek::ExpandedSecretKey::from(&private_key.0)
+                    .sign(&message, &public_key.0);
+                (message, public_key, Ed25519Signature(signature))
+            })
+            .collect()
+    }
+
+    fn as_triples(
+        items: &[(Vec<u8>, Ed25519PublicKey, Ed25519Signature)],
+    ) -> Vec<(&[u8], &Ed25519PublicKey, &Ed25519Signature)> {
+        items
+            .iter()
+            .map(|(message, key, signature)| (&message[..], key, signature))
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_verify_arbitrary_msgs() {
+        let mut items = signed_messages(16);
+        assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&as_triples(&items)).is_ok());
+        assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&[]).is_ok());
+
+        // A signature moved to another message must make the whole batch fail.
+        let signature = items[3].2.clone();
+        items[7].2 = signature;
+        assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&as_triples(&items)).is_err());
+    }
+
+    #[test]
+    fn test_batch_verify_rejects_small_order_public_key() {
+        let mut items = signed_messages(2);
+        // The identity point has order 1.
+        let mut identity = [0u8; ED25519_PUBLIC_KEY_LENGTH];
+        identity[0] = 1;
+        items[1].1 = Ed25519PublicKey::from_bytes_unchecked(&identity).unwrap();
+        assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&as_triples(&items)).is_err());
+    }
+
+    /// Single and batch verification must accept and reject the same signatures.
+    #[test]
+    fn test_batch_verify_agrees_with_verify() {
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let valid = signed_messages(1).remove(0);
+        let with_signature = |tamper: &dyn Fn(&mut [u8; ED25519_SIGNATURE_LENGTH])| {
+            let mut bytes = valid.2.to_bytes();
+            tamper(&mut bytes);
+            let signature = Ed25519Signature::from_bytes_unchecked(&bytes).unwrap();
+            (valid.0.clone(), valid.1.clone(), signature)
+        };
+
+        let mut other_message = valid.clone();
+        other_message.0.push(0);
+        // S + L is congruent to S, so only the malleability check rejects it.
+        let non_canonical_s = with_signature(&|bytes| {
+            let mut carry = 0u16;
+            for (s, l) in bytes[32..].iter_mut().zip(L.iter()) {
+                let sum = *s as u16 + *l as u16 + carry;
+                *s = sum as u8;
+                carry = sum >> 8;
+            }
+        });
+        let small_order_r = with_signature(&|bytes| bytes[..32].copy_from_slice(&identity));
+        let mut small_order_public_key = valid.clone();
+        small_order_public_key.1 = Ed25519PublicKey::from_bytes_unchecked(&identity).unwrap();
+
+        for item in [
+            valid,
+            other_message,
+            non_canonical_s,
+            small_order_r,
+            small_order_public_key,
+        ] {
+            let (message, key, signature) = &item;
+            let mut items = signed_messages(4);
+            items[2] = item.clone();
+            assert_eq!(
+                Ed25519Signature::batch_verify_arbitrary_msgs(&as_triples(&items)).is_ok(),
+                signature.verify_arbitrary_msg(message, key).is_ok(),
+            );
+        }
+    }
+
+    /// Signs `message` by hand with the secret scalar `a` and nonce `r`, publishing `r_point` as R
+    /// and `public_point` as the public key, so that either can be given a torsion component.
+    /// Also returns the challenge scalar modulo 8.
+    fn sign_with_points(
+        a: &Scalar,
+        r: &Scalar,
+        r_point: &EdwardsPoint,
+        public_point: &EdwardsPoint,
+        message: &[u8],
+    ) -> (Vec<u8>, Ed25519PublicKey, Ed25519Signature, u8) {
+        let r_bytes = r_point.compress().to_bytes();
+        let public_key_bytes = public_point.compress().to_bytes();
+        let k = Scalar::from_hash(
+            Sha512::new()
+                .chain(r_bytes)
+                .chain(public_key_bytes)
+                .chain(message),
+        );
+        let mut signature = [0u8; ED25519_SIGNATURE_LENGTH];
+        signature[..32].copy_from_slice(&r_bytes);
+        signature[32..].copy_from_slice((r + k * a).as_bytes());
+        (
+            message.to_vec(),
+            Ed25519PublicKey::from_bytes_unchecked(&public_key_bytes).unwrap(),
+            Ed25519Signature::from_bytes_unchecked(&signature).unwrap(),
+            k.to_bytes()[0] & 7,
+        )
+    }
+
+    /// Single and batch verification must also agree when R or the public key has a torsion
+    /// component, which the batch equation alone does not see.
+    #[test]
+    fn test_batch_verify_agrees_with_verify_on_mixed_order_points() {
+        let private_key =
+            Ed25519PrivateKey::try_from(&[7u8; ED25519_PRIVATE_KEY_LENGTH][..]).unwrap();
+        let expanded = ed25519_dalek::ExpandedSecretKey::from(&private_key.0).to_bytes();
+        let a = Scalar::from_bits(expanded[..32].try_into().unwrap());
+        let public_point = &a * &ED25519_BASEPOINT_TABLE;
+        let torsion = EIGHT_TORSION[1];
+        let message = b"mixed order";
+
+        let mut cases = vec![];
+        let r = Scalar::from(1000u64);
+        let (message_bytes, key, signature, _) = sign_with_points(
+            &a,
+            &r,
+            &(&r * &ED25519_BASEPOINT_TABLE + torsion),
+            &public_point,
+            message,
+        );
+        assert!(signature
+            .verify_arbitrary_msg(&message_bytes, &key)
+            .is_err());
+        cases.push((message_bytes, key, signature));
+
+        // With a mixed-order public key, single verification accepts exactly when the challenge
+        // is a multiple of 8, so find a nonce for either outcome.
+        let mut outcomes = [false, false];
+        for nonce in 1u64.. {
+            let r = Scalar::from(nonce);
+            let (message_bytes, key, signature, k_mod_8) = sign_with_points(
+                &a,
+                &r,
+                &(&r * &ED25519_BASEPOINT_TABLE),
+                &(public_point + torsion),
+                message,
+            );
+            let accepted = k_mod_8 == 0;
+            assert_eq!(
+                signature.verify_arbitrary_msg(&message_bytes, &key).is_ok(),
+                accepted
+            );
+            if !outcomes[accepted as usize] {
+                outcomes[accepted as usize] = true;
+                cases.push((message_bytes, key, signature));
+            }
+            if outcomes == [true, true] {
+                break;
+            }
+        }
+
+        for item in cases {
+            let (message, key, signature) = &item;
+            let mut items = signed_messages(4);
+            items[1] = item.clone();
+            assert_eq!(
+                Ed25519Signature::batch_verify_arbitrary_msgs(&as_triples(&items)).is_ok(),
+                signature.verify_arbitrary_msg(message, key).is_ok(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_has_small_order() {
+        assert_eq!(SMALL_ORDER_ENCODINGS.len(), 7);
+        for point in EIGHT_TORSION.iter() {
+            let bytes = point.compress().to_bytes();
+            assert!(has_small_order(&bytes));
+            let mut flipped = bytes;
+            flipped[31] ^= 0x80;
+            assert!(has_small_order(&flipped));
+        }
+        for (_, public_key, signature) in signed_messages(8) {
+            assert!(!has_small_order(&public_key.to_bytes()));
+            let r_bytes: [u8; 32] = signature.to_bytes()[..32].try_into().unwrap();
+            assert!(!has_small_order(&r_bytes));
+        }
+    }
+
+    #[test]
+    fn test_is_torsion_free() {
+        for (_, public_key, signature) in signed_messages(8) {
+            let public_key_bytes = public_key.to_bytes();
+            assert!(is_torsion_free(&public_key_bytes));
+            let r_bytes: [u8; 32] = signature.to_bytes()[..32].try_into().unwrap();
+            assert!(is_torsion_free(&r_bytes));
+            let public_point = CompressedEdwardsY(public_key_bytes).decompress().unwrap();
+            let mixed = (public_point + EIGHT_TORSION[1]).compress().to_bytes();
+            assert!(!is_torsion_free(&mixed));
+        }
+    }
+}
@@ -105,7 +105,9 @@ use more_asserts::debug_assert_lt;
 use once_cell::sync::{Lazy, OnceCell};
 // #[cfg(any(test, feature = "fuzzing"))]
 // use proptest_derive::Arbitrary;
-// use rand::{distributions::Standard, prelude::Distribution, rngs::OsRng, Rng};
+#[cfg(feature = "random")]
+use crate::rng::OsRng;
+use rand_core::RngCore;
 use serde::{de, ser, Deserialize, Serialize};
 use std::{
     self,
@@ -158,14 +160,17 @@ impl HashValue {
     }
 
     /// Create a cryptographically random instance.
-    // pub fn random() -> Self {
-    //     Self::random_with_rng(&mut OsRng)
-    // }
+    #[cfg(feature = "random")]
+    pub fn random() -> Self {
+        Self::random_with_rng(&mut OsRng)
+    }
 
-    // /// Creates a random instance with given rng. Useful in unit tests.
-    // pub fn random_with_rng<R: Rng>(rng: &mut R) -> Self {
-    //     rng.gen()
-    // }
+    /// Creates a random instance with given rng. Useful in unit tests.
+    pub fn random_with_rng<R: RngCore>(rng: &mut R) -> Self {
+        let mut hash = Self::zero();
+        rng.fill_bytes(hash.as_ref_mut());
+        hash
+    }
 
     /// Convenience function that computes a `HashValue` internally equal to
     /// the sha3_256 of a byte buffer. It will handle hasher creation, data
@@ -2,6 +2,8 @@
 // https://github.com/aptos-labs/aptos-core/tree/mainnet/crates/aptos-crypto
 pub mod ed25519;
 pub mod hash;
+pub mod multi_ed25519;
+pub mod rng;
 pub mod traits;
 
 pub use self::traits::*;
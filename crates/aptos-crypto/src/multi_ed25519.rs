@@ -0,0 +1,627 @@
+// Copyright © Aptos Foundation
+// Parts of the project are originally copyright © Meta Platforms, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module provides an API for the accountable threshold multi-sig PureEdDSA signature scheme
+//! over the ed25519 twisted Edwards curve as defined in [RFC8032](https://tools.ietf.org/html/rfc8032).
+//!
+//! Signature verification also checks and rejects non-canonical signatures.
+
+use crate::{
+    ed25519::{
+        Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature, ED25519_PRIVATE_KEY_LENGTH,
+        ED25519_PUBLIC_KEY_LENGTH, ED25519_SIGNATURE_LENGTH,
+    },
+    hash::CryptoHash,
+    traits::*,
+};
+use anyhow::{anyhow, Result};
+use aptos_crypto_derive::{DeserializeKey, SerializeKey, SilentDebug, SilentDisplay};
+use core::convert::TryFrom;
+use serde::Serialize;
+use std::{convert::TryInto, fmt};
+
+/// The maximum number of keys (and therefore signatures) in a multi-key Ed25519 structure.
+pub const MAX_NUM_OF_KEYS: usize = 32;
+
+/// The number of bytes of the bitmap in a `MultiEd25519Signature`.
+pub const BITMAP_NUM_OF_BYTES: usize = 4;
+
+/// Vector of private keys in the multi-key Ed25519 structure along with the threshold.
+#[derive(DeserializeKey, Eq, PartialEq, SilentDisplay, SilentDebug, SerializeKey)]
+pub struct MultiEd25519PrivateKey {
+    private_keys: Vec<Ed25519PrivateKey>,
+    threshold: u8,
+}
+
+/// Vector of public keys in the multi-key Ed25519 structure along with the threshold.
+#[derive(Clone, DeserializeKey, Eq, Hash, PartialEq, SerializeKey)]
+pub struct MultiEd25519PublicKey {
+    public_keys: Vec<Ed25519PublicKey>,
+    threshold: u8,
+}
+
+/// Vector of the multi-key signatures along with a 32bit [u8; 4] bitmap required to map signatures
+/// with their corresponding public keys.
+///
+/// Note that bits are read from left to right. For instance, in the following bitmap
+/// [0b0001_0000, 0b0000_0000, 0b0000_0000, 0b0000_0001], the 3rd and 31st positions are set.
+#[derive(Clone, DeserializeKey, Eq, Hash, PartialEq, SerializeKey)]
+pub struct MultiEd25519Signature {
+    signatures: Vec<Ed25519Signature>,
+    bitmap: [u8; BITMAP_NUM_OF_BYTES],
+}
+
+impl MultiEd25519PrivateKey {
+    /// Construct a new MultiEd25519PrivateKey.
+    pub fn new(
+        private_keys: Vec<Ed25519PrivateKey>,
+        threshold: u8,
+    ) -> std::result::Result<Self, CryptoMaterialError> {
+        check_threshold(private_keys.len(), threshold)?;
+        Ok(MultiEd25519PrivateKey {
+            private_keys,
+            threshold,
+        })
+    }
+
+    /// Getter for private keys.
+    pub fn private_keys(&self) -> &Vec<Ed25519PrivateKey> {
+        &self.private_keys
+    }
+
+    /// Getter for threshold.
+    pub fn threshold(&self) -> &u8 {
+        &self.threshold
+    }
+
+    /// Serialize a MultiEd25519PrivateKey.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .private_keys
+            .iter()
+            .flat_map(|key| key.to_bytes().to_vec())
+            .collect();
+        bytes.push(self.threshold);
+        bytes
+    }
+}
+
+impl MultiEd25519PublicKey {
+    /// Construct a new MultiEd25519PublicKey.
+    /// --- Rules ---
+    /// a) threshold cannot be zero.
+    /// b) public_keys.len() should be equal to or larger than threshold.
+    /// c) support up to MAX_NUM_OF_KEYS public keys.
+    pub fn new(
+        public_keys: Vec<Ed25519PublicKey>,
+        threshold: u8,
+    ) -> std::result::Result<Self, CryptoMaterialError> {
+        check_threshold(public_keys.len(), threshold)?;
+        Ok(MultiEd25519PublicKey {
+            public_keys,
+            threshold,
+        })
+    }
+
+    /// Getter for public keys.
+    pub fn public_keys(&self) -> &Vec<Ed25519PublicKey> {
+        &self.public_keys
+    }
+
+    /// Getter for threshold.
+    pub fn threshold(&self) -> &u8 {
+        &self.threshold
+    }
+
+    /// Serialize a MultiEd25519PublicKey.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .public_keys
+            .iter()
+            .flat_map(|key| key.to_bytes().to_vec())
+            .collect();
+        bytes.push(self.threshold);
+        bytes
+    }
+}
+
+///////////////////////
+// PrivateKey Traits //
+///////////////////////
+
+impl PrivateKey for MultiEd25519PrivateKey {
+    type PublicKeyMaterial = MultiEd25519PublicKey;
+}
+
+impl SigningKey for MultiEd25519PrivateKey {
+    type SignatureMaterial = MultiEd25519Signature;
+    type VerifyingKeyMaterial = MultiEd25519PublicKey;
+
+    /// Signs with the first `threshold` private keys.
+    fn sign<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+    ) -> Result<MultiEd25519Signature, CryptoMaterialError> {
+        Ok(self.sign_arbitrary_message(signing_message(message)?.as_ref()))
+    }
+
+    /// Signs with the first `threshold` private keys.
+    fn sign_arbitrary_message(&self, message: &[u8]) -> MultiEd25519Signature {
+        let mut bitmap = [0u8; BITMAP_NUM_OF_BYTES];
+        let signatures: Vec<Ed25519Signature> = self
+            .private_keys
+            .iter()
+            .take(self.threshold as usize)
+            .enumerate()
+            .map(|(i, item)| {
+                bitmap_set_bit(&mut bitmap, i);
+                item.sign_arbitrary_message(message)
+            })
+            .collect();
+        MultiEd25519Signature { signatures, bitmap }
+    }
+}
+
+impl TryFrom<&[u8]> for MultiEd25519PrivateKey {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize an Ed25519PrivateKey. This method will also check for key and threshold
+    /// validity.
+    fn try_from(bytes: &[u8]) -> std::result::Result<MultiEd25519PrivateKey, CryptoMaterialError> {
+        let threshold = check_and_get_threshold(bytes, ED25519_PRIVATE_KEY_LENGTH)?;
+        let private_keys: Result<Vec<Ed25519PrivateKey>, _> = bytes
+            .chunks_exact(ED25519_PRIVATE_KEY_LENGTH)
+            .map(Ed25519PrivateKey::try_from)
+            .collect();
+        private_keys.map(|private_keys| MultiEd25519PrivateKey {
+            private_keys,
+            threshold,
+        })
+    }
+}
+
+impl Length for MultiEd25519PrivateKey {
+    fn length(&self) -> usize {
+        self.private_keys.len() * ED25519_PRIVATE_KEY_LENGTH + 1
+    }
+}
+
+impl ValidCryptoMaterial for MultiEd25519PrivateKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+}
+
+//////////////////////
+// PublicKey Traits //
+//////////////////////
+
+// Implementing From<&PrivateKey<...>> allows to derive a public key in a more elegant fashion
+impl From<&MultiEd25519PrivateKey> for MultiEd25519PublicKey {
+    fn from(private_key: &MultiEd25519PrivateKey) -> Self {
+        let public_keys = private_key
+            .private_keys
+            .iter()
+            .map(PrivateKey::public_key)
+            .collect();
+        MultiEd25519PublicKey {
+            public_keys,
+            threshold: private_key.threshold,
+        }
+    }
+}
+
+/// A 1-of-1 multi-key public key, which is how single Ed25519 keys are represented in
+/// multi-key authentication keys.
+impl From<&Ed25519PublicKey> for MultiEd25519PublicKey {
+    fn from(ed_public_key: &Ed25519PublicKey) -> Self {
+        MultiEd25519PublicKey {
+            public_keys: vec![ed_public_key.clone()],
+            threshold: 1u8,
+        }
+    }
+}
+
+// We deduce PublicKey from this
+impl PublicKey for MultiEd25519PublicKey {
+    type PrivateKeyMaterial = MultiEd25519PrivateKey;
+}
+
+// We deduce VerifyingKey from pointing to the signature material
+// we get the ability to do `pubkey.validate(msg, signature)`
+impl VerifyingKey for MultiEd25519PublicKey {
+    type SignatureMaterial = MultiEd25519Signature;
+    type SigningKeyMaterial = MultiEd25519PrivateKey;
+}
+
+impl fmt::Display for MultiEd25519PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.to_bytes()))
+    }
+}
+
+impl fmt::Debug for MultiEd25519PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MultiEd25519PublicKey({})", self)
+    }
+}
+
+impl TryFrom<&[u8]> for MultiEd25519PublicKey {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a MultiEd25519PublicKey. This method will also check for key and threshold
+    /// validity, but NOT for small subgroup membership (see [`Ed25519PublicKey::try_from`]).
+    fn try_from(bytes: &[u8]) -> std::result::Result<MultiEd25519PublicKey, CryptoMaterialError> {
+        let threshold = check_and_get_threshold(bytes, ED25519_PUBLIC_KEY_LENGTH)?;
+        let public_keys: Result<Vec<Ed25519PublicKey>, _> = bytes
+            .chunks_exact(ED25519_PUBLIC_KEY_LENGTH)
+            .map(Ed25519PublicKey::try_from)
+            .collect();
+        public_keys.map(|public_keys| MultiEd25519PublicKey {
+            public_keys,
+            threshold,
+        })
+    }
+}
+
+impl Length for MultiEd25519PublicKey {
+    fn length(&self) -> usize {
+        self.public_keys.len() * ED25519_PUBLIC_KEY_LENGTH + 1
+    }
+}
+
+impl ValidCryptoMaterial for MultiEd25519PublicKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+}
+
+//////////////////////
+// Signature Traits //
+//////////////////////
+
+impl MultiEd25519Signature {
+    /// This method will also sort signatures based on index.
+    pub fn new(
+        signatures: Vec<(Ed25519Signature, u8)>,
+    ) -> std::result::Result<Self, CryptoMaterialError> {
+        let num_of_sigs = signatures.len();
+        if num_of_sigs == 0 || num_of_sigs > MAX_NUM_OF_KEYS {
+            return Err(CryptoMaterialError::ValidationError);
+        }
+
+        let mut sorted_signatures = signatures;
+        sorted_signatures.sort_by_key(|(_, index)| *index);
+
+        let mut bitmap = [0u8; BITMAP_NUM_OF_BYTES];
+
+        // Check if all indexes are unique and < MAX_NUM_OF_KEYS
+        let (sigs, indexes): (Vec<_>, Vec<_>) = sorted_signatures.into_iter().unzip();
+        for i in indexes {
+            if i as usize >= MAX_NUM_OF_KEYS {
+                return Err(CryptoMaterialError::BitVecError(
+                    "Signature index is out of range".to_string(),
+                ));
+            }
+            if bitmap_get_bit(bitmap, i as usize) {
+                return Err(CryptoMaterialError::BitVecError(
+                    "Duplicate signature index".to_string(),
+                ));
+            }
+            bitmap_set_bit(&mut bitmap, i as usize);
+        }
+        Ok(MultiEd25519Signature {
+            signatures: sigs,
+            bitmap,
+        })
+    }
+
+    /// Creates a MultiEd25519Signature from a list of signatures and the bitmap of the keys that
+    /// produced them, without checking that they match.
+    pub fn new_with_signatures_and_bitmap(
+        signatures: Vec<Ed25519Signature>,
+        bitmap: [u8; BITMAP_NUM_OF_BYTES],
+    ) -> Self {
+        Self { signatures, bitmap }
+    }
+
+    /// Getter for signatures.
+    pub fn signatures(&self) -> &Vec<Ed25519Signature> {
+        &self.signatures
+    }
+
+    /// Getter for the bitmap.
+    pub fn bitmap(&self) -> &[u8; BITMAP_NUM_OF_BYTES] {
+        &self.bitmap
+    }
+
+    /// Serialize a MultiEd25519Signature in the form of sig0||sig1||..sigN||bitmap.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .signatures
+            .iter()
+            .flat_map(|sig| sig.to_bytes().to_vec())
+            .collect();
+        bytes.extend(&self.bitmap[..]);
+        bytes
+    }
+}
+
+impl TryFrom<&[u8]> for MultiEd25519Signature {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a MultiEd25519Signature. This method will also check for malleable signatures
+    /// and bitmap validity.
+    fn try_from(bytes: &[u8]) -> std::result::Result<MultiEd25519Signature, CryptoMaterialError> {
+        let length = bytes.len();
+        let bitmap_num_of_bytes = length % ED25519_SIGNATURE_LENGTH;
+        let num_of_sigs = length / ED25519_SIGNATURE_LENGTH;
+
+        if num_of_sigs == 0
+            || num_of_sigs > MAX_NUM_OF_KEYS
+            || bitmap_num_of_bytes != BITMAP_NUM_OF_BYTES
+        {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+
+        let bitmap: [u8; BITMAP_NUM_OF_BYTES] = bytes[length - BITMAP_NUM_OF_BYTES..]
+            .try_into()
+            .map_err(|_| CryptoMaterialError::DeserializationError)?;
+        if bitmap_count_ones(bitmap) != num_of_sigs as u32 {
+            return Err(CryptoMaterialError::DeserializationError);
+        }
+
+        let signatures: Result<Vec<Ed25519Signature>, _> = bytes
+            .chunks_exact(ED25519_SIGNATURE_LENGTH)
+            .map(Ed25519Signature::try_from)
+            .collect();
+        signatures.map(|signatures| MultiEd25519Signature { signatures, bitmap })
+    }
+}
+
+impl Length for MultiEd25519Signature {
+    fn length(&self) -> usize {
+        self.signatures.len() * ED25519_SIGNATURE_LENGTH + BITMAP_NUM_OF_BYTES
+    }
+}
+
+impl ValidCryptoMaterial for MultiEd25519Signature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+}
+
+impl fmt::Display for MultiEd25519Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.to_bytes()))
+    }
+}
+
+impl fmt::Debug for MultiEd25519Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MultiEd25519Signature({})", self)
+    }
+}
+
+impl Signature for MultiEd25519Signature {
+    type SigningKeyMaterial = MultiEd25519PrivateKey;
+    type VerifyingKeyMaterial = MultiEd25519PublicKey;
+
+    /// Checks that `self` is valid for `message` using `public_key`.
+    fn verify<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_key: &MultiEd25519PublicKey,
+    ) -> Result<()> {
+        self.verify_arbitrary_msg(&signing_message(message)?, public_key)
+    }
+
+    /// Checks that `self` is valid for an arbitrary &[u8] `message` using `public_key`.
+    /// Outside of this crate, this particular function should only be used for native signature
+    /// verification in Move.
+    fn verify_arbitrary_msg(
+        &self,
+        message: &[u8],
+        public_key: &MultiEd25519PublicKey,
+    ) -> Result<()> {
+        // NOTE: Public keys need not be validated because we use ed25519_dalek's verify_strict,
+        // which checks for small order public keys.
+        match bitmap_last_set_bit(self.bitmap) {
+            Some(last_bit) if (last_bit as usize) < public_key.public_keys.len() => (),
+            _ => {
+                return Err(anyhow!(
+                    "{}",
+                    CryptoMaterialError::BitVecError("Signature index is out of range".to_string())
+                ))
+            },
+        };
+
+        let num_ones_in_bitmap = bitmap_count_ones(self.bitmap);
+        if num_ones_in_bitmap < public_key.threshold as u32 {
+            return Err(anyhow!(
+                "{}",
+                CryptoMaterialError::BitVecError(
+                    "Not enough signatures to meet the threshold".to_string()
+                )
+            ));
+        }
+        if num_ones_in_bitmap != self.signatures.len() as u32 {
+            return Err(anyhow!(
+                "{}",
+                CryptoMaterialError::BitVecError(
+                    "Bitmap does not match the number of signatures".to_string()
+                )
+            ));
+        }
+
+        let mut bitmap_index = 0;
+        for sig in &self.signatures {
+            while !bitmap_get_bit(self.bitmap, bitmap_index) {
+                bitmap_index += 1;
+            }
+            sig.verify_arbitrary_msg(message, &public_key.public_keys[bitmap_index])?;
+            bitmap_index += 1;
+        }
+        Ok(())
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+}
+
+impl From<&Ed25519Signature> for MultiEd25519Signature {
+    fn from(ed_signature: &Ed25519Signature) -> Self {
+        MultiEd25519Signature {
+            signatures: vec![ed_signature.clone()],
+            // "1000_0000 0000_0000 0000_0000 0000_0000"
+            bitmap: [0b1000_0000u8, 0u8, 0u8, 0u8],
+        }
+    }
+}
+
+//////////////////////
+// Helper functions //
+//////////////////////
+
+fn check_threshold(
+    num_of_keys: usize,
+    threshold: u8,
+) -> std::result::Result<(), CryptoMaterialError> {
+    if threshold == 0 || num_of_keys < threshold as usize {
+        Err(CryptoMaterialError::ValidationError)
+    } else if num_of_keys > MAX_NUM_OF_KEYS {
+        Err(CryptoMaterialError::WrongLengthError)
+    } else {
+        Ok(())
+    }
+}
+
+// Helper function required to MultiEd25519 keys deserialization.
+fn check_and_get_threshold(
+    bytes: &[u8],
+    key_size: usize,
+) -> std::result::Result<u8, CryptoMaterialError> {
+    let payload_length = bytes.len();
+    if bytes.is_empty() {
+        return Err(CryptoMaterialError::WrongLengthError);
+    }
+    let threshold_num_of_bytes = payload_length % key_size;
+    let num_of_keys = payload_length / key_size;
+    let threshold_byte = bytes[bytes.len() - 1];
+
+    if num_of_keys == 0 || num_of_keys > MAX_NUM_OF_KEYS || threshold_num_of_bytes != 1 {
+        Err(CryptoMaterialError::WrongLengthError)
+    } else if threshold_byte == 0 || threshold_byte as usize > num_of_keys {
+        Err(CryptoMaterialError::ValidationError)
+    } else {
+        Ok(threshold_byte)
+    }
+}
+
+fn bitmap_set_bit(input: &mut [u8; BITMAP_NUM_OF_BYTES], index: usize) {
+    let bucket = index / 8;
+    // It's always invoked with index < 32, thus there is no need to check range.
+    let bucket_pos = index - (bucket * 8);
+    input[bucket] |= 128 >> bucket_pos as u8;
+}
+
+// Helper method to get the input's bit at index.
+fn bitmap_get_bit(input: [u8; BITMAP_NUM_OF_BYTES], index: usize) -> bool {
+    let bucket = index / 8;
+    // It's always invoked with index < 32, thus there is no need to check range.
+    let bucket_pos = index - (bucket * 8);
+    (input[bucket] & (128 >> bucket_pos as u8)) != 0
+}
+
+// Returns the number of set bits.
+fn bitmap_count_ones(input: [u8; BITMAP_NUM_OF_BYTES]) -> u32 {
+    input.iter().map(|a| a.count_ones()).sum()
+}
+
+// Find the last set bit.
+fn bitmap_last_set_bit(input: [u8; BITMAP_NUM_OF_BYTES]) -> Option<u8> {
+    input
+        .iter()
+        .rev()
+        .enumerate()
+        .find(|(_, byte)| byte != &&0u8)
+        .map(|(i, byte)| (8 * (BITMAP_NUM_OF_BYTES - i) - byte.trailing_zeros() as usize - 1) as u8)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn private_keys(n: u8) -> Vec<Ed25519PrivateKey> {
+        (1..=n)
+            .map(|i| Ed25519PrivateKey::try_from(&[i; ED25519_PRIVATE_KEY_LENGTH][..]).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_bitmap_helpers() {
+        let mut bitmap = [0u8; BITMAP_NUM_OF_BYTES];
+        assert_eq!(bitmap_last_set_bit(bitmap), None);
+        bitmap_set_bit(&mut bitmap, 3);
+        bitmap_set_bit(&mut bitmap, 31);
+        assert_eq!(bitmap, [0b0001_0000, 0, 0, 0b0000_0001]);
+        assert!(bitmap_get_bit(bitmap, 3));
+        assert!(!bitmap_get_bit(bitmap, 4));
+        assert_eq!(bitmap_count_ones(bitmap), 2);
+        assert_eq!(bitmap_last_set_bit(bitmap), Some(31));
+    }
+
+    #[test]
+    fn test_multi_ed25519_sign_and_verify() {
+        let private_key = MultiEd25519PrivateKey::new(private_keys(3), 2).unwrap();
+        let public_key = MultiEd25519PublicKey::from(&private_key);
+        let message = b"multi-ed25519 message";
+
+        let signature = private_key.sign_arbitrary_message(message);
+        assert_eq!(signature.signatures().len(), 2);
+        assert!(signature.verify_arbitrary_msg(message, &public_key).is_ok());
+        assert!(signature
+            .verify_arbitrary_msg(b"another message", &public_key)
+            .is_err());
+
+        // Round trip through bytes.
+        let bytes = public_key.to_bytes();
+        assert_eq!(bytes.len(), 3 * ED25519_PUBLIC_KEY_LENGTH + 1);
+        assert_eq!(
+            MultiEd25519PublicKey::try_from(&bytes[..]).unwrap(),
+            public_key
+        );
+        let bytes = signature.to_bytes();
+        assert_eq!(
+            MultiEd25519Signature::try_from(&bytes[..]).unwrap(),
+            signature
+        );
+
+        // Signatures by keys 0 and 2 also meet the threshold.
+        let keys = private_key.private_keys();
+        let signature = MultiEd25519Signature::new(vec![
+            (keys[2].sign_arbitrary_message(message), 2),
+            (keys[0].sign_arbitrary_message(message), 0),
+        ])
+        .unwrap();
+        assert_eq!(signature.bitmap(), &[0b1010_0000, 0, 0, 0]);
+        assert!(signature.verify_arbitrary_msg(message, &public_key).is_ok());
+
+        // A single signature does not.
+        let signature =
+            MultiEd25519Signature::new(vec![(keys[1].sign_arbitrary_message(message), 1)]).unwrap();
+        assert!(signature
+            .verify_arbitrary_msg(message, &public_key)
+            .is_err());
+    }
+
+    #[test]
+    fn test_multi_ed25519_invalid_threshold() {
+        assert!(MultiEd25519PrivateKey::new(private_keys(2), 0).is_err());
+        assert!(MultiEd25519PrivateKey::new(private_keys(2), 3).is_err());
+        let public_keys: Vec<_> = private_keys(2).iter().map(PrivateKey::public_key).collect();
+        assert!(MultiEd25519PublicKey::new(public_keys, 3).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module provides cryptographically-secure random number generators that work inside
+//! Internet Computer canisters.
+//!
+//! Canisters are compiled to `wasm32-unknown-unknown`, where `getrandom` has no source of entropy
+//! (the workspace builds it with the `custom` backend). Randomness has to be fetched from the
+//! management canister's `raw_rand` method instead, which returns 32 random bytes. Those bytes
+//! seed a ChaCha20 CSPRNG:
+//!
+//! ```ignore
+//! let (seed,): (Vec<u8>,) = ic_cdk::api::management_canister::main::raw_rand().await?;
+//! let mut rng = CanisterRng::from_raw_rand(&seed)?;
+//! let ephemeral_key = Ed25519PrivateKey::generate(&mut rng);
+//! ```
+//!
+//! Code that cannot thread an RNG through (e.g., the `random` constructors of `HashValue` or
+//! `AccountAddress`) draws from `getrandom` instead. A canister can route `getrandom` to a
+//! `raw_rand`-seeded generator by seeding the global RNG and registering it as the custom
+//! `getrandom` backend:
+//!
+//! ```ignore
+//! getrandom::register_custom_getrandom!(aptos_crypto::rng::fill_from_global_rng);
+//!
+//! #[ic_cdk::init]
+//! fn init() {
+//!     ic_cdk::spawn(async {
+//!         let (seed,) = ic_cdk::api::management_canister::main::raw_rand().await.unwrap();
+//!         aptos_crypto::rng::seed_global_rng_from_raw_rand(&seed).unwrap();
+//!     });
+//! }
+//! ```
+
+use crate::traits::CryptoMaterialError;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+use std::{cell::RefCell, num::NonZeroU32};
+
+/// The number of bytes returned by the management canister's `raw_rand` method.
+pub const RAW_RAND_LENGTH: usize = 32;
+
+/// A fixed seed for deterministic tests.
+pub const TEST_SEED: [u8; RAW_RAND_LENGTH] = [0u8; RAW_RAND_LENGTH];
+
+/// A ChaCha20 CSPRNG seeded from the output of `raw_rand`.
+pub struct CanisterRng(ChaCha20Rng);
+
+impl CanisterRng {
+    /// Creates a generator from a 32-byte seed.
+    pub fn from_seed(seed: [u8; RAW_RAND_LENGTH]) -> Self {
+        Self(ChaCha20Rng::from_seed(seed))
+    }
+
+    /// Creates a generator from the bytes returned by `raw_rand`, which must be exactly
+    /// [`RAW_RAND_LENGTH`] bytes long.
+    pub fn from_raw_rand(raw_rand: &[u8]) -> Result<Self, CryptoMaterialError> {
+        let seed = <[u8; RAW_RAND_LENGTH]>::try_from(raw_rand)
+            .map_err(|_| CryptoMaterialError::WrongLengthError)?;
+        Ok(Self::from_seed(seed))
+    }
+}
+
+impl RngCore for CanisterRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for CanisterRng {}
+
+thread_local! {
+    static GLOBAL_RNG: RefCell<Option<CanisterRng>> = const { RefCell::new(None) };
+}
+
+/// (Re)seeds the global RNG used by [`with_global_rng`] and [`fill_from_global_rng`].
+pub fn seed_global_rng(seed: [u8; RAW_RAND_LENGTH]) {
+    GLOBAL_RNG.with(|rng| *rng.borrow_mut() = Some(CanisterRng::from_seed(seed)));
+}
+
+/// (Re)seeds the global RNG from the bytes returned by `raw_rand`.
+pub fn seed_global_rng_from_raw_rand(raw_rand: &[u8]) -> Result<(), CryptoMaterialError> {
+    let rng = CanisterRng::from_raw_rand(raw_rand)?;
+    GLOBAL_RNG.with(|global| *global.borrow_mut() = Some(rng));
+    Ok(())
+}
+
+/// Returns true once the global RNG has been seeded.
+pub fn is_global_rng_seeded() -> bool {
+    GLOBAL_RNG.with(|rng| rng.borrow().is_some())
+}
+
+/// Runs `f` with the global RNG, or returns `None` if it has not been seeded yet.
+pub fn with_global_rng<T>(f: impl FnOnce(&mut CanisterRng) -> T) -> Option<T> {
+    GLOBAL_RNG.with(|rng| rng.borrow_mut().as_mut().map(f))
+}
+
+/// Fills `dest` from the global RNG. This has the signature expected by
+/// `getrandom::register_custom_getrandom!`, and fails with `getrandom::Error::CUSTOM_START` if
+/// the global RNG has not been seeded yet.
+pub fn fill_from_global_rng(dest: &mut [u8]) -> Result<(), getrandom::Error> {
+    with_global_rng(|rng| rng.fill_bytes(dest)).ok_or_else(|| {
+        getrandom::Error::from(
+            NonZeroU32::new(getrandom::Error::CUSTOM_START).expect("CUSTOM_START is not zero"),
+        )
+    })
+}
+
+/// A generator that draws every byte from `getrandom`, i.e., from the OS on native targets and
+/// from the registered custom backend (e.g., [`fill_from_global_rng`]) inside canisters.
+#[cfg(feature = "random")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsRng;
+
+#[cfg(feature = "random")]
+impl RngCore for OsRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        getrandom::getrandom(dest).expect("getrandom should not fail")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        getrandom::getrandom(dest).map_err(|e| rand_core::Error::from(e.code()))
+    }
+}
+
+#[cfg(feature = "random")]
+impl CryptoRng for OsRng {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ed25519::Ed25519PrivateKey, Uniform};
+
+    #[test]
+    fn test_canister_rng_is_deterministic() {
+        let mut a = CanisterRng::from_raw_rand(&TEST_SEED).unwrap();
+        let mut b = CanisterRng::from_seed(TEST_SEED);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_eq!(
+            Ed25519PrivateKey::generate(&mut a),
+            Ed25519PrivateKey::generate(&mut b)
+        );
+        assert_ne!(
+            Ed25519PrivateKey::generate(&mut a).to_bytes(),
+            Ed25519PrivateKey::generate(&mut a).to_bytes()
+        );
+        assert!(CanisterRng::from_raw_rand(&[0u8; 31]).is_err());
+    }
+
+    #[test]
+    fn test_global_rng() {
+        let mut buf = [0u8; 16];
+        assert!(!is_global_rng_seeded());
+        assert!(fill_from_global_rng(&mut buf).is_err());
+
+        seed_global_rng([7u8; RAW_RAND_LENGTH]);
+        assert!(is_global_rng_seeded());
+        fill_from_global_rng(&mut buf).unwrap();
+        let mut expected = [0u8; 16];
+        CanisterRng::from_seed([7u8; RAW_RAND_LENGTH]).fill_bytes(&mut expected);
+        assert_eq!(buf, expected);
+    }
+}
@@ -10,7 +10,7 @@ use super::ed25519;
 use super::hash::{CryptoHash, CryptoHasher};
 use anyhow::Result;
 use core::convert::{From, TryFrom};
-// use rand::{rngs::StdRng, CryptoRng, RngCore, SeedableRng};
+use rand_core::{CryptoRng, RngCore};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{fmt::Debug, hash::Hash};
 use thiserror::Error;
@@ -137,9 +137,10 @@ pub trait SigningKey:
         message: &T,
     ) -> Result<Self::SignatureMaterial, CryptoMaterialError>;
 
-    /// Signs a non-hash input message. For testing only.
-    // #[cfg(any(test, feature = "fuzzing"))]
-    // fn sign_arbitrary_message(&self, message: &[u8]) -> Self::SignatureMaterial;
+    /// Signs a non-hash input message, without a domain-separating hasher prefix. This is what
+    /// off-chain message signing and Move's `signature_verify_strict` expect; on-chain
+    /// transactions must go through [`SigningKey::sign`] instead.
+    fn sign_arbitrary_message(&self, message: &[u8]) -> Self::SignatureMaterial;
 
     /// Returns the associated verifying key
     fn verifying_key(&self) -> Self::VerifyingKeyMaterial {
@@ -278,21 +279,21 @@ pub trait Signature:
 
 /// A type family for schemes which know how to generate key material from
 /// a cryptographically-secure [`CryptoRng`].
-// pub trait Uniform {
-//     /// Generate key material from a cryptographically-secure RNG.
-//     fn generate<R>(rng: &mut R) -> Self
-//     where
-//         R: RngCore + CryptoRng;
-
-//     /// Generate a random key using the shared TEST_SEED
-//     fn generate_for_testing() -> Self
-//     where
-//         Self: Sized,
-//     {
-//         let mut rng: StdRng = SeedableRng::from_seed(crate::test_utils::TEST_SEED);
-//         Self::generate(&mut rng)
-//     }
-// }
+pub trait Uniform {
+    /// Generate key material from a cryptographically-secure RNG.
+    fn generate<R>(rng: &mut R) -> Self
+    where
+        R: RngCore + CryptoRng;
+
+    /// Generate a random key using the shared TEST_SEED
+    fn generate_for_testing() -> Self
+    where
+        Self: Sized,
+    {
+        let mut rng = crate::rng::CanisterRng::from_seed(crate::rng::TEST_SEED);
+        Self::generate(&mut rng)
+    }
+}
 
 /// A type family with a by-convention notion of genesis private key.
 pub trait Genesis: PrivateKey {
@@ -315,9 +316,9 @@ pub(crate) mod private {
     impl Sealed for super::ed25519::Ed25519PublicKey {}
     impl Sealed for super::ed25519::Ed25519Signature {}
 
-    // impl Sealed for crate::multi_ed25519::MultiEd25519PrivateKey {}
-    // impl Sealed for crate::multi_ed25519::MultiEd25519PublicKey {}
-    // impl Sealed for crate::multi_ed25519::MultiEd25519Signature {}
+    impl Sealed for crate::multi_ed25519::MultiEd25519PrivateKey {}
+    impl Sealed for crate::multi_ed25519::MultiEd25519PublicKey {}
+    impl Sealed for crate::multi_ed25519::MultiEd25519Signature {}
 
     // impl Sealed for crate::secp256r1_ecdsa::PrivateKey {}
     // impl Sealed for crate::secp256r1_ecdsa::PublicKey {}
@@ -0,0 +1,271 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! # Derive macros for Move struct mirrors
+//!
+//! The `MoveStruct` derive implements, for a Rust struct with the same BCS representation as a
+//! Move struct:
+//!
+//! - `move_core_types::move_resource::MoveStructType`, from the `#[move_struct(...)]` attribute,
+//!   and with it `MoveTypeTag`,
+//! - an inherent `move_struct_layout()`, which gives the layout of the struct with its field
+//!   names and types,
+//! - `aptos_types::move_utils::move_layout::MoveLayout`, so that the struct can be nested in
+//!   other mirrors,
+//! - `aptos_types::move_utils::as_move_value::AsMoveValue`.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize, MoveStruct)]
+//! #[move_struct(address = "0x1", module = "coin", name = "CoinDeposit")]
+//! pub struct CoinDeposit {
+//!     pub coin_type: String,
+//!     pub account: AccountAddress,
+//!     pub amount: u64,
+//! }
+//! ```
+//!
+//! The address defaults to `0x1` and the name to the name of the Rust struct. The type
+//! arguments of the Move struct are the type parameters of the Rust struct, which must
+//! implement `MoveTypeTag`, followed by those listed in a `type_args = "..."` attribute,
+//! e.g. `type_args = "0x1::aptos_coin::AptosCoin"`. `PhantomData` fields are left out of the
+//! layout and value, as they have no Move counterpart.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, Data, DeriveInput, Fields, Lit, LitStr, Meta,
+    NestedMeta, Type,
+};
+
+#[proc_macro_derive(MoveStruct, attributes(move_struct))]
+pub fn derive_move_struct(input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as DeriveInput);
+    match move_struct(&item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct MoveStructAttrs {
+    address: [u8; 32],
+    module: LitStr,
+    name: LitStr,
+    type_args: Option<LitStr>,
+}
+
+fn move_struct(item: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = parse_attrs(item)?;
+    let fields = match &item.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .filter(|f| !is_phantom_data(&f.ty))
+                .collect::<Vec<_>>(),
+            Fields::Unit => vec![],
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    &item.ident,
+                    "`MoveStruct` only applies to structs with named fields",
+                ))
+            },
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &item.ident,
+                "`MoveStruct` only applies to structs",
+            ))
+        },
+    };
+
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let type_params = item
+        .generics
+        .type_params()
+        .map(|p| &p.ident)
+        .collect::<Vec<_>>();
+    let field_names = fields
+        .iter()
+        .map(|f| f.ident.as_ref().expect("named field"))
+        .collect::<Vec<_>>();
+    let field_name_strs = field_names
+        .iter()
+        .map(|ident| LitStr::new(&ident.unraw().to_string(), Span::call_site()))
+        .collect::<Vec<_>>();
+    let field_types = fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+
+    // Paths go through `aptos_types`, which the crates deriving `MoveStruct` depend on anyway.
+    let move_core_types = quote!(::aptos_types::__private::move_core_types);
+    let address = attrs.address;
+    let module = &attrs.module;
+    let struct_name = &attrs.name;
+    let explicit_type_args = attrs.type_args.iter().map(|type_args| {
+        quote! {
+            type_args.extend(
+                #move_core_types::parser::parse_type_tags(#type_args)
+                    .expect("`type_args` of the `move_struct` attribute are valid type tags"),
+            );
+        }
+    });
+
+    let mut struct_type_where = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    for param in &type_params {
+        struct_type_where
+            .predicates
+            .push(parse_quote!(#param: #move_core_types::move_resource::MoveTypeTag));
+    }
+    let mut layout_where = struct_type_where.clone();
+    for ty in &field_types {
+        layout_where
+            .predicates
+            .push(parse_quote!(#ty: ::aptos_types::move_utils::move_layout::MoveLayout));
+    }
+    let mut value_where = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    for ty in &field_types {
+        value_where
+            .predicates
+            .push(parse_quote!(#ty: ::aptos_types::move_utils::as_move_value::AsMoveValue));
+    }
+
+    Ok(quote! {
+        impl #impl_generics #move_core_types::move_resource::MoveStructType
+            for #name #ty_generics #struct_type_where
+        {
+            const ADDRESS: #move_core_types::account_address::AccountAddress =
+                #move_core_types::account_address::AccountAddress::new([#(#address),*]);
+            const MODULE_NAME: &'static #move_core_types::identifier::IdentStr =
+                #move_core_types::ident_str!(#module);
+            const STRUCT_NAME: &'static #move_core_types::identifier::IdentStr =
+                #move_core_types::ident_str!(#struct_name);
+
+            fn type_args() -> ::std::vec::Vec<#move_core_types::language_storage::TypeTag> {
+                #[allow(unused_mut)]
+                let mut type_args = ::std::vec![
+                    #(<#type_params as #move_core_types::move_resource::MoveTypeTag>::type_tag(),)*
+                ];
+                #(#explicit_type_args)*
+                type_args
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #layout_where {
+            /// The layout of the Move struct, decorated with its type and field names
+            pub fn move_struct_layout() -> #move_core_types::value::MoveStructLayout {
+                #move_core_types::value::MoveStructLayout::with_types(
+                    <Self as #move_core_types::move_resource::MoveStructType>::struct_tag(),
+                    ::std::vec![
+                        #(
+                            #move_core_types::value::MoveFieldLayout::new(
+                                #move_core_types::ident_str!(#field_name_strs).to_owned(),
+                                <#field_types as ::aptos_types::move_utils::move_layout::MoveLayout>::move_layout(),
+                            ),
+                        )*
+                    ],
+                )
+            }
+        }
+
+        impl #impl_generics ::aptos_types::move_utils::move_layout::MoveLayout
+            for #name #ty_generics #layout_where
+        {
+            fn move_layout() -> #move_core_types::value::MoveTypeLayout {
+                #move_core_types::value::MoveTypeLayout::Struct(Self::move_struct_layout())
+            }
+        }
+
+        impl #impl_generics ::aptos_types::move_utils::as_move_value::AsMoveValue
+            for #name #ty_generics #value_where
+        {
+            fn as_move_value(&self) -> #move_core_types::value::MoveValue {
+                #move_core_types::value::MoveValue::Struct(
+                    #move_core_types::value::MoveStruct::Runtime(::std::vec![
+                        #(
+                            ::aptos_types::move_utils::as_move_value::AsMoveValue::as_move_value(
+                                &self.#field_names,
+                            ),
+                        )*
+                    ]),
+                )
+            }
+        }
+    })
+}
+
+fn parse_attrs(item: &DeriveInput) -> syn::Result<MoveStructAttrs> {
+    let mut attrs = MoveStructAttrs {
+        address: parse_address(&LitStr::new("0x1", Span::call_site()))?,
+        module: LitStr::new("", Span::call_site()),
+        name: LitStr::new(&item.ident.to_string(), Span::call_site()),
+        type_args: None,
+    };
+    let mut module = None;
+    for attr in item.attrs.iter().filter(|a| a.path.is_ident("move_struct")) {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "expected #[move_struct(...)]",
+            ));
+        };
+        for nested in list.nested {
+            let NestedMeta::Meta(Meta::NameValue(pair)) = nested else {
+                return Err(syn::Error::new_spanned(
+                    nested,
+                    "expected `key = \"value\"`",
+                ));
+            };
+            let Lit::Str(value) = &pair.lit else {
+                return Err(syn::Error::new_spanned(&pair.lit, "expected a string"));
+            };
+            let key = pair
+                .path
+                .get_ident()
+                .map(|i| i.to_string())
+                .unwrap_or_default();
+            match key.as_str() {
+                "address" => attrs.address = parse_address(value)?,
+                "module" => module = Some(value.clone()),
+                "name" => attrs.name = value.clone(),
+                "type_args" => attrs.type_args = Some(value.clone()),
+                _ => return Err(syn::Error::new_spanned(&pair.path, "unknown attribute")),
+            }
+        }
+    }
+    attrs.module = module.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &item.ident,
+            "missing #[move_struct(module = \"...\")] attribute",
+        )
+    })?;
+    Ok(attrs)
+}
+
+/// Parse a hex address literal into its bytes, as `AccountAddress::new` takes them in a const
+fn parse_address(lit: &LitStr) -> syn::Result<[u8; 32]> {
+    let invalid = || syn::Error::new_spanned(lit, "expected a hex address such as \"0x1\"");
+    let value = lit.value();
+    let hex = value.strip_prefix("0x").ok_or_else(invalid)?;
+    if hex.is_empty() || hex.len() > 64 {
+        return Err(invalid());
+    }
+    let padded = format!("{:0>64}", hex);
+    let mut address = [0u8; 32];
+    for (i, byte) in address.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&padded[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(address)
+}
+
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
@@ -40,9 +40,9 @@
 
 use crate::{
     account_address::AccountAddress,
-    // state_store::state_key::{inner::StateKeyInner, StateKey},
+    state_store::state_key::{inner::StateKeyInner, StateKey},
 };
-use anyhow::Result;
+use anyhow::{Error, Result};
 use aptos_crypto::hash::HashValue;
 use move_core_types::language_storage::{ModuleId, StructTag};
 // #[cfg(any(test, feature = "fuzzing"))]
@@ -229,16 +229,16 @@ impl From<&ModuleId> for AccessPath {
     }
 }
 
-// impl TryFrom<StateKey> for AccessPath {
-//     type Error = Error;
+impl TryFrom<StateKey> for AccessPath {
+    type Error = Error;
 
-//     fn try_from(state_key: StateKey) -> Result<Self> {
-//         match state_key.inner() {
-//             StateKeyInner::AccessPath(access_path) => Ok(access_path.clone()),
-//             _ => anyhow::bail!("Unsupported state key type"),
-//         }
-//     }
-// }
+    fn try_from(state_key: StateKey) -> Result<Self> {
+        match state_key.into_inner() {
+            StateKeyInner::AccessPath(access_path) => Ok(access_path),
+            _ => anyhow::bail!("Unsupported state key type"),
+        }
+    }
+}
 
 impl TryFrom<&[u8]> for Path {
     type Error = bcs::Error;
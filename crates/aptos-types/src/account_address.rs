@@ -16,6 +16,7 @@ use std::{
 };
 
 const MULTISIG_ACCOUNT_DOMAIN_SEPARATOR: &[u8] = b"aptos_framework::multisig_account";
+const OBJECT_CODE_DEPLOYMENT_DOMAIN_SEPARATOR: &[u8] = b"aptos_framework::object_code_deployment";
 const STAKING_CONTRACT_DOMAIN_SEPARATOR: &[u8] = b"aptos_framework::staking_contract";
 const VESTING_POOL_DOMAIN_SEPARATOR: &[u8] = b"aptos_framework::vesting";
 
@@ -246,6 +247,24 @@ pub fn create_multisig_account_address(
     create_resource_address(creator, &full_seed)
 }
 
+/// The address of the object `0x1::object_code_deployment::publish` deploys a package to, when
+/// it is called by the transaction of `publisher` with sequence number `sequence_number`.
+///
+/// The object seed uses the sequence number the account will have after the transaction, i.e.
+/// `sequence_number + 1`, so that each publishing transaction deploys to a fresh object. This only
+/// holds for transactions protected by a sequence number; orderless transactions, whose sequence
+/// number is `ORDERLESS_SEQUENCE_NUMBER`, give `None`.
+pub fn create_object_code_deployment_address(
+    publisher: AccountAddress,
+    sequence_number: u64,
+) -> Option<AccountAddress> {
+    let next_sequence_number = sequence_number.checked_add(1)?;
+    let mut seed = vec![];
+    seed.extend(bcs::to_bytes(OBJECT_CODE_DEPLOYMENT_DOMAIN_SEPARATOR).unwrap());
+    seed.extend(bcs::to_bytes(&next_sequence_number).unwrap());
+    Some(create_object_address(publisher, &seed))
+}
+
 // Define the Hasher used for hashing AccountAddress types. In order to properly use the
 // CryptoHasher derive macro we need to have this in its own module so that it doesn't conflict
 // with the imported `AccountAddress` from move-core-types. It needs to have the same name since
@@ -304,4 +323,32 @@ mod test {
             super::create_resource_address(address, &[0x0B, 0x00, 0x0B])
         );
     }
+
+    #[test]
+    fn deployment_addresses() {
+        let publisher = AccountAddress::from_hex_literal("0xb0b").unwrap();
+        assert_eq!(
+            super::create_object_code_deployment_address(publisher, 5),
+            Some(
+                AccountAddress::from_hex_literal(
+                    "0x5dbf52a0693e48c9e61bc5be147d239b54d9827cdc9b8d7166aeb8685da0db01"
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(
+            super::create_object_code_deployment_address(
+                publisher,
+                crate::transaction::ORDERLESS_SEQUENCE_NUMBER
+            ),
+            None
+        );
+        assert_eq!(
+            super::create_resource_address(publisher, b"vault"),
+            AccountAddress::from_hex_literal(
+                "0xfffc4f1f7343872239d31e4e0b32ded74d15baf10968b29700e72d3d812ddc2f"
+            )
+            .unwrap()
+        );
+    }
 }
@@ -1,18 +1,17 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::move_utils::move_event_v2::MoveEventV2Type;
+use crate::move_utils::{move_event_v2::MoveEventV2Type, move_layout::MoveStruct};
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
-    identifier::IdentStr,
     language_storage::{StructTag, TypeTag, CORE_CODE_ADDRESS},
-    move_resource::MoveStructType,
 };
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(module = "coin")]
 pub struct CoinDeposit {
     pub coin_type: String,
     pub account: AccountAddress,
@@ -45,11 +44,6 @@ impl CoinDeposit {
     }
 }
 
-impl MoveStructType for CoinDeposit {
-    const MODULE_NAME: &'static IdentStr = ident_str!("coin");
-    const STRUCT_NAME: &'static IdentStr = ident_str!("CoinDeposit");
-}
-
 impl MoveEventV2Type for CoinDeposit {}
 
 pub static COIN_DEPOSIT_TYPE: Lazy<TypeTag> = Lazy::new(|| {
@@ -60,3 +54,31 @@ pub static COIN_DEPOSIT_TYPE: Lazy<TypeTag> = Lazy::new(|| {
         type_args: vec![],
     }))
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::move_utils::as_move_value::AsMoveValue;
+    use move_core_types::{move_resource::MoveTypeTag, value::MoveValue};
+
+    #[test]
+    fn test_coin_deposit_move_struct() {
+        assert_eq!(CoinDeposit::type_tag(), *COIN_DEPOSIT_TYPE);
+
+        let deposit = CoinDeposit::new(
+            "0x1::aptos_coin::AptosCoin".to_string(),
+            AccountAddress::TWO,
+            100,
+        );
+        let event = deposit.create_event_v2();
+        assert_eq!(event.type_tag(), &*COIN_DEPOSIT_TYPE);
+        assert_eq!(
+            CoinDeposit::try_from_bytes(event.event_data()).unwrap(),
+            deposit
+        );
+
+        let value =
+            MoveValue::simple_deserialize(event.event_data(), &CoinDeposit::move_layout()).unwrap();
+        assert_eq!(value.undecorate(), deposit.as_move_value());
+    }
+}
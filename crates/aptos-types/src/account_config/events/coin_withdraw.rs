@@ -1,18 +1,17 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::move_utils::move_event_v2::MoveEventV2Type;
+use crate::move_utils::{move_event_v2::MoveEventV2Type, move_layout::MoveStruct};
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
-    identifier::IdentStr,
     language_storage::{StructTag, TypeTag, CORE_CODE_ADDRESS},
-    move_resource::MoveStructType,
 };
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, MoveStruct)]
+#[move_struct(module = "coin")]
 pub struct CoinWithdraw {
     pub coin_type: String,
     pub account: AccountAddress,
@@ -45,11 +44,6 @@ impl CoinWithdraw {
     }
 }
 
-impl MoveStructType for CoinWithdraw {
-    const MODULE_NAME: &'static IdentStr = ident_str!("coin");
-    const STRUCT_NAME: &'static IdentStr = ident_str!("CoinWithdraw");
-}
-
 impl MoveEventV2Type for CoinWithdraw {}
 
 pub static COIN_WITHDRAW_TYPE: Lazy<TypeTag> = Lazy::new(|| {
@@ -28,12 +28,28 @@ pub struct OptionType<T> {
     vec: Vec<T>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct PropertyValue {
     value: Vec<u8>,
     typ: String,
 }
 
+impl PropertyValue {
+    pub fn new(value: Vec<u8>, typ: String) -> Self {
+        Self { value, typ }
+    }
+
+    /// The BCS bytes of the value
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// The Move type of the value, e.g. `u64` or `0x1::string::String`
+    pub fn typ(&self) -> &String {
+        &self.typ
+    }
+}
+
 impl DefaultPropertyMutate {
     pub fn new(
         creator: AccountAddress,
@@ -29,7 +29,7 @@ pub struct TokenDataCreation {
     property_values: Vec<Vec<u8>>,
     property_types: Vec<String>,
 }
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct TokenMutabilityConfig {
     maximum: bool,
     uri: bool,
@@ -20,19 +20,70 @@ pub struct TokenDeposit {
     amount: u64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct TokenId {
     token_data_id: TokenDataId,
     property_version: u64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+impl TokenId {
+    pub fn new(token_data_id: TokenDataId, property_version: u64) -> Self {
+        Self {
+            token_data_id,
+            property_version,
+        }
+    }
+
+    pub fn token_data_id(&self) -> &TokenDataId {
+        &self.token_data_id
+    }
+
+    pub fn property_version(&self) -> u64 {
+        self.property_version
+    }
+}
+
+impl MoveStructType for TokenId {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("token");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TokenId");
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct TokenDataId {
     creator: AccountAddress,
     collection: String,
     name: String,
 }
 
+impl TokenDataId {
+    pub fn new(creator: AccountAddress, collection: String, name: String) -> Self {
+        Self {
+            creator,
+            collection,
+            name,
+        }
+    }
+
+    pub fn creator(&self) -> &AccountAddress {
+        &self.creator
+    }
+
+    pub fn collection(&self) -> &String {
+        &self.collection
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+}
+
+impl MoveStructType for TokenDataId {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("token");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TokenDataId");
+}
+
 impl TokenDeposit {
     pub fn new(account: AccountAddress, id: TokenId, amount: u64) -> Self {
         Self {
@@ -1,7 +1,7 @@
 // Copyright (c) Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-// use crate::state_store::{state_key::StateKey, table::TableHandle};
+use crate::state_store::{state_key::StateKey, table::TableHandle};
 use move_core_types::account_address::AccountAddress;
 use serde::{Deserialize, Serialize};
 
@@ -51,10 +51,10 @@ impl AggregatorV1Resource {
         Self { handle, key, limit }
     }
 
-    // /// Helper function to return the state key where the actual value is stored.
-    // pub fn state_key(&self) -> StateKey {
-    //     StateKey::table_item(&TableHandle(self.handle), self.key.as_ref())
-    // }
+    /// Helper function to return the state key where the actual value is stored.
+    pub fn state_key(&self) -> StateKey {
+        StateKey::table_item(&TableHandle(self.handle), self.key.as_ref())
+    }
 }
 
 /// Rust representation of Integer Move struct.
@@ -1,7 +1,19 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::account_address::AccountAddress;
+use crate::{
+    account_address::AccountAddress,
+    account_config::{AccountResource, CORE_CODE_ADDRESS},
+    chain_id::ChainId,
+    transaction::authenticator::{AuthenticationKey, Scheme},
+};
+use anyhow::{ensure, Result};
+use aptos_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+    multi_ed25519::{MultiEd25519PrivateKey, MultiEd25519PublicKey},
+    SigningKey,
+};
+use move_core_types::{ident_str, identifier::IdentStr, move_resource::MoveStructType};
 use serde::{Deserialize, Serialize};
 
 // This struct includes TypeInfo (account_address, module_name, and struct_name)
@@ -22,3 +34,454 @@ pub struct RotationProofChallenge {
     pub current_auth_key: AccountAddress,
     pub new_public_key: Vec<u8>,
 }
+
+impl RotationProofChallenge {
+    /// Builds the challenge with the type info of `0x1::account::RotationProofChallenge`.
+    pub fn new(
+        sequence_number: u64,
+        originator: AccountAddress,
+        current_auth_key: AccountAddress,
+        new_public_key: Vec<u8>,
+    ) -> Self {
+        Self {
+            account_address: CORE_CODE_ADDRESS,
+            module_name: Self::MODULE_NAME.to_string(),
+            struct_name: Self::STRUCT_NAME.to_string(),
+            sequence_number,
+            originator,
+            current_auth_key,
+            new_public_key,
+        }
+    }
+
+    /// Builds the challenge for rotating the key of `originator`, whose current on-chain state
+    /// is `account`.
+    pub fn from_account_resource(
+        originator: AccountAddress,
+        account: &AccountResource,
+        new_public_key: Vec<u8>,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            account.sequence_number(),
+            originator,
+            AccountAddress::from_bytes(account.authentication_key())?,
+            new_public_key,
+        ))
+    }
+
+    /// The message both keys have to sign. `0x1::account` checks the signatures with
+    /// `signature_verify_strict_t`, i.e., over the BCS of the type info followed by the
+    /// challenge, which is exactly the BCS of this struct.
+    pub fn signing_message(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Only unhandleable errors happen here.")
+    }
+}
+
+impl MoveStructType for RotationProofChallenge {
+    const MODULE_NAME: &'static IdentStr = ident_str!("account");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RotationProofChallenge");
+}
+
+/// A key that `0x1::account` accepts as proof of ownership of an account: for either side of
+/// `rotate_authentication_key`, and for signing capability offers.
+#[derive(Clone, Copy, Debug)]
+pub enum RotationKey<'a> {
+    Ed25519(&'a Ed25519PrivateKey),
+    MultiEd25519(&'a MultiEd25519PrivateKey),
+}
+
+impl RotationKey<'_> {
+    pub fn scheme(&self) -> Scheme {
+        match self {
+            Self::Ed25519(_) => Scheme::Ed25519,
+            Self::MultiEd25519(_) => Scheme::MultiEd25519,
+        }
+    }
+
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Ed25519(private_key) => Ed25519PublicKey::from(*private_key).to_bytes().to_vec(),
+            Self::MultiEd25519(private_key) => MultiEd25519PublicKey::from(*private_key).to_bytes(),
+        }
+    }
+
+    pub fn authentication_key(&self) -> AuthenticationKey {
+        AuthenticationKey::from_preimage(self.public_key_bytes(), self.scheme())
+    }
+
+    /// Signs `message` as is and returns the signature bytes expected by `0x1::account`.
+    pub fn sign_arbitrary_message(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Ed25519(private_key) => private_key
+                .sign_arbitrary_message(message)
+                .to_bytes()
+                .to_vec(),
+            Self::MultiEd25519(private_key) => {
+                private_key.sign_arbitrary_message(message).to_bytes()
+            },
+        }
+    }
+}
+
+/// The arguments of `0x1::account::rotate_authentication_key`: both public keys, and the
+/// signatures of the `RotationProofChallenge` by the current key (`cap_rotate_key`) and by the
+/// new key (`cap_update_table`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RotationProof {
+    pub from_scheme: u8,
+    pub from_public_key_bytes: Vec<u8>,
+    pub to_scheme: u8,
+    pub to_public_key_bytes: Vec<u8>,
+    pub cap_rotate_key: Vec<u8>,
+    pub cap_update_table: Vec<u8>,
+}
+
+impl RotationProof {
+    /// Builds the challenge for `originator` and has `current_key` and `new_key` sign it.
+    /// Fails if `current_key` is not the account's current authentication key.
+    pub fn new(
+        originator: AccountAddress,
+        account: &AccountResource,
+        current_key: RotationKey,
+        new_key: RotationKey,
+    ) -> Result<Self> {
+        ensure_current_key(originator, account, &current_key)?;
+        let to_public_key_bytes = new_key.public_key_bytes();
+        let challenge = RotationProofChallenge::from_account_resource(
+            originator,
+            account,
+            to_public_key_bytes.clone(),
+        )?;
+        let message = challenge.signing_message();
+        Ok(Self {
+            from_scheme: current_key.scheme() as u8,
+            from_public_key_bytes: current_key.public_key_bytes(),
+            to_scheme: new_key.scheme() as u8,
+            to_public_key_bytes,
+            cap_rotate_key: current_key.sign_arbitrary_message(&message),
+            cap_update_table: new_key.sign_arbitrary_message(&message),
+        })
+    }
+}
+
+// Since the struct RotationCapabilityOfferProofChallengeV2 is defined in
+// "0x1::account::RotationCapabilityOfferProofChallengeV2", the type info is "0x1", "account" and
+// "RotationCapabilityOfferProofChallengeV2", as for `RotationProofChallenge`.
+#[derive(Serialize, Deserialize)]
+pub struct RotationCapabilityOfferProofChallengeV2 {
+    // Should be `CORE_CODE_ADDRESS`
+    pub account_address: AccountAddress,
+    // Should be `account`
+    pub module_name: String,
+    // Should be `RotationCapabilityOfferProofChallengeV2`
+    pub struct_name: String,
+    pub chain_id: u8,
+    pub sequence_number: u64,
+    pub source_address: AccountAddress,
+    pub recipient_address: AccountAddress,
+}
+
+impl RotationCapabilityOfferProofChallengeV2 {
+    /// Builds the challenge with the type info of
+    /// `0x1::account::RotationCapabilityOfferProofChallengeV2`.
+    pub fn new(
+        chain_id: ChainId,
+        sequence_number: u64,
+        source_address: AccountAddress,
+        recipient_address: AccountAddress,
+    ) -> Self {
+        Self {
+            account_address: CORE_CODE_ADDRESS,
+            module_name: Self::MODULE_NAME.to_string(),
+            struct_name: Self::STRUCT_NAME.to_string(),
+            chain_id: chain_id.id(),
+            sequence_number,
+            source_address,
+            recipient_address,
+        }
+    }
+
+    /// The message the offering account signs, see `RotationProofChallenge::signing_message`.
+    pub fn signing_message(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Only unhandleable errors happen here.")
+    }
+}
+
+impl MoveStructType for RotationCapabilityOfferProofChallengeV2 {
+    const MODULE_NAME: &'static IdentStr = ident_str!("account");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RotationCapabilityOfferProofChallengeV2");
+}
+
+// Since the struct SignerCapabilityOfferProofChallengeV2 is defined in
+// "0x1::account::SignerCapabilityOfferProofChallengeV2", the type info is "0x1", "account" and
+// "SignerCapabilityOfferProofChallengeV2". Unlike rotation offers, it is not bound to a chain.
+#[derive(Serialize, Deserialize)]
+pub struct SignerCapabilityOfferProofChallengeV2 {
+    // Should be `CORE_CODE_ADDRESS`
+    pub account_address: AccountAddress,
+    // Should be `account`
+    pub module_name: String,
+    // Should be `SignerCapabilityOfferProofChallengeV2`
+    pub struct_name: String,
+    pub sequence_number: u64,
+    pub source_address: AccountAddress,
+    pub recipient_address: AccountAddress,
+}
+
+impl SignerCapabilityOfferProofChallengeV2 {
+    /// Builds the challenge with the type info of
+    /// `0x1::account::SignerCapabilityOfferProofChallengeV2`.
+    pub fn new(
+        sequence_number: u64,
+        source_address: AccountAddress,
+        recipient_address: AccountAddress,
+    ) -> Self {
+        Self {
+            account_address: CORE_CODE_ADDRESS,
+            module_name: Self::MODULE_NAME.to_string(),
+            struct_name: Self::STRUCT_NAME.to_string(),
+            sequence_number,
+            source_address,
+            recipient_address,
+        }
+    }
+
+    /// The message the offering account signs, see `RotationProofChallenge::signing_message`.
+    pub fn signing_message(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Only unhandleable errors happen here.")
+    }
+}
+
+impl MoveStructType for SignerCapabilityOfferProofChallengeV2 {
+    const MODULE_NAME: &'static IdentStr = ident_str!("account");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SignerCapabilityOfferProofChallengeV2");
+}
+
+/// The arguments of `0x1::account::offer_signer_capability` and
+/// `0x1::account::offer_rotation_capability`: the offering account's key, and its signature of
+/// the matching offer challenge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapabilityOfferProof {
+    pub signature: Vec<u8>,
+    pub account_scheme: u8,
+    pub account_public_key_bytes: Vec<u8>,
+    pub recipient_address: AccountAddress,
+}
+
+impl CapabilityOfferProof {
+    /// Signs a `SignerCapabilityOfferProofChallengeV2` offering the signer of `source_address`,
+    /// whose current on-chain state is `account`, to `recipient_address`.
+    pub fn signer_capability(
+        source_address: AccountAddress,
+        account: &AccountResource,
+        key: RotationKey,
+        recipient_address: AccountAddress,
+    ) -> Result<Self> {
+        let challenge = SignerCapabilityOfferProofChallengeV2::new(
+            account.sequence_number(),
+            source_address,
+            recipient_address,
+        );
+        Self::new(
+            source_address,
+            account,
+            key,
+            recipient_address,
+            &challenge.signing_message(),
+        )
+    }
+
+    /// Signs a `RotationCapabilityOfferProofChallengeV2` offering the rotation capability of
+    /// `source_address`, whose current on-chain state is `account`, to `recipient_address` on
+    /// `chain_id`.
+    pub fn rotation_capability(
+        chain_id: ChainId,
+        source_address: AccountAddress,
+        account: &AccountResource,
+        key: RotationKey,
+        recipient_address: AccountAddress,
+    ) -> Result<Self> {
+        let challenge = RotationCapabilityOfferProofChallengeV2::new(
+            chain_id,
+            account.sequence_number(),
+            source_address,
+            recipient_address,
+        );
+        Self::new(
+            source_address,
+            account,
+            key,
+            recipient_address,
+            &challenge.signing_message(),
+        )
+    }
+
+    fn new(
+        source_address: AccountAddress,
+        account: &AccountResource,
+        key: RotationKey,
+        recipient_address: AccountAddress,
+        message: &[u8],
+    ) -> Result<Self> {
+        ensure_current_key(source_address, account, &key)?;
+        Ok(Self {
+            signature: key.sign_arbitrary_message(message),
+            account_scheme: key.scheme() as u8,
+            account_public_key_bytes: key.public_key_bytes(),
+            recipient_address,
+        })
+    }
+}
+
+/// `0x1::account` only accepts proofs signed by the account's current authentication key.
+fn ensure_current_key(
+    address: AccountAddress,
+    account: &AccountResource,
+    key: &RotationKey,
+) -> Result<()> {
+    ensure!(
+        key.authentication_key().to_vec() == account.authentication_key(),
+        "key {} does not match the authentication key of {}",
+        key.authentication_key(),
+        address.to_standard_string(),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        event::{EventHandle, EventKey},
+        test_helpers::private_key,
+    };
+    use aptos_crypto::{
+        ed25519::Ed25519Signature, multi_ed25519::MultiEd25519Signature, Signature,
+    };
+
+    fn account(sequence_number: u64, authentication_key: AuthenticationKey) -> AccountResource {
+        let originator = authentication_key.account_address();
+        AccountResource::new(
+            sequence_number,
+            authentication_key.to_vec(),
+            EventHandle::new(EventKey::new(0, originator), 0),
+            EventHandle::new(EventKey::new(1, originator), 0),
+        )
+    }
+
+    #[test]
+    fn test_challenge_bcs() {
+        let challenge = RotationProofChallenge::new(
+            7,
+            AccountAddress::TWO,
+            AccountAddress::THREE,
+            vec![0xab; 2],
+        );
+        let mut expected = CORE_CODE_ADDRESS.to_vec();
+        expected.extend(bcs::to_bytes(b"account".as_slice()).unwrap());
+        expected.extend(bcs::to_bytes(b"RotationProofChallenge".as_slice()).unwrap());
+        expected.extend(7u64.to_le_bytes());
+        expected.extend(AccountAddress::TWO.to_vec());
+        expected.extend(AccountAddress::THREE.to_vec());
+        expected.extend([2, 0xab, 0xab]);
+        assert_eq!(challenge.signing_message(), expected);
+    }
+
+    #[test]
+    fn test_rotate_ed25519_to_multi_ed25519() {
+        let current = private_key(1);
+        let current_key = RotationKey::Ed25519(&current);
+        let new = MultiEd25519PrivateKey::new(vec![private_key(2), private_key(3)], 1).unwrap();
+        let new_key = RotationKey::MultiEd25519(&new);
+
+        let auth_key = current_key.authentication_key();
+        let originator = auth_key.account_address();
+        let account = account(5, auth_key);
+        let proof = RotationProof::new(originator, &account, current_key, new_key).unwrap();
+        assert_eq!(proof.from_scheme, Scheme::Ed25519 as u8);
+        assert_eq!(proof.to_scheme, Scheme::MultiEd25519 as u8);
+
+        let message = RotationProofChallenge::new(
+            5,
+            originator,
+            originator,
+            MultiEd25519PublicKey::from(&new).to_bytes(),
+        )
+        .signing_message();
+        Ed25519Signature::try_from(proof.cap_rotate_key.as_slice())
+            .unwrap()
+            .verify_arbitrary_msg(&message, &Ed25519PublicKey::from(&current))
+            .unwrap();
+        MultiEd25519Signature::try_from(proof.cap_update_table.as_slice())
+            .unwrap()
+            .verify_arbitrary_msg(&message, &MultiEd25519PublicKey::from(&new))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_offer_capabilities() {
+        let private_key = private_key(1);
+        let key = RotationKey::Ed25519(&private_key);
+        let public_key = Ed25519PublicKey::from(&private_key);
+        let source = key.authentication_key().account_address();
+        let account = account(3, key.authentication_key());
+
+        let proof =
+            CapabilityOfferProof::signer_capability(source, &account, key, AccountAddress::TWO)
+                .unwrap();
+        assert_eq!(proof.account_scheme, Scheme::Ed25519 as u8);
+        let message = SignerCapabilityOfferProofChallengeV2::new(3, source, AccountAddress::TWO)
+            .signing_message();
+        Ed25519Signature::try_from(proof.signature.as_slice())
+            .unwrap()
+            .verify_arbitrary_msg(&message, &public_key)
+            .unwrap();
+
+        let proof = CapabilityOfferProof::rotation_capability(
+            ChainId::testnet(),
+            source,
+            &account,
+            key,
+            AccountAddress::TWO,
+        )
+        .unwrap();
+        let message = RotationCapabilityOfferProofChallengeV2::new(
+            ChainId::testnet(),
+            3,
+            source,
+            AccountAddress::TWO,
+        )
+        .signing_message();
+        let signature = Ed25519Signature::try_from(proof.signature.as_slice()).unwrap();
+        signature
+            .verify_arbitrary_msg(&message, &public_key)
+            .unwrap();
+
+        // Rotation offers are bound to a chain.
+        let message = RotationCapabilityOfferProofChallengeV2::new(
+            ChainId::mainnet(),
+            3,
+            source,
+            AccountAddress::TWO,
+        )
+        .signing_message();
+        assert!(signature
+            .verify_arbitrary_msg(&message, &public_key)
+            .is_err());
+    }
+
+    #[test]
+    fn test_rotate_with_wrong_current_key() {
+        let current = private_key(1);
+        let other = private_key(2);
+        let auth_key = AuthenticationKey::ed25519(&Ed25519PublicKey::from(&current));
+        let account = account(0, auth_key);
+        assert!(RotationProof::new(
+            auth_key.account_address(),
+            &account,
+            RotationKey::Ed25519(&other),
+            RotationKey::Ed25519(&current),
+        )
+        .is_err());
+    }
+}
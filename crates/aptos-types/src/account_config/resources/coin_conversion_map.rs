@@ -0,0 +1,38 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::account_config::Table;
+use move_core_types::{
+    ident_str,
+    identifier::IdentStr,
+    move_resource::{MoveResource, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+/// A Rust representation of `0x1::coin::CoinConversionMap`, stored under the framework account.
+///
+/// Its table maps the `TypeInfo` of each coin type that has been paired with a fungible asset to
+/// the `Object<Metadata>` of that fungible asset.
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CoinConversionMapResource {
+    coin_to_fungible_asset_map: Table,
+}
+
+impl CoinConversionMapResource {
+    pub fn new(coin_to_fungible_asset_map: Table) -> Self {
+        Self {
+            coin_to_fungible_asset_map,
+        }
+    }
+
+    pub fn coin_to_fungible_asset_map(&self) -> &Table {
+        &self.coin_to_fungible_asset_map
+    }
+}
+
+impl MoveStructType for CoinConversionMapResource {
+    const MODULE_NAME: &'static IdentStr = ident_str!("coin");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("CoinConversionMap");
+}
+
+impl MoveResource for CoinConversionMapResource {}
@@ -3,8 +3,10 @@
 
 use crate::{account_config::Table, event::EventHandle};
 use move_core_types::{
+    account_address::AccountAddress,
     ident_str,
     identifier::IdentStr,
+    language_storage::TOKEN_ADDRESS,
     move_resource::{MoveResource, MoveStructType},
 };
 use serde::{Deserialize, Serialize};
@@ -57,6 +59,7 @@ impl CollectionsResource {
 }
 
 impl MoveStructType for CollectionsResource {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
     const MODULE_NAME: &'static IdentStr = ident_str!("token");
     const STRUCT_NAME: &'static IdentStr = ident_str!("Collections");
 }
@@ -74,6 +74,16 @@ impl AccountResource {
     pub fn signer_capability_offer(&self) -> Option<AccountAddress> {
         self.signer_capability_offer
     }
+
+    /// Returns true if the account's rotation capability is currently offered to `recipient`.
+    pub fn is_rotation_capability_offered_to(&self, recipient: AccountAddress) -> bool {
+        self.rotation_capability_offer == Some(recipient)
+    }
+
+    /// Returns true if the account's signer capability is currently offered to `recipient`.
+    pub fn is_signer_capability_offered_to(&self, recipient: AccountAddress) -> bool {
+        self.signer_capability_offer == Some(recipient)
+    }
 }
 
 impl MoveStructType for AccountResource {
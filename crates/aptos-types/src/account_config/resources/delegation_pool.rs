@@ -0,0 +1,176 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::pool_u64::{PoolU64Unbound, SignerCapability};
+use crate::{account_config::Table, event::EventHandle};
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    move_resource::{MoveResource, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+/// A Rust representation of `0x1::delegation_pool::ObservedLockupCycle`, the index of the
+/// lockup cycle of a delegation pool.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct ObservedLockupCycle {
+    index: u64,
+}
+
+impl ObservedLockupCycle {
+    pub fn new(index: u64) -> Self {
+        Self { index }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+impl MoveStructType for ObservedLockupCycle {
+    const MODULE_NAME: &'static IdentStr = ident_str!("delegation_pool");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("ObservedLockupCycle");
+}
+
+/// A Rust representation of `0x1::delegation_pool::DelegationPool`, stored under the address of
+/// the stake pool it owns.
+///
+/// `inactive_shares` maps each `ObservedLockupCycle` to the `PoolU64Unbound` of the stake
+/// unlocked in it, and `pending_withdrawals` maps each delegator to the `ObservedLockupCycle` of
+/// its unlocked stake.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DelegationPoolResource {
+    active_shares: PoolU64Unbound,
+    observed_lockup_cycle: ObservedLockupCycle,
+    inactive_shares: Table,
+    pending_withdrawals: Table,
+    stake_pool_signer_cap: SignerCapability,
+    total_coins_inactive: u64,
+    operator_commission_percentage: u64,
+    add_stake_events: EventHandle,
+    reactivate_stake_events: EventHandle,
+    unlock_stake_events: EventHandle,
+    withdraw_stake_events: EventHandle,
+    distribute_commission_events: EventHandle,
+}
+
+impl DelegationPoolResource {
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes(bytes).map_err(Into::into)
+    }
+
+    /// The shares of the delegators in the active and pending active stake
+    pub fn active_shares(&self) -> &PoolU64Unbound {
+        &self.active_shares
+    }
+
+    pub fn observed_lockup_cycle(&self) -> ObservedLockupCycle {
+        self.observed_lockup_cycle
+    }
+
+    pub fn inactive_shares(&self) -> &Table {
+        &self.inactive_shares
+    }
+
+    pub fn pending_withdrawals(&self) -> &Table {
+        &self.pending_withdrawals
+    }
+
+    /// The address of the stake pool the delegation pool owns
+    pub fn pool_address(&self) -> AccountAddress {
+        self.stake_pool_signer_cap.account()
+    }
+
+    pub fn total_coins_inactive(&self) -> u64 {
+        self.total_coins_inactive
+    }
+
+    /// The commission of the operator, in hundredths of a percent
+    pub fn operator_commission_percentage(&self) -> u64 {
+        self.operator_commission_percentage
+    }
+
+    pub fn add_stake_events(&self) -> &EventHandle {
+        &self.add_stake_events
+    }
+
+    pub fn reactivate_stake_events(&self) -> &EventHandle {
+        &self.reactivate_stake_events
+    }
+
+    pub fn unlock_stake_events(&self) -> &EventHandle {
+        &self.unlock_stake_events
+    }
+
+    pub fn withdraw_stake_events(&self) -> &EventHandle {
+        &self.withdraw_stake_events
+    }
+
+    pub fn distribute_commission_events(&self) -> &EventHandle {
+        &self.distribute_commission_events
+    }
+}
+
+impl MoveStructType for DelegationPoolResource {
+    const MODULE_NAME: &'static IdentStr = ident_str!("delegation_pool");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("DelegationPool");
+}
+
+impl MoveResource for DelegationPoolResource {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::EventKey, state_store::table::TableHandle};
+
+    #[test]
+    fn test_decode_delegation_pool() {
+        let pool_address = AccountAddress::TWO;
+        let table = |n: u8| Table::new(TableHandle(AccountAddress::new([n; 32])));
+        let events: [EventHandle; 5] = std::array::from_fn(|creation_number| {
+            EventHandle::new(EventKey::new(creation_number as u64, pool_address), 0)
+        });
+        let bytes = bcs::to_bytes(&(
+            PoolU64Unbound::new(1_000_000, 100_000_000, table(0xa), 1),
+            ObservedLockupCycle::new(7),
+            table(0xb),
+            table(0xc),
+            SignerCapability::new(pool_address),
+            500u64,
+            1_000u64,
+            events,
+        ))
+        .unwrap();
+
+        let pool = DelegationPoolResource::try_from_bytes(&bytes).unwrap();
+        assert_eq!(pool.pool_address(), pool_address);
+        assert_eq!(pool.active_shares().total_coins(), 1_000_000);
+        assert_eq!(
+            pool.active_shares().shares().handle(),
+            TableHandle(AccountAddress::new([0xa; 32]))
+        );
+        assert_eq!(pool.active_shares().shares_to_amount(1_000_000), 10_000);
+        assert_eq!(pool.observed_lockup_cycle().index(), 7);
+        assert_eq!(
+            pool.inactive_shares().handle(),
+            TableHandle(AccountAddress::new([0xb; 32]))
+        );
+        assert_eq!(
+            pool.pending_withdrawals().handle(),
+            TableHandle(AccountAddress::new([0xc; 32]))
+        );
+        assert_eq!(pool.total_coins_inactive(), 500);
+        assert_eq!(pool.operator_commission_percentage(), 1_000);
+        assert_eq!(
+            pool.distribute_commission_events().key(),
+            &EventKey::new(4, pool_address)
+        );
+        assert_eq!(bcs::to_bytes(&pool).unwrap(), bytes);
+        assert_eq!(
+            DelegationPoolResource::struct_tag().to_string(),
+            "0x1::delegation_pool::DelegationPool"
+        );
+    }
+}
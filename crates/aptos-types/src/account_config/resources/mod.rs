@@ -6,38 +6,54 @@ pub mod aggregator;
 pub mod any;
 pub mod chain_id;
 pub mod challenge;
+pub mod coin_conversion_map;
 pub mod coin_info;
 pub mod coin_store;
 pub mod collection;
 pub mod collections;
 pub mod core_account;
+pub mod delegation_pool;
 pub mod fixed_supply;
 pub mod fungible_asset_metadata;
 pub mod fungible_store;
 pub mod object;
+pub mod package_metadata;
 pub mod pending_claims;
+pub mod pool_u64;
+pub mod stake_pool;
+pub mod staking_contract;
 pub mod token;
 pub mod token_event_store_v1;
 pub mod token_store;
+pub mod token_v1;
 pub mod type_info;
 pub mod unlimited_supply;
+pub mod vesting;
 
 pub use aggregator::*;
 pub use any::*;
 pub use chain_id::*;
 pub use challenge::*;
+pub use coin_conversion_map::*;
 pub use coin_info::*;
 pub use coin_store::*;
 pub use collection::*;
 pub use collections::*;
 pub use core_account::*;
+pub use delegation_pool::*;
 pub use fixed_supply::*;
 pub use fungible_asset_metadata::*;
 pub use fungible_store::*;
 pub use object::*;
+pub use package_metadata::*;
 pub use pending_claims::*;
+pub use pool_u64::*;
+pub use stake_pool::*;
+pub use staking_contract::*;
 pub use token::*;
 pub use token_event_store_v1::*;
 pub use token_store::*;
+pub use token_v1::*;
 pub use type_info::*;
 pub use unlimited_supply::*;
+pub use vesting::*;
@@ -6,13 +6,13 @@ use move_core_types::{
     account_address::AccountAddress,
     ident_str,
     identifier::IdentStr,
-    language_storage::StructTag,
+    language_storage::{StructTag, TypeTag},
     move_resource::{MoveResource, MoveStructType},
 };
 // #[cfg(any(test, feature = "fuzzing"))]
 // use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
 
 /// A Rust representation of ObjectGroup.
 #[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Default)]
@@ -74,17 +74,76 @@ impl MoveStructType for ObjectCoreResource {
 
 impl MoveResource for ObjectCoreResource {}
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Object {
+/// A reference to an object of type `T`, i.e. `0x1::object::Object<T>`. Only the address is
+/// stored on chain, so `T` only affects the type tag.
+#[derive(Serialize, Deserialize)]
+pub struct Object<T = ObjectCoreResource> {
     inner: AccountAddress,
+    #[serde(skip)]
+    phantom: PhantomData<T>,
 }
 
-impl Object {
+impl<T> Object<T> {
     pub fn new(inner: AccountAddress) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
     }
 
     pub fn inner(&self) -> &AccountAddress {
         &self.inner
     }
 }
+
+impl<T> fmt::Debug for Object<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Object")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T> Clone for Object<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner)
+    }
+}
+
+impl<T> PartialEq for Object<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T> Eq for Object<T> {}
+
+impl<T: MoveStructType> MoveStructType for Object<T> {
+    const MODULE_NAME: &'static IdentStr = ident_str!("object");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Object");
+
+    fn type_args() -> Vec<TypeTag> {
+        vec![TypeTag::Struct(Box::new(T::struct_tag()))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::move_resource::MoveTypeTag;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_object_type_tag() {
+        assert_eq!(
+            Object::<ObjectCoreResource>::type_tag(),
+            TypeTag::from_str("0x1::object::Object<0x1::object::ObjectCore>").unwrap()
+        );
+
+        let object = Object::<ObjectCoreResource>::new(AccountAddress::ONE);
+        assert_eq!(
+            bcs::to_bytes(&object).unwrap(),
+            bcs::to_bytes(&AccountAddress::ONE).unwrap()
+        );
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::any::AnyResource;
+use anyhow::{bail, Result};
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    move_resource::{MoveResource, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A Rust representation of `0x1::code::PackageRegistry`, the packages published under an
+/// account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PackageRegistryResource {
+    pub packages: Vec<PackageMetadata>,
+}
+
+impl PackageRegistryResource {
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes(bytes).map_err(Into::into)
+    }
+
+    pub fn package(&self, name: &str) -> Option<&PackageMetadata> {
+        self.packages.iter().find(|package| package.name == name)
+    }
+}
+
+impl MoveStructType for PackageRegistryResource {
+    const MODULE_NAME: &'static IdentStr = ident_str!("code");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PackageRegistry");
+}
+
+impl MoveResource for PackageRegistryResource {}
+
+/// A Rust representation of `0x1::code::PackageMetadata`. Its BCS encoding is the
+/// `metadata_serialized` argument of the entry functions that publish packages.
+///
+/// The framework does not interpret `manifest`, `source` and `source_map`, which the Move
+/// compiler fills with gzip-compressed files, so they can be left empty. The module names, on
+/// the other hand, must match the names of the published modules.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PackageMetadata {
+    pub name: String,
+    pub upgrade_policy: UpgradePolicy,
+    /// The number of times the package has been upgraded, which the framework sets on publish
+    pub upgrade_number: u64,
+    pub source_digest: String,
+    #[serde(with = "serde_bytes")]
+    pub manifest: Vec<u8>,
+    pub modules: Vec<ModuleMetadata>,
+    pub deps: Vec<PackageDep>,
+    pub extension: Option<AnyResource>,
+}
+
+impl PackageMetadata {
+    /// Metadata of a package without sources or dependencies
+    pub fn new(name: String, upgrade_policy: UpgradePolicy, modules: Vec<ModuleMetadata>) -> Self {
+        Self {
+            name,
+            upgrade_policy,
+            upgrade_number: 0,
+            source_digest: String::new(),
+            manifest: vec![],
+            modules,
+            deps: vec![],
+            extension: None,
+        }
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes(bytes).map_err(Into::into)
+    }
+
+    /// The BCS encoding the publishing entry functions take as `metadata_serialized`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("PackageMetadata is BCS encodable")
+    }
+}
+
+impl MoveStructType for PackageMetadata {
+    const MODULE_NAME: &'static IdentStr = ident_str!("code");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PackageMetadata");
+}
+
+/// A Rust representation of `0x1::code::PackageDep`, a package the published package depends on
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PackageDep {
+    pub account: AccountAddress,
+    pub package_name: String,
+}
+
+impl MoveStructType for PackageDep {
+    const MODULE_NAME: &'static IdentStr = ident_str!("code");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PackageDep");
+}
+
+/// A Rust representation of `0x1::code::ModuleMetadata`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ModuleMetadata {
+    pub name: String,
+    #[serde(with = "serde_bytes")]
+    pub source: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub source_map: Vec<u8>,
+    pub extension: Option<AnyResource>,
+}
+
+impl ModuleMetadata {
+    /// Metadata of a module without its source
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            source: vec![],
+            source_map: vec![],
+            extension: None,
+        }
+    }
+}
+
+impl MoveStructType for ModuleMetadata {
+    const MODULE_NAME: &'static IdentStr = ident_str!("code");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("ModuleMetadata");
+}
+
+/// A Rust representation of `0x1::code::UpgradePolicy`. The framework no longer accepts
+/// packages published with the arbitrary policy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct UpgradePolicy {
+    pub policy: u8,
+}
+
+impl UpgradePolicy {
+    /// Upgrades may change the package in any way
+    pub fn arbitrary() -> Self {
+        Self { policy: 0 }
+    }
+
+    /// Upgrades must keep the package compatible with its previous versions
+    pub fn compat() -> Self {
+        Self { policy: 1 }
+    }
+
+    /// The package cannot be upgraded
+    pub fn immutable() -> Self {
+        Self { policy: 2 }
+    }
+}
+
+impl FromStr for UpgradePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "arbitrary" => Self::arbitrary(),
+            "compatible" => Self::compat(),
+            "immutable" => Self::immutable(),
+            _ => bail!("unknown upgrade policy: {}", s),
+        })
+    }
+}
+
+impl fmt::Display for UpgradePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self.policy {
+            0 => "arbitrary",
+            1 => "compatible",
+            _ => "immutable",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_package_metadata_bcs() {
+        let metadata = PackageMetadata::new(
+            "Vault".to_string(),
+            UpgradePolicy::compat(),
+            vec![ModuleMetadata::new("vault".to_string())],
+        );
+        let bytes = metadata.to_bytes();
+        #[rustfmt::skip]
+        let expected = vec![
+            5, b'V', b'a', b'u', b'l', b't', // name
+            1, // upgrade_policy
+            0, 0, 0, 0, 0, 0, 0, 0, // upgrade_number
+            0, // source_digest
+            0, // manifest
+            1, 5, b'v', b'a', b'u', b'l', b't', 0, 0, 0, // modules
+            0, // deps
+            0, // extension
+        ];
+        assert_eq!(bytes, expected);
+        assert_eq!(PackageMetadata::try_from_bytes(&bytes).unwrap(), metadata);
+
+        let registry = PackageRegistryResource {
+            packages: vec![metadata.clone()],
+        };
+        let registry =
+            PackageRegistryResource::try_from_bytes(&bcs::to_bytes(&registry).unwrap()).unwrap();
+        assert_eq!(registry.package("Vault"), Some(&metadata));
+    }
+
+    #[test]
+    fn test_upgrade_policy() {
+        for policy in ["arbitrary", "compatible", "immutable"] {
+            assert_eq!(policy.parse::<UpgradePolicy>().unwrap().to_string(), policy);
+        }
+        assert!("frozen".parse::<UpgradePolicy>().is_err());
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::account_config::Table;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, move_resource::MoveStructType,
+};
+use serde::{Deserialize, Serialize};
+
+/// A Rust representation of `0x1::pool_u64::Pool`, the share pool that staking contracts and
+/// vesting contracts split their coins with.
+///
+/// The `shares` field is a Move `SimpleMap<address, u64>`, which has the same BCS layout as a
+/// vector of tuples.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PoolU64 {
+    shareholders_limit: u64,
+    total_coins: u64,
+    total_shares: u64,
+    shares: Vec<(AccountAddress, u64)>,
+    shareholders: Vec<AccountAddress>,
+    scaling_factor: u64,
+}
+
+impl PoolU64 {
+    pub fn new(
+        shareholders_limit: u64,
+        total_coins: u64,
+        total_shares: u64,
+        shares: Vec<(AccountAddress, u64)>,
+        scaling_factor: u64,
+    ) -> Self {
+        let shareholders = shares.iter().map(|(shareholder, _)| *shareholder).collect();
+        Self {
+            shareholders_limit,
+            total_coins,
+            total_shares,
+            shares,
+            shareholders,
+            scaling_factor,
+        }
+    }
+
+    pub fn shareholders_limit(&self) -> u64 {
+        self.shareholders_limit
+    }
+
+    pub fn total_coins(&self) -> u64 {
+        self.total_coins
+    }
+
+    pub fn total_shares(&self) -> u64 {
+        self.total_shares
+    }
+
+    pub fn shareholders(&self) -> &[AccountAddress] {
+        &self.shareholders
+    }
+
+    pub fn scaling_factor(&self) -> u64 {
+        self.scaling_factor
+    }
+
+    /// The shares of `shareholder`, or 0 if it is not in the pool
+    pub fn shares(&self, shareholder: &AccountAddress) -> u64 {
+        self.shares
+            .iter()
+            .find(|(address, _)| address == shareholder)
+            .map_or(0, |(_, shares)| *shares)
+    }
+
+    /// The coins `shareholder` can redeem, as `0x1::pool_u64::balance` computes them
+    pub fn balance(&self, shareholder: &AccountAddress) -> u64 {
+        self.shares_to_amount(self.shares(shareholder))
+    }
+
+    /// The coins `shares` are worth, as `0x1::pool_u64::shares_to_amount` computes them
+    pub fn shares_to_amount(&self, shares: u64) -> u64 {
+        shares_to_amount(shares as u128, self.total_coins, self.total_shares as u128)
+    }
+}
+
+impl MoveStructType for PoolU64 {
+    const MODULE_NAME: &'static IdentStr = ident_str!("pool_u64");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Pool");
+}
+
+/// A Rust representation of `0x1::pool_u64_unbound::Pool`, the share pool of delegation pools.
+///
+/// Its shares are kept in a table of `address` to `u128`, so the shares of a delegator have to
+/// be fetched as a table item before [`Self::shares_to_amount`] can value them.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PoolU64Unbound {
+    total_coins: u64,
+    total_shares: u128,
+    shares: Table,
+    scaling_factor: u64,
+}
+
+impl PoolU64Unbound {
+    pub fn new(total_coins: u64, total_shares: u128, shares: Table, scaling_factor: u64) -> Self {
+        Self {
+            total_coins,
+            total_shares,
+            shares,
+            scaling_factor,
+        }
+    }
+
+    pub fn total_coins(&self) -> u64 {
+        self.total_coins
+    }
+
+    pub fn total_shares(&self) -> u128 {
+        self.total_shares
+    }
+
+    pub fn shares(&self) -> &Table {
+        &self.shares
+    }
+
+    pub fn scaling_factor(&self) -> u64 {
+        self.scaling_factor
+    }
+
+    /// The coins `shares` are worth, as `0x1::pool_u64_unbound::shares_to_amount` computes them
+    pub fn shares_to_amount(&self, shares: u128) -> u64 {
+        shares_to_amount(shares, self.total_coins, self.total_shares)
+    }
+}
+
+impl MoveStructType for PoolU64Unbound {
+    const MODULE_NAME: &'static IdentStr = ident_str!("pool_u64_unbound");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Pool");
+}
+
+/// Both pools value shares by rounding `shares * total_coins / total_shares` down, and value
+/// everything at 0 while the pool is empty.
+fn shares_to_amount(shares: u128, total_coins: u64, total_shares: u128) -> u64 {
+    if total_coins == 0 || total_shares == 0 {
+        return 0;
+    }
+    // The result is at most `total_coins` as long as `shares <= total_shares`.
+    (shares * total_coins as u128 / total_shares) as u64
+}
+
+/// A Rust representation of `0x1::account::SignerCapability`, which lets a module sign for a
+/// resource account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SignerCapability {
+    account: AccountAddress,
+}
+
+impl SignerCapability {
+    pub fn new(account: AccountAddress) -> Self {
+        Self { account }
+    }
+
+    pub fn account(&self) -> AccountAddress {
+        self.account
+    }
+}
+
+impl MoveStructType for SignerCapability {
+    const MODULE_NAME: &'static IdentStr = ident_str!("account");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SignerCapability");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_balance() {
+        let alice = AccountAddress::TWO;
+        let bob = AccountAddress::THREE;
+        let pool = PoolU64::new(30, 1_000, 300, vec![(alice, 100), (bob, 200)], 1);
+
+        assert_eq!(pool.shareholders(), &[alice, bob]);
+        assert_eq!(pool.balance(&alice), 333);
+        assert_eq!(pool.balance(&bob), 666);
+        assert_eq!(pool.balance(&AccountAddress::FOUR), 0);
+
+        let decoded: PoolU64 = bcs::from_bytes(&bcs::to_bytes(&pool).unwrap()).unwrap();
+        assert_eq!(decoded, pool);
+        assert_eq!(PoolU64::struct_tag().to_string(), "0x1::pool_u64::Pool");
+    }
+
+    #[test]
+    fn test_empty_pool() {
+        let pool = PoolU64::new(30, 0, 0, vec![], 1);
+        assert_eq!(pool.shares_to_amount(100), 0);
+    }
+}
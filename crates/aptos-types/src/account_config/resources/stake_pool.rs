@@ -0,0 +1,388 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::event::EventHandle;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    move_resource::{MoveResource, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+/// The status of a stake pool in the validator set, as returned by
+/// `0x1::stake::get_validator_state`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValidatorStatus {
+    PendingActive = 1,
+    Active = 2,
+    PendingInactive = 3,
+    Inactive = 4,
+}
+
+impl TryFrom<u64> for ValidatorStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(status: u64) -> Result<Self> {
+        Ok(match status {
+            1 => Self::PendingActive,
+            2 => Self::Active,
+            3 => Self::PendingInactive,
+            4 => Self::Inactive,
+            _ => anyhow::bail!("invalid validator status: {}", status),
+        })
+    }
+}
+
+/// A Rust representation of `0x1::stake::StakePool`, stored under the pool address.
+///
+/// Each `Coin<AptosCoin>` of the Move struct only holds its value, so it is kept as a `u64`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StakePoolResource {
+    active: u64,
+    inactive: u64,
+    pending_active: u64,
+    pending_inactive: u64,
+    locked_until_secs: u64,
+    operator_address: AccountAddress,
+    delegated_voter: AccountAddress,
+    initialize_validator_events: EventHandle,
+    set_operator_events: EventHandle,
+    add_stake_events: EventHandle,
+    reactivate_stake_events: EventHandle,
+    rotate_consensus_key_events: EventHandle,
+    update_network_and_fullnode_addresses_events: EventHandle,
+    increase_lockup_events: EventHandle,
+    join_validator_set_events: EventHandle,
+    distribute_rewards_events: EventHandle,
+    unlock_stake_events: EventHandle,
+    withdraw_stake_events: EventHandle,
+    leave_validator_set_events: EventHandle,
+}
+
+impl StakePoolResource {
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes(bytes).map_err(Into::into)
+    }
+
+    /// Stake that is earning rewards and counts towards voting power
+    pub fn active(&self) -> u64 {
+        self.active
+    }
+
+    /// Stake that can be withdrawn
+    pub fn inactive(&self) -> u64 {
+        self.inactive
+    }
+
+    /// Stake that becomes active at the next epoch
+    pub fn pending_active(&self) -> u64 {
+        self.pending_active
+    }
+
+    /// Unlocked stake that still earns rewards until the lockup ends
+    pub fn pending_inactive(&self) -> u64 {
+        self.pending_inactive
+    }
+
+    pub fn locked_until_secs(&self) -> u64 {
+        self.locked_until_secs
+    }
+
+    pub fn operator_address(&self) -> AccountAddress {
+        self.operator_address
+    }
+
+    pub fn delegated_voter(&self) -> AccountAddress {
+        self.delegated_voter
+    }
+
+    /// All the stake of the pool, including stake that is pending or withdrawable. It is a
+    /// `u128` as the four amounts can add up to more than a `u64` holds.
+    pub fn total_stake(&self) -> u128 {
+        self.active as u128
+            + self.inactive as u128
+            + self.pending_active as u128
+            + self.pending_inactive as u128
+    }
+
+    /// When the lockup ends, in seconds. It is renewed at the end of the epoch it lapses in for
+    /// as long as the pool is in the validator set.
+    pub fn lockup_end_secs(&self) -> u64 {
+        self.locked_until_secs
+    }
+
+    /// The seconds left until the lockup ends, or 0 if it has ended by `now_secs`
+    pub fn remaining_lockup_secs(&self, now_secs: u64) -> u64 {
+        self.locked_until_secs.saturating_sub(now_secs)
+    }
+
+    /// The stake `0x1::stake::withdraw` would release at `now_secs`. Pending inactive stake is
+    /// only withdrawable immediately once the pool has left the validator set and its lockup has
+    /// ended; otherwise it becomes inactive at the end of the epoch the lockup ends in.
+    pub fn withdrawable_stake(&self, status: ValidatorStatus, now_secs: u64) -> u64 {
+        if status == ValidatorStatus::Inactive && now_secs >= self.locked_until_secs {
+            self.inactive + self.pending_inactive
+        } else {
+            self.inactive
+        }
+    }
+
+    /// The rewards the pool earns at the end of the epoch, as `0x1::stake::distribute_rewards`
+    /// computes them for its active and pending inactive stake. The rewards rate comes from
+    /// `0x1::staking_config::get_reward_rate`, and the proposals from the validator's
+    /// performance in the epoch.
+    pub fn pending_rewards(
+        &self,
+        num_successful_proposals: u64,
+        num_total_proposals: u64,
+        rewards_rate: u64,
+        rewards_rate_denominator: u64,
+    ) -> u64 {
+        [self.active, self.pending_inactive]
+            .into_iter()
+            .map(|stake_amount| {
+                calculate_rewards_amount(
+                    stake_amount,
+                    num_successful_proposals,
+                    num_total_proposals,
+                    rewards_rate,
+                    rewards_rate_denominator,
+                )
+            })
+            .sum()
+    }
+
+    pub fn initialize_validator_events(&self) -> &EventHandle {
+        &self.initialize_validator_events
+    }
+
+    pub fn set_operator_events(&self) -> &EventHandle {
+        &self.set_operator_events
+    }
+
+    pub fn add_stake_events(&self) -> &EventHandle {
+        &self.add_stake_events
+    }
+
+    pub fn reactivate_stake_events(&self) -> &EventHandle {
+        &self.reactivate_stake_events
+    }
+
+    pub fn rotate_consensus_key_events(&self) -> &EventHandle {
+        &self.rotate_consensus_key_events
+    }
+
+    pub fn update_network_and_fullnode_addresses_events(&self) -> &EventHandle {
+        &self.update_network_and_fullnode_addresses_events
+    }
+
+    pub fn increase_lockup_events(&self) -> &EventHandle {
+        &self.increase_lockup_events
+    }
+
+    pub fn join_validator_set_events(&self) -> &EventHandle {
+        &self.join_validator_set_events
+    }
+
+    pub fn distribute_rewards_events(&self) -> &EventHandle {
+        &self.distribute_rewards_events
+    }
+
+    pub fn unlock_stake_events(&self) -> &EventHandle {
+        &self.unlock_stake_events
+    }
+
+    pub fn withdraw_stake_events(&self) -> &EventHandle {
+        &self.withdraw_stake_events
+    }
+
+    pub fn leave_validator_set_events(&self) -> &EventHandle {
+        &self.leave_validator_set_events
+    }
+}
+
+impl MoveStructType for StakePoolResource {
+    const MODULE_NAME: &'static IdentStr = ident_str!("stake");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("StakePool");
+}
+
+impl MoveResource for StakePoolResource {}
+
+/// Mirrors `0x1::stake::calculate_rewards_amount`, which rounds down and pays nothing when no
+/// proposals were expected.
+fn calculate_rewards_amount(
+    stake_amount: u64,
+    num_successful_proposals: u64,
+    num_total_proposals: u64,
+    rewards_rate: u64,
+    rewards_rate_denominator: u64,
+) -> u64 {
+    let numerator = stake_amount as u128 * rewards_rate as u128 * num_successful_proposals as u128;
+    let denominator = rewards_rate_denominator as u128 * num_total_proposals as u128;
+    if denominator > 0 {
+        (numerator / denominator) as u64
+    } else {
+        0
+    }
+}
+
+/// A Rust representation of `0x1::stake::ValidatorConfig`, stored under the pool address.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorConfigResource {
+    consensus_pubkey: Vec<u8>,
+    network_addresses: Vec<u8>,
+    fullnode_addresses: Vec<u8>,
+    validator_index: u64,
+}
+
+impl ValidatorConfigResource {
+    pub fn new(
+        consensus_pubkey: Vec<u8>,
+        network_addresses: Vec<u8>,
+        fullnode_addresses: Vec<u8>,
+        validator_index: u64,
+    ) -> Self {
+        Self {
+            consensus_pubkey,
+            network_addresses,
+            fullnode_addresses,
+            validator_index,
+        }
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes(bytes).map_err(Into::into)
+    }
+
+    /// The BLS12-381 public key of the validator
+    pub fn consensus_pubkey(&self) -> &[u8] {
+        &self.consensus_pubkey
+    }
+
+    /// The BCS-encoded network addresses of the validator
+    pub fn network_addresses(&self) -> &[u8] {
+        &self.network_addresses
+    }
+
+    /// The BCS-encoded network addresses of the validator's fullnode
+    pub fn fullnode_addresses(&self) -> &[u8] {
+        &self.fullnode_addresses
+    }
+
+    pub fn validator_index(&self) -> u64 {
+        self.validator_index
+    }
+}
+
+impl MoveStructType for ValidatorConfigResource {
+    const MODULE_NAME: &'static IdentStr = ident_str!("stake");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("ValidatorConfig");
+}
+
+impl MoveResource for ValidatorConfigResource {}
+
+/// A Rust representation of `0x1::stake::OwnerCapability`, which lets its holder manage the
+/// stake of the pool at `pool_address`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OwnerCapability {
+    pool_address: AccountAddress,
+}
+
+impl OwnerCapability {
+    pub fn new(pool_address: AccountAddress) -> Self {
+        Self { pool_address }
+    }
+
+    pub fn pool_address(&self) -> AccountAddress {
+        self.pool_address
+    }
+}
+
+impl MoveStructType for OwnerCapability {
+    const MODULE_NAME: &'static IdentStr = ident_str!("stake");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("OwnerCapability");
+}
+
+impl MoveResource for OwnerCapability {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventKey;
+
+    fn stake_pool(locked_until_secs: u64) -> StakePoolResource {
+        let pool_address = AccountAddress::TWO;
+        let events: [EventHandle; 12] = std::array::from_fn(|creation_number| {
+            EventHandle::new(EventKey::new(creation_number as u64, pool_address), 0)
+        });
+        let bytes = bcs::to_bytes(&(
+            1_000_000u64,
+            500u64,
+            2_000u64,
+            10_000u64,
+            locked_until_secs,
+            AccountAddress::THREE,
+            AccountAddress::FOUR,
+            events,
+        ))
+        .unwrap();
+        StakePoolResource::try_from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_decode_stake_pool() {
+        let pool = stake_pool(1_700_000_000);
+        assert_eq!(pool.active(), 1_000_000);
+        assert_eq!(pool.pending_inactive(), 10_000);
+        assert_eq!(pool.total_stake(), 1_012_500);
+        assert_eq!(pool.operator_address(), AccountAddress::THREE);
+        assert_eq!(pool.delegated_voter(), AccountAddress::FOUR);
+        assert_eq!(
+            pool.leave_validator_set_events().key(),
+            &EventKey::new(11, AccountAddress::TWO)
+        );
+
+        // The total of a pool holding close to the whole supply does not wrap around.
+        let pool = StakePoolResource {
+            active: u64::MAX,
+            ..pool
+        };
+        assert_eq!(pool.total_stake(), u64::MAX as u128 + 12_500);
+    }
+
+    #[test]
+    fn test_stake_pool_calculators() {
+        let pool = stake_pool(1_700_000_000);
+        assert_eq!(pool.lockup_end_secs(), 1_700_000_000);
+        assert_eq!(pool.remaining_lockup_secs(1_699_999_000), 1_000);
+        assert_eq!(pool.remaining_lockup_secs(1_700_000_001), 0);
+
+        assert_eq!(
+            pool.withdrawable_stake(ValidatorStatus::Active, 1_700_000_001),
+            500
+        );
+        assert_eq!(
+            pool.withdrawable_stake(ValidatorStatus::Inactive, 1_699_999_999),
+            500
+        );
+        assert_eq!(
+            pool.withdrawable_stake(ValidatorStatus::Inactive, 1_700_000_000),
+            10_500
+        );
+
+        // 1% per epoch with 9 of 10 proposals: 9_000 on active and 90 on pending inactive stake
+        assert_eq!(pool.pending_rewards(9, 10, 1, 100), 9_090);
+        assert_eq!(pool.pending_rewards(0, 0, 1, 100), 0);
+    }
+
+    #[test]
+    fn test_validator_status() {
+        assert_eq!(
+            ValidatorStatus::try_from(4).unwrap(),
+            ValidatorStatus::Inactive
+        );
+        assert!(ValidatorStatus::try_from(5).is_err());
+    }
+}
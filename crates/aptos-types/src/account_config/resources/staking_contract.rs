@@ -0,0 +1,183 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    pool_u64::{PoolU64, SignerCapability},
+    stake_pool::OwnerCapability,
+};
+use crate::event::EventHandle;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    move_resource::{MoveResource, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+/// A Rust representation of `0x1::staking_contract::StakingContract`, the contract between a
+/// staker and one of its operators.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StakingContract {
+    principal: u64,
+    pool_address: AccountAddress,
+    owner_cap: OwnerCapability,
+    commission_percentage: u64,
+    distribution_pool: PoolU64,
+    signer_cap: SignerCapability,
+}
+
+impl StakingContract {
+    /// The stake the staker has put in, which the operator earns no commission on
+    pub fn principal(&self) -> u64 {
+        self.principal
+    }
+
+    pub fn pool_address(&self) -> AccountAddress {
+        self.pool_address
+    }
+
+    pub fn owner_cap(&self) -> &OwnerCapability {
+        &self.owner_cap
+    }
+
+    /// The commission of the operator, in percent
+    pub fn commission_percentage(&self) -> u64 {
+        self.commission_percentage
+    }
+
+    /// The unlocked coins waiting to be distributed to the staker and the operator
+    pub fn distribution_pool(&self) -> &PoolU64 {
+        &self.distribution_pool
+    }
+
+    pub fn signer_cap(&self) -> &SignerCapability {
+        &self.signer_cap
+    }
+}
+
+impl MoveStructType for StakingContract {
+    const MODULE_NAME: &'static IdentStr = ident_str!("staking_contract");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("StakingContract");
+}
+
+/// A Rust representation of `0x1::staking_contract::Store`, stored under the staker's account.
+///
+/// The `staking_contracts` field is a Move `SimpleMap<address, StakingContract>` keyed by
+/// operator, which has the same BCS layout as a vector of tuples.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StakingContractStoreResource {
+    staking_contracts: Vec<(AccountAddress, StakingContract)>,
+    create_staking_contract_events: EventHandle,
+    update_voter_events: EventHandle,
+    reset_lockup_events: EventHandle,
+    add_stake_events: EventHandle,
+    request_commission_events: EventHandle,
+    unlock_stake_events: EventHandle,
+    switch_operator_events: EventHandle,
+    add_distribution_events: EventHandle,
+    distribute_events: EventHandle,
+}
+
+impl StakingContractStoreResource {
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes(bytes).map_err(Into::into)
+    }
+
+    /// The staking contract with `operator`, if any
+    pub fn staking_contract(&self, operator: &AccountAddress) -> Option<&StakingContract> {
+        self.staking_contracts
+            .iter()
+            .find(|(address, _)| address == operator)
+            .map(|(_, contract)| contract)
+    }
+
+    /// The staking contracts of the staker, by operator
+    pub fn staking_contracts(&self) -> impl Iterator<Item = (&AccountAddress, &StakingContract)> {
+        self.staking_contracts
+            .iter()
+            .map(|(operator, contract)| (operator, contract))
+    }
+
+    pub fn create_staking_contract_events(&self) -> &EventHandle {
+        &self.create_staking_contract_events
+    }
+
+    pub fn update_voter_events(&self) -> &EventHandle {
+        &self.update_voter_events
+    }
+
+    pub fn reset_lockup_events(&self) -> &EventHandle {
+        &self.reset_lockup_events
+    }
+
+    pub fn add_stake_events(&self) -> &EventHandle {
+        &self.add_stake_events
+    }
+
+    pub fn request_commission_events(&self) -> &EventHandle {
+        &self.request_commission_events
+    }
+
+    pub fn unlock_stake_events(&self) -> &EventHandle {
+        &self.unlock_stake_events
+    }
+
+    pub fn switch_operator_events(&self) -> &EventHandle {
+        &self.switch_operator_events
+    }
+
+    pub fn add_distribution_events(&self) -> &EventHandle {
+        &self.add_distribution_events
+    }
+
+    pub fn distribute_events(&self) -> &EventHandle {
+        &self.distribute_events
+    }
+}
+
+impl MoveStructType for StakingContractStoreResource {
+    const MODULE_NAME: &'static IdentStr = ident_str!("staking_contract");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Store");
+}
+
+impl MoveResource for StakingContractStoreResource {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventKey;
+
+    #[test]
+    fn test_decode_staking_contract_store() {
+        let staker = AccountAddress::TWO;
+        let operator = AccountAddress::THREE;
+        let pool_address = AccountAddress::FOUR;
+        let contract = (
+            10_000u64,
+            pool_address,
+            OwnerCapability::new(pool_address),
+            10u64,
+            PoolU64::new(20, 1_000, 1_000, vec![(operator, 100), (staker, 900)], 1),
+            SignerCapability::new(pool_address),
+        );
+        let events: [EventHandle; 9] = std::array::from_fn(|creation_number| {
+            EventHandle::new(EventKey::new(creation_number as u64, staker), 0)
+        });
+        // A `SimpleMap` only holds a vector of key-value structs.
+        let bytes = bcs::to_bytes(&(vec![(operator, contract)], events)).unwrap();
+
+        let store = StakingContractStoreResource::try_from_bytes(&bytes).unwrap();
+        assert_eq!(store.staking_contracts().count(), 1);
+        assert!(store.staking_contract(&staker).is_none());
+        let contract = store.staking_contract(&operator).unwrap();
+        assert_eq!(contract.principal(), 10_000);
+        assert_eq!(contract.pool_address(), pool_address);
+        assert_eq!(contract.owner_cap().pool_address(), pool_address);
+        assert_eq!(contract.commission_percentage(), 10);
+        assert_eq!(contract.distribution_pool().balance(&operator), 100);
+        assert_eq!(contract.distribution_pool().balance(&staker), 900);
+        assert_eq!(contract.signer_cap().account(), pool_address);
+        assert_eq!(store.distribute_events().key(), &EventKey::new(8, staker));
+    }
+}
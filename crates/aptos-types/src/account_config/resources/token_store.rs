@@ -1,11 +1,12 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::event::EventHandle;
+use crate::{event::EventHandle, state_store::table::TableHandle};
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
     identifier::IdentStr,
+    language_storage::TOKEN_ADDRESS,
     move_resource::{MoveResource, MoveStructType},
 };
 use serde::{Deserialize, Serialize};
@@ -20,11 +21,22 @@ pub struct TokenStoreResource {
     mutate_token_property_events: EventHandle,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The `0x1::table::Table` a resource holds, which only stores the handle its items live under.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Table {
     handle: AccountAddress,
 }
 
+impl Table {
+    pub fn new(handle: TableHandle) -> Self {
+        Self { handle: handle.0 }
+    }
+
+    pub fn handle(&self) -> TableHandle {
+        TableHandle(self.handle)
+    }
+}
+
 impl TokenStoreResource {
     pub fn new(
         tokens: Table,
@@ -70,6 +82,7 @@ impl TokenStoreResource {
 }
 
 impl MoveStructType for TokenStoreResource {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
     const MODULE_NAME: &'static IdentStr = ident_str!("token");
     const STRUCT_NAME: &'static IdentStr = ident_str!("TokenStore");
 }
@@ -0,0 +1,283 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The values stored in the tables of the token v1 standard (`0x3::token`): the tokens held in a
+//! `TokenStore`, and the token and collection data held in a creator's `Collections`.
+
+use crate::account_config::{PropertyValue, TokenId, TokenMutabilityConfig};
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    language_storage::TOKEN_ADDRESS,
+    move_resource::MoveStructType,
+};
+use serde::{Deserialize, Serialize};
+
+/// A Rust representation of `0x3::token::Token`, the value type of `TokenStore.tokens`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TokenV1 {
+    id: TokenId,
+    amount: u64,
+    token_properties: PropertyMap,
+}
+
+impl TokenV1 {
+    pub fn new(id: TokenId, amount: u64, token_properties: PropertyMap) -> Self {
+        Self {
+            id,
+            amount,
+            token_properties,
+        }
+    }
+
+    pub fn id(&self) -> &TokenId {
+        &self.id
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn token_properties(&self) -> &PropertyMap {
+        &self.token_properties
+    }
+}
+
+impl MoveStructType for TokenV1 {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("token");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Token");
+}
+
+/// A Rust representation of `0x3::token::TokenData`, the value type of `Collections.token_data`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TokenDataV1 {
+    maximum: u64,
+    largest_property_version: u64,
+    supply: u64,
+    uri: String,
+    royalty: RoyaltyV1,
+    name: String,
+    description: String,
+    default_properties: PropertyMap,
+    mutability_config: TokenMutabilityConfig,
+}
+
+impl TokenDataV1 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        maximum: u64,
+        largest_property_version: u64,
+        supply: u64,
+        uri: String,
+        royalty: RoyaltyV1,
+        name: String,
+        description: String,
+        default_properties: PropertyMap,
+        mutability_config: TokenMutabilityConfig,
+    ) -> Self {
+        Self {
+            maximum,
+            largest_property_version,
+            supply,
+            uri,
+            royalty,
+            name,
+            description,
+            default_properties,
+            mutability_config,
+        }
+    }
+
+    pub fn maximum(&self) -> u64 {
+        self.maximum
+    }
+
+    pub fn largest_property_version(&self) -> u64 {
+        self.largest_property_version
+    }
+
+    pub fn supply(&self) -> u64 {
+        self.supply
+    }
+
+    pub fn uri(&self) -> &String {
+        &self.uri
+    }
+
+    pub fn royalty(&self) -> &RoyaltyV1 {
+        &self.royalty
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    pub fn default_properties(&self) -> &PropertyMap {
+        &self.default_properties
+    }
+
+    pub fn mutability_config(&self) -> &TokenMutabilityConfig {
+        &self.mutability_config
+    }
+}
+
+impl MoveStructType for TokenDataV1 {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("token");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TokenData");
+}
+
+/// A Rust representation of `0x3::token::Royalty`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RoyaltyV1 {
+    royalty_points_numerator: u64,
+    royalty_points_denominator: u64,
+    payee_address: AccountAddress,
+}
+
+impl RoyaltyV1 {
+    pub fn new(
+        royalty_points_numerator: u64,
+        royalty_points_denominator: u64,
+        payee_address: AccountAddress,
+    ) -> Self {
+        Self {
+            royalty_points_numerator,
+            royalty_points_denominator,
+            payee_address,
+        }
+    }
+
+    pub fn royalty_points_numerator(&self) -> u64 {
+        self.royalty_points_numerator
+    }
+
+    pub fn royalty_points_denominator(&self) -> u64 {
+        self.royalty_points_denominator
+    }
+
+    pub fn payee_address(&self) -> &AccountAddress {
+        &self.payee_address
+    }
+}
+
+impl MoveStructType for RoyaltyV1 {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("token");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Royalty");
+}
+
+/// A Rust representation of `0x3::token::CollectionData`, the value type of
+/// `Collections.collection_data`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CollectionDataV1 {
+    description: String,
+    name: String,
+    uri: String,
+    supply: u64,
+    maximum: u64,
+    mutability_config: CollectionMutabilityConfig,
+}
+
+impl CollectionDataV1 {
+    pub fn new(
+        description: String,
+        name: String,
+        uri: String,
+        supply: u64,
+        maximum: u64,
+        mutability_config: CollectionMutabilityConfig,
+    ) -> Self {
+        Self {
+            description,
+            name,
+            uri,
+            supply,
+            maximum,
+            mutability_config,
+        }
+    }
+
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn uri(&self) -> &String {
+        &self.uri
+    }
+
+    pub fn supply(&self) -> u64 {
+        self.supply
+    }
+
+    pub fn maximum(&self) -> u64 {
+        self.maximum
+    }
+
+    pub fn mutability_config(&self) -> &CollectionMutabilityConfig {
+        &self.mutability_config
+    }
+}
+
+impl MoveStructType for CollectionDataV1 {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("token");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("CollectionData");
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CollectionMutabilityConfig {
+    pub description: bool,
+    pub uri: bool,
+    pub maximum: bool,
+}
+
+/// A Rust representation of `0x3::property_map::PropertyMap`.
+///
+/// The Move `SimpleMap` keeps its entries as a vector of key-value structs in insertion order,
+/// which has the same BCS layout as a vector of tuples.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PropertyMap {
+    map: Vec<(String, PropertyValue)>,
+}
+
+impl PropertyMap {
+    pub fn new(entries: Vec<(String, PropertyValue)>) -> Self {
+        Self { map: entries }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&PropertyValue> {
+        self.map
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &PropertyValue)> {
+        self.map.iter().map(|(name, value)| (name, value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl MoveStructType for PropertyMap {
+    const ADDRESS: AccountAddress = TOKEN_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("property_map");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PropertyMap");
+}
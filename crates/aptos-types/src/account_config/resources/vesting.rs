@@ -0,0 +1,322 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::pool_u64::{PoolU64, SignerCapability};
+use crate::event::EventHandle;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    move_resource::{MoveResource, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+/// A Rust representation of `0x1::fixed_point32::FixedPoint32`, a fraction with 32 fractional
+/// bits.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FixedPoint32 {
+    value: u64,
+}
+
+impl FixedPoint32 {
+    pub fn from_raw_value(value: u64) -> Self {
+        Self { value }
+    }
+
+    pub fn raw_value(&self) -> u64 {
+        self.value
+    }
+
+    /// `amount` multiplied by the fraction and rounded down, as
+    /// `0x1::fixed_point32::multiply_u64` computes it, or `None` where it aborts because the
+    /// product does not fit in a `u64`
+    pub fn multiply_u64(&self, amount: u64) -> Option<u64> {
+        u64::try_from((amount as u128 * self.value as u128) >> 32).ok()
+    }
+}
+
+impl MoveStructType for FixedPoint32 {
+    const MODULE_NAME: &'static IdentStr = ident_str!("fixed_point32");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("FixedPoint32");
+}
+
+/// A Rust representation of `0x1::vesting::VestingSchedule`.
+///
+/// Each period vests the fraction of the grant at its index in `schedule`, and the periods past
+/// the end of the schedule vest its last fraction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VestingSchedule {
+    schedule: Vec<FixedPoint32>,
+    start_timestamp_secs: u64,
+    period_duration: u64,
+    last_vested_period: u64,
+}
+
+impl VestingSchedule {
+    pub fn schedule(&self) -> &[FixedPoint32] {
+        &self.schedule
+    }
+
+    pub fn start_timestamp_secs(&self) -> u64 {
+        self.start_timestamp_secs
+    }
+
+    pub fn period_duration(&self) -> u64 {
+        self.period_duration
+    }
+
+    pub fn last_vested_period(&self) -> u64 {
+        self.last_vested_period
+    }
+}
+
+impl MoveStructType for VestingSchedule {
+    const MODULE_NAME: &'static IdentStr = ident_str!("vesting");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("VestingSchedule");
+}
+
+/// A Rust representation of `0x1::vesting::StakingInfo`, the stake pool a vesting contract
+/// stakes its grant in.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StakingInfo {
+    pool_address: AccountAddress,
+    operator: AccountAddress,
+    voter: AccountAddress,
+    commission_percentage: u64,
+}
+
+impl StakingInfo {
+    pub fn pool_address(&self) -> AccountAddress {
+        self.pool_address
+    }
+
+    pub fn operator(&self) -> AccountAddress {
+        self.operator
+    }
+
+    pub fn voter(&self) -> AccountAddress {
+        self.voter
+    }
+
+    /// The commission of the operator, in percent
+    pub fn commission_percentage(&self) -> u64 {
+        self.commission_percentage
+    }
+}
+
+impl MoveStructType for StakingInfo {
+    const MODULE_NAME: &'static IdentStr = ident_str!("vesting");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("StakingInfo");
+}
+
+/// The state of a vesting contract that is still vesting
+pub const VESTING_POOL_ACTIVE: u64 = 1;
+/// The state of a vesting contract whose admin has terminated it
+pub const VESTING_POOL_TERMINATED: u64 = 2;
+
+/// A Rust representation of `0x1::vesting::VestingContract`, stored under the contract's
+/// resource account.
+///
+/// The `beneficiaries` field is a Move `SimpleMap<address, address>` of shareholders to the
+/// addresses their vested coins are paid to, which has the same BCS layout as a vector of tuples.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VestingContractResource {
+    state: u64,
+    admin: AccountAddress,
+    grant_pool: PoolU64,
+    beneficiaries: Vec<(AccountAddress, AccountAddress)>,
+    vesting_schedule: VestingSchedule,
+    withdrawal_address: AccountAddress,
+    staking: StakingInfo,
+    remaining_grant: u64,
+    signer_cap: SignerCapability,
+    update_operator_events: EventHandle,
+    update_voter_events: EventHandle,
+    reset_lockup_events: EventHandle,
+    set_beneficiary_events: EventHandle,
+    unlock_rewards_events: EventHandle,
+    vest_events: EventHandle,
+    distribute_events: EventHandle,
+    terminate_events: EventHandle,
+    admin_withdraw_events: EventHandle,
+}
+
+impl VestingContractResource {
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes(bytes).map_err(Into::into)
+    }
+
+    /// Either [`VESTING_POOL_ACTIVE`] or [`VESTING_POOL_TERMINATED`]
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == VESTING_POOL_ACTIVE
+    }
+
+    pub fn admin(&self) -> AccountAddress {
+        self.admin
+    }
+
+    /// The shares of the shareholders in the grant
+    pub fn grant_pool(&self) -> &PoolU64 {
+        &self.grant_pool
+    }
+
+    /// The address the vested coins of `shareholder` are paid to
+    pub fn beneficiary(&self, shareholder: &AccountAddress) -> AccountAddress {
+        self.beneficiaries
+            .iter()
+            .find(|(address, _)| address == shareholder)
+            .map_or(*shareholder, |(_, beneficiary)| *beneficiary)
+    }
+
+    pub fn vesting_schedule(&self) -> &VestingSchedule {
+        &self.vesting_schedule
+    }
+
+    pub fn withdrawal_address(&self) -> AccountAddress {
+        self.withdrawal_address
+    }
+
+    pub fn staking(&self) -> &StakingInfo {
+        &self.staking
+    }
+
+    /// The part of the grant that has not vested yet
+    pub fn remaining_grant(&self) -> u64 {
+        self.remaining_grant
+    }
+
+    /// The address of the contract's resource account
+    pub fn contract_address(&self) -> AccountAddress {
+        self.signer_cap.account()
+    }
+
+    pub fn update_operator_events(&self) -> &EventHandle {
+        &self.update_operator_events
+    }
+
+    pub fn update_voter_events(&self) -> &EventHandle {
+        &self.update_voter_events
+    }
+
+    pub fn reset_lockup_events(&self) -> &EventHandle {
+        &self.reset_lockup_events
+    }
+
+    pub fn set_beneficiary_events(&self) -> &EventHandle {
+        &self.set_beneficiary_events
+    }
+
+    pub fn unlock_rewards_events(&self) -> &EventHandle {
+        &self.unlock_rewards_events
+    }
+
+    pub fn vest_events(&self) -> &EventHandle {
+        &self.vest_events
+    }
+
+    pub fn distribute_events(&self) -> &EventHandle {
+        &self.distribute_events
+    }
+
+    pub fn terminate_events(&self) -> &EventHandle {
+        &self.terminate_events
+    }
+
+    pub fn admin_withdraw_events(&self) -> &EventHandle {
+        &self.admin_withdraw_events
+    }
+}
+
+impl MoveStructType for VestingContractResource {
+    const MODULE_NAME: &'static IdentStr = ident_str!("vesting");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("VestingContract");
+}
+
+impl MoveResource for VestingContractResource {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventKey;
+
+    #[test]
+    fn test_fixed_point32_multiply() {
+        let quarter = FixedPoint32::from_raw_value(1 << 30);
+        assert_eq!(quarter.multiply_u64(1_000), Some(250));
+        assert_eq!(quarter.multiply_u64(u64::MAX), Some(u64::MAX >> 2));
+        let third = FixedPoint32::from_raw_value((1 << 32) / 3);
+        assert_eq!(third.multiply_u64(300), Some(99));
+        let two = FixedPoint32::from_raw_value(2 << 32);
+        assert_eq!(two.multiply_u64(u64::MAX / 2), Some(u64::MAX - 1));
+        assert_eq!(two.multiply_u64(u64::MAX / 2 + 1), None);
+    }
+
+    #[test]
+    fn test_decode_vesting_contract() {
+        let admin = AccountAddress::TWO;
+        let shareholder = AccountAddress::THREE;
+        let beneficiary = AccountAddress::FOUR;
+        let contract_address = AccountAddress::new([0xc; 32]);
+        let events: [EventHandle; 9] = std::array::from_fn(|creation_number| {
+            EventHandle::new(EventKey::new(creation_number as u64, contract_address), 0)
+        });
+        let bytes = bcs::to_bytes(&(
+            VESTING_POOL_ACTIVE,
+            admin,
+            PoolU64::new(30, 1_000, 1_000, vec![(shareholder, 1_000)], 1),
+            // A `SimpleMap` only holds a vector of key-value structs.
+            vec![(shareholder, beneficiary)],
+            (
+                vec![FixedPoint32::from_raw_value(1 << 30)],
+                1_700_000_000u64,
+                2_592_000u64,
+                0u64,
+            ),
+            admin,
+            (
+                AccountAddress::new([0xd; 32]),
+                AccountAddress::new([0xe; 32]),
+                admin,
+                10u64,
+            ),
+            1_000u64,
+            SignerCapability::new(contract_address),
+            events,
+        ))
+        .unwrap();
+
+        let contract = VestingContractResource::try_from_bytes(&bytes).unwrap();
+        assert!(contract.is_active());
+        assert_eq!(contract.admin(), admin);
+        assert_eq!(contract.grant_pool().balance(&shareholder), 1_000);
+        assert_eq!(contract.beneficiary(&shareholder), beneficiary);
+        assert_eq!(contract.beneficiary(&admin), admin);
+        let schedule = contract.vesting_schedule();
+        assert_eq!(schedule.start_timestamp_secs(), 1_700_000_000);
+        assert_eq!(schedule.period_duration(), 2_592_000);
+        assert_eq!(
+            schedule.schedule()[0].multiply_u64(contract.remaining_grant()),
+            Some(250)
+        );
+        assert_eq!(contract.withdrawal_address(), admin);
+        assert_eq!(
+            contract.staking().pool_address(),
+            AccountAddress::new([0xd; 32])
+        );
+        assert_eq!(
+            contract.staking().operator(),
+            AccountAddress::new([0xe; 32])
+        );
+        assert_eq!(contract.staking().commission_percentage(), 10);
+        assert_eq!(contract.contract_address(), contract_address);
+        assert_eq!(
+            contract.admin_withdraw_events().key(),
+            &EventKey::new(8, contract_address)
+        );
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+/// A BLS signature aggregated from the signatures of a subset of the validators.
+///
+/// BLS signatures are not verified here, so the signature is kept as its compressed bytes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregateSignature {
+    /// A bit per validator in the validator set, set for those that signed
+    #[serde(with = "serde_bytes")]
+    validator_bitmask: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    sig: Option<Vec<u8>>,
+}
+
+impl AggregateSignature {
+    pub fn new(validator_bitmask: Vec<u8>, sig: Option<Vec<u8>>) -> Self {
+        Self {
+            validator_bitmask,
+            sig,
+        }
+    }
+
+    /// The indices of the validators that signed
+    pub fn signer_indices(&self) -> Vec<usize> {
+        (0..self.validator_bitmask.len() * 8)
+            .filter(|index| self.validator_bitmask[index / 8] & (0b1000_0000 >> (index % 8)) != 0)
+            .collect()
+    }
+
+    pub fn sig(&self) -> Option<&[u8]> {
+        self.sig.as_deref()
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{block_metadata::BlockMetadata, randomness::Randomness};
+use aptos_crypto::HashValue;
+use move_core_types::account_address::AccountAddress;
+use serde::{Deserialize, Serialize};
+
+/// The extended block metadata, which also carries the block randomness once on-chain
+/// randomness is enabled.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockMetadataExt {
+    V0(BlockMetadata),
+    V1(BlockMetadataWithRandomness),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockMetadataWithRandomness {
+    pub id: HashValue,
+    pub epoch: u64,
+    pub round: u64,
+    pub proposer: AccountAddress,
+    #[serde(with = "serde_bytes")]
+    pub previous_block_votes_bitvec: Vec<u8>,
+    pub failed_proposer_indices: Vec<u32>,
+    pub timestamp_usecs: u64,
+    pub randomness: Option<Randomness>,
+}
+
+impl BlockMetadataExt {
+    pub fn id(&self) -> HashValue {
+        match self {
+            BlockMetadataExt::V0(obj) => obj.id(),
+            BlockMetadataExt::V1(obj) => obj.id,
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        match self {
+            BlockMetadataExt::V0(obj) => obj.epoch(),
+            BlockMetadataExt::V1(obj) => obj.epoch,
+        }
+    }
+
+    pub fn round(&self) -> u64 {
+        match self {
+            BlockMetadataExt::V0(obj) => obj.round(),
+            BlockMetadataExt::V1(obj) => obj.round,
+        }
+    }
+
+    pub fn proposer(&self) -> AccountAddress {
+        match self {
+            BlockMetadataExt::V0(obj) => obj.proposer(),
+            BlockMetadataExt::V1(obj) => obj.proposer,
+        }
+    }
+
+    pub fn previous_block_votes_bitvec(&self) -> &Vec<u8> {
+        match self {
+            BlockMetadataExt::V0(obj) => obj.previous_block_votes_bitvec(),
+            BlockMetadataExt::V1(obj) => &obj.previous_block_votes_bitvec,
+        }
+    }
+
+    pub fn failed_proposer_indices(&self) -> &Vec<u32> {
+        match self {
+            BlockMetadataExt::V0(obj) => obj.failed_proposer_indices(),
+            BlockMetadataExt::V1(obj) => &obj.failed_proposer_indices,
+        }
+    }
+
+    pub fn timestamp_usecs(&self) -> u64 {
+        match self {
+            BlockMetadataExt::V0(obj) => obj.timestamp_usecs(),
+            BlockMetadataExt::V1(obj) => obj.timestamp_usecs,
+        }
+    }
+
+    pub fn randomness(&self) -> Option<&Randomness> {
+        match self {
+            BlockMetadataExt::V0(_) => None,
+            BlockMetadataExt::V1(obj) => obj.randomness.as_ref(),
+        }
+    }
+}
+
+impl From<BlockMetadata> for BlockMetadataExt {
+    fn from(v0: BlockMetadata) -> Self {
+        BlockMetadataExt::V0(v0)
+    }
+}
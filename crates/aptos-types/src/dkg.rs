@@ -0,0 +1,29 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::account_address::AccountAddress;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DKGTranscriptMetadata {
+    pub epoch: u64,
+    pub author: AccountAddress,
+}
+
+/// The transcript of a distributed key generation session, which a validator proposes once the
+/// session has finished. The transcript itself is opaque to everyone but the validators.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DKGTranscript {
+    pub metadata: DKGTranscriptMetadata,
+    #[serde(with = "serde_bytes")]
+    pub transcript_bytes: Vec<u8>,
+}
+
+impl DKGTranscript {
+    pub fn new(epoch: u64, author: AccountAddress, transcript_bytes: Vec<u8>) -> Self {
+        Self {
+            metadata: DKGTranscriptMetadata { epoch, author },
+            transcript_bytes,
+        }
+    }
+}
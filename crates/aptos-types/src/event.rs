@@ -3,8 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::account_address::AccountAddress;
-// #[cfg(any(test, feature = "fuzzing"))]
-// use rand::{rngs::OsRng, RngCore};
+#[cfg(feature = "random")]
+use aptos_crypto::rng::OsRng;
+#[cfg(feature = "random")]
+use rand_core::RngCore;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -39,13 +41,13 @@ impl EventKey {
         self.creation_number
     }
 
-    // #[cfg(any(test, feature = "fuzzing"))]
-    // /// Create a random event key for testing
-    // pub fn random() -> Self {
-    //     let mut rng = OsRng;
-    //     let salt = rng.next_u64();
-    //     EventKey::new(salt, AccountAddress::random())
-    // }
+    /// Create a random event key for testing
+    #[cfg(feature = "random")]
+    pub fn random() -> Self {
+        let mut rng = OsRng;
+        let salt = rng.next_u64();
+        EventKey::new(salt, AccountAddress::random())
+    }
 
     pub fn size(&self) -> usize {
         8 /* u64 */ + 32 /* address */
@@ -108,14 +110,14 @@ impl EventHandle {
         self.count
     }
 
-    // #[cfg(any(test, feature = "fuzzing"))]
-    // /// Create a random event key for testing
-    // pub fn random(count: u64) -> Self {
-    //     Self {
-    //         key: EventKey::random(),
-    //         count,
-    //     }
-    // }
+    /// Create a random event key for testing
+    #[cfg(feature = "random")]
+    pub fn random(count: u64) -> Self {
+        Self {
+            key: EventKey::random(),
+            count,
+        }
+    }
 
     pub fn count_mut(&mut self) -> &mut u64 {
         &mut self.count
@@ -0,0 +1,185 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Execution hashes of `0x1::aptos_governance` proposals.
+//!
+//! A proposal commits to the SHA3-256 hash of the script that resolves it, and only that exact
+//! script can be executed once the proposal passes. A multi-step proposal commits to its first
+//! script, and each script but the last passes the hash of the next one to
+//! `0x1::aptos_governance::resolve_multi_step_proposal`, so the hashes form a chain.
+
+use anyhow::{bail, ensure, Result};
+use aptos_crypto::HashValue;
+use move_binary_format::file_format::{CompiledScript, SignatureToken};
+
+/// The execution hash of a compiled script, as `0x1::transaction_context::get_script_hash`
+/// returns it while the script runs
+pub fn script_execution_hash(code: &[u8]) -> HashValue {
+    HashValue::sha3_256_of(code)
+}
+
+/// The `metadata_hash` argument of `0x1::aptos_governance::create_proposal_v2` for the proposal
+/// metadata at `metadata_location`, i.e. the hex-encoded SHA3-256 hash of its content
+pub fn proposal_metadata_hash(metadata: &[u8]) -> Vec<u8> {
+    HashValue::sha3_256_of(metadata).to_hex().into_bytes()
+}
+
+/// The compiled scripts of a proposal, in the order they are executed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalScripts {
+    scripts: Vec<Vec<u8>>,
+}
+
+impl ProposalScripts {
+    /// A single-step proposal, resolved by `script`
+    pub fn single_step(script: Vec<u8>) -> Self {
+        Self {
+            scripts: vec![script],
+        }
+    }
+
+    /// A multi-step proposal with `num_steps` scripts. As each script embeds the execution hash
+    /// of the next one, they are built in reverse: `build(step, next_execution_hash)` returns
+    /// the compiled script of `step`, and `next_execution_hash` is empty for the last step.
+    pub fn multi_step(
+        num_steps: usize,
+        mut build: impl FnMut(usize, &[u8]) -> Result<Vec<u8>>,
+    ) -> Result<Self> {
+        ensure!(num_steps > 0, "a proposal needs at least one script");
+        let mut scripts = Vec::with_capacity(num_steps);
+        let mut next_execution_hash = vec![];
+        for step in (0..num_steps).rev() {
+            let script = build(step, &next_execution_hash)?;
+            next_execution_hash = script_execution_hash(&script).to_vec();
+            scripts.push(script);
+        }
+        scripts.reverse();
+        Ok(Self { scripts })
+    }
+
+    /// The scripts of a multi-step proposal that have already been compiled, which are checked
+    /// to chain: each script but the last must hold the execution hash of the next one as a
+    /// `vector<u8>` constant. Any such constant counts as a link, as it can't be told from the
+    /// bytecode whether the script passes it to `resolve_multi_step_proposal`.
+    pub fn from_scripts(scripts: Vec<Vec<u8>>) -> Result<Self> {
+        ensure!(!scripts.is_empty(), "a proposal needs at least one script");
+        for (step, pair) in scripts.windows(2).enumerate() {
+            let next_execution_hash = script_execution_hash(&pair[1]);
+            if !embeds_hash(&pair[0], &next_execution_hash)? {
+                bail!(
+                    "script of step {} does not resolve to the next script, whose execution hash is {}",
+                    step,
+                    next_execution_hash.to_hex()
+                );
+            }
+        }
+        Ok(Self { scripts })
+    }
+
+    /// The `execution_hash` argument of `0x1::aptos_governance::create_proposal_v2`, i.e. the
+    /// execution hash of the first script
+    pub fn execution_hash(&self) -> HashValue {
+        script_execution_hash(&self.scripts[0])
+    }
+
+    pub fn is_multi_step(&self) -> bool {
+        self.scripts.len() > 1
+    }
+
+    pub fn scripts(&self) -> &[Vec<u8>] {
+        &self.scripts
+    }
+
+    /// The execution hash of each script, in the order the scripts are executed
+    pub fn execution_hashes(&self) -> Vec<HashValue> {
+        self.scripts
+            .iter()
+            .map(|script| script_execution_hash(script))
+            .collect()
+    }
+}
+
+fn embeds_hash(code: &[u8], hash: &HashValue) -> Result<bool> {
+    let script = CompiledScript::deserialize(code)?;
+    Ok(script.constant_pool.iter().any(|constant| {
+        matches!(&constant.type_, SignatureToken::Vector(inner) if **inner == SignatureToken::U8)
+            && bcs::from_bytes::<Vec<u8>>(&constant.data)
+                .is_ok_and(|bytes| bytes.as_slice() == hash.as_ref())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::file_format::{empty_script, Constant};
+
+    /// A compiled script holding `constant` as a `vector<u8>` constant
+    fn script(constant: &[u8]) -> Vec<u8> {
+        let mut script = empty_script();
+        script.constant_pool.push(Constant {
+            type_: SignatureToken::Vector(Box::new(SignatureToken::U8)),
+            data: bcs::to_bytes(constant).unwrap(),
+        });
+        let mut bytes = vec![];
+        script.serialize(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_multi_step_hashes() {
+        let mut next_hashes = vec![];
+        let proposal = ProposalScripts::multi_step(3, |step, next_execution_hash| {
+            next_hashes.push((step, next_execution_hash.to_vec()));
+            Ok(script(next_execution_hash))
+        })
+        .unwrap();
+
+        let hashes = proposal.execution_hashes();
+        assert_eq!(
+            next_hashes,
+            vec![
+                (2, vec![]),
+                (1, hashes[2].to_vec()),
+                (0, hashes[1].to_vec()),
+            ]
+        );
+        assert_eq!(
+            proposal.execution_hash(),
+            script_execution_hash(&proposal.scripts()[0])
+        );
+        assert!(proposal.is_multi_step());
+        // The scripts built by `multi_step` chain.
+        assert_eq!(
+            ProposalScripts::from_scripts(proposal.scripts().to_vec()).unwrap(),
+            proposal
+        );
+        assert!(!ProposalScripts::single_step(b"step 0".to_vec()).is_multi_step());
+        assert!(ProposalScripts::multi_step(0, |_, _| Ok(vec![])).is_err());
+    }
+
+    #[test]
+    fn test_metadata_hash() {
+        assert_eq!(
+            proposal_metadata_hash(b""),
+            b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_from_scripts() {
+        // The last script is not parsed, as it embeds no hash.
+        assert!(ProposalScripts::from_scripts(vec![b"step 0".to_vec()]).is_ok());
+        assert!(ProposalScripts::from_scripts(vec![]).is_err());
+        assert!(ProposalScripts::from_scripts(vec![b"a".to_vec(), b"b".to_vec()]).is_err());
+
+        let last = script(b"");
+        let chaining = script(script_execution_hash(&last).as_ref());
+        assert!(ProposalScripts::from_scripts(vec![chaining, last.clone()]).is_ok());
+
+        let not_chaining = script(script_execution_hash(b"another script").as_ref());
+        let error = ProposalScripts::from_scripts(vec![not_chaining, last]).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("does not resolve to the next script"));
+    }
+}
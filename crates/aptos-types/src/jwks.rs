@@ -0,0 +1,30 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{account_config::AnyResource, aggregate_signature::AggregateSignature};
+use serde::{Deserialize, Serialize};
+
+pub type Issuer = Vec<u8>;
+
+/// A JWK as stored on chain, i.e. a `0x1::jwks::JWK` wrapping an RSA or unsupported key in a
+/// `0x1::copyable_any::Any`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JWKMoveStruct {
+    pub variant: AnyResource,
+}
+
+/// The JWKs of an OIDC provider, as observed by the validators.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderJWKs {
+    #[serde(with = "serde_bytes")]
+    pub issuer: Issuer,
+    pub version: u64,
+    pub jwks: Vec<JWKMoveStruct>,
+}
+
+/// An update of the JWKs of a provider, certified by a quorum of the validators.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuorumCertifiedUpdate {
+    pub update: ProviderJWKs,
+    pub multi_sig: AggregateSignature,
+}
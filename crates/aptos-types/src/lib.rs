@@ -1,14 +1,38 @@
 pub mod access_path;
 pub mod account_address;
 pub mod account_config;
+pub mod aggregate_signature;
+pub mod block_metadata;
+pub mod block_metadata_ext;
 pub mod chain_id;
 pub mod contract_event;
+pub mod dkg;
 pub mod event;
+pub mod governance;
+pub mod jwks;
 pub mod move_utils;
 pub mod on_chain_config;
+pub mod randomness;
 pub mod serde_helper;
+pub mod sign_message;
+pub mod state_store;
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod test_helpers;
 pub mod transaction;
 pub mod utility_coin;
+pub mod validator_txn;
+pub mod vm;
 pub mod vm_status;
+pub mod write_set;
+
+// Lets `#[derive(MoveStruct)]`, which refers to `::aptos_types`, be used within this crate.
+extern crate self as aptos_types;
+
+/// Dependencies of the code `#[derive(MoveStruct)]` generates, so that crates using it only need
+/// to depend on `aptos-types`.
+#[doc(hidden)]
+pub mod __private {
+    pub use move_core_types;
+}
 
 pub use utility_coin::*;
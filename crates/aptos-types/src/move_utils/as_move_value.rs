@@ -1,7 +1,11 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use move_core_types::value::{MoveStruct, MoveValue};
+use move_core_types::{
+    account_address::AccountAddress,
+    u256::U256,
+    value::{MoveStruct, MoveValue},
+};
 
 pub trait AsMoveValue {
     fn as_move_value(&self) -> MoveValue;
@@ -63,3 +67,21 @@ impl AsMoveValue for u64 {
         MoveValue::U64(*self)
     }
 }
+
+impl AsMoveValue for u128 {
+    fn as_move_value(&self) -> MoveValue {
+        MoveValue::U128(*self)
+    }
+}
+
+impl AsMoveValue for U256 {
+    fn as_move_value(&self) -> MoveValue {
+        MoveValue::U256(*self)
+    }
+}
+
+impl AsMoveValue for AccountAddress {
+    fn as_move_value(&self) -> MoveValue {
+        MoveValue::Address(*self)
+    }
+}
@@ -42,3 +42,4 @@ impl FromStr for MemberId {
 pub mod as_move_value;
 pub mod move_event_v1;
 pub mod move_event_v2;
+pub mod move_layout;
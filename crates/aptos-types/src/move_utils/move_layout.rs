@@ -0,0 +1,171 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    language_storage::{StructTag, TypeTag},
+    u256::U256,
+    value::{MoveFieldLayout, MoveStructLayout, MoveTypeLayout},
+};
+
+pub use aptos_types_derive::MoveStruct;
+
+/// Rust types with a known Move layout, so that their BCS bytes can be decoded as Move values.
+///
+/// Struct layouts are decorated with their type and field names, which is what
+/// `#[derive(MoveStruct)]` generates for Move struct mirrors.
+pub trait MoveLayout {
+    fn move_layout() -> MoveTypeLayout;
+}
+
+macro_rules! impl_move_layout {
+    ($($ty:ty => $layout:ident),* $(,)?) => {
+        $(
+            impl MoveLayout for $ty {
+                fn move_layout() -> MoveTypeLayout {
+                    MoveTypeLayout::$layout
+                }
+            }
+        )*
+    };
+}
+
+impl_move_layout!(
+    bool => Bool,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    U256 => U256,
+    AccountAddress => Address,
+);
+
+impl<T: MoveLayout> MoveLayout for Vec<T> {
+    fn move_layout() -> MoveTypeLayout {
+        MoveTypeLayout::Vector(Box::new(T::move_layout()))
+    }
+}
+
+impl MoveLayout for String {
+    fn move_layout() -> MoveTypeLayout {
+        MoveTypeLayout::Struct(MoveStructLayout::with_types(
+            StructTag {
+                address: AccountAddress::ONE,
+                module: ident_str!("string").to_owned(),
+                name: ident_str!("String").to_owned(),
+                type_args: vec![],
+            },
+            vec![MoveFieldLayout::new(
+                ident_str!("bytes").to_owned(),
+                Vec::<u8>::move_layout(),
+            )],
+        ))
+    }
+}
+
+impl<T: MoveLayout> MoveLayout for Option<T> {
+    fn move_layout() -> MoveTypeLayout {
+        let layout = T::move_layout();
+        let type_arg: TypeTag = (&layout)
+            .try_into()
+            .expect("layouts of MoveLayout types have type tags");
+        MoveTypeLayout::Struct(MoveStructLayout::with_types(
+            StructTag {
+                address: AccountAddress::ONE,
+                module: ident_str!("option").to_owned(),
+                name: ident_str!("Option").to_owned(),
+                type_args: vec![type_arg],
+            },
+            vec![MoveFieldLayout::new(
+                ident_str!("vec").to_owned(),
+                MoveTypeLayout::Vector(Box::new(layout)),
+            )],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{move_utils::as_move_value::AsMoveValue, utility_coin::AptosCoinType};
+    use move_core_types::{
+        move_resource::{MoveStructType, MoveTypeTag},
+        value::{MoveStruct as VmMoveStruct, MoveValue},
+    };
+    use serde::{Deserialize, Serialize};
+    use std::{marker::PhantomData, str::FromStr};
+
+    #[derive(Debug, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0xcafe", module = "bridge", name = "Ticket")]
+    struct BridgeTicket {
+        receiver: AccountAddress,
+        memo: String,
+        amount: u64,
+        fee: Option<u128>,
+        tags: Vec<u8>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, MoveStruct)]
+    #[move_struct(address = "0xcafe", module = "bridge")]
+    struct Wrapper<T> {
+        value: u64,
+        #[serde(skip)]
+        phantom: PhantomData<T>,
+    }
+
+    #[test]
+    fn test_derive_move_struct() {
+        assert_eq!(
+            BridgeTicket::struct_tag(),
+            StructTag::from_str("0xcafe::bridge::Ticket").unwrap()
+        );
+        assert_eq!(
+            BridgeTicket::type_tag(),
+            TypeTag::from_str("0xcafe::bridge::Ticket").unwrap()
+        );
+
+        let ticket = BridgeTicket {
+            receiver: AccountAddress::from_str("0x2").unwrap(),
+            memo: "hello".to_string(),
+            amount: 42,
+            fee: Some(7),
+            tags: vec![1, 2, 3],
+        };
+        let bytes = bcs::to_bytes(&ticket).unwrap();
+        assert_eq!(ticket.as_move_value().simple_serialize().unwrap(), bytes);
+
+        let value = MoveValue::simple_deserialize(&bytes, &BridgeTicket::move_layout()).unwrap();
+        let MoveValue::Struct(VmMoveStruct::WithTypes { _type_, _fields }) = value else {
+            panic!("expected a decorated struct");
+        };
+        assert_eq!(_type_, BridgeTicket::struct_tag());
+        let names = _fields.iter().map(|(n, _)| n.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["receiver", "memo", "amount", "fee", "tags"]);
+        assert_eq!(_fields[2].1, MoveValue::U64(42));
+    }
+
+    #[test]
+    fn test_derive_generic_move_struct() {
+        assert_eq!(
+            Wrapper::<AptosCoinType>::type_tag(),
+            TypeTag::from_str("0xcafe::bridge::Wrapper<0x1::aptos_coin::AptosCoin>").unwrap()
+        );
+
+        let wrapper = Wrapper::<AptosCoinType> {
+            value: 5,
+            phantom: PhantomData,
+        };
+        let bytes = bcs::to_bytes(&wrapper).unwrap();
+        assert_eq!(wrapper.as_move_value().simple_serialize().unwrap(), bytes);
+        let MoveStructLayout::WithTypes { type_, fields } =
+            Wrapper::<AptosCoinType>::move_struct_layout()
+        else {
+            panic!("expected a decorated layout");
+        };
+        assert_eq!(type_, Wrapper::<AptosCoinType>::struct_tag());
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name.as_str(), "value");
+    }
+}
@@ -1,10 +1,13 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::on_chain_config::OnChainConfig;
+use crate::{governance::script_execution_hash, on_chain_config::OnChainConfig};
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+/// The execution hashes of the scripts of passed proposals, by proposal id. The VM lets these
+/// scripts exceed the transaction size limit.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct ApprovedExecutionHashes {
     pub entries: Vec<(u64, Vec<u8>)>,
@@ -14,9 +17,66 @@ impl ApprovedExecutionHashes {
     pub fn to_btree_map(self) -> BTreeMap<u64, Vec<u8>> {
         self.entries.into_iter().collect()
     }
+
+    /// The approved execution hash of `proposal_id`, if any
+    pub fn execution_hash(&self, proposal_id: u64) -> Option<&[u8]> {
+        self.entries
+            .iter()
+            .find(|(id, _)| *id == proposal_id)
+            .map(|(_, hash)| hash.as_slice())
+    }
+
+    /// The id of the proposal `script` resolves, if its execution hash is approved
+    pub fn approved_proposal_id(&self, script: &[u8]) -> Option<u64> {
+        let hash = script_execution_hash(script);
+        self.entries
+            .iter()
+            .find(|(_, approved)| approved.as_slice() == hash.as_ref())
+            .map(|(id, _)| *id)
+    }
+
+    /// Checks that `script` is the approved next script of `proposal_id`
+    pub fn verify_script(&self, proposal_id: u64, script: &[u8]) -> Result<()> {
+        let Some(approved) = self.execution_hash(proposal_id) else {
+            bail!("proposal {} has no approved execution hash", proposal_id);
+        };
+        let hash = script_execution_hash(script);
+        if approved != hash.as_ref() {
+            bail!(
+                "script hash {} does not match the approved execution hash {} of proposal {}",
+                hash.to_hex(),
+                hex::encode(approved),
+                proposal_id
+            );
+        }
+        Ok(())
+    }
 }
 
 impl OnChainConfig for ApprovedExecutionHashes {
     const MODULE_IDENTIFIER: &'static str = "aptos_governance";
     const TYPE_IDENTIFIER: &'static str = "ApprovedExecutionHashes";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_script() {
+        let config = ApprovedExecutionHashes {
+            entries: vec![
+                (7, script_execution_hash(b"step 0").to_vec()),
+                (8, script_execution_hash(b"upgrade").to_vec()),
+            ],
+        };
+        let bytes = bcs::to_bytes(&config).unwrap();
+        let config = ApprovedExecutionHashes::deserialize_into_config(&bytes).unwrap();
+
+        assert_eq!(config.approved_proposal_id(b"upgrade"), Some(8));
+        assert_eq!(config.approved_proposal_id(b"step 1"), None);
+        assert!(config.verify_script(7, b"step 0").is_ok());
+        assert!(config.verify_script(7, b"upgrade").is_err());
+        assert!(config.verify_script(9, b"step 0").is_err());
+    }
+}
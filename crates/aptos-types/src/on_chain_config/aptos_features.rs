@@ -1,15 +1,15 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::on_chain_config::OnChainConfig;
+use crate::{
+    on_chain_config::OnChainConfig,
+    state_store::state_key::StateKey,
+    write_set::{WriteOp, WriteSet, WriteSetMut},
+};
 use move_binary_format::{
     file_format_common,
     file_format_common::{IDENTIFIER_SIZE_MAX, LEGACY_IDENTIFIER_SIZE_MAX},
 };
-use move_core_types::{
-    effects::{ChangeSet, Op},
-    language_storage::CORE_CODE_ADDRESS,
-};
 use serde::{Deserialize, Serialize};
 use strum_macros::{EnumString, FromRepr};
 
@@ -100,6 +100,12 @@ pub enum FeatureFlag {
     /// AIP-105 (https://github.com/aptos-foundation/AIPs/blob/main/aips/aip-105.md)
     NATIVE_MEMORY_OPERATIONS = 80,
     ENABLE_LOADER_V2 = 81,
+    /// Enables the `TransactionPayload::Payload` format, which carries a
+    /// `TransactionExtraConfig` next to the executable.
+    TRANSACTION_PAYLOAD_V2 = 93,
+    /// Enables transactions that are protected against replay by a nonce instead of the
+    /// sender's sequence number. Requires `TRANSACTION_PAYLOAD_V2`.
+    ORDERLESS_TRANSACTIONS = 94,
 }
 
 impl FeatureFlag {
@@ -234,7 +240,8 @@ impl Features {
             .flat_map(|byte| (0..8).map(move |bit_idx| byte & (1 << bit_idx) != 0))
             .enumerate()
             .filter(|(_feature_idx, enabled)| *enabled)
-            .map(|(feature_idx, _)| FeatureFlag::from_repr(feature_idx).unwrap())
+            // Skip flags enabled on chain that this crate does not know about yet.
+            .filter_map(|(feature_idx, _)| FeatureFlag::from_repr(feature_idx))
             .collect()
     }
 
@@ -326,6 +333,16 @@ impl Features {
         self.is_enabled(FeatureFlag::ENABLE_LOADER_V2)
     }
 
+    pub fn is_transaction_payload_v2_enabled(&self) -> bool {
+        self.is_enabled(FeatureFlag::TRANSACTION_PAYLOAD_V2)
+    }
+
+    pub fn is_orderless_txns_enabled(&self) -> bool {
+        // requires the V2 payload format, which carries the replay protection nonce
+        self.is_transaction_payload_v2_enabled()
+            && self.is_enabled(FeatureFlag::ORDERLESS_TRANSACTIONS)
+    }
+
     pub fn get_max_identifier_size(&self) -> u64 {
         if self.is_enabled(FeatureFlag::LIMIT_MAX_IDENTIFIER_LENGTH) {
             IDENTIFIER_SIZE_MAX
@@ -345,20 +362,22 @@ impl Features {
     }
 }
 
-pub fn aptos_test_feature_flags_genesis() -> ChangeSet {
+pub fn aptos_test_feature_flags_genesis() -> WriteSet {
     let features_value = bcs::to_bytes(&Features::default()).unwrap();
 
-    let mut change_set = ChangeSet::new();
+    let mut write_set = WriteSetMut::default();
     // we need to initialize features to their defaults.
-    change_set
-        .add_resource_op(
-            CORE_CODE_ADDRESS,
-            Features::struct_tag(),
-            Op::New(features_value.into()),
+    write_set
+        .try_insert(
+            StateKey::on_chain_config::<Features>()
+                .expect("the Features resource must have a state key"),
+            WriteOp::legacy_creation(features_value.into()),
         )
         .expect("adding genesis Feature resource must succeed");
 
-    change_set
+    write_set
+        .freeze()
+        .expect("freezing the genesis write set must succeed")
 }
 
 #[cfg(test)]
@@ -3,24 +3,26 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::event::{EventHandle, EventKey};
+use anyhow::{format_err, Result};
 
 use move_core_types::{
+    account_address::AccountAddress,
     ident_str,
     identifier::{IdentStr, Identifier},
     language_storage::{StructTag, TypeTag, CORE_CODE_ADDRESS},
     move_resource::{MoveResource, MoveStructType},
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{collections::HashMap, fmt, fmt::Debug, str::FromStr};
 
-// mod approved_execution_hashes;
-// mod aptos_features;
+mod approved_execution_hashes;
+mod aptos_features;
 // mod aptos_version;
 // mod chain_id;
 // mod commit_history;
 // mod consensus_config;
 // mod execution_config;
-// mod gas_schedule;
+mod gas_schedule;
 // mod jwk_consensus_config;
 // pub mod randomness_api_v0_config;
 // mod randomness_config;
@@ -29,6 +31,12 @@ use std::{collections::HashMap, fmt, fmt::Debug, str::FromStr};
 // mod transaction_fee;
 // mod validator_set;
 
+pub use self::{
+    approved_execution_hashes::ApprovedExecutionHashes,
+    aptos_features::*,
+    gas_schedule::{DiffItem, GasSchedule, GasScheduleV2, StorageGasSchedule},
+};
+
 /// To register an on-chain config in Rust:
 /// 1. Implement the `OnChainConfig` trait for the Rust representation of the config
 /// 2. Add the config's `ConfigID` to `ON_CHAIN_CONFIG_REGISTRY`
@@ -52,6 +60,47 @@ impl fmt::Display for ConfigID {
     }
 }
 
+/// Trait to be implemented by a Rust struct representation of an on-chain config
+/// that is stored in storage as a serialized byte array
+pub trait OnChainConfig: Send + Sync + DeserializeOwned {
+    const ADDRESS: &'static str = "0x1";
+    const MODULE_IDENTIFIER: &'static str;
+    const TYPE_IDENTIFIER: &'static str;
+    const CONFIG_ID: ConfigID = ConfigID(
+        Self::ADDRESS,
+        Self::MODULE_IDENTIFIER,
+        Self::TYPE_IDENTIFIER,
+    );
+
+    // Single-round BCS deserialization from bytes to `Self`
+    // This is the expected deserialization pattern if the Rust representation lives natively in Move.
+    // but sometimes `deserialize_into_config` may need an extra customized round of deserialization
+    // when the data is represented as opaque vec<u8> in Move.
+    // In the override, we can reuse this default logic via this function
+    // Note: we cannot directly call the default trait implementation
+    // due to how Rust traits work, see https://doc.rust-lang.org/book/ch19-03-advanced-traits.html
+    fn deserialize_default_impl(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes::<Self>(bytes)
+            .map_err(|e| format_err!("[on-chain config] Failed to deserialize into config: {}", e))
+    }
+
+    // Function for deserializing bytes to `Self`
+    // It will by default try one round of BCS deserialization directly to `Self`
+    // The implementation for the concrete type should override this function if this
+    // logic needs to be customized
+    fn deserialize_into_config(bytes: &[u8]) -> Result<Self> {
+        Self::deserialize_default_impl(bytes)
+    }
+
+    fn address() -> &'static AccountAddress {
+        &CORE_CODE_ADDRESS
+    }
+
+    fn struct_tag() -> StructTag {
+        struct_tag_for_config(Self::CONFIG_ID)
+    }
+}
+
 // pub trait OnChainConfigProvider: Debug + Clone + Send + Sync + 'static {
 //     fn get<T: OnChainConfig>(&self) -> Result<T>;
 // }
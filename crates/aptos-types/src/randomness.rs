@@ -0,0 +1,46 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+pub type Round = u64;
+
+/// The epoch and round a piece of randomness was generated for.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RandMetadata {
+    pub epoch: u64,
+    pub round: Round,
+}
+
+/// The randomness of a block, as aggregated by the validators.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Randomness {
+    metadata: RandMetadata,
+    #[serde(with = "serde_bytes")]
+    randomness: Vec<u8>,
+}
+
+impl Randomness {
+    pub fn new(metadata: RandMetadata, randomness: Vec<u8>) -> Self {
+        Self {
+            metadata,
+            randomness,
+        }
+    }
+
+    pub fn metadata(&self) -> &RandMetadata {
+        &self.metadata
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.metadata.epoch
+    }
+
+    pub fn round(&self) -> Round {
+        self.metadata.round
+    }
+
+    pub fn randomness(&self) -> &[u8] {
+        &self.randomness
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Off-chain message signing as specified by the Aptos wallet standard (`signMessage`).
+//!
+//! Wallets do not sign the user's message directly. They sign a "full message" made of the
+//! `APTOS` prefix followed by the requested fields, one `key: value` pair per line:
+//!
+//! ```text
+//! APTOS
+//! address: 0x1a2b...
+//! application: https://example.com
+//! chainId: 1
+//! message: Sign in to example.com
+//! nonce: 1234
+//! ```
+//!
+//! `address`, `application` and `chainId` are only included when the dApp asked for them. The
+//! signature is computed over the raw UTF-8 bytes of the full message, without a hasher prefix.
+
+use crate::{
+    chain_id::ChainId,
+    transaction::authenticator::{AccountAuthenticator, AuthenticationKey},
+};
+use anyhow::{ensure, Context, Result};
+use move_core_types::account_address::AccountAddress;
+use std::fmt;
+
+/// The fields of a wallet-standard `signMessage` request, from which the signed full message is
+/// built.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignMessagePayload {
+    address: Option<AccountAddress>,
+    application: Option<String>,
+    chain_id: Option<ChainId>,
+    message: String,
+    nonce: String,
+}
+
+impl SignMessagePayload {
+    /// The first line of every full message.
+    pub const PREFIX: &'static str = "APTOS";
+
+    pub fn new(message: impl Into<String>, nonce: impl Into<String>) -> Self {
+        Self {
+            address: None,
+            application: None,
+            chain_id: None,
+            message: message.into(),
+            nonce: nonce.into(),
+        }
+    }
+
+    /// Include the signer's address in the full message.
+    pub fn address(mut self, address: AccountAddress) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Include the requesting application (usually the dApp's origin) in the full message.
+    pub fn application(mut self, application: impl Into<String>) -> Self {
+        self.application = Some(application.into());
+        self
+    }
+
+    /// Include the chain id in the full message.
+    pub fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+
+    /// Returns the full message that wallets sign.
+    pub fn full_message(&self) -> String {
+        let mut full_message = Self::PREFIX.to_string();
+        if let Some(address) = &self.address {
+            full_message.push_str(&format!("\naddress: {}", address.to_standard_string()));
+        }
+        if let Some(application) = &self.application {
+            full_message.push_str(&format!("\napplication: {}", application));
+        }
+        if let Some(chain_id) = &self.chain_id {
+            full_message.push_str(&format!("\nchainId: {}", chain_id.id()));
+        }
+        full_message.push_str(&format!("\nmessage: {}", self.message));
+        full_message.push_str(&format!("\nnonce: {}", self.nonce));
+        full_message
+    }
+
+    /// Returns the bytes that are signed, i.e., the UTF-8 encoding of the full message.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.full_message().into_bytes()
+    }
+
+    /// Checks that `authenticator` holds a valid signature of the full message, and that its
+    /// public key is the one currently authorized by `authentication_key` (e.g., the
+    /// `authentication_key` of the signer's on-chain `AccountResource`).
+    pub fn verify(
+        &self,
+        authenticator: &AccountAuthenticator,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<()> {
+        let derived_key = authenticator
+            .authentication_key()
+            .context("signMessage requires a signature")?;
+        ensure!(
+            &derived_key == authentication_key,
+            "public key does not match the authentication key: expected {}, derived {}",
+            authentication_key,
+            derived_key
+        );
+        authenticator
+            .verify_arbitrary_msg(&self.to_bytes())
+            .context("invalid signMessage signature")
+    }
+}
+
+impl fmt::Display for SignMessagePayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.full_message())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_helpers::private_key,
+        transaction::authenticator::{AnyPublicKey, AnySignature, SingleKeyAuthenticator},
+    };
+    use aptos_crypto::{
+        ed25519::Ed25519PublicKey,
+        multi_ed25519::{MultiEd25519PrivateKey, MultiEd25519PublicKey},
+        PrivateKey, SigningKey,
+    };
+
+    fn ed25519_public_key(seed: u8) -> Ed25519PublicKey {
+        private_key(seed).public_key()
+    }
+
+    fn payload() -> SignMessagePayload {
+        SignMessagePayload::new("Sign in to example.com", "1234")
+            .address(AccountAddress::from_hex_literal("0x1a2b").unwrap())
+            .application("https://example.com")
+            .chain_id(ChainId::mainnet())
+    }
+
+    #[test]
+    fn test_full_message() {
+        assert_eq!(
+            payload().full_message(),
+            "APTOS\n\
+             address: 0x0000000000000000000000000000000000000000000000000000000000001a2b\n\
+             application: https://example.com\n\
+             chainId: 1\n\
+             message: Sign in to example.com\n\
+             nonce: 1234"
+        );
+        assert_eq!(
+            SignMessagePayload::new("hello", "0").to_bytes(),
+            b"APTOS\nmessage: hello\nnonce: 0".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_verify_ed25519() {
+        let private_key = private_key(1);
+        let public_key = private_key.public_key();
+        let auth_key = AuthenticationKey::ed25519(&public_key);
+        let payload = payload();
+
+        let signature = private_key.sign_arbitrary_message(&payload.to_bytes());
+        let authenticator = AccountAuthenticator::ed25519(public_key, signature);
+        payload.verify(&authenticator, &auth_key).unwrap();
+
+        // The signature does not cover a different message.
+        let other = SignMessagePayload::new("Sign in to example.com", "1235");
+        assert!(other.verify(&authenticator, &auth_key).is_err());
+
+        // The key must match the account's authentication key.
+        let other_key = AuthenticationKey::ed25519(&ed25519_public_key(2));
+        assert!(payload.verify(&authenticator, &other_key).is_err());
+    }
+
+    #[test]
+    fn test_verify_multi_ed25519() {
+        let private_key =
+            MultiEd25519PrivateKey::new(vec![private_key(1), private_key(2), private_key(3)], 2)
+                .unwrap();
+        let public_key = MultiEd25519PublicKey::from(&private_key);
+        let auth_key = AuthenticationKey::multi_ed25519(&public_key);
+        let payload = payload();
+
+        let signature = private_key.sign_arbitrary_message(&payload.to_bytes());
+        let authenticator = AccountAuthenticator::multi_ed25519(public_key, signature);
+        payload.verify(&authenticator, &auth_key).unwrap();
+        assert!(payload
+            .verify(
+                &authenticator,
+                &AuthenticationKey::ed25519(&ed25519_public_key(1))
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_single_key() {
+        let private_key = private_key(1);
+        let public_key = AnyPublicKey::ed25519(private_key.public_key());
+        let auth_key = AuthenticationKey::any_key(public_key.clone());
+        let payload = payload();
+
+        let signature =
+            AnySignature::ed25519(private_key.sign_arbitrary_message(&payload.to_bytes()));
+        let authenticator =
+            AccountAuthenticator::single_key(SingleKeyAuthenticator::new(public_key, signature));
+        payload.verify(&authenticator, &auth_key).unwrap();
+
+        // Single-key and legacy Ed25519 accounts derive different authentication keys.
+        assert!(payload
+            .verify(
+                &authenticator,
+                &AuthenticationKey::ed25519(&private_key.public_key())
+            )
+            .is_err());
+        assert!(payload
+            .verify(&AccountAuthenticator::NoAccountAuthenticator, &auth_key)
+            .is_err());
+    }
+}
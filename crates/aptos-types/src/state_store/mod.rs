@@ -0,0 +1,9 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The keys and values of the global state, as stored in the leaves of the state Sparse Merkle
+//! Tree.
+
+pub mod state_key;
+pub mod state_value;
+pub mod table;
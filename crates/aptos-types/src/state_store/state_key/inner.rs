@@ -0,0 +1,120 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{access_path::AccessPath, state_store::table::TableHandle};
+use aptos_crypto_derive::CryptoHasher;
+use serde::{Deserialize, Serialize};
+use std::{fmt, fmt::Formatter};
+use strum_macros::FromRepr;
+use thiserror::Error;
+
+/// The leading byte of an encoded state key, which tells its kind.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromRepr)]
+#[repr(u8)]
+pub enum StateKeyTag {
+    AccessPath,
+    TableItem,
+    Raw = 255,
+}
+
+#[derive(Clone, CryptoHasher, Eq, PartialEq, Serialize, Deserialize, Ord, PartialOrd, Hash)]
+#[serde(rename = "StateKey")]
+pub enum StateKeyInner {
+    AccessPath(AccessPath),
+    TableItem {
+        handle: TableHandle,
+        #[serde(with = "serde_bytes")]
+        key: Vec<u8>,
+    },
+    // Only used for testing
+    #[serde(with = "serde_bytes")]
+    Raw(Vec<u8>),
+}
+
+impl StateKeyInner {
+    /// Encode the key as the tag of its kind followed by its content. This is the form that is
+    /// hashed into the state Sparse Merkle Tree and used as the key of the state DB.
+    pub fn encode(&self) -> Result<Vec<u8>, StateKeyDecodeErr> {
+        let mut out = vec![];
+        match self {
+            StateKeyInner::AccessPath(access_path) => {
+                out.push(StateKeyTag::AccessPath as u8);
+                out.extend(bcs::to_bytes(access_path)?);
+            },
+            StateKeyInner::TableItem { handle, key } => {
+                out.push(StateKeyTag::TableItem as u8);
+                bcs::serialize_into(&mut out, &handle)?;
+                out.extend(key);
+            },
+            StateKeyInner::Raw(raw_bytes) => {
+                out.push(StateKeyTag::Raw as u8);
+                out.extend(raw_bytes);
+            },
+        };
+        Ok(out)
+    }
+
+    /// Decode a key produced by [`StateKeyInner::encode`].
+    pub fn decode(val: &[u8]) -> Result<Self, StateKeyDecodeErr> {
+        let (&tag, rest) = val.split_first().ok_or(StateKeyDecodeErr::EmptyInput)?;
+        let state_key_tag = StateKeyTag::from_repr(tag)
+            .ok_or(StateKeyDecodeErr::UnknownTag { unknown_tag: tag })?;
+        Ok(match state_key_tag {
+            StateKeyTag::AccessPath => StateKeyInner::AccessPath(bcs::from_bytes(rest)?),
+            StateKeyTag::TableItem => {
+                const HANDLE_SIZE: usize = std::mem::size_of::<TableHandle>();
+                if rest.len() < HANDLE_SIZE {
+                    return Err(StateKeyDecodeErr::NotEnoughBytes {
+                        tag,
+                        num_bytes: val.len(),
+                    });
+                }
+                let (handle, key) = rest.split_at(HANDLE_SIZE);
+                StateKeyInner::TableItem {
+                    handle: bcs::from_bytes(handle)?,
+                    key: key.to_vec(),
+                }
+            },
+            StateKeyTag::Raw => StateKeyInner::Raw(rest.to_vec()),
+        })
+    }
+}
+
+impl fmt::Debug for StateKeyInner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StateKeyInner::AccessPath(access_path) => {
+                write!(f, "{:?}", access_path)
+            },
+            StateKeyInner::TableItem { handle, key } => {
+                write!(
+                    f,
+                    "TableItem {{ handle: {:x}, key: {} }}",
+                    handle.0,
+                    hex::encode(key),
+                )
+            },
+            StateKeyInner::Raw(bytes) => {
+                write!(f, "Raw({})", hex::encode(bytes))
+            },
+        }
+    }
+}
+
+/// Error thrown when a [`StateKeyInner`] fails to be encoded or decoded.
+#[derive(Debug, Error)]
+pub enum StateKeyDecodeErr {
+    /// Input is empty.
+    #[error("Missing tag due to empty input")]
+    EmptyInput,
+
+    /// The first byte of the input is not a known tag representing one of the variants.
+    #[error("lead tag byte is unknown: {}", unknown_tag)]
+    UnknownTag { unknown_tag: u8 },
+
+    #[error("Not enough bytes: tag: {}, num bytes: {}", tag, num_bytes)]
+    NotEnoughBytes { tag: u8, num_bytes: usize },
+
+    #[error(transparent)]
+    BcsError(#[from] bcs::Error),
+}
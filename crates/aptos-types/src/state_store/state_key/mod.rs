@@ -0,0 +1,273 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod inner;
+
+use crate::{
+    access_path::AccessPath, on_chain_config::OnChainConfig, state_store::table::TableHandle,
+};
+use anyhow::Result;
+use aptos_crypto::hash::{CryptoHash, CryptoHasher, HashValue};
+use inner::{StateKeyDecodeErr, StateKeyInner, StateKeyInnerHasher};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::IdentStr,
+    language_storage::{ModuleId, ResourceKey, StructTag, CORE_CODE_ADDRESS},
+};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// The key of an item in the global state: a resource or module under an account, a table item,
+/// or raw bytes. Its hash is the key of the item's leaf in the state Sparse Merkle Tree.
+#[derive(Clone)]
+pub struct StateKey {
+    inner: StateKeyInner,
+    hash: OnceCell<HashValue>,
+}
+
+impl StateKey {
+    pub fn new(inner: StateKeyInner) -> Self {
+        Self {
+            inner,
+            hash: OnceCell::new(),
+        }
+    }
+
+    pub fn inner(&self) -> &StateKeyInner {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> StateKeyInner {
+        self.inner
+    }
+
+    pub fn access_path(access_path: AccessPath) -> Self {
+        Self::new(StateKeyInner::AccessPath(access_path))
+    }
+
+    pub fn resource(address: &AccountAddress, struct_tag: &StructTag) -> Result<Self> {
+        Ok(Self::access_path(AccessPath::resource_access_path(
+            *address,
+            struct_tag.clone(),
+        )?))
+    }
+
+    pub fn resource_group(address: &AccountAddress, group_tag: &StructTag) -> Self {
+        Self::access_path(AccessPath::resource_group_access_path(
+            *address,
+            group_tag.clone(),
+        ))
+    }
+
+    pub fn module(address: &AccountAddress, name: &IdentStr) -> Self {
+        Self::module_id(&ModuleId::new(*address, name.to_owned()))
+    }
+
+    pub fn module_id(module_id: &ModuleId) -> Self {
+        Self::access_path(AccessPath::code_access_path(module_id.clone()))
+    }
+
+    /// The key of the on-chain config `T`, which is stored under the framework account.
+    pub fn on_chain_config<T: OnChainConfig>() -> Result<Self> {
+        Self::resource(&CORE_CODE_ADDRESS, &T::struct_tag())
+    }
+
+    pub fn table_item(handle: &TableHandle, key: &[u8]) -> Self {
+        Self::new(StateKeyInner::TableItem {
+            handle: *handle,
+            key: key.to_vec(),
+        })
+    }
+
+    pub fn raw(raw_key: &[u8]) -> Self {
+        Self::new(StateKeyInner::Raw(raw_key.to_vec()))
+    }
+
+    /// See [`StateKeyInner::encode`].
+    pub fn encode(&self) -> Result<Vec<u8>, StateKeyDecodeErr> {
+        self.inner.encode()
+    }
+
+    /// See [`StateKeyInner::decode`].
+    pub fn decode(val: &[u8]) -> Result<Self, StateKeyDecodeErr> {
+        StateKeyInner::decode(val).map(Self::new)
+    }
+
+    pub fn size(&self) -> usize {
+        match &self.inner {
+            StateKeyInner::AccessPath(access_path) => access_path.size(),
+            StateKeyInner::TableItem { handle, key } => handle.size() + key.len(),
+            StateKeyInner::Raw(bytes) => bytes.len(),
+        }
+    }
+}
+
+impl CryptoHash for StateKey {
+    type Hasher = StateKeyInnerHasher;
+
+    fn hash(&self) -> HashValue {
+        *self.hash.get_or_init(|| {
+            let mut state = Self::Hasher::default();
+            state.update(
+                self.inner
+                    .encode()
+                    .expect("Failed to serialize the state key")
+                    .as_ref(),
+            );
+            state.finish()
+        })
+    }
+}
+
+impl From<AccessPath> for StateKey {
+    fn from(access_path: AccessPath) -> Self {
+        Self::access_path(access_path)
+    }
+}
+
+impl TryFrom<&ResourceKey> for StateKey {
+    type Error = anyhow::Error;
+
+    fn try_from(resource_key: &ResourceKey) -> Result<Self> {
+        Self::resource(&resource_key.address(), resource_key.type_())
+    }
+}
+
+impl TryFrom<ResourceKey> for StateKey {
+    type Error = anyhow::Error;
+
+    fn try_from(resource_key: ResourceKey) -> Result<Self> {
+        Self::try_from(&resource_key)
+    }
+}
+
+// The cached hash is left out of comparisons, as it may or may not be initialized.
+impl PartialEq for StateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for StateKey {}
+
+impl Hash for StateKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+impl PartialOrd for StateKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StateKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl Serialize for StateKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StateKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        StateKeyInner::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl fmt::Debug for StateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "StateKey::{:?}", self.inner)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use inner::StateKeyTag;
+    use move_core_types::{identifier::Identifier, language_storage::TypeTag};
+
+    fn coin_store() -> StructTag {
+        StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("coin").unwrap(),
+            name: Identifier::new("CoinStore").unwrap(),
+            type_args: vec![TypeTag::Struct(Box::new(StructTag {
+                address: AccountAddress::ONE,
+                module: Identifier::new("aptos_coin").unwrap(),
+                name: Identifier::new("AptosCoin").unwrap(),
+                type_args: vec![],
+            }))],
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let address = AccountAddress::from_hex_literal("0xa550c18").unwrap();
+        let keys = [
+            StateKey::resource(&address, &coin_store()).unwrap(),
+            StateKey::resource_group(&address, &coin_store()),
+            StateKey::module(&AccountAddress::ONE, IdentStr::new("coin").unwrap()),
+            StateKey::table_item(&TableHandle(address), b"key"),
+            StateKey::raw(b"raw"),
+        ];
+        for key in keys {
+            let encoded = key.encode().unwrap();
+            assert_eq!(StateKey::decode(&encoded).unwrap(), key);
+            let bcs_bytes = bcs::to_bytes(&key).unwrap();
+            assert_eq!(bcs::from_bytes::<StateKey>(&bcs_bytes).unwrap(), key);
+        }
+
+        let table_item = StateKey::table_item(&TableHandle(address), b"key");
+        let mut expected = vec![StateKeyTag::TableItem as u8];
+        expected.extend(address.to_vec());
+        expected.extend(b"key");
+        assert_eq!(table_item.encode().unwrap(), expected);
+
+        assert!(matches!(
+            StateKey::decode(&[]),
+            Err(StateKeyDecodeErr::EmptyInput)
+        ));
+        assert!(matches!(
+            StateKey::decode(&[7]),
+            Err(StateKeyDecodeErr::UnknownTag { unknown_tag: 7 })
+        ));
+        assert!(matches!(
+            StateKey::decode(&[StateKeyTag::TableItem as u8, 1, 2]),
+            Err(StateKeyDecodeErr::NotEnoughBytes { .. })
+        ));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let address = AccountAddress::from_hex_literal("0xa550c18").unwrap();
+        let key = StateKey::resource(&address, &coin_store()).unwrap();
+        assert_eq!(
+            StateKey::try_from(ResourceKey::new(address, coin_store())).unwrap(),
+            key
+        );
+        let access_path = AccessPath::resource_access_path(address, coin_store()).unwrap();
+        assert_eq!(StateKey::from(access_path.clone()), key);
+        assert_eq!(AccessPath::try_from(key.clone()).unwrap(), access_path);
+        assert!(AccessPath::try_from(StateKey::raw(b"raw")).is_err());
+
+        // The hash covers the encoded key under the `StateKey` salt.
+        let mut hasher = StateKeyInnerHasher::default();
+        hasher.update(&key.encode().unwrap());
+        assert_eq!(CryptoHash::hash(&key), hasher.finish());
+        assert_ne!(
+            CryptoHash::hash(&key),
+            CryptoHash::hash(&StateKey::raw(&key.encode().unwrap()))
+        );
+    }
+}
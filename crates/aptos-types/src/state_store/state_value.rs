@@ -0,0 +1,197 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_crypto::hash::{CryptoHash, HashValue};
+use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
+use bytes::Bytes;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The storage fee paid for a state slot and the time it was created, as charged under the
+/// storage gas schedule.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum StateValueMetadata {
+    V0 {
+        deposit: u64,
+        creation_time_usecs: u64,
+    },
+    V1 {
+        slot_deposit: u64,
+        bytes_deposit: u64,
+        creation_time_usecs: u64,
+    },
+}
+
+impl StateValueMetadata {
+    pub fn new(slot_deposit: u64, bytes_deposit: u64, creation_time_usecs: u64) -> Self {
+        Self::V1 {
+            slot_deposit,
+            bytes_deposit,
+            creation_time_usecs,
+        }
+    }
+
+    /// Metadata in the format used before the storage fee was split into a slot and a bytes
+    /// deposit.
+    pub fn legacy(deposit: u64, creation_time_usecs: u64) -> Self {
+        Self::V0 {
+            deposit,
+            creation_time_usecs,
+        }
+    }
+
+    pub fn creation_time_usecs(&self) -> u64 {
+        match self {
+            Self::V0 {
+                creation_time_usecs,
+                ..
+            }
+            | Self::V1 {
+                creation_time_usecs,
+                ..
+            } => *creation_time_usecs,
+        }
+    }
+
+    pub fn slot_deposit(&self) -> u64 {
+        match self {
+            Self::V0 { deposit, .. } => *deposit,
+            Self::V1 { slot_deposit, .. } => *slot_deposit,
+        }
+    }
+
+    pub fn bytes_deposit(&self) -> u64 {
+        match self {
+            Self::V0 { .. } => 0,
+            Self::V1 { bytes_deposit, .. } => *bytes_deposit,
+        }
+    }
+
+    pub fn total_deposit(&self) -> u64 {
+        self.slot_deposit() + self.bytes_deposit()
+    }
+}
+
+#[derive(
+    BCSCryptoHash, Clone, CryptoHasher, Debug, Deserialize, Eq, PartialEq, Serialize, Hash,
+)]
+#[serde(rename = "StateValue")]
+enum StateValueInner {
+    V0(Bytes),
+    WithMetadata {
+        data: Bytes,
+        metadata: StateValueMetadata,
+    },
+}
+
+/// The value of an item in the global state. Its hash is the value hash of the item's leaf in
+/// the state Sparse Merkle Tree.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct StateValue {
+    inner: StateValueInner,
+    hash: HashValue,
+}
+
+impl StateValue {
+    fn new_impl(inner: StateValueInner) -> Self {
+        let hash = CryptoHash::hash(&inner);
+        Self { inner, hash }
+    }
+
+    /// A value without metadata, as written before storage fees were tracked per slot.
+    pub fn new_legacy(bytes: Bytes) -> Self {
+        Self::new_impl(StateValueInner::V0(bytes))
+    }
+
+    pub fn new_with_metadata(data: Bytes, metadata: StateValueMetadata) -> Self {
+        Self::new_impl(StateValueInner::WithMetadata { data, metadata })
+    }
+
+    pub fn bytes(&self) -> &Bytes {
+        match &self.inner {
+            StateValueInner::V0(data) | StateValueInner::WithMetadata { data, .. } => data,
+        }
+    }
+
+    pub fn metadata(&self) -> Option<&StateValueMetadata> {
+        match &self.inner {
+            StateValueInner::V0(_) => None,
+            StateValueInner::WithMetadata { metadata, .. } => Some(metadata),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.bytes().len()
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.unpack().0
+    }
+
+    pub fn unpack(self) -> (Bytes, Option<StateValueMetadata>) {
+        match self.inner {
+            StateValueInner::V0(data) => (data, None),
+            StateValueInner::WithMetadata { data, metadata } => (data, Some(metadata)),
+        }
+    }
+}
+
+impl From<Vec<u8>> for StateValue {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new_legacy(bytes.into())
+    }
+}
+
+impl From<Bytes> for StateValue {
+    fn from(bytes: Bytes) -> Self {
+        Self::new_legacy(bytes)
+    }
+}
+
+impl CryptoHash for StateValue {
+    type Hasher = StateValueInnerHasher;
+
+    fn hash(&self) -> HashValue {
+        self.hash
+    }
+}
+
+impl Serialize for StateValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StateValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        StateValueInner::deserialize(deserializer).map(Self::new_impl)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aptos_crypto::hash::CryptoHasher;
+
+    #[test]
+    fn test_bcs_layout() {
+        let legacy = StateValue::new_legacy(Bytes::from_static(b"abc"));
+        assert_eq!(
+            bcs::to_bytes(&legacy).unwrap(),
+            vec![0, 3, b'a', b'b', b'c']
+        );
+        assert_eq!(legacy.metadata(), None);
+
+        let metadata = StateValueMetadata::new(50_000, 400, 1_700_000_000_000_000);
+        let value = StateValue::new_with_metadata(Bytes::from_static(b"abc"), metadata.clone());
+        let mut expected = vec![1, 3, b'a', b'b', b'c'];
+        expected.extend(bcs::to_bytes(&metadata).unwrap());
+        assert_eq!(bcs::to_bytes(&value).unwrap(), expected);
+        assert_eq!(bcs::from_bytes::<StateValue>(&expected).unwrap(), value);
+        assert_eq!(value.metadata().unwrap().total_deposit(), 50_400);
+
+        // The hash covers the BCS bytes under the `StateValue` salt.
+        let mut hasher = StateValueInnerHasher::default();
+        hasher.update(&expected);
+        assert_eq!(CryptoHash::hash(&value), hasher.finish());
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::account_address::AccountAddress;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The handle of a Move table, i.e. the address its items are stored under.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct TableHandle(pub AccountAddress);
+
+impl TableHandle {
+    pub fn size(&self) -> usize {
+        std::mem::size_of_val(&self.0)
+    }
+}
+
+impl FromStr for TableHandle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let handle = AccountAddress::from_str(s)?;
+        Ok(Self(handle))
+    }
+}
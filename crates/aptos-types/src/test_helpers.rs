@@ -0,0 +1,56 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fixtures shared by the tests of this crate and, with the `fuzzing` feature, of the crates
+//! depending on it.
+
+use crate::{
+    account_address,
+    chain_id::ChainId,
+    transaction::{EntryFunction, RawTransaction, SignedTransaction},
+};
+use aptos_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+    SigningKey,
+};
+use move_core_types::{account_address::AccountAddress, ident_str, language_storage::ModuleId};
+
+/// The Ed25519 private key whose bytes are all `seed`.
+pub fn private_key(seed: u8) -> Ed25519PrivateKey {
+    Ed25519PrivateKey::try_from(&[seed; 32][..]).unwrap()
+}
+
+/// A call to `0x1::aptos_account::transfer` with the given BCS-encoded arguments.
+pub fn transfer(args: Vec<Vec<u8>>) -> EntryFunction {
+    EntryFunction::new(
+        ModuleId::new(AccountAddress::ONE, ident_str!("aptos_account").to_owned()),
+        ident_str!("transfer").to_owned(),
+        vec![],
+        args,
+    )
+}
+
+/// A transfer without arguments sent by `sender`, which never expires.
+pub fn raw_transaction(sender: AccountAddress, sequence_number: u64) -> RawTransaction {
+    RawTransaction::new_entry_function(
+        sender,
+        sequence_number,
+        transfer(vec![]),
+        1_000,
+        100,
+        u64::MAX,
+        ChainId::test(),
+    )
+}
+
+/// A transfer sent and signed by the account of `private_key(seed)`.
+pub fn signed_transaction(seed: u8, sequence_number: u64) -> SignedTransaction {
+    let private_key = private_key(seed);
+    let public_key = Ed25519PublicKey::from(&private_key);
+    let raw_txn = raw_transaction(
+        account_address::from_public_key(&public_key),
+        sequence_number,
+    );
+    let signature = private_key.sign(&raw_txn).unwrap();
+    SignedTransaction::new(raw_txn, public_key, signature)
+}
@@ -2,7 +2,7 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{ensure, Error, Result};
+use anyhow::{bail, ensure, Error, Result};
 use aptos_crypto::{Signature, ValidCryptoMaterial, ValidCryptoMaterialStringExt};
 use aptos_crypto_derive::{CryptoHasher, DeserializeKey, SerializeKey};
 // use aptos_crypto::{
@@ -16,10 +16,11 @@ use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
-use super::RawTransaction;
+use super::{RawTransaction, RawTransactionWithData};
 use aptos_crypto::{
     ed25519::{Ed25519PublicKey, Ed25519Signature},
-    hash::HashValue,
+    hash::{CryptoHash, HashValue},
+    multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
     traits::CryptoMaterialError,
 };
 use candid::CandidType;
@@ -43,12 +44,35 @@ pub enum AuthenticationError {
 /// `AccountAuthenticator`'s `AuthenticationKeyPreimage` matches the `AuthenticationKey` stored
 /// under the participating signer's account address.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
 pub enum TransactionAuthenticator {
     /// Single Ed25519 signature
     Ed25519 {
         public_key: Ed25519PublicKey,
         signature: Ed25519Signature,
     },
+    /// K-of-N multisignature
+    MultiEd25519 {
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    },
+    /// Multi-agent transaction.
+    MultiAgent {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+    },
+    /// Optional Multi-agent transaction with a fee payer.
+    FeePayer {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_address: AccountAddress,
+        fee_payer_signer: AccountAuthenticator,
+    },
+    SingleSender {
+        sender: AccountAuthenticator,
+    },
 }
 
 impl TransactionAuthenticator {
@@ -61,38 +85,116 @@ impl TransactionAuthenticator {
     }
 
     /// Create a (optional) multi-agent fee payer authenticator
-    // pub fn fee_payer(
-    //     sender: AccountAuthenticator,
-    //     secondary_signer_addresses: Vec<AccountAddress>,
-    //     secondary_signers: Vec<AccountAuthenticator>,
-    //     fee_payer_address: AccountAddress,
-    //     fee_payer_signer: AccountAuthenticator,
-    // ) -> Self {
-    //     Self::FeePayer {
-    //         sender,
-    //         secondary_signer_addresses,
-    //         secondary_signers,
-    //         fee_payer_address,
-    //         fee_payer_signer,
-    //     }
-    // }
+    pub fn fee_payer(
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_address: AccountAddress,
+        fee_payer_signer: AccountAuthenticator,
+    ) -> Self {
+        Self::FeePayer {
+            sender,
+            secondary_signer_addresses,
+            secondary_signers,
+            fee_payer_address,
+            fee_payer_signer,
+        }
+    }
+
+    /// Create a multisignature ed25519 authenticator
+    pub fn multi_ed25519(
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    ) -> Self {
+        Self::MultiEd25519 {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Create a multi-agent authenticator
+    pub fn multi_agent(
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+    ) -> Self {
+        Self::MultiAgent {
+            sender,
+            secondary_signer_addresses,
+            secondary_signers,
+        }
+    }
+
+    /// Create a single-sender authenticator
+    pub fn single_sender(sender: AccountAuthenticator) -> Self {
+        Self::SingleSender { sender }
+    }
 
     /// Return Ok if all AccountAuthenticator's public keys match their signatures, Err otherwise
     pub fn verify(&self, raw_txn: &RawTransaction) -> Result<()> {
-        // let num_sigs: usize = self.sender().number_of_signatures()
-        //     + self
-        //         .secondary_signers()
-        //         .iter()
-        //         .map(|auth| auth.number_of_signatures())
-        //         .sum::<usize>();
-        // if num_sigs > MAX_NUM_OF_SIGS {
-        //     return Err(Error::new(AuthenticationError::MaxSignaturesExceeded));
-        // }
+        let num_sigs: usize = self.sender().number_of_signatures()
+            + self
+                .secondary_signers()
+                .iter()
+                .map(|auth| auth.number_of_signatures())
+                .sum::<usize>();
+        if num_sigs > MAX_NUM_OF_SIGS {
+            return Err(Error::new(AuthenticationError::MaxSignaturesExceeded));
+        }
         match self {
             Self::Ed25519 {
                 public_key,
                 signature,
             } => signature.verify(raw_txn, public_key),
+            Self::MultiEd25519 {
+                public_key,
+                signature,
+            } => signature.verify(raw_txn, public_key),
+            Self::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                fee_payer_signer,
+            } => {
+                ensure_secondary_signers(secondary_signer_addresses, secondary_signers)?;
+                let message = RawTransactionWithData::new_fee_payer(
+                    raw_txn.clone(),
+                    secondary_signer_addresses.clone(),
+                    *fee_payer_address,
+                );
+                // Wallets sign sponsored transactions before the sponsor is known, with the zero
+                // address as the fee payer, so the sender and secondary signers may have signed
+                // either message. The fee payer always signs over its own address.
+                let zero_fee_payer_message = RawTransactionWithData::new_fee_payer(
+                    raw_txn.clone(),
+                    secondary_signer_addresses.clone(),
+                    AccountAddress::ZERO,
+                );
+                for signer in std::iter::once(sender).chain(secondary_signers) {
+                    if signer.verify(&message).is_err() {
+                        signer.verify(&zero_fee_payer_message)?;
+                    }
+                }
+                fee_payer_signer.verify(&message)
+            },
+            Self::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            } => {
+                ensure_secondary_signers(secondary_signer_addresses, secondary_signers)?;
+                let message = RawTransactionWithData::new_multi_agent(
+                    raw_txn.clone(),
+                    secondary_signer_addresses.clone(),
+                );
+                sender.verify(&message)?;
+                for signer in secondary_signers {
+                    signer.verify(&message)?;
+                }
+                Ok(())
+            },
+            Self::SingleSender { sender } => sender.verify(raw_txn),
         }
     }
 
@@ -102,96 +204,103 @@ impl TransactionAuthenticator {
                 public_key,
                 signature,
             } => AccountAuthenticator::ed25519(public_key.clone(), signature.clone()),
+            Self::MultiEd25519 {
+                public_key,
+                signature,
+            } => AccountAuthenticator::multi_ed25519(public_key.clone(), signature.clone()),
+            Self::FeePayer { sender, .. } => sender.clone(),
+            Self::MultiAgent { sender, .. } => sender.clone(),
+            Self::SingleSender { sender } => sender.clone(),
         }
     }
 
-    // pub fn secondary_signer_addresses(&self) -> Vec<AccountAddress> {
-    //     match self {
-    //         Self::Ed25519 { .. } | Self::MultiEd25519 { .. } | Self::SingleSender { .. } => {
-    //             vec![]
-    //         }
-    //         Self::FeePayer {
-    //             sender: _,
-    //             secondary_signer_addresses,
-    //             ..
-    //         } => secondary_signer_addresses.to_vec(),
-    //         Self::MultiAgent {
-    //             sender: _,
-    //             secondary_signer_addresses,
-    //             ..
-    //         } => secondary_signer_addresses.to_vec(),
-    //     }
-    // }
+    pub fn secondary_signer_addresses(&self) -> Vec<AccountAddress> {
+        match self {
+            Self::Ed25519 { .. } | Self::MultiEd25519 { .. } | Self::SingleSender { .. } => {
+                vec![]
+            },
+            Self::FeePayer {
+                sender: _,
+                secondary_signer_addresses,
+                ..
+            } => secondary_signer_addresses.to_vec(),
+            Self::MultiAgent {
+                sender: _,
+                secondary_signer_addresses,
+                ..
+            } => secondary_signer_addresses.to_vec(),
+        }
+    }
 
-    // pub fn secondary_signers(&self) -> Vec<AccountAuthenticator> {
-    //     match self {
-    //         Self::Ed25519 { .. } | Self::MultiEd25519 { .. } | Self::SingleSender { .. } => {
-    //             vec![]
-    //         }
-    //         Self::FeePayer {
-    //             sender: _,
-    //             secondary_signer_addresses: _,
-    //             secondary_signers,
-    //             ..
-    //         } => secondary_signers.to_vec(),
-    //         Self::MultiAgent {
-    //             sender: _,
-    //             secondary_signer_addresses: _,
-    //             secondary_signers,
-    //         } => secondary_signers.to_vec(),
-    //     }
-    // }
+    pub fn secondary_signers(&self) -> Vec<AccountAuthenticator> {
+        match self {
+            Self::Ed25519 { .. } | Self::MultiEd25519 { .. } | Self::SingleSender { .. } => {
+                vec![]
+            },
+            Self::FeePayer {
+                sender: _,
+                secondary_signer_addresses: _,
+                secondary_signers,
+                ..
+            } => secondary_signers.to_vec(),
+            Self::MultiAgent {
+                sender: _,
+                secondary_signer_addresses: _,
+                secondary_signers,
+            } => secondary_signers.to_vec(),
+        }
+    }
 
-    // pub fn fee_payer_address(&self) -> Option<AccountAddress> {
-    //     match self {
-    //         Self::Ed25519 { .. }
-    //         | Self::MultiEd25519 { .. }
-    //         | Self::MultiAgent { .. }
-    //         | Self::SingleSender { .. } => None,
-    //         Self::FeePayer {
-    //             sender: _,
-    //             secondary_signer_addresses: _,
-    //             secondary_signers: _,
-    //             fee_payer_address,
-    //             ..
-    //         } => Some(*fee_payer_address),
-    //     }
-    // }
+    pub fn fee_payer_address(&self) -> Option<AccountAddress> {
+        match self {
+            Self::Ed25519 { .. }
+            | Self::MultiEd25519 { .. }
+            | Self::MultiAgent { .. }
+            | Self::SingleSender { .. } => None,
+            Self::FeePayer {
+                sender: _,
+                secondary_signer_addresses: _,
+                secondary_signers: _,
+                fee_payer_address,
+                ..
+            } => Some(*fee_payer_address),
+        }
+    }
 
-    // pub fn fee_payer_signer(&self) -> Option<AccountAuthenticator> {
-    //     match self {
-    //         Self::Ed25519 { .. }
-    //         | Self::MultiEd25519 { .. }
-    //         | Self::MultiAgent { .. }
-    //         | Self::SingleSender { .. } => None,
-    //         Self::FeePayer {
-    //             sender: _,
-    //             secondary_signer_addresses: _,
-    //             secondary_signers: _,
-    //             fee_payer_address: _,
-    //             fee_payer_signer,
-    //         } => Some(fee_payer_signer.clone()),
-    //     }
-    // }
+    pub fn fee_payer_signer(&self) -> Option<AccountAuthenticator> {
+        match self {
+            Self::Ed25519 { .. }
+            | Self::MultiEd25519 { .. }
+            | Self::MultiAgent { .. }
+            | Self::SingleSender { .. } => None,
+            Self::FeePayer {
+                sender: _,
+                secondary_signer_addresses: _,
+                secondary_signers: _,
+                fee_payer_address: _,
+                fee_payer_signer,
+            } => Some(fee_payer_signer.clone()),
+        }
+    }
 
-    // pub fn all_signers(&self) -> Vec<AccountAuthenticator> {
-    //     match self {
-    //         // This is to ensure that any new TransactionAuthenticator variant must update this function.
-    //         Self::Ed25519 { .. }
-    //         | Self::MultiEd25519 { .. }
-    //         | Self::MultiAgent { .. }
-    //         | Self::FeePayer { .. }
-    //         | Self::SingleSender { .. } => {
-    //             let mut account_authenticators: Vec<AccountAuthenticator> = vec![];
-    //             account_authenticators.push(self.sender());
-    //             account_authenticators.extend(self.secondary_signers());
-    //             if let Some(fee_payer) = self.fee_payer_signer() {
-    //                 account_authenticators.push(fee_payer);
-    //             }
-    //             account_authenticators
-    //         }
-    //     }
-    // }
+    pub fn all_signers(&self) -> Vec<AccountAuthenticator> {
+        match self {
+            // This is to ensure that any new TransactionAuthenticator variant must update this function.
+            Self::Ed25519 { .. }
+            | Self::MultiEd25519 { .. }
+            | Self::MultiAgent { .. }
+            | Self::FeePayer { .. }
+            | Self::SingleSender { .. } => {
+                let mut account_authenticators: Vec<AccountAuthenticator> = vec![];
+                account_authenticators.push(self.sender());
+                account_authenticators.extend(self.secondary_signers());
+                if let Some(fee_payer) = self.fee_payer_signer() {
+                    account_authenticators.push(fee_payer);
+                }
+                account_authenticators
+            },
+        }
+    }
 
     // pub fn to_single_key_authenticators(&self) -> Result<Vec<SingleKeyAuthenticator>> {
     //     let account_authenticators = self.all_signers();
@@ -242,6 +351,20 @@ impl TransactionAuthenticator {
     // }
 }
 
+/// Each secondary signer must sign for exactly one of the secondary signer addresses.
+fn ensure_secondary_signers(
+    secondary_signer_addresses: &[AccountAddress],
+    secondary_signers: &[AccountAuthenticator],
+) -> Result<()> {
+    ensure!(
+        secondary_signer_addresses.len() == secondary_signers.len(),
+        "{} secondary signer addresses but {} secondary signers",
+        secondary_signer_addresses.len(),
+        secondary_signers.len()
+    );
+    Ok(())
+}
+
 // impl fmt::Display for TransactionAuthenticator {
 //     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 //         match self {
@@ -364,14 +487,28 @@ impl fmt::Display for Scheme {
 /// (public_key | signature_scheme_id).
 /// Each on-chain `Account` must store an `AuthenticationKey` (computed via a sha3 hash of `(public
 /// key bytes | scheme as u8)`).
-#[derive(Debug, Eq, PartialEq, Hash, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum AccountAuthenticator {
     /// Ed25519 Single signature
     Ed25519 {
         public_key: Ed25519PublicKey,
         signature: Ed25519Signature,
     },
-
+    /// Ed25519 K-of-N multisignature
+    MultiEd25519 {
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    },
+    /// A single key of any supported scheme
+    SingleKey {
+        authenticator: SingleKeyAuthenticator,
+    },
+    /// K-of-N signatures by keys of any supported scheme
+    MultiKey {
+        authenticator: MultiKeyAuthenticator,
+    },
+    /// No signature at all. Only accepted by transaction simulation, for signers whose keys are
+    /// not known.
     NoAccountAuthenticator,
     // ... add more schemes here
 }
@@ -381,7 +518,9 @@ impl AccountAuthenticator {
     pub fn scheme(&self) -> Scheme {
         match self {
             Self::Ed25519 { .. } => Scheme::Ed25519,
-
+            Self::MultiEd25519 { .. } => Scheme::MultiEd25519,
+            Self::SingleKey { .. } => Scheme::SingleKey,
+            Self::MultiKey { .. } => Scheme::MultiKey,
             Self::NoAccountAuthenticator => Scheme::NoScheme,
         }
     }
@@ -394,10 +533,80 @@ impl AccountAuthenticator {
         }
     }
 
+    /// Create a multisignature ed25519 authenticator
+    pub fn multi_ed25519(
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    ) -> Self {
+        Self::MultiEd25519 {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Create a single-key authenticator
+    pub fn single_key(authenticator: SingleKeyAuthenticator) -> Self {
+        Self::SingleKey { authenticator }
+    }
+
+    /// Create a multi-key authenticator
+    pub fn multi_key(authenticator: MultiKeyAuthenticator) -> Self {
+        Self::MultiKey { authenticator }
+    }
+
+    /// Create the authenticator of a signer in a transaction that is only simulated: an Ed25519
+    /// public key with an all-zero signature, or `NoAccountAuthenticator` if the key is unknown.
+    /// The simulation endpoint rejects valid signatures, so that simulated transactions cannot be
+    /// submitted by whoever sees them.
+    pub fn simulation(public_key: Option<Ed25519PublicKey>) -> Self {
+        match public_key {
+            Some(public_key) => Self::ed25519(public_key, Ed25519Signature::dummy_signature()),
+            None => Self::NoAccountAuthenticator,
+        }
+    }
+
+    /// Return Ok if the authenticator's public key matches its signature, Err otherwise
+    pub fn verify<T: Serialize + CryptoHash>(&self, message: &T) -> Result<()> {
+        match self {
+            Self::Ed25519 {
+                public_key,
+                signature,
+            } => signature.verify(message, public_key),
+            Self::MultiEd25519 {
+                public_key,
+                signature,
+            } => signature.verify(message, public_key),
+            Self::SingleKey { authenticator } => authenticator.verify(message),
+            Self::MultiKey { authenticator } => authenticator.verify(message),
+            Self::NoAccountAuthenticator => bail!("No signature to verify."),
+        }
+    }
+
+    /// Return Ok if the authenticator's signature is valid for the raw `message` bytes, i.e.,
+    /// for a message that was signed without a hasher prefix.
+    pub fn verify_arbitrary_msg(&self, message: &[u8]) -> Result<()> {
+        match self {
+            Self::Ed25519 {
+                public_key,
+                signature,
+            } => signature.verify_arbitrary_msg(message, public_key),
+            Self::MultiEd25519 {
+                public_key,
+                signature,
+            } => signature.verify_arbitrary_msg(message, public_key),
+            Self::SingleKey { authenticator } => authenticator.verify_arbitrary_msg(message),
+            Self::MultiKey { authenticator } => authenticator.verify_arbitrary_msg(message),
+            Self::NoAccountAuthenticator => bail!("No signature to verify."),
+        }
+    }
+
     /// Return the raw bytes of `self.public_key`
     pub fn public_key_bytes(&self) -> Vec<u8> {
         match self {
             Self::Ed25519 { public_key, .. } => public_key.to_bytes().to_vec(),
+            Self::MultiEd25519 { public_key, .. } => public_key.to_bytes(),
+            Self::SingleKey { authenticator } => authenticator.public_key_bytes(),
+            Self::MultiKey { authenticator } => authenticator.public_key_bytes(),
             Self::NoAccountAuthenticator => vec![],
         }
     }
@@ -406,6 +615,9 @@ impl AccountAuthenticator {
     pub fn signature_bytes(&self) -> Vec<u8> {
         match self {
             Self::Ed25519 { signature, .. } => signature.to_bytes().to_vec(),
+            Self::MultiEd25519 { signature, .. } => signature.to_bytes(),
+            Self::SingleKey { authenticator } => authenticator.signature_bytes(),
+            Self::MultiKey { authenticator } => authenticator.signature_bytes(),
             Self::NoAccountAuthenticator => vec![],
         }
     }
@@ -426,11 +638,302 @@ impl AccountAuthenticator {
     pub fn number_of_signatures(&self) -> usize {
         match self {
             Self::Ed25519 { .. } => 1,
+            Self::MultiEd25519 { signature, .. } => signature.signatures().len(),
+            Self::SingleKey { .. } => 1,
+            Self::MultiKey { authenticator } => authenticator.signatures.len(),
             Self::NoAccountAuthenticator => 0,
         }
     }
 }
 
+/// A single signature, together with the public key that produced it, of any scheme supported by
+/// `SingleKey` accounts.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct SingleKeyAuthenticator {
+    public_key: AnyPublicKey,
+    signature: AnySignature,
+}
+
+impl SingleKeyAuthenticator {
+    pub fn new(public_key: AnyPublicKey, signature: AnySignature) -> Self {
+        Self {
+            public_key,
+            signature,
+        }
+    }
+
+    pub fn public_key(&self) -> &AnyPublicKey {
+        &self.public_key
+    }
+
+    pub fn signature(&self) -> &AnySignature {
+        &self.signature
+    }
+
+    /// The authentication key preimage of a `SingleKey` account is the BCS encoding of its
+    /// `AnyPublicKey`.
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.public_key).expect("Only unhandleable errors happen here.")
+    }
+
+    pub fn signature_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.signature).expect("Only unhandleable errors happen here.")
+    }
+
+    pub fn verify<T: Serialize + CryptoHash>(&self, message: &T) -> Result<()> {
+        self.signature.verify(&self.public_key, message)
+    }
+
+    pub fn verify_arbitrary_msg(&self, message: &[u8]) -> Result<()> {
+        self.signature
+            .verify_arbitrary_msg(&self.public_key, message)
+    }
+}
+
+/// K-of-N signatures by the keys of a `MultiKey` account. `signatures_bitmap` marks which of the
+/// keys signed, and `signatures` holds their signatures in key order.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MultiKeyAuthenticator {
+    public_keys: MultiKey,
+    signatures: Vec<AnySignature>,
+    signatures_bitmap: BitVec,
+}
+
+impl MultiKeyAuthenticator {
+    /// Create an authenticator from signatures indexed by the position of their key in
+    /// `public_keys`.
+    pub fn new(public_keys: MultiKey, mut signatures: Vec<(u8, AnySignature)>) -> Result<Self> {
+        signatures.sort_by_key(|(index, _)| *index);
+        let mut signatures_bitmap = BitVec::default();
+        for (index, _) in &signatures {
+            ensure!(
+                (*index as usize) < public_keys.len(),
+                "Signature index {} is out of bounds for {} public keys",
+                index,
+                public_keys.len()
+            );
+            ensure!(
+                !signatures_bitmap.is_set(*index),
+                "Duplicate signature index {}",
+                index
+            );
+            signatures_bitmap.set(*index);
+        }
+        let authenticator = Self {
+            public_keys,
+            signatures: signatures
+                .into_iter()
+                .map(|(_, signature)| signature)
+                .collect(),
+            signatures_bitmap,
+        };
+        authenticator.check_threshold()?;
+        Ok(authenticator)
+    }
+
+    pub fn public_keys(&self) -> &MultiKey {
+        &self.public_keys
+    }
+
+    pub fn signatures(&self) -> Vec<(u8, AnySignature)> {
+        self.signatures_bitmap
+            .iter_ones()
+            .zip(self.signatures.iter().cloned())
+            .collect()
+    }
+
+    /// The authentication key preimage of a `MultiKey` account is the BCS encoding of its
+    /// `MultiKey`.
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.public_keys).expect("Only unhandleable errors happen here.")
+    }
+
+    pub fn signature_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&(&self.signatures, &self.signatures_bitmap))
+            .expect("Only unhandleable errors happen here.")
+    }
+
+    fn check_threshold(&self) -> Result<()> {
+        ensure!(
+            self.signatures_bitmap.count_ones() == self.signatures.len(),
+            "Bitmap marks {} signers but {} signatures are present",
+            self.signatures_bitmap.count_ones(),
+            self.signatures.len()
+        );
+        ensure!(
+            self.signatures.len() >= self.public_keys.signatures_required() as usize,
+            "Not enough signatures: {} of {} required",
+            self.signatures.len(),
+            self.public_keys.signatures_required()
+        );
+        Ok(())
+    }
+
+    fn signers(&self) -> Result<Vec<(&AnyPublicKey, &AnySignature)>> {
+        self.check_threshold()?;
+        self.signatures_bitmap
+            .iter_ones()
+            .zip(&self.signatures)
+            .map(|(index, signature)| {
+                let public_key = self
+                    .public_keys
+                    .public_keys()
+                    .get(index as usize)
+                    .ok_or_else(|| anyhow::anyhow!("Signature index {} is out of bounds", index))?;
+                Ok((public_key, signature))
+            })
+            .collect()
+    }
+
+    pub fn verify<T: Serialize + CryptoHash>(&self, message: &T) -> Result<()> {
+        for (public_key, signature) in self.signers()? {
+            signature.verify(public_key, message)?;
+        }
+        Ok(())
+    }
+
+    pub fn verify_arbitrary_msg(&self, message: &[u8]) -> Result<()> {
+        for (public_key, signature) in self.signers()? {
+            signature.verify_arbitrary_msg(public_key, message)?;
+        }
+        Ok(())
+    }
+}
+
+/// The public keys of a `MultiKey` account, any `signatures_required` of which must sign.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MultiKey {
+    public_keys: Vec<AnyPublicKey>,
+    signatures_required: u8,
+}
+
+impl MultiKey {
+    pub fn new(public_keys: Vec<AnyPublicKey>, signatures_required: u8) -> Result<Self> {
+        ensure!(
+            signatures_required > 0,
+            "The number of required signatures is 0."
+        );
+        ensure!(
+            public_keys.len() <= MAX_NUM_OF_SIGS,
+            "Too many public keys: {} > {}",
+            public_keys.len(),
+            MAX_NUM_OF_SIGS
+        );
+        ensure!(
+            public_keys.len() >= signatures_required as usize,
+            "The number of public keys is smaller than the number of required signatures, {} < {}",
+            public_keys.len(),
+            signatures_required
+        );
+        Ok(Self {
+            public_keys,
+            signatures_required,
+        })
+    }
+
+    pub fn public_keys(&self) -> &[AnyPublicKey] {
+        &self.public_keys
+    }
+
+    pub fn signatures_required(&self) -> u8 {
+        self.signatures_required
+    }
+
+    pub fn len(&self) -> usize {
+        self.public_keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.public_keys.is_empty()
+    }
+}
+
+/// A bitmap in which bit `i` is the `i % 8`-th most significant bit of byte `i / 8`. It
+/// serializes as a byte vector.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct BitVec {
+    #[serde(with = "serde_bytes")]
+    inner: Vec<u8>,
+}
+
+impl BitVec {
+    pub fn set(&mut self, index: u8) {
+        let byte = index as usize / 8;
+        if byte >= self.inner.len() {
+            self.inner.resize(byte + 1, 0);
+        }
+        self.inner[byte] |= 0b1000_0000 >> (index % 8);
+    }
+
+    pub fn is_set(&self, index: u8) -> bool {
+        self.inner
+            .get(index as usize / 8)
+            .is_some_and(|byte| byte & (0b1000_0000 >> (index % 8)) != 0)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.inner
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterate over the indices of the set bits, in increasing order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..(self.inner.len() * 8).min(u8::MAX as usize + 1))
+            .map(|index| index as u8)
+            .filter(|index| self.is_set(*index))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum AnySignature {
+    Ed25519 { signature: Ed25519Signature },
+    // ... add more schemes here
+}
+
+impl AnySignature {
+    pub fn ed25519(signature: Ed25519Signature) -> Self {
+        Self::Ed25519 { signature }
+    }
+
+    pub fn verify<T: Serialize + CryptoHash>(
+        &self,
+        public_key: &AnyPublicKey,
+        message: &T,
+    ) -> Result<()> {
+        match (self, public_key) {
+            (Self::Ed25519 { signature }, AnyPublicKey::Ed25519 { public_key }) => {
+                signature.verify(message, public_key)
+            },
+        }
+    }
+
+    pub fn verify_arbitrary_msg(&self, public_key: &AnyPublicKey, message: &[u8]) -> Result<()> {
+        match (self, public_key) {
+            (Self::Ed25519 { signature }, AnyPublicKey::Ed25519 { public_key }) => {
+                signature.verify_arbitrary_msg(message, public_key)
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum AnyPublicKey {
+    Ed25519 { public_key: Ed25519PublicKey },
+    // ... add more schemes here
+}
+
+impl AnyPublicKey {
+    pub fn ed25519(public_key: Ed25519PublicKey) -> Self {
+        Self::Ed25519 { public_key }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Only unhandleable errors happen here.")
+    }
+}
+
 /// A struct that represents an account authentication key. An account's address is the last 32
 /// bytes of authentication key used to create it
 #[derive(
@@ -491,6 +994,24 @@ impl AuthenticationKey {
         Self::from_preimage(public_key.to_bytes().to_vec(), Scheme::Ed25519)
     }
 
+    /// Create an authentication key from a MultiEd25519 public key
+    pub fn multi_ed25519(public_key: &MultiEd25519PublicKey) -> Self {
+        Self::from_preimage(public_key.to_bytes(), Scheme::MultiEd25519)
+    }
+
+    /// Create an authentication key from an AnyPublicKey, i.e., for a `SingleKey` account
+    pub fn any_key(public_key: AnyPublicKey) -> AuthenticationKey {
+        Self::from_preimage(public_key.to_bytes(), Scheme::SingleKey)
+    }
+
+    /// Create an authentication key for a `MultiKey` account
+    pub fn multi_key(public_keys: MultiKey) -> AuthenticationKey {
+        Self::from_preimage(
+            bcs::to_bytes(&public_keys).expect("Only unhandleable errors happen here."),
+            Scheme::MultiKey,
+        )
+    }
+
     /// Return the authentication key as an account address
     pub fn account_address(&self) -> AccountAddress {
         AccountAddress::new(self.0)
@@ -573,3 +1094,138 @@ impl fmt::Display for AuthenticationKey {
         write!(f, "{:#x}", self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_helpers::{private_key, raw_transaction};
+    use aptos_crypto::{PrivateKey, SigningKey};
+
+    #[test]
+    fn test_multi_key() {
+        let private_keys: Vec<_> = (1..=3u8).map(private_key).collect();
+        let multi_key = MultiKey::new(
+            private_keys
+                .iter()
+                .map(|key| AnyPublicKey::ed25519(key.public_key()))
+                .collect(),
+            2,
+        )
+        .unwrap();
+        let message = b"multi key".to_vec();
+        let sign = |index: u8| {
+            (
+                index,
+                AnySignature::ed25519(
+                    private_keys[index as usize].sign_arbitrary_message(&message),
+                ),
+            )
+        };
+
+        let authenticator =
+            MultiKeyAuthenticator::new(multi_key.clone(), vec![sign(2), sign(0)]).unwrap();
+        assert_eq!(authenticator.signatures_bitmap.inner, vec![0b1010_0000]);
+        assert_eq!(
+            authenticator
+                .signatures()
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+        let account_authenticator = AccountAuthenticator::multi_key(authenticator);
+        assert!(account_authenticator.verify_arbitrary_msg(&message).is_ok());
+        assert_eq!(account_authenticator.number_of_signatures(), 2);
+        assert_eq!(
+            account_authenticator.authentication_key(),
+            Some(AuthenticationKey::multi_key(multi_key.clone()))
+        );
+
+        // Below the threshold, duplicated or out of range.
+        assert!(MultiKeyAuthenticator::new(multi_key.clone(), vec![sign(1)]).is_err());
+        assert!(MultiKeyAuthenticator::new(multi_key.clone(), vec![sign(1), sign(1)]).is_err());
+        let mut out_of_range = sign(1);
+        out_of_range.0 = 3;
+        assert!(MultiKeyAuthenticator::new(multi_key, vec![sign(0), out_of_range]).is_err());
+
+        // MultiKey sits before NoAccountAuthenticator in the BCS layout.
+        assert_eq!(
+            bcs::to_bytes(&AccountAuthenticator::NoAccountAuthenticator).unwrap(),
+            vec![4]
+        );
+    }
+
+    fn sign<T: Serialize + CryptoHash>(seed: u8, message: &T) -> AccountAuthenticator {
+        let private_key = private_key(seed);
+        AccountAuthenticator::ed25519(private_key.public_key(), private_key.sign(message).unwrap())
+    }
+
+    #[test]
+    fn test_fee_payer_signed_over_zero_address() {
+        let raw_txn = raw_transaction(AccountAddress::TWO, 0);
+        let secondary_signer_addresses = vec![AccountAddress::THREE];
+        let fee_payer_address = AccountAddress::FOUR;
+        let message = |fee_payer_address| {
+            RawTransactionWithData::new_fee_payer(
+                raw_txn.clone(),
+                secondary_signer_addresses.clone(),
+                fee_payer_address,
+            )
+        };
+        let authenticator = |signed_fee_payer_address, fee_payer_signed_fee_payer_address| {
+            TransactionAuthenticator::fee_payer(
+                sign(1, &message(signed_fee_payer_address)),
+                secondary_signer_addresses.clone(),
+                vec![sign(2, &message(signed_fee_payer_address))],
+                fee_payer_address,
+                sign(3, &message(fee_payer_signed_fee_payer_address)),
+            )
+        };
+
+        assert!(authenticator(fee_payer_address, fee_payer_address)
+            .verify(&raw_txn)
+            .is_ok());
+        // The sender and secondary signers signed before the fee payer was known.
+        assert!(authenticator(AccountAddress::ZERO, fee_payer_address)
+            .verify(&raw_txn)
+            .is_ok());
+        // The fee payer has to sign over its own address.
+        assert!(authenticator(fee_payer_address, AccountAddress::ZERO)
+            .verify(&raw_txn)
+            .is_err());
+        // Signatures over another fee payer are not accepted.
+        assert!(authenticator(AccountAddress::THREE, fee_payer_address)
+            .verify(&raw_txn)
+            .is_err());
+    }
+
+    #[test]
+    fn test_secondary_signer_count_mismatch() {
+        let raw_txn = raw_transaction(AccountAddress::TWO, 0);
+        let secondary_signer_addresses = vec![AccountAddress::THREE, AccountAddress::FOUR];
+        let multi_agent_message = RawTransactionWithData::new_multi_agent(
+            raw_txn.clone(),
+            secondary_signer_addresses.clone(),
+        );
+        let multi_agent = TransactionAuthenticator::multi_agent(
+            sign(1, &multi_agent_message),
+            secondary_signer_addresses.clone(),
+            vec![sign(2, &multi_agent_message)],
+        );
+        assert!(multi_agent.verify(&raw_txn).is_err());
+
+        let fee_payer_message = RawTransactionWithData::new_fee_payer(
+            raw_txn.clone(),
+            secondary_signer_addresses.clone(),
+            AccountAddress::ONE,
+        );
+        let fee_payer = TransactionAuthenticator::fee_payer(
+            sign(1, &fee_payer_message),
+            secondary_signer_addresses,
+            vec![sign(2, &fee_payer_message)],
+            AccountAddress::ONE,
+            sign(3, &fee_payer_message),
+        );
+        assert!(fee_payer.verify(&raw_txn).is_err());
+    }
+}
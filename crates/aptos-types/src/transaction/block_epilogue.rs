@@ -0,0 +1,68 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_crypto::HashValue;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The payload of the transaction closing a block, which records why the block ended.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum BlockEpiloguePayload {
+    V0 {
+        block_id: HashValue,
+        block_end_info: BlockEndInfo,
+    },
+    V1 {
+        block_id: HashValue,
+        block_end_info: BlockEndInfo,
+        fee_distribution: FeeDistribution,
+    },
+}
+
+impl BlockEpiloguePayload {
+    pub fn block_id(&self) -> HashValue {
+        match self {
+            BlockEpiloguePayload::V0 { block_id, .. }
+            | BlockEpiloguePayload::V1 { block_id, .. } => *block_id,
+        }
+    }
+
+    pub fn block_end_info(&self) -> &BlockEndInfo {
+        match self {
+            BlockEpiloguePayload::V0 { block_end_info, .. }
+            | BlockEpiloguePayload::V1 { block_end_info, .. } => block_end_info,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum BlockEndInfo {
+    V0 {
+        /// Whether block gas limit was reached
+        block_gas_limit_reached: bool,
+        /// Whether block output limit was reached
+        block_output_limit_reached: bool,
+        /// If either of the last two is true, this is the effective gas units of the block
+        block_effective_block_gas_units: u64,
+        /// The approximate size of the outputs of the block
+        block_approx_output_size: u64,
+    },
+}
+
+impl BlockEndInfo {
+    pub fn limit_reached(&self) -> bool {
+        match self {
+            BlockEndInfo::V0 {
+                block_gas_limit_reached,
+                block_output_limit_reached,
+                ..
+            } => *block_gas_limit_reached || *block_output_limit_reached,
+        }
+    }
+}
+
+/// The gas fees of the block paid out to the validators, by validator index.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FeeDistribution {
+    V0 { amount: BTreeMap<u64, u64> },
+}
@@ -0,0 +1,31 @@
+// Copyright © Aptos Foundation
+// Parts of the project are originally copyright © Meta Platforms, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{contract_event::ContractEvent, write_set::WriteSet};
+use serde::{Deserialize, Serialize};
+
+/// The writes and events of a transaction that is applied directly, such as genesis.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChangeSet {
+    write_set: WriteSet,
+    events: Vec<ContractEvent>,
+}
+
+impl ChangeSet {
+    pub fn new(write_set: WriteSet, events: Vec<ContractEvent>) -> Self {
+        Self { write_set, events }
+    }
+
+    pub fn into_inner(self) -> (WriteSet, Vec<ContractEvent>) {
+        (self.write_set, self.events)
+    }
+
+    pub fn write_set(&self) -> &WriteSet {
+        &self.write_set
+    }
+
+    pub fn events(&self) -> &[ContractEvent] {
+        &self.events
+    }
+}
@@ -0,0 +1,392 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Builders for `RawTransaction`s that fill in the chain id, gas parameters and expiration from
+//! per-chain defaults.
+//!
+//! ```ignore
+//! let factory = TransactionFactory::new(ChainId::mainnet(), ic_cdk::api::time);
+//! let raw_txn = factory
+//!     .entry_function(entry_function)
+//!     .sender(sender)
+//!     .sequence_number(sequence_number)
+//!     .build()?;
+//! ```
+
+use crate::{
+    chain_id::ChainId,
+    transaction::{
+        EntryFunction, RawTransaction, RawTransactionWithData, Script, SignedTransaction,
+        TransactionPayload,
+    },
+};
+use aptos_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+    traits::CryptoMaterialError,
+    SigningKey,
+};
+use move_core_types::account_address::AccountAddress;
+use thiserror::Error;
+
+/// The default maximum number of gas units a transaction may consume.
+pub const DEFAULT_MAX_GAS_AMOUNT: u64 = 200_000;
+/// The default price per gas unit, in octas. This is the minimum gas unit price on mainnet.
+pub const DEFAULT_GAS_UNIT_PRICE: u64 = 100;
+/// The default time, in seconds, a transaction stays valid after it was built.
+pub const DEFAULT_EXPIRATION_TTL_SECS: u64 = 30;
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// A clock returning the current time in nanoseconds since the Unix epoch, with the same
+/// signature as `ic_cdk::api::time`. There is no default, as `std::time::SystemTime::now`
+/// panics on `wasm32-unknown-unknown`.
+pub type Clock = fn() -> u64;
+
+/// An error enum for transactions that cannot be built.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum TransactionBuilderError {
+    #[error("transaction sender is not set")]
+    MissingSender,
+    #[error("transaction sequence number is not set")]
+    MissingSequenceNumber,
+    #[error("max gas amount must be greater than zero")]
+    ZeroMaxGasAmount,
+    #[error("gas unit price must be greater than zero")]
+    ZeroGasUnitPrice,
+    #[error("transaction expired at {expiration_timestamp_secs}s, current time is {now_secs}s")]
+    Expired {
+        expiration_timestamp_secs: u64,
+        now_secs: u64,
+    },
+    #[error("transaction sender {sender} does not match the signing key's address {expected}")]
+    SenderMismatch {
+        sender: AccountAddress,
+        expected: AccountAddress,
+    },
+    #[error("failed to sign transaction: {0}")]
+    Signing(#[from] CryptoMaterialError),
+}
+
+/// Holds the chain-wide defaults of the transactions it builds.
+#[derive(Clone, Debug)]
+pub struct TransactionFactory {
+    chain_id: ChainId,
+    max_gas_amount: u64,
+    gas_unit_price: u64,
+    expiration_ttl_secs: u64,
+    clock: Clock,
+}
+
+impl TransactionFactory {
+    /// Create a factory for `chain_id` with the default gas parameters and expiration TTL,
+    /// reading the current time from `clock`.
+    pub fn new(chain_id: ChainId, clock: Clock) -> Self {
+        Self {
+            chain_id,
+            max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            gas_unit_price: DEFAULT_GAS_UNIT_PRICE,
+            expiration_ttl_secs: DEFAULT_EXPIRATION_TTL_SECS,
+            clock,
+        }
+    }
+
+    pub fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn with_max_gas_amount(mut self, max_gas_amount: u64) -> Self {
+        self.max_gas_amount = max_gas_amount;
+        self
+    }
+
+    pub fn with_gas_unit_price(mut self, gas_unit_price: u64) -> Self {
+        self.gas_unit_price = gas_unit_price;
+        self
+    }
+
+    /// Set how many seconds built transactions stay valid.
+    pub fn with_expiration_ttl_secs(mut self, expiration_ttl_secs: u64) -> Self {
+        self.expiration_ttl_secs = expiration_ttl_secs;
+        self
+    }
+
+    /// Use `clock` to compute expiration timestamps, e.g. `ic_cdk::api::time` in a canister.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    pub fn max_gas_amount(&self) -> u64 {
+        self.max_gas_amount
+    }
+
+    pub fn gas_unit_price(&self) -> u64 {
+        self.gas_unit_price
+    }
+
+    pub fn expiration_ttl_secs(&self) -> u64 {
+        self.expiration_ttl_secs
+    }
+
+    /// The current time of the factory's clock, in seconds since the Unix epoch.
+    pub fn now_secs(&self) -> u64 {
+        (self.clock)() / NANOS_PER_SEC
+    }
+
+    /// Start building a transaction that executes `payload`.
+    pub fn payload(&self, payload: TransactionPayload) -> TransactionBuilder {
+        let now_secs = self.now_secs();
+        TransactionBuilder {
+            sender: None,
+            sequence_number: None,
+            payload,
+            max_gas_amount: self.max_gas_amount,
+            gas_unit_price: self.gas_unit_price,
+            expiration_timestamp_secs: now_secs.saturating_add(self.expiration_ttl_secs),
+            chain_id: self.chain_id,
+            now_secs,
+        }
+    }
+
+    pub fn entry_function(&self, entry_function: EntryFunction) -> TransactionBuilder {
+        self.payload(TransactionPayload::EntryFunction(entry_function))
+    }
+
+    pub fn script(&self, script: Script) -> TransactionBuilder {
+        self.payload(TransactionPayload::Script(script))
+    }
+}
+
+/// Builds a single transaction. Created by [`TransactionFactory`], which supplies every field
+/// but the sender and its sequence number.
+#[derive(Clone, Debug)]
+pub struct TransactionBuilder {
+    sender: Option<AccountAddress>,
+    sequence_number: Option<u64>,
+    payload: TransactionPayload,
+    max_gas_amount: u64,
+    gas_unit_price: u64,
+    expiration_timestamp_secs: u64,
+    chain_id: ChainId,
+    /// When the builder was created, to reject transactions that are already expired.
+    now_secs: u64,
+}
+
+impl TransactionBuilder {
+    pub fn sender(mut self, sender: AccountAddress) -> Self {
+        self.sender = Some(sender);
+        self
+    }
+
+    pub fn sequence_number(mut self, sequence_number: u64) -> Self {
+        self.sequence_number = Some(sequence_number);
+        self
+    }
+
+    pub fn max_gas_amount(mut self, max_gas_amount: u64) -> Self {
+        self.max_gas_amount = max_gas_amount;
+        self
+    }
+
+    pub fn gas_unit_price(mut self, gas_unit_price: u64) -> Self {
+        self.gas_unit_price = gas_unit_price;
+        self
+    }
+
+    /// Override the expiration computed from the factory's TTL.
+    pub fn expiration_timestamp_secs(mut self, expiration_timestamp_secs: u64) -> Self {
+        self.expiration_timestamp_secs = expiration_timestamp_secs;
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn build(self) -> Result<RawTransaction, TransactionBuilderError> {
+        if self.max_gas_amount == 0 {
+            return Err(TransactionBuilderError::ZeroMaxGasAmount);
+        }
+        if self.gas_unit_price == 0 {
+            return Err(TransactionBuilderError::ZeroGasUnitPrice);
+        }
+        if self.expiration_timestamp_secs <= self.now_secs {
+            return Err(TransactionBuilderError::Expired {
+                expiration_timestamp_secs: self.expiration_timestamp_secs,
+                now_secs: self.now_secs,
+            });
+        }
+        Ok(RawTransaction::new(
+            self.sender.ok_or(TransactionBuilderError::MissingSender)?,
+            self.sequence_number
+                .ok_or(TransactionBuilderError::MissingSequenceNumber)?,
+            self.payload,
+            self.max_gas_amount,
+            self.gas_unit_price,
+            self.expiration_timestamp_secs,
+            self.chain_id,
+        ))
+    }
+
+    /// Build a transaction that is also signed by `secondary_signer_addresses`.
+    pub fn build_multi_agent(
+        self,
+        secondary_signer_addresses: Vec<AccountAddress>,
+    ) -> Result<RawTransactionWithData, TransactionBuilderError> {
+        Ok(RawTransactionWithData::new_multi_agent(
+            self.build()?,
+            secondary_signer_addresses,
+        ))
+    }
+
+    /// Build a transaction whose gas is paid by `fee_payer_address`.
+    pub fn build_fee_payer(
+        self,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        fee_payer_address: AccountAddress,
+    ) -> Result<RawTransactionWithData, TransactionBuilderError> {
+        Ok(RawTransactionWithData::new_fee_payer(
+            self.build()?,
+            secondary_signer_addresses,
+            fee_payer_address,
+        ))
+    }
+
+    /// Build the transaction and sign it with `private_key`. The sender defaults to the account
+    /// address derived from the key.
+    pub fn sign(
+        mut self,
+        private_key: &Ed25519PrivateKey,
+    ) -> Result<SignedTransaction, TransactionBuilderError> {
+        let public_key = Ed25519PublicKey::from(private_key);
+        let expected = crate::account_address::from_public_key(&public_key);
+        let sender = *self.sender.get_or_insert(expected);
+        if sender != expected {
+            return Err(TransactionBuilderError::SenderMismatch { sender, expected });
+        }
+        let raw_txn = self.build()?;
+        let signature = private_key.sign(&raw_txn)?;
+        Ok(SignedTransaction::new(raw_txn, public_key, signature))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_helpers::{private_key, transfer};
+
+    const NOW_SECS: u64 = 1_700_000_000;
+
+    fn fixed_clock() -> u64 {
+        NOW_SECS * NANOS_PER_SEC + 123
+    }
+
+    fn factory() -> TransactionFactory {
+        TransactionFactory::new(ChainId::test(), fixed_clock)
+    }
+
+    #[test]
+    fn test_build() {
+        let raw_txn = factory()
+            .with_gas_unit_price(150)
+            .entry_function(transfer(vec![]))
+            .sender(AccountAddress::TWO)
+            .sequence_number(5)
+            .build()
+            .unwrap();
+        assert_eq!(
+            raw_txn,
+            RawTransaction::new_entry_function(
+                AccountAddress::TWO,
+                5,
+                transfer(vec![]),
+                DEFAULT_MAX_GAS_AMOUNT,
+                150,
+                NOW_SECS + DEFAULT_EXPIRATION_TTL_SECS,
+                ChainId::test(),
+            )
+        );
+
+        let with_data = factory()
+            .entry_function(transfer(vec![]))
+            .sender(AccountAddress::TWO)
+            .sequence_number(5)
+            .build_fee_payer(vec![], AccountAddress::THREE)
+            .unwrap();
+        assert!(matches!(
+            with_data,
+            RawTransactionWithData::MultiAgentWithFeePayer {
+                fee_payer_address: AccountAddress::THREE,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_validation() {
+        let builder = factory()
+            .entry_function(transfer(vec![]))
+            .sender(AccountAddress::TWO)
+            .sequence_number(0);
+        assert_eq!(
+            builder.clone().max_gas_amount(0).build(),
+            Err(TransactionBuilderError::ZeroMaxGasAmount)
+        );
+        assert_eq!(
+            builder.clone().gas_unit_price(0).build(),
+            Err(TransactionBuilderError::ZeroGasUnitPrice)
+        );
+        assert_eq!(
+            builder.clone().expiration_timestamp_secs(NOW_SECS).build(),
+            Err(TransactionBuilderError::Expired {
+                expiration_timestamp_secs: NOW_SECS,
+                now_secs: NOW_SECS,
+            })
+        );
+        assert_eq!(
+            factory()
+                .script(Script::new(vec![], vec![], vec![]))
+                .build(),
+            Err(TransactionBuilderError::MissingSender)
+        );
+        assert_eq!(
+            factory()
+                .entry_function(transfer(vec![]))
+                .sender(AccountAddress::TWO)
+                .build(),
+            Err(TransactionBuilderError::MissingSequenceNumber)
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        let private_key = private_key(1);
+        let sender = crate::account_address::from_public_key(&Ed25519PublicKey::from(&private_key));
+        let signed = factory()
+            .entry_function(transfer(vec![]))
+            .sequence_number(2)
+            .sign(&private_key)
+            .unwrap();
+        assert_eq!(signed.sender(), sender);
+        assert!(signed.verify_signature().is_ok());
+
+        assert_eq!(
+            factory()
+                .entry_function(transfer(vec![]))
+                .sender(AccountAddress::TWO)
+                .sequence_number(2)
+                .sign(&private_key)
+                .unwrap_err(),
+            TransactionBuilderError::SenderMismatch {
+                sender: AccountAddress::TWO,
+                expected: sender,
+            }
+        );
+    }
+}
@@ -1,16 +1,29 @@
 pub mod authenticator;
+pub mod block_epilogue;
+pub mod change_set;
+pub mod factory;
+pub mod multisig;
+pub mod prologue;
 pub mod script;
 pub mod user_transaction_context;
 
-use super::chain_id::ChainId;
-use anyhow::Result;
+use super::{
+    block_metadata::BlockMetadata, block_metadata_ext::BlockMetadataExt, chain_id::ChainId,
+    contract_event::ContractEvent, on_chain_config::Features, validator_txn::ValidatorTransaction,
+    write_set::WriteSet,
+};
+use anyhow::{bail, ensure, Result};
 use aptos_crypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
 use aptos_crypto::hash::{CryptoHash, HashValue};
-use aptos_crypto::traits::CryptoMaterialError;
+use aptos_crypto::traits::{signing_message, CryptoMaterialError};
 use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
-use authenticator::TransactionAuthenticator;
+use authenticator::{AccountAuthenticator, TransactionAuthenticator};
+pub use block_epilogue::{BlockEndInfo, BlockEpiloguePayload, FeeDistribution};
+pub use change_set::ChangeSet;
+pub use factory::{TransactionBuilder, TransactionBuilderError, TransactionFactory};
 use move_core_types::account_address::AccountAddress;
-use move_core_types::vm_status::{AbortLocation, StatusCode};
+use move_core_types::vm_status::{AbortLocation, DiscardedVMStatus, StatusCode};
+pub use multisig::{Multisig, MultisigTransactionPayload};
 use once_cell::sync::OnceCell;
 pub use script::{EntryFunction, Script};
 
@@ -21,6 +34,10 @@ use std::{hash::Hash, ops::Deref, sync::atomic::AtomicU64};
 pub type Version = u64; // Height - also used for MVCC in StateDB
 pub type AtomicVersion = AtomicU64;
 
+/// The sequence number carried by orderless transactions. It is ignored by the VM, which uses
+/// the replay protection nonce of the payload instead.
+pub const ORDERLESS_SEQUENCE_NUMBER: u64 = u64::MAX;
+
 /// RawTransaction is the portion of a transaction that a client signs.
 #[derive(
     Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize, CryptoHasher, BCSCryptoHash,
@@ -122,6 +139,41 @@ impl RawTransaction {
         }
     }
 
+    /// Create a new orderless `RawTransaction`, which is protected against replay by
+    /// `replay_protection_nonce` instead of the sender's sequence number. The nonce must be
+    /// unique among the sender's transactions that have not expired yet.
+    ///
+    /// Fails if `features` do not enable orderless transactions.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_orderless(
+        sender: AccountAddress,
+        executable: TransactionExecutable,
+        replay_protection_nonce: u64,
+        max_gas_amount: u64,
+        gas_unit_price: u64,
+        expiration_timestamp_secs: u64,
+        chain_id: ChainId,
+        features: &Features,
+    ) -> Result<Self> {
+        let payload = TransactionPayload::new_v1(
+            executable,
+            TransactionExtraConfig::V1 {
+                multisig_address: None,
+                replay_protection_nonce: Some(replay_protection_nonce),
+            },
+        );
+        payload.check_features(features)?;
+        Ok(RawTransaction {
+            sender,
+            sequence_number: ORDERLESS_SEQUENCE_NUMBER,
+            payload,
+            max_gas_amount,
+            gas_unit_price,
+            expiration_timestamp_secs,
+            chain_id,
+        })
+    }
+
     /// Signs the given `RawTransaction`. Note that this consumes the `RawTransaction` and turns it
     /// into a `SignatureCheckedTransaction`.
     ///
@@ -146,14 +198,53 @@ impl RawTransaction {
         self.sender
     }
 
+    /// Return the payload of this transaction.
+    pub fn payload(&self) -> &TransactionPayload {
+        &self.payload
+    }
+
+    /// Return the sequence number of this transaction.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Return the maximum number of gas units this transaction may use.
+    pub fn max_gas_amount(&self) -> u64 {
+        self.max_gas_amount
+    }
+
+    /// Return the price of a gas unit, in octas.
+    pub fn gas_unit_price(&self) -> u64 {
+        self.gas_unit_price
+    }
+
+    /// Return the expiration time of this transaction, in seconds since the Unix Epoch.
+    pub fn expiration_timestamp_secs(&self) -> u64 {
+        self.expiration_timestamp_secs
+    }
+
+    /// Return the chain this transaction is intended for.
+    pub fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    /// Return how this transaction is protected against replay.
+    pub fn replay_protector(&self) -> ReplayProtector {
+        match self.payload.replay_protection_nonce() {
+            Some(nonce) => ReplayProtector::Nonce(nonce),
+            None => ReplayProtector::SequenceNumber(self.sequence_number),
+        }
+    }
+
     /// Return the signing message for creating transaction signature.
     pub fn signing_message(&self) -> Result<Vec<u8>, CryptoMaterialError> {
-        // signing_message(self)
-        todo!()
+        signing_message(self)
     }
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(
+    Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize, CryptoHasher, BCSCryptoHash,
+)]
 pub enum RawTransactionWithData {
     MultiAgent {
         raw_txn: RawTransaction,
@@ -167,27 +258,41 @@ pub enum RawTransactionWithData {
 }
 
 impl RawTransactionWithData {
-    // pub fn new_fee_payer(
-    //     raw_txn: RawTransaction,
-    //     secondary_signer_addresses: Vec<AccountAddress>,
-    //     fee_payer_address: AccountAddress,
-    // ) -> Self {
-    //     Self::MultiAgentWithFeePayer {
-    //         raw_txn,
-    //         secondary_signer_addresses,
-    //         fee_payer_address,
-    //     }
-    // }
+    pub fn new_fee_payer(
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        fee_payer_address: AccountAddress,
+    ) -> Self {
+        Self::MultiAgentWithFeePayer {
+            raw_txn,
+            secondary_signer_addresses,
+            fee_payer_address,
+        }
+    }
 
-    // pub fn new_multi_agent(
-    //     raw_txn: RawTransaction,
-    //     secondary_signer_addresses: Vec<AccountAddress>,
-    // ) -> Self {
-    //     Self::MultiAgent {
-    //         raw_txn,
-    //         secondary_signer_addresses,
-    //     }
-    // }
+    pub fn new_multi_agent(
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+    ) -> Self {
+        Self::MultiAgent {
+            raw_txn,
+            secondary_signer_addresses,
+        }
+    }
+
+    pub fn raw_txn(&self) -> &RawTransaction {
+        match self {
+            Self::MultiAgent { raw_txn, .. } | Self::MultiAgentWithFeePayer { raw_txn, .. } => {
+                raw_txn
+            },
+        }
+    }
+
+    /// Return the signing message that the sender, the secondary signers and the fee payer
+    /// each sign.
+    pub fn signing_message(&self) -> Result<Vec<u8>, CryptoMaterialError> {
+        signing_message(self)
+    }
 }
 
 /// Marks payload as deprecated. We need to use it to ensure serialization or
@@ -207,42 +312,180 @@ pub enum TransactionPayload {
     ModuleBundle(DeprecatedPayload),
     /// A transaction that executes an existing entry function published on-chain.
     EntryFunction(EntryFunction),
-    // / A multisig transaction that allows an owner of a multisig account to execute a pre-approved
-    // / transaction as the multisig account.
-    // Multisig(Multisig),
+    /// A multisig transaction that allows an owner of a multisig account to execute a pre-approved
+    /// transaction as the multisig account.
+    Multisig(Multisig),
+    /// The versioned payload format: an executable together with extra configuration, such as
+    /// a replay protection nonce. Requires `FeatureFlag::TRANSACTION_PAYLOAD_V2`.
+    Payload(TransactionPayloadInner),
 }
 
 impl TransactionPayload {
     pub fn into_entry_function(self) -> EntryFunction {
         match self {
             Self::EntryFunction(f) => f,
+            Self::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::EntryFunction(f),
+                extra_config:
+                    TransactionExtraConfig::V1 {
+                        multisig_address: None,
+                        ..
+                    },
+            }) => f,
             payload => panic!("Expected EntryFunction(_) payload, found: {:#?}", payload),
         }
     }
+
+    /// Create a payload in the versioned format.
+    pub fn new_v1(executable: TransactionExecutable, extra_config: TransactionExtraConfig) -> Self {
+        Self::Payload(TransactionPayloadInner::V1 {
+            executable,
+            extra_config,
+        })
+    }
+
+    /// Return the replay protection nonce of an orderless transaction payload, or None if the
+    /// transaction is protected by the sender's sequence number.
+    pub fn replay_protection_nonce(&self) -> Option<u64> {
+        match self {
+            Self::Payload(TransactionPayloadInner::V1 { extra_config, .. }) => {
+                extra_config.replay_protection_nonce()
+            },
+            _ => None,
+        }
+    }
+
+    /// Return the multisig account the payload is executed as, if any.
+    pub fn multisig_address(&self) -> Option<AccountAddress> {
+        match self {
+            Self::Multisig(multisig) => Some(multisig.multisig_address),
+            Self::Payload(TransactionPayloadInner::V1 { extra_config, .. }) => {
+                extra_config.multisig_address()
+            },
+            _ => None,
+        }
+    }
+
+    /// Convert the payload into the versioned format, keeping what it executes. Legacy multisig
+    /// payloads must carry their transaction payload to be converted.
+    pub fn upgrade(self, replay_protection_nonce: Option<u64>) -> Result<Self> {
+        let (executable, multisig_address) = match self {
+            Self::Script(script) => (TransactionExecutable::Script(script), None),
+            Self::EntryFunction(entry_function) => {
+                (TransactionExecutable::EntryFunction(entry_function), None)
+            },
+            Self::Multisig(Multisig {
+                multisig_address,
+                transaction_payload,
+            }) => {
+                let executable = match transaction_payload {
+                    Some(MultisigTransactionPayload::EntryFunction(entry_function)) => {
+                        TransactionExecutable::EntryFunction(entry_function)
+                    },
+                    None => TransactionExecutable::Empty,
+                };
+                (executable, Some(multisig_address))
+            },
+            Self::Payload(TransactionPayloadInner::V1 {
+                executable,
+                extra_config,
+            }) => (executable, extra_config.multisig_address()),
+            Self::ModuleBundle(_) => bail!("Module bundle payload has been removed"),
+        };
+        Ok(Self::new_v1(
+            executable,
+            TransactionExtraConfig::V1 {
+                multisig_address,
+                replay_protection_nonce,
+            },
+        ))
+    }
+
+    /// Return an error if the payload uses a format that the given on-chain features do not
+    /// allow yet.
+    pub fn check_features(&self, features: &Features) -> Result<()> {
+        if let Self::Payload(_) = self {
+            ensure!(
+                features.is_transaction_payload_v2_enabled(),
+                "TRANSACTION_PAYLOAD_V2 is not enabled"
+            );
+            if self.replay_protection_nonce().is_some() {
+                ensure!(
+                    features.is_orderless_txns_enabled(),
+                    "ORDERLESS_TRANSACTIONS is not enabled"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionPayloadInner {
+    V1 {
+        executable: TransactionExecutable,
+        extra_config: TransactionExtraConfig,
+    },
+}
+
+/// The code a `TransactionPayload::Payload` executes.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionExecutable {
+    Script(Script),
+    EntryFunction(EntryFunction),
+    /// Used by multisig transactions whose payload is stored on chain.
+    Empty,
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionExtraConfig {
+    V1 {
+        multisig_address: Option<AccountAddress>,
+        // None for regular transactions
+        // Some(nonce) for orderless transactions
+        replay_protection_nonce: Option<u64>,
+    },
+}
+
+impl TransactionExtraConfig {
+    pub fn multisig_address(&self) -> Option<AccountAddress> {
+        match self {
+            Self::V1 {
+                multisig_address, ..
+            } => *multisig_address,
+        }
+    }
+
+    pub fn replay_protection_nonce(&self) -> Option<u64> {
+        match self {
+            Self::V1 {
+                replay_protection_nonce,
+                ..
+            } => *replay_protection_nonce,
+        }
+    }
 }
 
-// /// Two different kinds of WriteSet transactions.
-// #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
-// pub enum WriteSetPayload {
-//     /// Directly passing in the WriteSet.
-//     Direct(ChangeSet),
-//     /// Generate the WriteSet by running a script.
-//     Script {
-//         /// Execute the script as the designated signer.
-//         execute_as: AccountAddress,
-//         /// Script body that gets executed.
-//         script: Script,
-//     },
-// }
-
-// impl WriteSetPayload {
-//     pub fn should_trigger_reconfiguration_by_default(&self) -> bool {
-//         match self {
-//             Self::Direct(_) => true,
-//             Self::Script { .. } => false,
-//         }
-//     }
-// }
+/// How a transaction is protected against replay.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReplayProtector {
+    /// The transaction can only be executed once the sender's account reaches this sequence
+    /// number, and bumps it.
+    SequenceNumber(u64),
+    /// The transaction can be executed in any order, at most once per nonce before it expires.
+    Nonce(u64),
+}
+
+impl fmt::Display for ReplayProtector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SequenceNumber(sequence_number) => {
+                write!(f, "SequenceNumber({})", sequence_number)
+            },
+            Self::Nonce(nonce) => write!(f, "Nonce({})", nonce),
+        }
+    }
+}
 
 /// A transaction that has been signed.
 ///
@@ -350,6 +593,49 @@ impl SignedTransaction {
         }
     }
 
+    /// Create a transaction for the node's `/transactions/simulate` endpoint. Signers whose
+    /// Ed25519 public key is given get an all-zero signature, the others get a
+    /// `NoAccountAuthenticator` (which requires the `TRANSACTION_SIMULATION_ENHANCEMENT`
+    /// feature).
+    ///
+    /// The authenticator layout follows the signers: a fee payer makes it a fee payer
+    /// transaction, secondary signers a multi-agent one. Simulation skips gas payment when the
+    /// fee payer address is `0x0`.
+    pub fn new_for_simulation(
+        raw_txn: RawTransaction,
+        sender: Option<Ed25519PublicKey>,
+        secondary_signers: Vec<(AccountAddress, Option<Ed25519PublicKey>)>,
+        fee_payer: Option<(AccountAddress, Option<Ed25519PublicKey>)>,
+    ) -> SignedTransaction {
+        let sender = AccountAuthenticator::simulation(sender);
+        let (secondary_signer_addresses, secondary_signers): (Vec<_>, Vec<_>) = secondary_signers
+            .into_iter()
+            .map(|(address, public_key)| (address, AccountAuthenticator::simulation(public_key)))
+            .unzip();
+        let authenticator = match fee_payer {
+            Some((fee_payer_address, fee_payer_public_key)) => TransactionAuthenticator::fee_payer(
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                AccountAuthenticator::simulation(fee_payer_public_key),
+            ),
+            None if !secondary_signers.is_empty() => TransactionAuthenticator::multi_agent(
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            ),
+            None => match sender {
+                AccountAuthenticator::Ed25519 {
+                    public_key,
+                    signature,
+                } => TransactionAuthenticator::ed25519(public_key, signature),
+                sender => TransactionAuthenticator::single_sender(sender),
+            },
+        };
+        Self::new_signed_transaction(raw_txn, authenticator)
+    }
+
     pub fn authenticator(&self) -> TransactionAuthenticator {
         self.authenticator.clone()
     }
@@ -374,6 +660,10 @@ impl SignedTransaction {
         self.raw_txn.sequence_number
     }
 
+    pub fn replay_protector(&self) -> ReplayProtector {
+        self.raw_txn.replay_protector()
+    }
+
     pub fn chain_id(&self) -> ChainId {
         self.raw_txn.chain_id
     }
@@ -420,6 +710,50 @@ impl SignedTransaction {
         Ok(())
     }
 
+    /// Checks the signatures of all `txns` with one Ed25519 batch verification, with the same
+    /// result as [`SignedTransaction::verify_signature`]. On failure, returns the offending indices.
+    pub fn verify_signatures_batch(
+        txns: &[SignedTransaction],
+    ) -> std::result::Result<(), Vec<(usize, anyhow::Error)>> {
+        if Self::batch_verify(txns).is_ok() {
+            return Ok(());
+        }
+
+        let failures: Vec<_> = txns
+            .iter()
+            .enumerate()
+            .filter_map(|(index, txn)| txn.verify_signature().err().map(|e| (index, e)))
+            .collect();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+
+    fn batch_verify(txns: &[SignedTransaction]) -> Result<()> {
+        let mut messages = vec![];
+        let mut keys_and_signatures = vec![];
+        for txn in txns {
+            match &txn.authenticator {
+                TransactionAuthenticator::Ed25519 {
+                    public_key,
+                    signature,
+                } => {
+                    messages.push(txn.raw_txn.signing_message()?);
+                    keys_and_signatures.push((public_key, signature));
+                },
+                _ => txn.verify_signature()?,
+            }
+        }
+        let triples: Vec<_> = messages
+            .iter()
+            .zip(keys_and_signatures)
+            .map(|(message, (public_key, signature))| (&message[..], public_key, signature))
+            .collect();
+        Ed25519Signature::batch_verify_arbitrary_msgs(&triples)
+    }
+
     /// Returns the hash when the transaction is committed onchain.
     pub fn committed_hash(&self) -> HashValue {
         *self
@@ -456,6 +790,116 @@ pub struct AbortInfo {
     pub description: String,
 }
 
+/// The status of executing a transaction. The VM decides whether or not we should `Keep` the
+/// transaction output or `Discard` it based upon the execution of the transaction. We wrap these
+/// decisions around a `VMStatus` that provides more detail on the final execution state of the VM.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionStatus {
+    /// Discard the transaction output
+    Discard(DiscardedVMStatus),
+
+    /// Keep the transaction output
+    Keep(ExecutionStatus),
+
+    /// Retry the transaction, e.g., after a reconfiguration
+    Retry,
+}
+
+impl TransactionStatus {
+    pub fn status(&self) -> Result<ExecutionStatus, StatusCode> {
+        match self {
+            TransactionStatus::Keep(status) => Ok(status.clone()),
+            TransactionStatus::Discard(code) => Err(*code),
+            TransactionStatus::Retry => Err(StatusCode::UNKNOWN_VALIDATION_STATUS),
+        }
+    }
+
+    pub fn is_discarded(&self) -> bool {
+        match self {
+            TransactionStatus::Discard(_) => true,
+            TransactionStatus::Keep(_) => false,
+            TransactionStatus::Retry => true,
+        }
+    }
+
+    pub fn as_kept_status(&self) -> Option<&ExecutionStatus> {
+        match self {
+            TransactionStatus::Keep(status) => Some(status),
+            _ => None,
+        }
+    }
+}
+
+impl From<ExecutionStatus> for TransactionStatus {
+    fn from(status: ExecutionStatus) -> Self {
+        TransactionStatus::Keep(status)
+    }
+}
+
+/// The output of executing a transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionOutput {
+    /// The list of writes this transaction intends to do.
+    write_set: WriteSet,
+
+    /// The list of events emitted during this transaction.
+    events: Vec<ContractEvent>,
+
+    /// The amount of gas used during execution.
+    gas_used: u64,
+
+    /// The execution status.
+    status: TransactionStatus,
+}
+
+impl TransactionOutput {
+    pub fn new(
+        write_set: WriteSet,
+        events: Vec<ContractEvent>,
+        gas_used: u64,
+        status: TransactionStatus,
+    ) -> Self {
+        TransactionOutput {
+            write_set,
+            events,
+            gas_used,
+            status,
+        }
+    }
+
+    pub fn write_set(&self) -> &WriteSet {
+        &self.write_set
+    }
+
+    pub fn events(&self) -> &[ContractEvent] {
+        &self.events
+    }
+
+    pub fn gas_used(&self) -> u64 {
+        self.gas_used
+    }
+
+    pub fn status(&self) -> &TransactionStatus {
+        &self.status
+    }
+
+    /// The hash of the write set, which the `TransactionInfo` of a kept transaction commits to
+    /// as its `state_change_hash`.
+    pub fn state_change_hash(&self) -> HashValue {
+        CryptoHash::hash(&self.write_set)
+    }
+
+    pub fn unpack(self) -> (WriteSet, Vec<ContractEvent>, u64, TransactionStatus) {
+        let Self {
+            write_set,
+            events,
+            gas_used,
+            status,
+        } = self;
+        (write_set, events, gas_used, status)
+    }
+}
+
 /// `TransactionInfo` is the object we store in the transaction accumulator. It consists of the
 /// transaction as well as the execution result of this transaction.
 #[derive(Clone, CryptoHasher, BCSCryptoHash, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -464,63 +908,63 @@ pub enum TransactionInfo {
     V0(TransactionInfoV0),
 }
 
-// impl TransactionInfo {
-//     pub fn new(
-//         transaction_hash: HashValue,
-//         state_change_hash: HashValue,
-//         event_root_hash: HashValue,
-//         state_checkpoint_hash: Option<HashValue>,
-//         gas_used: u64,
-//         status: ExecutionStatus,
-//     ) -> Self {
-//         Self::V0(TransactionInfoV0::new(
-//             transaction_hash,
-//             state_change_hash,
-//             event_root_hash,
-//             state_checkpoint_hash,
-//             gas_used,
-//             status,
-//         ))
-//     }
-
-//     #[cfg(any(test, feature = "fuzzing"))]
-//     pub fn new_placeholder(
-//         gas_used: u64,
-//         state_checkpoint_hash: Option<HashValue>,
-//         status: ExecutionStatus,
-//     ) -> Self {
-//         Self::new(
-//             HashValue::default(),
-//             HashValue::default(),
-//             HashValue::default(),
-//             state_checkpoint_hash,
-//             gas_used,
-//             status,
-//         )
-//     }
-
-//     #[cfg(any(test, feature = "fuzzing"))]
-//     fn dummy() -> Self {
-//         Self::new(
-//             HashValue::default(),
-//             HashValue::default(),
-//             HashValue::default(),
-//             None,
-//             0,
-//             ExecutionStatus::Success,
-//         )
-//     }
-// }
-
-// impl Deref for TransactionInfo {
-//     type Target = TransactionInfoV0;
-
-//     fn deref(&self) -> &Self::Target {
-//         match self {
-//             Self::V0(txn_info) => txn_info,
-//         }
-//     }
-// }
+impl TransactionInfo {
+    pub fn new(
+        transaction_hash: HashValue,
+        state_change_hash: HashValue,
+        event_root_hash: HashValue,
+        state_checkpoint_hash: Option<HashValue>,
+        gas_used: u64,
+        status: ExecutionStatus,
+    ) -> Self {
+        Self::V0(TransactionInfoV0::new(
+            transaction_hash,
+            state_change_hash,
+            event_root_hash,
+            state_checkpoint_hash,
+            gas_used,
+            status,
+        ))
+    }
+
+    // #[cfg(any(test, feature = "fuzzing"))]
+    // pub fn new_placeholder(
+    //     gas_used: u64,
+    //     state_checkpoint_hash: Option<HashValue>,
+    //     status: ExecutionStatus,
+    // ) -> Self {
+    //     Self::new(
+    //         HashValue::default(),
+    //         HashValue::default(),
+    //         HashValue::default(),
+    //         state_checkpoint_hash,
+    //         gas_used,
+    //         status,
+    //     )
+    // }
+
+    // #[cfg(any(test, feature = "fuzzing"))]
+    // fn dummy() -> Self {
+    //     Self::new(
+    //         HashValue::default(),
+    //         HashValue::default(),
+    //         HashValue::default(),
+    //         None,
+    //         0,
+    //         ExecutionStatus::Success,
+    //     )
+    // }
+}
+
+impl Deref for TransactionInfo {
+    type Target = TransactionInfoV0;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::V0(txn_info) => txn_info,
+        }
+    }
+}
 
 #[derive(Clone, CryptoHasher, BCSCryptoHash, Debug, Eq, PartialEq, Serialize, Deserialize)]
 // #[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
@@ -552,6 +996,55 @@ pub struct TransactionInfoV0 {
     state_cemetery_hash: Option<HashValue>,
 }
 
+impl TransactionInfoV0 {
+    pub fn new(
+        transaction_hash: HashValue,
+        state_change_hash: HashValue,
+        event_root_hash: HashValue,
+        state_checkpoint_hash: Option<HashValue>,
+        gas_used: u64,
+        status: ExecutionStatus,
+    ) -> Self {
+        Self {
+            gas_used,
+            status,
+            transaction_hash,
+            event_root_hash,
+            state_change_hash,
+            state_checkpoint_hash,
+            state_cemetery_hash: None,
+        }
+    }
+
+    pub fn transaction_hash(&self) -> HashValue {
+        self.transaction_hash
+    }
+
+    pub fn state_change_hash(&self) -> HashValue {
+        self.state_change_hash
+    }
+
+    pub fn event_root_hash(&self) -> HashValue {
+        self.event_root_hash
+    }
+
+    pub fn state_checkpoint_hash(&self) -> Option<HashValue> {
+        self.state_checkpoint_hash
+    }
+
+    pub fn gas_used(&self) -> u64 {
+        self.gas_used
+    }
+
+    pub fn status(&self) -> &ExecutionStatus {
+        &self.status
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status == ExecutionStatus::Success
+    }
+}
+
 /// `Transaction` will be the transaction type used internally in the aptos node to represent the
 /// transaction to be processed and persisted.
 ///
@@ -566,28 +1059,363 @@ pub enum Transaction {
     /// TODO: We need to rename SignedTransaction to SignedUserTransaction, as well as all the other
     ///       transaction types we had in our codebase.
     UserTransaction(SignedTransaction),
-    // / Transaction that applies a WriteSet to the current storage, it's applied manually via aptos-db-bootstrapper.
-    // GenesisTransaction(WriteSetPayload),
-
-    // /// Transaction to update the block metadata resource at the beginning of a block,
-    // /// when on-chain randomness is disabled.
-    // BlockMetadata(BlockMetadata),
-
-    // /// Transaction to let the executor update the global state tree and record the root hash
-    // /// in the TransactionInfo
-    // /// The hash value inside is unique block id which can generate unique hash of state checkpoint transaction
-    // StateCheckpoint(HashValue),
-
-    // /// Transaction that only proposed by a validator mainly to update on-chain configs.
-    // ValidatorTransaction(ValidatorTransaction),
-
-    // /// Transaction to update the block metadata resource at the beginning of a block,
-    // /// when on-chain randomness is enabled.
-    // BlockMetadataExt(BlockMetadataExt),
-
-    // /// Transaction to let the executor update the global state tree and record the root hash
-    // /// in the TransactionInfo
-    // /// The hash value inside is unique block id which can generate unique hash of state checkpoint transaction
-    // /// Replaces StateCheckpoint, with optionally having more data.
-    // BlockEpilogue(BlockEpiloguePayload),
+
+    /// Transaction that applies a WriteSet to the current storage, it's applied manually via aptos-db-bootstrapper.
+    GenesisTransaction(WriteSetPayload),
+
+    /// Transaction to update the block metadata resource at the beginning of a block,
+    /// when on-chain randomness is disabled.
+    BlockMetadata(BlockMetadata),
+
+    /// Transaction to let the executor update the global state tree and record the root hash
+    /// in the TransactionInfo
+    /// The hash value inside is unique block id which can generate unique hash of state checkpoint transaction
+    StateCheckpoint(HashValue),
+
+    /// Transaction that only proposed by a validator mainly to update on-chain configs.
+    ValidatorTransaction(ValidatorTransaction),
+
+    /// Transaction to update the block metadata resource at the beginning of a block,
+    /// when on-chain randomness is enabled.
+    BlockMetadataExt(BlockMetadataExt),
+
+    /// Transaction to let the executor update the global state tree and record the root hash
+    /// in the TransactionInfo
+    /// The hash value inside is unique block id which can generate unique hash of state checkpoint transaction
+    /// Replaces StateCheckpoint, with optionally having more data.
+    BlockEpilogue(BlockEpiloguePayload),
+}
+
+impl Transaction {
+    pub fn try_as_signed_user_txn(&self) -> Option<&SignedTransaction> {
+        match self {
+            Transaction::UserTransaction(txn) => Some(txn),
+            _ => None,
+        }
+    }
+
+    /// The metadata of the block this transaction starts, if it is a block metadata transaction
+    pub fn try_as_block_metadata_ext(&self) -> Option<BlockMetadataExt> {
+        match self {
+            Transaction::BlockMetadata(metadata) => Some(metadata.clone().into()),
+            Transaction::BlockMetadataExt(metadata) => Some(metadata.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_block_epilogue(&self) -> Option<&BlockEpiloguePayload> {
+        match self {
+            Transaction::BlockEpilogue(payload) => Some(payload),
+            _ => None,
+        }
+    }
+
+    pub fn is_block_start(&self) -> bool {
+        matches!(
+            self,
+            Transaction::BlockMetadata(_) | Transaction::BlockMetadataExt(_)
+        )
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Transaction::UserTransaction(_) => "user_transaction",
+            Transaction::GenesisTransaction(_) => "genesis_transaction",
+            Transaction::BlockMetadata(_) => "block_metadata",
+            Transaction::StateCheckpoint(_) => "state_checkpoint",
+            Transaction::ValidatorTransaction(vt) => vt.type_name(),
+            Transaction::BlockMetadataExt(_) => "block_metadata_ext",
+            Transaction::BlockEpilogue(_) => "block_epilogue",
+        }
+    }
+}
+
+impl From<SignedTransaction> for Transaction {
+    fn from(txn: SignedTransaction) -> Self {
+        Transaction::UserTransaction(txn)
+    }
+}
+
+/// The payload of a genesis transaction, which writes the initial state either directly or by
+/// running a script.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum WriteSetPayload {
+    /// Directly passing in the WriteSet.
+    Direct(ChangeSet),
+    /// Generate the WriteSet by running a script.
+    Script {
+        /// Execute the script as the designated signer.
+        execute_as: AccountAddress,
+        /// Script body that gets executed.
+        script: Script,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        on_chain_config::FeatureFlag,
+        test_helpers::{private_key, signed_transaction, transfer},
+    };
+    use aptos_crypto::{ed25519::Ed25519PrivateKey, SigningKey};
+
+    #[test]
+    fn test_verify_signatures_batch() {
+        let mut txns: Vec<_> = (1..=8).map(|i| signed_transaction(i, i as u64)).collect();
+        assert!(SignedTransaction::verify_signatures_batch(&txns).is_ok());
+        assert!(SignedTransaction::verify_signatures_batch(&[]).is_ok());
+
+        // Re-use the authenticators of other transactions for two of them.
+        let forged = [(2, 5), (6, 1)];
+        for (target, source) in forged {
+            let authenticator = txns[source].authenticator();
+            let raw_txn = txns[target].raw_transaction_ref().clone();
+            txns[target] = SignedTransaction::new_signed_transaction(raw_txn, authenticator);
+        }
+        let failures = SignedTransaction::verify_signatures_batch(&txns).unwrap_err();
+        let indices: Vec<_> = failures.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![2, 6]);
+    }
+
+    fn orderless_features() -> Features {
+        let mut features = Features::default();
+        features.enable(FeatureFlag::TRANSACTION_PAYLOAD_V2);
+        features.enable(FeatureFlag::ORDERLESS_TRANSACTIONS);
+        features
+    }
+
+    fn orderless_transaction(nonce: u64) -> RawTransaction {
+        RawTransaction::new_orderless(
+            AccountAddress::TWO,
+            TransactionExecutable::EntryFunction(transfer(vec![])),
+            nonce,
+            1_000,
+            100,
+            60,
+            ChainId::test(),
+            &orderless_features(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_orderless_payload_layout() {
+        let payload = TransactionPayload::new_v1(
+            TransactionExecutable::EntryFunction(transfer(vec![])),
+            TransactionExtraConfig::V1 {
+                multisig_address: None,
+                replay_protection_nonce: Some(7),
+            },
+        );
+        // Payload variant, V1, EntryFunction executable, then the extra config.
+        let mut expected = vec![4u8, 0, 1];
+        expected.extend(bcs::to_bytes(&transfer(vec![])).unwrap());
+        expected.extend([0, 0, 1]);
+        expected.extend(7u64.to_le_bytes());
+        assert_eq!(bcs::to_bytes(&payload).unwrap(), expected);
+        assert_eq!(
+            bcs::from_bytes::<TransactionPayload>(&expected).unwrap(),
+            payload
+        );
+        assert_eq!(payload.replay_protection_nonce(), Some(7));
+        assert_eq!(payload.multisig_address(), None);
+    }
+
+    #[test]
+    fn test_new_orderless() {
+        let txn = orderless_transaction(7);
+        assert_eq!(txn.sequence_number, ORDERLESS_SEQUENCE_NUMBER);
+        assert_eq!(txn.replay_protector(), ReplayProtector::Nonce(7));
+
+        // Both feature flags are required.
+        let mut features = orderless_features();
+        features.disable(FeatureFlag::ORDERLESS_TRANSACTIONS);
+        assert!(RawTransaction::new_orderless(
+            AccountAddress::TWO,
+            TransactionExecutable::EntryFunction(transfer(vec![])),
+            7,
+            1_000,
+            100,
+            60,
+            ChainId::test(),
+            &features,
+        )
+        .is_err());
+
+        let regular = RawTransaction::new_entry_function(
+            AccountAddress::TWO,
+            3,
+            transfer(vec![]),
+            1_000,
+            100,
+            60,
+            ChainId::test(),
+        );
+        assert_eq!(
+            regular.replay_protector(),
+            ReplayProtector::SequenceNumber(3)
+        );
+    }
+
+    #[test]
+    fn test_orderless_signing_message_and_hash() {
+        let private_key = private_key(1);
+        let public_key = Ed25519PublicKey::from(&private_key);
+        let (first, second) = (orderless_transaction(7), orderless_transaction(8));
+
+        // The nonce is part of what is signed and of the transaction hash.
+        assert_ne!(
+            first.signing_message().unwrap(),
+            second.signing_message().unwrap()
+        );
+        let first = SignedTransaction::new(
+            first.clone(),
+            public_key.clone(),
+            private_key.sign(&first).unwrap(),
+        );
+        let second = SignedTransaction::new(
+            second.clone(),
+            public_key,
+            private_key.sign(&second).unwrap(),
+        );
+        assert!(first.clone().check_signature().is_ok());
+        assert_ne!(first.committed_hash(), second.committed_hash());
+        assert_eq!(first.replay_protector(), ReplayProtector::Nonce(7));
+    }
+
+    #[test]
+    fn test_new_for_simulation() {
+        let private_key = private_key(1);
+        let public_key = Ed25519PublicKey::from(&private_key);
+        let raw_txn = orderless_transaction(1);
+
+        let txn = SignedTransaction::new_for_simulation(
+            raw_txn.clone(),
+            Some(public_key.clone()),
+            vec![],
+            None,
+        );
+        assert_eq!(
+            txn.authenticator(),
+            TransactionAuthenticator::ed25519(
+                public_key.clone(),
+                Ed25519Signature::dummy_signature()
+            )
+        );
+        // Simulated transactions must not carry a valid signature.
+        assert!(txn.verify_signature().is_err());
+
+        let txn = SignedTransaction::new_for_simulation(raw_txn.clone(), None, vec![], None);
+        // SingleSender, then NoAccountAuthenticator.
+        assert_eq!(bcs::to_bytes(txn.authenticator_ref()).unwrap(), vec![4, 4]);
+
+        let txn = SignedTransaction::new_for_simulation(
+            raw_txn.clone(),
+            None,
+            vec![(AccountAddress::THREE, Some(public_key.clone()))],
+            None,
+        );
+        assert_eq!(
+            txn.authenticator_ref().secondary_signer_addresses(),
+            vec![AccountAddress::THREE]
+        );
+        assert_eq!(txn.authenticator_ref().fee_payer_address(), None);
+
+        let txn = SignedTransaction::new_for_simulation(
+            raw_txn,
+            Some(public_key),
+            vec![],
+            Some((AccountAddress::ZERO, None)),
+        );
+        let authenticator = txn.authenticator();
+        assert_eq!(
+            authenticator.fee_payer_address(),
+            Some(AccountAddress::ZERO)
+        );
+        assert_eq!(
+            authenticator.fee_payer_signer(),
+            Some(AccountAuthenticator::NoAccountAuthenticator)
+        );
+        assert_eq!(authenticator.all_signers().len(), 2);
+        let decoded: SignedTransaction = bcs::from_bytes(&bcs::to_bytes(&txn).unwrap()).unwrap();
+        assert_eq!(decoded, txn);
+    }
+
+    #[test]
+    fn test_verify_multi_agent() {
+        let sender_key = private_key(1);
+        let secondary_key = private_key(2);
+        let secondary =
+            crate::account_address::from_public_key(&Ed25519PublicKey::from(&secondary_key));
+        let raw_txn = signed_transaction(1, 0).into_raw_transaction();
+        let message = RawTransactionWithData::new_multi_agent(raw_txn.clone(), vec![secondary]);
+
+        let signer = |key: &Ed25519PrivateKey| {
+            AccountAuthenticator::ed25519(Ed25519PublicKey::from(key), key.sign(&message).unwrap())
+        };
+        let txn = SignedTransaction::new_signed_transaction(
+            raw_txn.clone(),
+            TransactionAuthenticator::multi_agent(
+                signer(&sender_key),
+                vec![secondary],
+                vec![signer(&secondary_key)],
+            ),
+        );
+        assert!(txn.verify_signature().is_ok());
+
+        // Signatures over the raw transaction alone do not cover the secondary signers.
+        let txn = SignedTransaction::new_signed_transaction(
+            raw_txn.clone(),
+            TransactionAuthenticator::multi_agent(
+                AccountAuthenticator::ed25519(
+                    Ed25519PublicKey::from(&sender_key),
+                    sender_key.sign(&raw_txn).unwrap(),
+                ),
+                vec![secondary],
+                vec![signer(&secondary_key)],
+            ),
+        );
+        assert!(txn.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_upgrade() {
+        let multisig = TransactionPayload::Multisig(Multisig::new(
+            AccountAddress::THREE,
+            Some(MultisigTransactionPayload::EntryFunction(transfer(vec![]))),
+        ));
+        let upgraded = multisig.upgrade(Some(1)).unwrap();
+        assert_eq!(upgraded.multisig_address(), Some(AccountAddress::THREE));
+        assert_eq!(upgraded.replay_protection_nonce(), Some(1));
+        assert!(matches!(
+            &upgraded,
+            TransactionPayload::Payload(TransactionPayloadInner::V1 {
+                executable: TransactionExecutable::EntryFunction(f),
+                ..
+            }) if *f == transfer(vec![])
+        ));
+        let orderless = TransactionPayload::EntryFunction(transfer(vec![]))
+            .upgrade(Some(1))
+            .unwrap();
+        assert_eq!(orderless.into_entry_function(), transfer(vec![]));
+
+        assert!(upgraded.check_features(&Features::default()).is_err());
+        assert!(upgraded.check_features(&orderless_features()).is_ok());
+        assert!(TransactionPayload::EntryFunction(transfer(vec![]))
+            .check_features(&Features::default())
+            .is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected EntryFunction(_) payload")]
+    fn test_multisig_payload_is_not_an_entry_function() {
+        TransactionPayload::Multisig(Multisig::new(
+            AccountAddress::THREE,
+            Some(MultisigTransactionPayload::EntryFunction(transfer(vec![]))),
+        ))
+        .upgrade(None)
+        .unwrap()
+        .into_entry_function();
+    }
 }
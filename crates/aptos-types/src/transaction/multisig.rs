@@ -0,0 +1,75 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::transaction::EntryFunction;
+use aptos_crypto::HashValue;
+use move_core_types::account_address::AccountAddress;
+use serde::{Deserialize, Serialize};
+
+/// A multisig transaction that allows an owner of a multisig account to execute a pre-approved
+/// transaction as the multisig account.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Multisig {
+    pub multisig_address: AccountAddress,
+
+    // Transaction payload is optional if already stored on chain.
+    pub transaction_payload: Option<MultisigTransactionPayload>,
+}
+
+impl Multisig {
+    pub fn new(
+        multisig_address: AccountAddress,
+        transaction_payload: Option<MultisigTransactionPayload>,
+    ) -> Self {
+        Self {
+            multisig_address,
+            transaction_payload,
+        }
+    }
+}
+
+// We use an enum here for extensibility so we can add Script payload support
+// in the future for example.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum MultisigTransactionPayload {
+    EntryFunction(EntryFunction),
+}
+
+impl MultisigTransactionPayload {
+    /// The BCS bytes of the payload, as passed to `0x1::multisig_account::create_transaction`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Only unhandleable errors happen here.")
+    }
+
+    /// The sha3-256 hash of the payload bytes, as passed to
+    /// `0x1::multisig_account::create_transaction_with_hash`. When the transaction is executed,
+    /// the multisig account checks the payload it is given against this hash.
+    pub fn payload_hash(&self) -> HashValue {
+        HashValue::sha3_256_of(&self.to_bytes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_helpers::transfer;
+
+    #[test]
+    fn test_payload_hash() {
+        let entry_function = transfer(vec![
+            bcs::to_bytes(&AccountAddress::TWO).unwrap(),
+            bcs::to_bytes(&100u64).unwrap(),
+        ]);
+        let payload = MultisigTransactionPayload::EntryFunction(entry_function.clone());
+
+        // The enum variant index precedes the entry function.
+        let mut expected = vec![0u8];
+        expected.extend(bcs::to_bytes(&entry_function).unwrap());
+        assert_eq!(payload.to_bytes(), expected);
+        assert_eq!(payload.payload_hash(), HashValue::sha3_256_of(&expected));
+
+        let multisig = Multisig::new(AccountAddress::THREE, Some(payload.clone()));
+        let decoded: Multisig = bcs::from_bytes(&bcs::to_bytes(&multisig).unwrap()).unwrap();
+        assert_eq!(decoded.transaction_payload, Some(payload));
+    }
+}
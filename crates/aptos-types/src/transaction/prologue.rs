@@ -0,0 +1,535 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Offline checks of a `SignedTransaction` against the rules the VM and the Move transaction
+//! prologue (`0x1::transaction_validation`) enforce, so that doomed transactions can be caught
+//! before they are submitted.
+//!
+//! Every check that needs on-chain state only runs when that state is provided: the sender's
+//! `AccountResource`, the fee payer's balance, the `GasScheduleV2` and the `Features`.
+
+use crate::{
+    account_config::AccountResource,
+    chain_id::ChainId,
+    on_chain_config::{Features, GasScheduleV2},
+    transaction::{ReplayProtector, SignedTransaction},
+};
+use move_core_types::vm_status::StatusCode;
+use std::fmt;
+
+/// The transaction size limit used when no gas schedule is provided.
+pub const DEFAULT_MAX_TRANSACTION_SIZE_IN_BYTES: u64 = 64 * 1024;
+
+/// A reason the transaction would be rejected, with the status code the VM would return.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrologueDiagnostic {
+    pub status_code: StatusCode,
+    pub message: String,
+}
+
+impl PrologueDiagnostic {
+    fn new(status_code: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status_code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for PrologueDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}: {}", self.status_code, self.message)
+    }
+}
+
+/// The transaction parameters of a `GasScheduleV2`, in the units the schedule stores them.
+struct TransactionGasParameters {
+    max_transaction_size_in_bytes: Option<u64>,
+    maximum_number_of_gas_units: Option<u64>,
+    min_price_per_gas_unit: Option<u64>,
+    max_price_per_gas_unit: Option<u64>,
+    min_transaction_gas_units: Option<u64>,
+    large_transaction_cutoff: Option<u64>,
+    intrinsic_gas_per_byte: Option<u64>,
+    gas_unit_scaling_factor: Option<u64>,
+}
+
+impl TransactionGasParameters {
+    fn new(gas_schedule: &GasScheduleV2) -> Self {
+        let entries = gas_schedule.to_btree_map_borrowed();
+        let param = |name: &str| entries.get(format!("txn.{}", name).as_str()).copied();
+        Self {
+            max_transaction_size_in_bytes: param("max_transaction_size_in_bytes"),
+            maximum_number_of_gas_units: param("maximum_number_of_gas_units"),
+            min_price_per_gas_unit: param("min_price_per_gas_unit"),
+            max_price_per_gas_unit: param("max_price_per_gas_unit"),
+            min_transaction_gas_units: param("min_transaction_gas_units"),
+            large_transaction_cutoff: param("large_transaction_cutoff"),
+            intrinsic_gas_per_byte: param("intrinsic_gas_per_byte"),
+            gas_unit_scaling_factor: param("gas_unit_scaling_factor"),
+        }
+    }
+
+    /// The gas units charged for the size of a transaction before it executes, rounded up to
+    /// external gas units, or None if a parameter is missing or the charge overflows.
+    fn intrinsic_gas(&self, txn_size: u64) -> Option<u64> {
+        let min_transaction_gas_units = self.min_transaction_gas_units?;
+        let per_byte = txn_size
+            .saturating_sub(self.large_transaction_cutoff?)
+            .checked_mul(self.intrinsic_gas_per_byte?)?;
+        let scaling_factor = self.gas_unit_scaling_factor?.max(1);
+        Some(
+            min_transaction_gas_units
+                .checked_add(per_byte)?
+                .div_ceil(scaling_factor),
+        )
+    }
+}
+
+/// Checks transactions against the Move prologue. Created with the chain id and the current
+/// on-chain time; everything else is optional.
+#[derive(Clone, Debug)]
+pub struct PrologueValidator<'a> {
+    chain_id: ChainId,
+    now_secs: u64,
+    account: Option<Option<&'a AccountResource>>,
+    balance: Option<u64>,
+    gas_schedule: Option<&'a GasScheduleV2>,
+    features: Option<&'a Features>,
+}
+
+impl<'a> PrologueValidator<'a> {
+    pub fn new(chain_id: ChainId, now_secs: u64) -> Self {
+        Self {
+            chain_id,
+            now_secs,
+            account: None,
+            balance: None,
+            gas_schedule: None,
+            features: None,
+        }
+    }
+
+    /// Check the sequence number and authentication key against the sender's account, or
+    /// report that the sender does not exist if `account` is None.
+    pub fn with_account(mut self, account: Option<&'a AccountResource>) -> Self {
+        self.account = Some(account);
+        self
+    }
+
+    /// Check that the account paying the fee, i.e., the fee payer if there is one and the
+    /// sender otherwise, can afford `max_gas_amount * gas_unit_price`.
+    pub fn with_balance(mut self, balance: u64) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+
+    pub fn with_gas_schedule(mut self, gas_schedule: &'a GasScheduleV2) -> Self {
+        self.gas_schedule = Some(gas_schedule);
+        self
+    }
+
+    pub fn with_features(mut self, features: &'a Features) -> Self {
+        self.features = Some(features);
+        self
+    }
+
+    /// Run every check that the provided state allows, in the order the VM runs them. An empty
+    /// list means no problem was found.
+    pub fn validate(&self, txn: &SignedTransaction) -> Vec<PrologueDiagnostic> {
+        let mut diagnostics = vec![];
+        self.check_signature(txn, &mut diagnostics);
+        self.check_gas(txn, &mut diagnostics);
+        self.check_features(txn, &mut diagnostics);
+        self.check_chain_id_and_expiration(txn, &mut diagnostics);
+        self.check_account(txn, &mut diagnostics);
+        self.check_balance(txn, &mut diagnostics);
+        diagnostics
+    }
+
+    fn check_signature(&self, txn: &SignedTransaction, diagnostics: &mut Vec<PrologueDiagnostic>) {
+        if let Err(e) = txn.verify_signature() {
+            diagnostics.push(PrologueDiagnostic::new(
+                StatusCode::INVALID_SIGNATURE,
+                e.to_string(),
+            ));
+        }
+    }
+
+    fn check_gas(&self, txn: &SignedTransaction, diagnostics: &mut Vec<PrologueDiagnostic>) {
+        let txn_size = txn.raw_txn_bytes_len() as u64;
+        let params = self.gas_schedule.map(TransactionGasParameters::new);
+        let max_transaction_size = params
+            .as_ref()
+            .and_then(|params| params.max_transaction_size_in_bytes)
+            .unwrap_or(DEFAULT_MAX_TRANSACTION_SIZE_IN_BYTES);
+        if txn_size > max_transaction_size {
+            diagnostics.push(PrologueDiagnostic::new(
+                StatusCode::EXCEEDED_MAX_TRANSACTION_SIZE,
+                format!(
+                    "transaction is {} bytes, the limit is {}",
+                    txn_size, max_transaction_size
+                ),
+            ));
+        }
+
+        let Some(params) = params else {
+            return;
+        };
+        if let Some(maximum) = params.maximum_number_of_gas_units {
+            if txn.max_gas_amount() > maximum {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::MAX_GAS_UNITS_EXCEEDS_MAX_GAS_UNITS_BOUND,
+                    format!(
+                        "max gas amount {} is above the maximum of {}",
+                        txn.max_gas_amount(),
+                        maximum
+                    ),
+                ));
+            }
+        }
+        if let Some(intrinsic_gas) = params.intrinsic_gas(txn_size) {
+            if txn.max_gas_amount() < intrinsic_gas {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::MAX_GAS_UNITS_BELOW_MIN_TRANSACTION_GAS_UNITS,
+                    format!(
+                        "max gas amount {} does not cover the {} gas units charged for the \
+                         transaction's size",
+                        txn.max_gas_amount(),
+                        intrinsic_gas
+                    ),
+                ));
+            }
+        }
+        if let Some(maximum) = params.max_price_per_gas_unit {
+            if txn.gas_unit_price() > maximum {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::GAS_UNIT_PRICE_ABOVE_MAX_BOUND,
+                    format!(
+                        "gas unit price {} is above the maximum of {}",
+                        txn.gas_unit_price(),
+                        maximum
+                    ),
+                ));
+            }
+        }
+        if let Some(minimum) = params.min_price_per_gas_unit {
+            if txn.gas_unit_price() < minimum {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::GAS_UNIT_PRICE_BELOW_MIN_BOUND,
+                    format!(
+                        "gas unit price {} is below the minimum of {}",
+                        txn.gas_unit_price(),
+                        minimum
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn check_features(&self, txn: &SignedTransaction, diagnostics: &mut Vec<PrologueDiagnostic>) {
+        let Some(features) = self.features else {
+            return;
+        };
+        if let Err(e) = txn.payload().check_features(features) {
+            diagnostics.push(PrologueDiagnostic::new(
+                StatusCode::FEATURE_UNDER_GATING,
+                e.to_string(),
+            ));
+        }
+    }
+
+    fn check_chain_id_and_expiration(
+        &self,
+        txn: &SignedTransaction,
+        diagnostics: &mut Vec<PrologueDiagnostic>,
+    ) {
+        if txn.chain_id() != self.chain_id {
+            diagnostics.push(PrologueDiagnostic::new(
+                StatusCode::BAD_CHAIN_ID,
+                format!(
+                    "transaction is for chain {}, not {}",
+                    txn.chain_id(),
+                    self.chain_id
+                ),
+            ));
+        }
+        if self.now_secs >= txn.expiration_timestamp_secs() {
+            diagnostics.push(PrologueDiagnostic::new(
+                StatusCode::TRANSACTION_EXPIRED,
+                format!(
+                    "transaction expired at {}, current time is {}",
+                    txn.expiration_timestamp_secs(),
+                    self.now_secs
+                ),
+            ));
+        }
+    }
+
+    fn check_account(&self, txn: &SignedTransaction, diagnostics: &mut Vec<PrologueDiagnostic>) {
+        let Some(account) = self.account else {
+            return;
+        };
+        let Some(account) = account else {
+            diagnostics.push(PrologueDiagnostic::new(
+                StatusCode::SENDING_ACCOUNT_DOES_NOT_EXIST,
+                format!("sender {} does not exist", txn.sender()),
+            ));
+            return;
+        };
+
+        // Simulated transactions may leave the sender without a key.
+        if let Some(authentication_key) = txn.authenticator_ref().sender().authentication_key() {
+            if authentication_key.to_vec() != account.authentication_key() {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::INVALID_AUTH_KEY,
+                    format!(
+                        "the sender's key derives authentication key {}, the account's is 0x{}",
+                        authentication_key,
+                        hex::encode(account.authentication_key())
+                    ),
+                ));
+            }
+        }
+
+        if let ReplayProtector::SequenceNumber(sequence_number) = txn.replay_protector() {
+            if sequence_number == u64::MAX {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::SEQUENCE_NUMBER_TOO_BIG,
+                    "sequence number must be below u64::MAX",
+                ));
+            } else if sequence_number < account.sequence_number() {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::SEQUENCE_NUMBER_TOO_OLD,
+                    format!(
+                        "sequence number {} was already used, the account is at {}",
+                        sequence_number,
+                        account.sequence_number()
+                    ),
+                ));
+            } else if sequence_number > account.sequence_number() {
+                diagnostics.push(PrologueDiagnostic::new(
+                    StatusCode::SEQUENCE_NUMBER_TOO_NEW,
+                    format!(
+                        "sequence number {} is ahead of the account's {}",
+                        sequence_number,
+                        account.sequence_number()
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn check_balance(&self, txn: &SignedTransaction, diagnostics: &mut Vec<PrologueDiagnostic>) {
+        let Some(balance) = self.balance else {
+            return;
+        };
+        let max_fee = (txn.max_gas_amount() as u128) * (txn.gas_unit_price() as u128);
+        if max_fee > balance as u128 {
+            diagnostics.push(PrologueDiagnostic::new(
+                StatusCode::INSUFFICIENT_BALANCE_FOR_TRANSACTION_FEE,
+                format!("max fee {} is above the balance of {}", max_fee, balance),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        event::{EventHandle, EventKey},
+        on_chain_config::FeatureFlag,
+        test_helpers::{private_key, transfer},
+        transaction::{authenticator::AuthenticationKey, RawTransaction, TransactionPayload},
+    };
+    use aptos_crypto::{ed25519::Ed25519PublicKey, SigningKey};
+    use move_core_types::account_address::AccountAddress;
+
+    const NOW_SECS: u64 = 1_700_000_000;
+
+    fn sign(raw_txn: RawTransaction) -> SignedTransaction {
+        let private_key = private_key(1);
+        let signature = private_key.sign(&raw_txn).unwrap();
+        SignedTransaction::new(raw_txn, Ed25519PublicKey::from(&private_key), signature)
+    }
+
+    fn transaction(
+        sequence_number: u64,
+        max_gas_amount: u64,
+        gas_unit_price: u64,
+    ) -> SignedTransaction {
+        let public_key = Ed25519PublicKey::from(&private_key(1));
+        sign(RawTransaction::new_entry_function(
+            crate::account_address::from_public_key(&public_key),
+            sequence_number,
+            transfer(vec![vec![0; 8]]),
+            max_gas_amount,
+            gas_unit_price,
+            NOW_SECS + 30,
+            ChainId::test(),
+        ))
+    }
+
+    fn account(sequence_number: u64) -> AccountResource {
+        let public_key = Ed25519PublicKey::from(&private_key(1));
+        AccountResource::new(
+            sequence_number,
+            AuthenticationKey::ed25519(&public_key).to_vec(),
+            EventHandle::new(EventKey::new(0, AccountAddress::ONE), 0),
+            EventHandle::new(EventKey::new(1, AccountAddress::ONE), 0),
+        )
+    }
+
+    fn gas_schedule() -> GasScheduleV2 {
+        GasScheduleV2 {
+            feature_version: 12,
+            entries: [
+                ("txn.max_transaction_size_in_bytes", 65_536),
+                ("txn.maximum_number_of_gas_units", 2_000_000),
+                ("txn.min_price_per_gas_unit", 100),
+                ("txn.max_price_per_gas_unit", 10_000_000_000),
+                ("txn.min_transaction_gas_units", 2_760_000),
+                ("txn.large_transaction_cutoff", 600),
+                ("txn.intrinsic_gas_per_byte", 1_158),
+                ("txn.gas_unit_scaling_factor", 1_000_000),
+            ]
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+        }
+    }
+
+    fn status_codes(diagnostics: Vec<PrologueDiagnostic>) -> Vec<StatusCode> {
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.status_code)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_transaction() {
+        let account = account(5);
+        let gas_schedule = gas_schedule();
+        let features = Features::default();
+        let validator = PrologueValidator::new(ChainId::test(), NOW_SECS)
+            .with_account(Some(&account))
+            .with_balance(1_000_000)
+            .with_gas_schedule(&gas_schedule)
+            .with_features(&features);
+        assert_eq!(validator.validate(&transaction(5, 1_000, 100)), vec![]);
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let account = account(5);
+        let gas_schedule = gas_schedule();
+        let validator = PrologueValidator::new(ChainId::mainnet(), NOW_SECS + 30)
+            .with_account(Some(&account))
+            .with_balance(1_000)
+            .with_gas_schedule(&gas_schedule);
+        assert_eq!(
+            status_codes(validator.validate(&transaction(4, 1, 99))),
+            vec![
+                StatusCode::MAX_GAS_UNITS_BELOW_MIN_TRANSACTION_GAS_UNITS,
+                StatusCode::GAS_UNIT_PRICE_BELOW_MIN_BOUND,
+                StatusCode::BAD_CHAIN_ID,
+                StatusCode::TRANSACTION_EXPIRED,
+                StatusCode::SEQUENCE_NUMBER_TOO_OLD,
+            ]
+        );
+        assert_eq!(
+            status_codes(validator.validate(&transaction(6, 3_000_000, 100))),
+            vec![
+                StatusCode::MAX_GAS_UNITS_EXCEEDS_MAX_GAS_UNITS_BOUND,
+                StatusCode::BAD_CHAIN_ID,
+                StatusCode::TRANSACTION_EXPIRED,
+                StatusCode::SEQUENCE_NUMBER_TOO_NEW,
+                StatusCode::INSUFFICIENT_BALANCE_FOR_TRANSACTION_FEE,
+            ]
+        );
+
+        let validator = PrologueValidator::new(ChainId::test(), NOW_SECS).with_account(None);
+        assert_eq!(
+            status_codes(validator.validate(&transaction(0, 1_000, 100))),
+            vec![StatusCode::SENDING_ACCOUNT_DOES_NOT_EXIST]
+        );
+    }
+
+    #[test]
+    fn test_intrinsic_gas_overflow() {
+        let mut gas_schedule = gas_schedule();
+        let params = TransactionGasParameters::new(&gas_schedule);
+        // 2_760_000 units plus 1_158 for each of the 400 bytes above the cutoff
+        assert_eq!(params.intrinsic_gas(1_000), Some(4));
+
+        for (name, value) in gas_schedule.entries.iter_mut() {
+            if name == "txn.intrinsic_gas_per_byte" {
+                *value = u64::MAX;
+            }
+        }
+        let params = TransactionGasParameters::new(&gas_schedule);
+        assert_eq!(params.intrinsic_gas(600), Some(3));
+        // The addition overflows, then the multiplication.
+        assert_eq!(params.intrinsic_gas(601), None);
+        assert_eq!(params.intrinsic_gas(602), None);
+    }
+
+    #[test]
+    fn test_size_and_signature() {
+        let txn = transaction(0, 1_000, 100);
+        let padding = vec![0; DEFAULT_MAX_TRANSACTION_SIZE_IN_BYTES as usize];
+        let oversized = SignedTransaction::new_signed_transaction(
+            RawTransaction::new_entry_function(
+                txn.sender(),
+                0,
+                transfer(vec![padding]),
+                1_000,
+                100,
+                NOW_SECS + 30,
+                ChainId::test(),
+            ),
+            txn.authenticator(),
+        );
+        assert_eq!(
+            status_codes(PrologueValidator::new(ChainId::test(), NOW_SECS).validate(&oversized)),
+            vec![
+                StatusCode::INVALID_SIGNATURE,
+                StatusCode::EXCEEDED_MAX_TRANSACTION_SIZE,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_orderless() {
+        let public_key = Ed25519PublicKey::from(&private_key(1));
+        let mut features = Features::default();
+        let txn = sign(RawTransaction::new(
+            crate::account_address::from_public_key(&public_key),
+            u64::MAX,
+            TransactionPayload::EntryFunction(transfer(vec![vec![0; 8]]))
+                .upgrade(Some(7))
+                .unwrap(),
+            1_000,
+            100,
+            NOW_SECS + 30,
+            ChainId::test(),
+        ));
+        let account = account(5);
+        let validator = PrologueValidator::new(ChainId::test(), NOW_SECS)
+            .with_account(Some(&account))
+            .with_features(&features);
+        assert_eq!(
+            status_codes(validator.validate(&txn)),
+            vec![StatusCode::FEATURE_UNDER_GATING]
+        );
+
+        features.enable(FeatureFlag::TRANSACTION_PAYLOAD_V2);
+        features.enable(FeatureFlag::ORDERLESS_TRANSACTIONS);
+        let validator = PrologueValidator::new(ChainId::test(), NOW_SECS)
+            .with_account(Some(&account))
+            .with_features(&features);
+        // The nonce replaces the sequence number checks.
+        assert_eq!(validator.validate(&txn), vec![]);
+    }
+}
@@ -6,34 +6,20 @@ use move_core_types::{
     ident_str,
     identifier::IdentStr,
     language_storage::{StructTag, TypeTag},
-    move_resource::MoveStructType,
+    move_resource::{MoveStructType, MoveTypeTag},
 };
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
-pub trait CoinType {
-    fn type_tag() -> TypeTag;
-
+/// A coin type, whose type tag is given by [`MoveTypeTag`]
+pub trait CoinType: MoveTypeTag {
     fn coin_info_address() -> AccountAddress;
 }
 
-static APTOS_COIN_TYPE: Lazy<TypeTag> = Lazy::new(|| {
-    TypeTag::Struct(Box::new(StructTag {
-        address: AccountAddress::ONE,
-        module: ident_str!("aptos_coin").to_owned(),
-        name: ident_str!("AptosCoin").to_owned(),
-        type_args: vec![],
-    }))
-});
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AptosCoinType;
 
 impl CoinType for AptosCoinType {
-    fn type_tag() -> TypeTag {
-        APTOS_COIN_TYPE.clone()
-    }
-
     fn coin_info_address() -> AccountAddress {
         AccountAddress::ONE
     }
@@ -55,11 +41,12 @@ pub static DUMMY_COIN_TYPE: Lazy<TypeTag> = Lazy::new(|| {
 
 pub struct DummyCoinType;
 impl CoinType for DummyCoinType {
-    fn type_tag() -> TypeTag {
-        DUMMY_COIN_TYPE.clone()
-    }
-
     fn coin_info_address() -> AccountAddress {
         AccountAddress::ONE
     }
 }
+
+impl MoveStructType for DummyCoinType {
+    const MODULE_NAME: &'static IdentStr = ident_str!("dummy_coin");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("DummyCoin");
+}
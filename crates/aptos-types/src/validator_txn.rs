@@ -0,0 +1,23 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{dkg::DKGTranscript, jwks::QuorumCertifiedUpdate};
+use serde::{Deserialize, Serialize};
+
+/// A transaction proposed by a validator, mainly to update on-chain configs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValidatorTransaction {
+    DKGResult(DKGTranscript),
+    ObservedJWKUpdate(QuorumCertifiedUpdate),
+}
+
+impl ValidatorTransaction {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ValidatorTransaction::DKGResult(_) => "validator_transaction__dkg_result",
+            ValidatorTransaction::ObservedJWKUpdate(_) => {
+                "validator_transaction__observed_jwk_update"
+            },
+        }
+    }
+}
@@ -0,0 +1,279 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The Aptos specific metadata the compiler attaches to the metadata section of modules and
+//! scripts, i.e. error descriptions and the `#[view]`, `#[event]` and resource group attributes.
+
+use move_binary_format::file_format::{CompiledModule, CompiledScript};
+use move_core_types::language_storage::StructTag;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The minimal file format version from which the V1 metadata is supported
+pub const METADATA_V1_MIN_FILE_FORMAT_VERSION: u32 = 6;
+
+/// The keys used to identify the metadata in the metadata section of the module bytecode.
+/// This is more or less arbitrary, besides we should use some unique key to identify
+/// Aptos specific metadata (`aptos::` here).
+pub static APTOS_METADATA_KEY: &[u8] = "aptos::metadata_v0".as_bytes();
+pub static APTOS_METADATA_KEY_V1: &[u8] = "aptos::metadata_v1".as_bytes();
+
+/// The description of an abort code, as grabbed from the doc comment of its constant
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ErrorDescription {
+    /// The constant name of the error
+    pub code_name: String,
+    /// The doc comment of the error
+    pub code_description: String,
+}
+
+/// Aptos specific metadata attached to the metadata section of file_format.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeModuleMetadata {
+    /// The error map containing the description of error reasons as grabbed from the source.
+    /// These are typically only a few entries so no relevant size difference.
+    pub error_map: BTreeMap<u64, ErrorDescription>,
+}
+
+/// V1 of Aptos specific metadata attached to the metadata section of file_format.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeModuleMetadataV1 {
+    /// The error map containing the description of error reasons as grabbed from the source.
+    /// These are typically only a few entries so no relevant size difference.
+    pub error_map: BTreeMap<u64, ErrorDescription>,
+
+    /// Attributes attached to structs.
+    pub struct_attributes: BTreeMap<String, Vec<KnownAttribute>>,
+
+    /// Attributes attached to functions, by function name.
+    pub fun_attributes: BTreeMap<String, Vec<KnownAttribute>>,
+}
+
+/// Enumeration of potentially known attributes
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KnownAttribute {
+    kind: u8,
+    args: Vec<String>,
+}
+
+/// Enumeration of known attributes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KnownAttributeKind {
+    // An older compiler placed view functions at 0. This was then published to
+    // Testnet and now we need to recognize this as a legacy index.
+    LegacyViewFunction = 0,
+    ViewFunction = 1,
+    ResourceGroup = 2,
+    ResourceGroupMember = 3,
+    Event = 4,
+    Randomness = 5,
+}
+
+impl KnownAttribute {
+    pub fn view_function() -> Self {
+        Self {
+            kind: KnownAttributeKind::ViewFunction as u8,
+            args: vec![],
+        }
+    }
+
+    pub fn is_view_function(&self) -> bool {
+        self.kind == (KnownAttributeKind::LegacyViewFunction as u8)
+            || self.kind == (KnownAttributeKind::ViewFunction as u8)
+    }
+
+    pub fn is_resource_group(&self) -> bool {
+        self.kind == KnownAttributeKind::ResourceGroup as u8
+    }
+
+    pub fn resource_group_member(container: String) -> Self {
+        Self {
+            kind: KnownAttributeKind::ResourceGroupMember as u8,
+            args: vec![container],
+        }
+    }
+
+    /// The resource group a struct is stored in, if it is a member of one
+    pub fn get_resource_group_member(&self) -> Option<StructTag> {
+        if self.kind == KnownAttributeKind::ResourceGroupMember as u8 {
+            self.args.first()?.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn is_resource_group_member(&self) -> bool {
+        self.kind == KnownAttributeKind::ResourceGroupMember as u8
+    }
+
+    pub fn event() -> Self {
+        Self {
+            kind: KnownAttributeKind::Event as u8,
+            args: vec![],
+        }
+    }
+
+    pub fn is_event(&self) -> bool {
+        self.kind == KnownAttributeKind::Event as u8
+    }
+
+    pub fn is_randomness(&self) -> bool {
+        self.kind == KnownAttributeKind::Randomness as u8
+    }
+}
+
+impl RuntimeModuleMetadata {
+    pub fn upgrade(self) -> RuntimeModuleMetadataV1 {
+        RuntimeModuleMetadataV1 {
+            error_map: self.error_map,
+            ..RuntimeModuleMetadataV1::default()
+        }
+    }
+}
+
+impl RuntimeModuleMetadataV1 {
+    pub fn is_empty(&self) -> bool {
+        self.error_map.is_empty()
+            && self.fun_attributes.is_empty()
+            && self.struct_attributes.is_empty()
+    }
+
+    /// Whether the function is marked with the `#[view]` attribute
+    pub fn fun_is_view(&self, name: &str) -> bool {
+        self.fun_attributes
+            .get(name)
+            .is_some_and(|attrs| attrs.iter().any(KnownAttribute::is_view_function))
+    }
+
+    /// Whether the struct is marked with the `#[event]` attribute
+    pub fn struct_is_event(&self, name: &str) -> bool {
+        self.struct_attributes
+            .get(name)
+            .is_some_and(|attrs| attrs.iter().any(KnownAttribute::is_event))
+    }
+}
+
+/// Extract metadata from a compiled module, upgrading V0 to V1 representation as needed.
+pub fn get_metadata_from_compiled_module(
+    module: &CompiledModule,
+) -> Option<RuntimeModuleMetadataV1> {
+    get_metadata_from_compiled_code(
+        module
+            .metadata
+            .iter()
+            .map(|md| (md.key.as_slice(), md.value.as_slice())),
+        module.version,
+    )
+}
+
+/// Extract metadata from a compiled script, upgrading V0 to V1 representation as needed.
+pub fn get_metadata_from_compiled_script(
+    script: &CompiledScript,
+) -> Option<RuntimeModuleMetadataV1> {
+    get_metadata_from_compiled_code(
+        script
+            .metadata
+            .iter()
+            .map(|md| (md.key.as_slice(), md.value.as_slice())),
+        script.version,
+    )
+}
+
+/// Extract the Aptos metadata from the `(key, value)` entries of a metadata section
+fn get_metadata_from_compiled_code<'a>(
+    entries: impl Iterator<Item = (&'a [u8], &'a [u8])> + Clone,
+    version: u32,
+) -> Option<RuntimeModuleMetadataV1> {
+    let find = |key: &[u8]| entries.clone().find(|(k, _)| *k == key).map(|(_, v)| v);
+    if let Some(data) = find(APTOS_METADATA_KEY_V1) {
+        let mut metadata = bcs::from_bytes::<RuntimeModuleMetadataV1>(data).ok();
+        // Clear out metadata for v5, since it shouldn't have existed in the first place and isn't
+        // being used. Note, this should have been gated in the verify module metadata.
+        if version == 5 {
+            if let Some(metadata) = metadata.as_mut() {
+                metadata.struct_attributes.clear();
+                metadata.fun_attributes.clear();
+            }
+        }
+        metadata
+    } else if let Some(data) = find(APTOS_METADATA_KEY) {
+        // Old format available, upgrade to new one on the fly
+        let data_v0 = bcs::from_bytes::<RuntimeModuleMetadata>(data).ok()?;
+        Some(data_v0.upgrade())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_from_code() {
+        let metadata = RuntimeModuleMetadataV1 {
+            error_map: BTreeMap::from([(
+                1,
+                ErrorDescription {
+                    code_name: "E_NOT_OWNER".into(),
+                    code_description: "The signer does not own the object".into(),
+                },
+            )]),
+            struct_attributes: BTreeMap::from([
+                ("Minted".into(), vec![KnownAttribute::event()]),
+                (
+                    "Token".into(),
+                    vec![KnownAttribute::resource_group_member(
+                        "0x1::object::ObjectGroup".into(),
+                    )],
+                ),
+            ]),
+            fun_attributes: BTreeMap::from([(
+                "balance".into(),
+                vec![KnownAttribute::view_function()],
+            )]),
+        };
+        let section: Vec<(&[u8], Vec<u8>)> = vec![
+            (b"other", vec![0xff]),
+            (APTOS_METADATA_KEY_V1, bcs::to_bytes(&metadata).unwrap()),
+        ];
+        let parsed = get_metadata_from_compiled_code(
+            section.iter().map(|(k, v)| (*k, v.as_slice())),
+            METADATA_V1_MIN_FILE_FORMAT_VERSION,
+        )
+        .unwrap();
+        assert_eq!(parsed, metadata);
+        assert!(parsed.fun_is_view("balance"));
+        assert!(!parsed.fun_is_view("mint"));
+        assert!(parsed.struct_is_event("Minted"));
+        assert!(!parsed.struct_is_event("Token"));
+        assert_eq!(
+            parsed.struct_attributes["Token"][0]
+                .get_resource_group_member()
+                .unwrap()
+                .to_string(),
+            "0x1::object::ObjectGroup"
+        );
+
+        // Attributes are ignored for version 5 modules.
+        let parsed =
+            get_metadata_from_compiled_code(section.iter().map(|(k, v)| (*k, v.as_slice())), 5)
+                .unwrap();
+        assert!(!parsed.fun_is_view("balance"));
+        assert_eq!(parsed.error_map, metadata.error_map);
+
+        let v0 = RuntimeModuleMetadata {
+            error_map: metadata.error_map.clone(),
+        };
+        let section: Vec<(&[u8], Vec<u8>)> =
+            vec![(APTOS_METADATA_KEY, bcs::to_bytes(&v0).unwrap())];
+        let parsed = get_metadata_from_compiled_code(
+            section.iter().map(|(k, v)| (*k, v.as_slice())),
+            METADATA_V1_MIN_FILE_FORMAT_VERSION,
+        )
+        .unwrap();
+        assert_eq!(parsed, v0.upgrade());
+
+        assert_eq!(get_metadata_from_compiled_code(std::iter::empty(), 6), None);
+    }
+}
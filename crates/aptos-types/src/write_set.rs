@@ -0,0 +1,287 @@
+// Copyright © Aptos Foundation
+// Parts of the project are originally copyright © Meta Platforms, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! For each transaction the VM executes, the VM will output a `WriteSet` that contains each
+//! access path it updates. For each access path, the VM can either give its new value or delete
+//! it.
+
+use crate::state_store::{
+    state_key::StateKey,
+    state_value::{StateValue, StateValueMetadata},
+};
+use anyhow::{bail, Result};
+use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::{collections::btree_map, ops::Deref};
+
+/// A write to a single state item.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum WriteOp {
+    Creation(Bytes),
+    Modification(Bytes),
+    Deletion,
+    CreationWithMetadata {
+        data: Bytes,
+        metadata: StateValueMetadata,
+    },
+    ModificationWithMetadata {
+        data: Bytes,
+        metadata: StateValueMetadata,
+    },
+    DeletionWithMetadata {
+        metadata: StateValueMetadata,
+    },
+}
+
+impl WriteOp {
+    pub fn legacy_creation(data: Bytes) -> Self {
+        Self::Creation(data)
+    }
+
+    pub fn legacy_modification(data: Bytes) -> Self {
+        Self::Modification(data)
+    }
+
+    pub fn legacy_deletion() -> Self {
+        Self::Deletion
+    }
+
+    pub fn creation(data: Bytes, metadata: StateValueMetadata) -> Self {
+        Self::CreationWithMetadata { data, metadata }
+    }
+
+    pub fn modification(data: Bytes, metadata: StateValueMetadata) -> Self {
+        Self::ModificationWithMetadata { data, metadata }
+    }
+
+    pub fn deletion(metadata: StateValueMetadata) -> Self {
+        Self::DeletionWithMetadata { metadata }
+    }
+
+    pub fn is_creation(&self) -> bool {
+        matches!(self, Self::Creation(_) | Self::CreationWithMetadata { .. })
+    }
+
+    pub fn is_modification(&self) -> bool {
+        matches!(
+            self,
+            Self::Modification(_) | Self::ModificationWithMetadata { .. }
+        )
+    }
+
+    pub fn is_deletion(&self) -> bool {
+        matches!(self, Self::Deletion | Self::DeletionWithMetadata { .. })
+    }
+
+    /// The data written, or None for deletions
+    pub fn bytes(&self) -> Option<&Bytes> {
+        match self {
+            Self::Creation(data)
+            | Self::Modification(data)
+            | Self::CreationWithMetadata { data, .. }
+            | Self::ModificationWithMetadata { data, .. } => Some(data),
+            Self::Deletion | Self::DeletionWithMetadata { .. } => None,
+        }
+    }
+
+    pub fn metadata(&self) -> Option<&StateValueMetadata> {
+        match self {
+            Self::Creation(_) | Self::Modification(_) | Self::Deletion => None,
+            Self::CreationWithMetadata { metadata, .. }
+            | Self::ModificationWithMetadata { metadata, .. }
+            | Self::DeletionWithMetadata { metadata } => Some(metadata),
+        }
+    }
+
+    /// The state value left behind by this write, or None for deletions
+    pub fn as_state_value(&self) -> Option<StateValue> {
+        let data = self.bytes()?.clone();
+        Some(match self.metadata() {
+            Some(metadata) => StateValue::new_with_metadata(data, metadata.clone()),
+            None => StateValue::new_legacy(data),
+        })
+    }
+}
+
+/// `WriteSet` contains all access paths that one transaction modifies. Each of them is a
+/// `WriteOp` where `Creation(value)`/`Modification(value)` means the access path should hold
+/// `value` afterwards and `Deletion` means it should be removed.
+#[derive(
+    BCSCryptoHash, Clone, CryptoHasher, Debug, Eq, Hash, PartialEq, Serialize, Deserialize,
+)]
+pub enum WriteSet {
+    V0(WriteSetV0),
+}
+
+impl Default for WriteSet {
+    fn default() -> Self {
+        Self::V0(WriteSetV0::default())
+    }
+}
+
+impl WriteSet {
+    pub fn into_mut(self) -> WriteSetMut {
+        match self {
+            Self::V0(write_set) => write_set.0,
+        }
+    }
+}
+
+impl Deref for WriteSet {
+    type Target = WriteSetV0;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::V0(write_set) => write_set,
+        }
+    }
+}
+
+/// The write set of a transaction. `WriteSetV0` cannot be modified; to build one, use
+/// [`WriteSetMut`] and freeze it.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WriteSetV0(WriteSetMut);
+
+impl WriteSetV0 {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&StateKey, &WriteOp)> {
+        self.0.write_set.iter()
+    }
+
+    pub fn get(&self, key: &StateKey) -> Option<&WriteOp> {
+        self.0.get(key)
+    }
+}
+
+/// A mutable version of `WriteSet`.
+///
+/// This is separate because it goes through validation before becoming an immutable `WriteSet`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WriteSetMut {
+    write_set: btree_map::BTreeMap<StateKey, WriteOp>,
+}
+
+impl WriteSetMut {
+    pub fn new(write_ops: impl IntoIterator<Item = (StateKey, WriteOp)>) -> Self {
+        Self {
+            write_set: write_ops.into_iter().collect(),
+        }
+    }
+
+    /// Add a write, failing if the key has already been written
+    pub fn try_insert(&mut self, key: StateKey, op: WriteOp) -> Result<()> {
+        match self.write_set.entry(key) {
+            btree_map::Entry::Occupied(entry) => {
+                bail!("{:?} is written more than once", entry.key())
+            },
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(op);
+                Ok(())
+            },
+        }
+    }
+
+    pub fn insert(&mut self, item: (StateKey, WriteOp)) {
+        self.write_set.insert(item.0, item.1);
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.write_set.is_empty()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.write_set.len()
+    }
+
+    pub fn get(&self, key: &StateKey) -> Option<&WriteOp> {
+        self.write_set.get(key)
+    }
+
+    pub fn as_inner_mut(&mut self) -> &mut btree_map::BTreeMap<StateKey, WriteOp> {
+        &mut self.write_set
+    }
+
+    pub fn freeze(self) -> Result<WriteSet> {
+        // TODO: add structural validation
+        Ok(WriteSet::V0(WriteSetV0(self)))
+    }
+}
+
+impl FromIterator<(StateKey, WriteOp)> for WriteSetMut {
+    fn from_iter<T: IntoIterator<Item = (StateKey, WriteOp)>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl IntoIterator for WriteSet {
+    type IntoIter = btree_map::IntoIter<StateKey, WriteOp>;
+    type Item = (StateKey, WriteOp);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_mut().write_set.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state_store::table::TableHandle;
+    use move_core_types::account_address::AccountAddress;
+
+    #[test]
+    fn test_bcs_layout() {
+        let created = StateKey::table_item(&TableHandle(AccountAddress::ONE), b"b");
+        let deleted = StateKey::raw(b"a");
+        let write_set = WriteSetMut::new([
+            (
+                created.clone(),
+                WriteOp::legacy_creation(Bytes::from_static(b"v")),
+            ),
+            (deleted.clone(), WriteOp::legacy_deletion()),
+        ])
+        .freeze()
+        .unwrap();
+
+        // Variant V0, then the items ordered by their BCS key bytes.
+        let mut expected = vec![0, 2];
+        expected.extend(bcs::to_bytes(&created).unwrap());
+        expected.extend([0, 1, b'v']);
+        expected.extend(bcs::to_bytes(&deleted).unwrap());
+        expected.push(2);
+        assert_eq!(bcs::to_bytes(&write_set).unwrap(), expected);
+        assert_eq!(bcs::from_bytes::<WriteSet>(&expected).unwrap(), write_set);
+
+        assert_eq!(
+            write_set.get(&created).unwrap().as_state_value(),
+            Some(StateValue::new_legacy(Bytes::from_static(b"v")))
+        );
+        assert!(write_set.get(&deleted).unwrap().is_deletion());
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let key = StateKey::raw(b"a");
+        let mut write_set = WriteSetMut::default();
+        write_set
+            .try_insert(key.clone(), WriteOp::legacy_deletion())
+            .unwrap();
+        assert!(write_set
+            .try_insert(key, WriteOp::legacy_deletion())
+            .is_err());
+        assert_eq!(write_set.len(), 1);
+    }
+}
@@ -66,11 +66,14 @@ impl AccountAddress {
         Self(addr)
     }
 
-    // pub fn random() -> Self {
-    //     let mut rng = OsRng;
-    //     let buf: [u8; Self::LENGTH] = rng.gen();
-    //     Self(buf)
-    // }
+    /// Creates a random address from `getrandom`. Inside a canister this requires a custom
+    /// `getrandom` backend to be registered (see `aptos_crypto::rng`).
+    #[cfg(feature = "random")]
+    pub fn random() -> Self {
+        let mut buf = [0u8; Self::LENGTH];
+        getrandom::getrandom(&mut buf).expect("getrandom should not fail");
+        Self(buf)
+    }
 
     /// Represent an account address in a way that is compliant with the v1 address
     /// standard. The standard is defined as part of AIP-40, read more here:
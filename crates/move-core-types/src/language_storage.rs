@@ -9,6 +9,7 @@
 //     safe_serialize,
 // };
 
+use anyhow::ensure;
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter},
@@ -169,6 +170,7 @@ impl StructTag {
             generics.push('<');
             generics.push_str(&first_ty.to_canonical_string());
             for ty in self.type_args.iter().skip(1) {
+                generics.push_str(", ");
                 generics.push_str(&ty.to_canonical_string())
             }
             generics.push('>');
@@ -186,8 +188,17 @@ impl StructTag {
 impl FromStr for StructTag {
     type Err = anyhow::Error;
 
+    /// Parses a struct tag, checking that it round-trips through `to_canonical_string`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse_struct_tag(s)
+        let struct_tag = parse_struct_tag(s)?;
+        let canonical = struct_tag.to_canonical_string();
+        ensure!(
+            parse_struct_tag(&canonical).ok().as_ref() == Some(&struct_tag),
+            "struct tag {} does not round-trip through its canonical form {}",
+            s,
+            canonical
+        );
+        Ok(struct_tag)
     }
 }
 
@@ -4,8 +4,10 @@
 
 use crate::{
     account_address::AccountAddress,
+    ident_str,
     identifier::{IdentStr, Identifier},
-    language_storage::{StructTag, TypeTag},
+    language_storage::{StructTag, TypeTag, CORE_CODE_ADDRESS},
+    u256::U256,
 };
 use serde::de::DeserializeOwned;
 
@@ -41,3 +43,101 @@ pub trait MoveResource: MoveStructType + DeserializeOwned {
         Self::struct_tag().access_vector()
     }
 }
+
+/// Rust types with a known Move type, so that type arguments of generic calls can be given as
+/// Rust types rather than untyped `TypeTag`s.
+pub trait MoveTypeTag {
+    fn type_tag() -> TypeTag;
+}
+
+impl<T: MoveStructType> MoveTypeTag for T {
+    fn type_tag() -> TypeTag {
+        TypeTag::Struct(Box::new(T::struct_tag()))
+    }
+}
+
+macro_rules! impl_move_type_tag {
+    ($($ty:ty => $tag:ident),* $(,)?) => {
+        $(
+            impl MoveTypeTag for $ty {
+                fn type_tag() -> TypeTag {
+                    TypeTag::$tag
+                }
+            }
+        )*
+    };
+}
+
+impl_move_type_tag!(
+    bool => Bool,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    U256 => U256,
+    AccountAddress => Address,
+);
+
+impl<T: MoveTypeTag> MoveTypeTag for Vec<T> {
+    fn type_tag() -> TypeTag {
+        TypeTag::Vector(Box::new(T::type_tag()))
+    }
+}
+
+impl MoveTypeTag for String {
+    fn type_tag() -> TypeTag {
+        TypeTag::Struct(Box::new(StructTag {
+            address: CORE_CODE_ADDRESS,
+            module: ident_str!("string").to_owned(),
+            name: ident_str!("String").to_owned(),
+            type_args: vec![],
+        }))
+    }
+}
+
+impl<T: MoveTypeTag> MoveTypeTag for Option<T> {
+    fn type_tag() -> TypeTag {
+        TypeTag::Struct(Box::new(StructTag {
+            address: CORE_CODE_ADDRESS,
+            module: ident_str!("option").to_owned(),
+            name: ident_str!("Option").to_owned(),
+            type_args: vec![T::type_tag()],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    struct Coin<T>(std::marker::PhantomData<T>);
+
+    impl<T: MoveTypeTag> MoveStructType for Coin<T> {
+        const MODULE_NAME: &'static IdentStr = ident_str!("coin");
+        const STRUCT_NAME: &'static IdentStr = ident_str!("Coin");
+
+        fn type_args() -> Vec<TypeTag> {
+            vec![T::type_tag()]
+        }
+    }
+
+    #[test]
+    fn test_move_type_tag() {
+        for (type_tag, expected) in [
+            (u64::type_tag(), "u64"),
+            (U256::type_tag(), "u256"),
+            (AccountAddress::type_tag(), "address"),
+            (Vec::<Vec<u8>>::type_tag(), "vector<vector<u8>>"),
+            (String::type_tag(), "0x1::string::String"),
+            (Option::<bool>::type_tag(), "0x1::option::Option<bool>"),
+            (
+                Coin::<Option<String>>::type_tag(),
+                "0x1::coin::Coin<0x1::option::Option<0x1::string::String>>",
+            ),
+        ] {
+            assert_eq!(type_tag, TypeTag::from_str(expected).unwrap());
+        }
+    }
+}
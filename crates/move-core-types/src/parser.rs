@@ -103,8 +103,19 @@ fn next_number(initial: char, mut it: impl Iterator<Item = char>) -> Result<(Tok
     }
 }
 
+/// Addresses in the output of `to_canonical_string` are the full hex of the address without a
+/// `0x` prefix, and are always followed by `::`. Module names can look the same, so this only
+/// applies where an address is expected, i.e. not right after `::`.
+fn is_canonical_address(s: &str) -> bool {
+    let len = AccountAddress::LENGTH * 2;
+    let bytes = s.as_bytes();
+    bytes.len() > len
+        && bytes[..len].iter().all(u8::is_ascii_hexdigit)
+        && bytes[len..].starts_with(b"::")
+}
+
 #[allow(clippy::many_single_char_names)]
-fn next_token(s: &str) -> Result<Option<(Token, usize)>> {
+fn next_token(s: &str, address_expected: bool) -> Result<Option<(Token, usize)>> {
     let mut it = s.chars().peekable();
     match it.next() {
         None => Ok(None),
@@ -137,6 +148,10 @@ fn next_token(s: &str) -> Result<Option<(Token, usize)>> {
                     _ => bail!("unrecognized token"),
                 }
             }
+            _ if address_expected && is_canonical_address(s) => {
+                let len = AccountAddress::LENGTH * 2;
+                (Token::Address(format!("0x{}", &s[..len])), len)
+            }
             c if c.is_ascii_digit() => next_number(c, it)?,
             'b' if it.peek() == Some(&'"') => {
                 it.next().unwrap();
@@ -196,8 +211,15 @@ fn next_token(s: &str) -> Result<Option<(Token, usize)>> {
 }
 
 fn tokenize(mut s: &str) -> Result<Vec<Token>> {
-    let mut v = vec![];
-    while let Some((tok, n)) = next_token(s)? {
+    let mut v: Vec<Token> = vec![];
+    loop {
+        let address_expected = !matches!(
+            v.iter().rev().find(|tok| !tok.is_whitespace()),
+            Some(Token::ColonColon)
+        );
+        let Some((tok, n)) = next_token(s, address_expected)? else {
+            break;
+        };
         v.push(tok);
         s = &s[n..];
     }
@@ -425,6 +447,7 @@ mod tests {
 
     use crate::{
         account_address::AccountAddress,
+        language_storage::StructTag,
         parser::{parse_struct_tag, parse_transaction_argument, parse_type_tag},
         transaction_argument::TransactionArgument,
         u256,
@@ -608,7 +631,7 @@ mod tests {
 
     #[test]
     fn test_parse_valid_struct_tag() {
-        let valid = vec![
+        let valid = [
             "0x1::Diem::Diem",
             "0x1::Diem_Type::Diem",
             "0x1::Diem_::Diem",
@@ -649,6 +672,18 @@ mod tests {
             );
         }
 
+        for text in valid {
+            let st = parse_struct_tag(text).expect("valid StructTag");
+            let canonical = st.to_canonical_string();
+            assert_eq!(StructTag::from_str(text).unwrap(), st);
+            assert_eq!(
+                StructTag::from_str(&canonical).expect("valid canonical StructTag"),
+                st,
+                "canonical: {:?}",
+                canonical
+            );
+        }
+
         let s = "0x1::Diem::Diem<vector<0x1::XDX::XDX<vector<vector<vector<0x1::XDX::XDX<vector<vector<u64>>>>>>>>>";
         assert!(
             parse_struct_tag(s).is_err(),
@@ -656,4 +691,27 @@ mod tests {
             s
         );
     }
+
+    #[test]
+    fn test_canonical_struct_tag() {
+        let st = parse_struct_tag(
+            "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin, u8, vector<address>>",
+        )
+        .unwrap();
+        let canonical = st.to_canonical_string();
+        assert_eq!(
+            canonical,
+            format!(
+                "{one}::coin::CoinStore<{one}::aptos_coin::AptosCoin, u8, vector<address>>",
+                one = AccountAddress::ONE.to_canonical_string()
+            )
+        );
+        assert_eq!(parse_struct_tag(&canonical).unwrap(), st);
+
+        // A module name made of 64 hex letters is not mistaken for a canonical address.
+        let module = "a".repeat(AccountAddress::LENGTH * 2);
+        let st = parse_struct_tag(&format!("0x1::{}::S", module)).unwrap();
+        assert_eq!(st.module.as_str(), module);
+        assert_eq!(parse_struct_tag(&st.to_canonical_string()).unwrap(), st);
+    }
 }